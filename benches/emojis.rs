@@ -0,0 +1,118 @@
+//! Benchmarks for the lookup and text scanning functions.
+//!
+//! # Usage
+//!
+//! ```sh
+//! $ cargo bench --features alloc
+//! ```
+
+use std::mem;
+use std::ptr;
+use std::time::{Duration, Instant};
+
+use emojis::{EmojiSet, Filter, FilterPolicy, Group, Replaced};
+
+/// Prevents the compiler from optimizing away the value.
+///
+/// `std::hint::black_box` is not available on the MSRV.
+fn black_box<T>(x: T) -> T {
+    // SAFETY: the value is read from a valid reference and the original is
+    // forgotten so it is not dropped twice.
+    unsafe {
+        let y = ptr::read_volatile(&x);
+        mem::forget(x);
+        y
+    }
+}
+
+/// Runs the function repeatedly for about a second and prints the mean time
+/// per iteration, and the throughput if the number of bytes is given.
+fn bench<F, R>(name: &str, bytes: Option<usize>, mut f: F)
+where
+    F: FnMut() -> R,
+{
+    let mut iters = 1;
+    let elapsed = loop {
+        let start = Instant::now();
+        for _ in 0..iters {
+            black_box(f());
+        }
+        let elapsed = start.elapsed();
+        if elapsed > Duration::from_millis(1000) {
+            break elapsed;
+        }
+        iters *= 2;
+    };
+    let per_iter = elapsed.as_nanos() as f64 / iters as f64;
+    match bytes {
+        Some(n) => {
+            let gbps = n as f64 / per_iter;
+            println!("{name:<40} {per_iter:>12.1} ns/iter {gbps:>8.3} GB/s");
+        }
+        None => println!("{name:<40} {per_iter:>12.1} ns/iter"),
+    }
+}
+
+/// Returns mostly ASCII text with the occasional emoji, like a log file.
+fn log_text() -> String {
+    let mut text = String::new();
+    for i in 0..10_000 {
+        text.push_str("2023-01-01T00:00:00Z INFO request handled in 12ms status=200 ");
+        if i % 100 == 0 {
+            text.push_str("🚀 :rocket: ");
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// Returns chat text with many emojis and shortcodes.
+fn chat_text() -> String {
+    let mut text = String::new();
+    for _ in 0..1_000 {
+        text.push_str("nice work 👍🏽 :tada: the launch 🚀 went well 🇿🇦 1️⃣ ");
+    }
+    text
+}
+
+fn main() {
+    let emojis: Vec<_> = emojis::iter().map(|e| e.as_str()).collect();
+    let shortcodes: Vec<_> = emojis::iter().filter_map(|e| e.shortcode()).collect();
+
+    bench("get", None, || {
+        emojis
+            .iter()
+            .filter_map(|s| emojis::get(black_box(s)))
+            .count()
+            / emojis.len()
+    });
+    bench("get_by_shortcode", None, || {
+        shortcodes
+            .iter()
+            .filter_map(|s| emojis::get_by_shortcode(black_box(s)))
+            .count()
+            / shortcodes.len()
+    });
+    bench("get_char", None, || emojis::get_char(black_box('🚀')));
+
+    for (name, text) in [("log", log_text()), ("chat", chat_text())] {
+        let n = Some(text.len());
+        bench(&format!("find_in/{name}"), n, || {
+            emojis::pattern().find_in(black_box(&text)).count()
+        });
+        bench(&format!("strip_groups/{name}"), n, || {
+            emojis::strip_groups(black_box(&text), &[Group::Flags])
+        });
+        let set: EmojiSet = emojis::get("🚀").into_iter().collect();
+        let filter = Filter::new(set, FilterPolicy::ReplaceWithShortcode);
+        bench(&format!("filter/{name}"), n, || {
+            filter.apply(black_box(&text))
+        });
+        bench(&format!("expand_shortcodes/{name}"), n, || {
+            Replaced::expand_shortcodes(black_box(&text)).to_string()
+        });
+        bench(&format!("replace_shortcodes_markdown/{name}"), n, || {
+            emojis::replace_shortcodes_markdown(black_box(&text))
+        });
+    }
+}
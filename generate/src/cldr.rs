@@ -0,0 +1,88 @@
+//! Fetch and parse emoji annotations from Unicode CLDR.
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use serde::Deserialize;
+
+const URLS: &[&str] = &[
+    "https://raw.githubusercontent.com/unicode-org/cldr-json/42.0.0/cldr-json/cldr-annotations-full/annotations/{locale}/annotations.json",
+    "https://raw.githubusercontent.com/unicode-org/cldr-json/42.0.0/cldr-json/cldr-annotations-derived-full/annotationsDerived/{locale}/annotations.json",
+];
+
+/// The locales other than English to generate keyword indexes for.
+pub const LOCALES: &[&str] = &["de", "es", "fr", "ja", "pt", "zh"];
+
+#[derive(Debug, Deserialize)]
+struct File {
+    #[serde(alias = "annotationsDerived")]
+    annotations: Annotations,
+}
+
+#[derive(Debug, Deserialize)]
+struct Annotations {
+    annotations: HashMap<String, Annotation>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Annotation {
+    #[serde(default)]
+    default: Vec<String>,
+    #[serde(default)]
+    tts: Vec<String>,
+}
+
+/// Annotations keyed by emoji with any emoji variation selectors removed.
+pub type ParsedData = HashMap<String, Annotation>;
+
+fn fetch(url: &str) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    let mut easy = curl::easy::Easy::new();
+    easy.fail_on_error(true)?;
+    easy.follow_location(true)?;
+    easy.url(url)?;
+    {
+        let mut transfer = easy.transfer();
+        transfer.write_function(|data| {
+            buf.extend_from_slice(data);
+            Ok(data.len())
+        })?;
+        transfer.perform()?;
+    }
+    Ok(buf)
+}
+
+pub fn fetch_and_parse_annotations(locale: &str) -> Result<ParsedData> {
+    let mut parsed_data = ParsedData::new();
+    for url in URLS {
+        let url = url.replace("{locale}", locale);
+        let file: File = serde_json::from_slice(&fetch(&url)?)?;
+        parsed_data.extend(file.annotations.annotations);
+    }
+    Ok(parsed_data)
+}
+
+impl Annotation {
+    pub fn keywords(&self) -> impl Iterator<Item = &str> {
+        self.default.iter().map(String::as_str)
+    }
+
+    /// Returns the text-to-speech name and the keywords, normalized to
+    /// lowercase.
+    pub fn localized_keywords(&self) -> impl Iterator<Item = String> + '_ {
+        self.tts
+            .iter()
+            .chain(&self.default)
+            .map(|w| w.trim().to_lowercase())
+            .filter(|w| !w.is_empty())
+    }
+
+    pub fn tts(&self) -> Option<&str> {
+        self.tts.first().map(String::as_str)
+    }
+}
+
+/// Returns the key used for the emoji in the CLDR annotations.
+pub fn key(emoji: &str) -> String {
+    emoji.replace('\u{fe0f}', "")
+}
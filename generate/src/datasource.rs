@@ -0,0 +1,90 @@
+//! Fetch and parse sprite sheet positions from emoji-datasource.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+const URL: &str = "https://raw.githubusercontent.com/iamcal/emoji-data/v15.0.1/emoji.json";
+
+#[derive(Debug, Deserialize)]
+struct Emoji {
+    unified: String,
+    non_qualified: Option<String>,
+    sheet_x: u8,
+    sheet_y: u8,
+    #[serde(default)]
+    skin_variations: HashMap<String, SkinVariation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SkinVariation {
+    unified: String,
+    non_qualified: Option<String>,
+    sheet_x: u8,
+    sheet_y: u8,
+}
+
+/// Sprite sheet positions `(x, y)` keyed by emoji.
+pub type ParsedData = HashMap<String, (u8, u8)>;
+
+fn parse_unified(unified: &str) -> Result<String> {
+    unified
+        .split('-')
+        .map(|cp| {
+            let scalar = u32::from_str_radix(cp, 16).context("not hex")?;
+            std::char::from_u32(scalar).context("not Unicode scalar value")
+        })
+        .collect()
+}
+
+fn insert(
+    parsed_data: &mut ParsedData,
+    unified: &str,
+    non_qualified: Option<&str>,
+    position: (u8, u8),
+) -> Result<()> {
+    parsed_data.insert(parse_unified(unified)?, position);
+    if let Some(non_qualified) = non_qualified {
+        parsed_data
+            .entry(parse_unified(non_qualified)?)
+            .or_insert(position);
+    }
+    Ok(())
+}
+
+pub fn fetch_and_parse_sprite_data() -> Result<ParsedData> {
+    let mut buf = Vec::new();
+    let mut easy = curl::easy::Easy::new();
+    easy.fail_on_error(true)?;
+    easy.follow_location(true)?;
+    easy.url(URL)?;
+    {
+        let mut transfer = easy.transfer();
+        transfer.write_function(|data| {
+            buf.extend_from_slice(data);
+            Ok(data.len())
+        })?;
+        transfer.perform()?;
+    }
+
+    let emojis: Vec<Emoji> = serde_json::from_slice(&buf)?;
+    let mut parsed_data = ParsedData::new();
+    for emoji in &emojis {
+        insert(
+            &mut parsed_data,
+            &emoji.unified,
+            emoji.non_qualified.as_deref(),
+            (emoji.sheet_x, emoji.sheet_y),
+        )?;
+        for v in emoji.skin_variations.values() {
+            insert(
+                &mut parsed_data,
+                &v.unified,
+                v.non_qualified.as_deref(),
+                (v.sheet_x, v.sheet_y),
+            )?;
+        }
+    }
+    Ok(parsed_data)
+}
@@ -0,0 +1,82 @@
+//! A simple LZ77 compressor for the tables stored with the `compressed-data`
+//! feature.
+//!
+//! The output is a sequence of tokens that is decoded by `src/lz.rs`:
+//!
+//! - A control byte `c < 0x80` followed by `c + 1` literal bytes.
+//! - A control byte `c >= 0x80` followed by a little endian `u16` distance,
+//!   which copies `(c & 0x7f) + MIN_MATCH` bytes starting `distance` bytes
+//!   before the end of the output so far.
+
+use std::collections::HashMap;
+
+const MIN_MATCH: usize = 4;
+const MAX_MATCH: usize = 0x7f + MIN_MATCH;
+const MAX_LITERALS: usize = 0x80;
+const WINDOW: usize = u16::MAX as usize;
+
+/// The number of previous positions to try when looking for a match.
+const MAX_CANDIDATES: usize = 256;
+
+type Key = [u8; MIN_MATCH];
+
+pub fn compress(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut literals = Vec::new();
+    let mut positions: HashMap<Key, Vec<usize>> = HashMap::new();
+    let key = |i: usize| -> Option<Key> { data.get(i..i + MIN_MATCH)?.try_into().ok() };
+    let insert = |positions: &mut HashMap<Key, Vec<usize>>, i| {
+        if let Some(k) = key(i) {
+            positions.entry(k).or_default().push(i);
+        }
+    };
+
+    let mut i = 0;
+    while i < data.len() {
+        let (len, distance) = key(i)
+            .and_then(|k| positions.get(&k))
+            .map_or((0, 0), |candidates| longest_match(data, i, candidates));
+        if len >= MIN_MATCH {
+            flush(&mut out, &mut literals);
+            out.push(0x80 | (len - MIN_MATCH) as u8);
+            out.extend((distance as u16).to_le_bytes());
+            for j in i..i + len {
+                insert(&mut positions, j);
+            }
+            i += len;
+        } else {
+            literals.push(data[i]);
+            if literals.len() == MAX_LITERALS {
+                flush(&mut out, &mut literals);
+            }
+            insert(&mut positions, i);
+            i += 1;
+        }
+    }
+    flush(&mut out, &mut literals);
+    out
+}
+
+/// Returns the length and distance of the longest match for the data at `i`,
+/// preferring the closest match if there are several.
+fn longest_match(data: &[u8], i: usize, candidates: &[usize]) -> (usize, usize) {
+    let max = MAX_MATCH.min(data.len() - i);
+    let mut best = (0, 0);
+    for &j in candidates.iter().rev().take(MAX_CANDIDATES) {
+        if i - j > WINDOW {
+            break;
+        }
+        let len = (0..max).take_while(|&k| data[j + k] == data[i + k]).count();
+        if len > best.0 {
+            best = (len, i - j);
+        }
+    }
+    best
+}
+
+fn flush(out: &mut Vec<u8>, literals: &mut Vec<u8>) {
+    if !literals.is_empty() {
+        out.push((literals.len() - 1) as u8);
+        out.append(literals);
+    }
+}
@@ -1,13 +1,18 @@
+mod cldr;
+mod datasource;
 mod github;
+mod lz;
 mod unicode;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
 use std::io;
 use std::io::Write as _;
-use std::path::PathBuf;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use heck::CamelCase;
 
 use crate::unicode::SkinTone;
 
@@ -19,6 +24,7 @@ fn write_group_enum<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData)
         w,
         "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]"
     )?;
+    writeln!(w, "#[non_exhaustive]")?;
     writeln!(w, "pub enum Group {{")?;
     for name in unicode_data.keys() {
         if name == "Component" {
@@ -32,9 +38,9 @@ fn write_group_enum<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData)
 
 fn write_emoji_struct<W: io::Write>(
     w: &mut W,
-    github_data: &github::ParsedData,
     group: &str,
     emoji: &unicode::Emoji,
+    index: usize,
     default_skin_tone_index: usize,
 ) -> Result<()> {
     let e = emoji.as_str();
@@ -51,10 +57,94 @@ fn write_emoji_struct<W: io::Write>(
         )?,
         None => write!(w, ", skin_tone: None")?,
     }
-    match &github_data.get(e) {
-        Some(github) => write!(w, ", aliases: Some(&{:?}) }}", github.aliases())?,
-        None => write!(w, ", aliases: None }}")?,
+    write!(w, ", index: {index} }}")?;
+    Ok(())
+}
+
+/// Indexes into the generated tables, collected while writing the emojis.
+#[derive(Default)]
+struct Tables {
+    emojis: Vec<String>,
+    unicode_map: HashMap<String, String>,
+    shortcode_map: HashMap<String, String>,
+    group_ranges: Vec<(String, Range<usize>)>,
+    subgroup_ranges: Vec<(String, String, Range<usize>)>,
+    default_indexes: Vec<usize>,
+    tts: Vec<(usize, String)>,
+    sprites: Vec<(usize, (u8, u8))>,
+    lines: Vec<String>,
+    shortcodes: Vec<Vec<String>>,
+    keywords: BTreeMap<String, BTreeSet<usize>>,
+    colors: Vec<(usize, &'static str)>,
+}
+
+/// Words that are not useful as keywords.
+const STOP_WORDS: &[&str] = &["a", "and", "in", "of", "on", "the", "with"];
+
+/// Returns the keywords for an emoji, i.e. the words in its name and its CLDR
+/// keywords, normalized to lowercase.
+fn keywords<'a>(
+    name: &'a str,
+    annotation: Option<&'a cldr::Annotation>,
+) -> impl Iterator<Item = String> + 'a {
+    name.split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+        .chain(annotation.into_iter().flat_map(cldr::Annotation::keywords))
+        .map(str::to_lowercase)
+        .filter(|w| !w.is_empty() && !STOP_WORDS.contains(&w.as_str()))
+}
+
+/// Color words in emoji names and the corresponding `Color` variant.
+const COLORS: &[(&str, &str)] = &[
+    ("red", "Red"),
+    ("orange", "Orange"),
+    ("yellow", "Yellow"),
+    ("green", "Green"),
+    ("blue", "Blue"),
+    ("purple", "Purple"),
+    ("pink", "Pink"),
+    ("brown", "Brown"),
+    ("black", "Black"),
+    ("grey", "Grey"),
+    ("white", "White"),
+];
+
+/// Returns the dominant color of an emoji, i.e. the first color named in its
+/// name.
+///
+/// Colors in the names of people describe a feature, e.g. "person: red
+/// hair", rather than the whole emoji so those are skipped.
+fn color(group: &str, name: &str) -> Option<&'static str> {
+    if group == "PeopleAndBody" {
+        return None;
+    }
+    name.split(|c: char| !c.is_ascii_alphabetic())
+        .find_map(|w| COLORS.iter().find(|(c, _)| *c == w).map(|(_, v)| *v))
+}
+
+fn subgroup_name(subgroup: &str) -> String {
+    subgroup.replace('&', "And").to_camel_case()
+}
+
+fn write_subgroup_enum<W: io::Write>(w: &mut W, unicode_data: &unicode::ParsedData) -> Result<()> {
+    writeln!(w, "/// A subcategory for an emoji.")?;
+    writeln!(w, "///")?;
+    writeln!(w, "/// Based on Unicode CLDR data.")?;
+    writeln!(
+        w,
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]"
+    )?;
+    writeln!(w, "#[non_exhaustive]")?;
+    writeln!(w, "pub enum Subgroup {{")?;
+    for (group, subgroups) in unicode_data {
+        if group == "Component" {
+            continue;
+        }
+        for subgroup in subgroups.keys() {
+            writeln!(w, "   {},", subgroup_name(subgroup))?;
+        }
     }
+    writeln!(w, "}}")?;
     Ok(())
 }
 
@@ -62,43 +152,350 @@ fn write_emojis_slice<W: io::Write>(
     w: &mut W,
     unicode_data: &unicode::ParsedData,
     github_data: &github::ParsedData,
-    unicode_map: &mut HashMap<String, String>,
-    shortcode_map: &mut HashMap<String, String>,
+    cldr_data: &cldr::ParsedData,
+    sprite_data: &datasource::ParsedData,
+    tables: &mut Tables,
 ) -> Result<()> {
     let mut i = 0;
     let mut default_skin_tone_index = 0;
 
     writeln!(w, "pub const EMOJIS: &[Emoji] = &[")?;
     for (group, subgroups) in unicode_data {
-        for subgroup in subgroups.values() {
-            for emoji in subgroup {
+        let start = i;
+        for (subgroup, emojis) in subgroups {
+            let subgroup_start = tables.default_indexes.len();
+            for emoji in emojis {
                 if matches!(emoji.skin_tone(), Some(SkinTone::Default)) {
                     default_skin_tone_index = i;
                 }
+                if matches!(emoji.skin_tone(), Some(SkinTone::Default) | None) {
+                    tables.default_indexes.push(i);
+                    let annotation = cldr_data.get(&cldr::key(emoji.as_str()));
+                    for keyword in keywords(emoji.name(), annotation) {
+                        tables.keywords.entry(keyword).or_default().insert(i);
+                    }
+                    if let Some(color) = color(group, emoji.name()) {
+                        tables.colors.push((i, color));
+                    }
+                }
                 write!(w, "    ")?;
-                write_emoji_struct(w, github_data, group, emoji, default_skin_tone_index)?;
+                write_emoji_struct(w, group, emoji, i, default_skin_tone_index)?;
                 writeln!(w, ",")?;
 
-                unicode_map.insert(emoji.as_str().to_owned(), i.to_string());
+                tables.emojis.push(emoji.as_str().to_owned());
+                tables.lines.push(emoji.line().to_owned());
+                tables
+                    .unicode_map
+                    .insert(emoji.as_str().to_owned(), i.to_string());
                 for v in emoji.variations() {
-                    assert!(unicode_map.insert(v.to_owned(), i.to_string()).is_none());
+                    assert!(tables
+                        .unicode_map
+                        .insert(v.to_owned(), i.to_string())
+                        .is_none());
+                }
+
+                if let Some(tts) = cldr_data
+                    .get(&cldr::key(emoji.as_str()))
+                    .and_then(cldr::Annotation::tts)
+                {
+                    if tts != emoji.name() {
+                        tables.tts.push((i, tts.to_owned()));
+                    }
                 }
 
+                if let Some(&position) = sprite_data.get(emoji.as_str()) {
+                    tables.sprites.push((i, position));
+                }
+
+                let mut shortcodes = Vec::new();
                 if let Some(github) = &github_data.get(emoji.as_str()) {
                     for alias in github.aliases() {
-                        assert!(shortcode_map
+                        assert!(tables
+                            .shortcode_map
                             .insert(alias.to_owned(), i.to_string())
                             .is_none());
+                        shortcodes.push(alias.to_owned());
                     }
                 }
+                tables.shortcodes.push(shortcodes);
                 i += 1;
             }
+            tables.subgroup_ranges.push((
+                group.clone(),
+                subgroup_name(subgroup),
+                subgroup_start..tables.default_indexes.len(),
+            ));
+        }
+        tables.group_ranges.push((group.clone(), start..i));
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_default_emojis_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    writeln!(w, "pub const DEFAULT_EMOJIS: &[&Emoji] = &[")?;
+    for i in &tables.default_indexes {
+        writeln!(w, "    &EMOJIS[{i}],")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_shortcodes_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    writeln!(w, "#[cfg(feature = \"shortcodes\")]")?;
+    writeln!(w, "pub const SHORTCODES: &[&[&str]] = &[")?;
+    for shortcodes in &tables.shortcodes {
+        writeln!(w, "    &{shortcodes:?},")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_tts_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    writeln!(w, "pub const TTS: &[(usize, &str)] = &[")?;
+    for (i, tts) in &tables.tts {
+        writeln!(w, "    ({i}, {tts:?}),")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_keywords_slice<W: io::Write>(
+    w: &mut W,
+    name: &str,
+    keywords: &BTreeMap<String, BTreeSet<usize>>,
+) -> Result<()> {
+    writeln!(w, "pub const {name}: &[(&str, &[&Emoji])] = &[")?;
+    for (keyword, indexes) in keywords {
+        write!(w, "    ({keyword:?}, &[")?;
+        for (j, i) in indexes.iter().enumerate() {
+            if j > 0 {
+                write!(w, ", ")?;
+            }
+            write!(w, "&EMOJIS[{i}]")?;
+        }
+        writeln!(w, "]),")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_chars_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    let mut chars: Vec<(char, &str)> = tables
+        .unicode_map
+        .iter()
+        .filter_map(|(key, i)| {
+            let mut chars = key.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some((c, i.as_str())),
+                _ => None,
+            }
+        })
+        .collect();
+    chars.sort();
+    writeln!(w, "pub const CHARS: &[(char, u16)] = &[")?;
+    for (c, i) in chars {
+        writeln!(w, "    ('\\u{{{:x}}}', {i}),", c as u32)?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_first_chars_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    let firsts: BTreeSet<char> = tables
+        .unicode_map
+        .keys()
+        .filter_map(|key| key.chars().next())
+        .collect();
+    write_char_ranges_slice(w, "FIRST_CHARS", firsts)
+}
+
+fn write_all_chars_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    let chars: BTreeSet<char> = tables
+        .unicode_map
+        .keys()
+        .flat_map(|key| key.chars())
+        .collect();
+    write_char_ranges_slice(w, "ALL_CHARS", chars)
+}
+
+fn write_char_ranges_slice<W: io::Write>(
+    w: &mut W,
+    name: &str,
+    chars: BTreeSet<char>,
+) -> Result<()> {
+    let mut ranges: Vec<(char, char)> = Vec::new();
+    for c in chars {
+        match ranges.last_mut() {
+            Some((_, end)) if *end as u32 + 1 == c as u32 => *end = c,
+            _ => ranges.push((c, c)),
+        }
+    }
+    writeln!(w, "pub const {name}: &[(char, char)] = &[")?;
+    for (start, end) in ranges {
+        writeln!(
+            w,
+            "    ('\\u{{{:x}}}', '\\u{{{:x}}}'),",
+            start as u32, end as u32
+        )?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_sprites_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    writeln!(w, "#[cfg(feature = \"sprite-sheet\")]")?;
+    writeln!(w, "pub const SPRITES: &[(usize, u8, u8)] = &[")?;
+    for (i, (x, y)) in &tables.sprites {
+        writeln!(w, "    ({i}, {x}, {y}),")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+/// Writes the source lines as a slice, and compressed to `source_lines.lz` for
+/// the `compressed-data` feature.
+fn write_source_lines_slice<W: io::Write>(w: &mut W, dir: &Path, tables: &Tables) -> Result<()> {
+    writeln!(
+        w,
+        "#[cfg(all(feature = \"source-lines\", not(all(feature = \"compressed-data\", target_has_atomic = \"ptr\"))))]"
+    )?;
+    writeln!(w, "pub const SOURCE_LINES: &[&str] = &[")?;
+    for line in &tables.lines {
+        writeln!(w, "    \"{line}\",")?;
+    }
+    writeln!(w, "];")?;
+    writeln!(w)?;
+
+    let data = tables.lines.join("\n");
+    fs::write(dir.join("source_lines.lz"), lz::compress(data.as_bytes()))?;
+    writeln!(
+        w,
+        "#[cfg(all(feature = \"source-lines\", feature = \"compressed-data\", target_has_atomic = \"ptr\"))]"
+    )?;
+    writeln!(
+        w,
+        "pub const SOURCE_LINES_LZ: &[u8] = include_bytes!(\"source_lines.lz\");"
+    )?;
+    writeln!(
+        w,
+        "#[cfg(all(feature = \"source-lines\", feature = \"compressed-data\", target_has_atomic = \"ptr\"))]"
+    )?;
+    writeln!(w, "pub const SOURCE_LINES_LEN: usize = {};", data.len())?;
+    Ok(())
+}
+
+/// Writes the descriptions in `descriptions.tsv` ordered by emoji index.
+fn write_descriptions_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "descriptions.tsv"]
+        .iter()
+        .collect();
+    let contents = fs::read_to_string(path)?;
+    let mut descriptions = BTreeMap::new();
+    for line in contents.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (emoji, description) = line.split_once('\t').context("no tab")?;
+        let i = tables
+            .emojis
+            .iter()
+            .position(|e| e == emoji)
+            .with_context(|| format!("unknown emoji `{emoji}`"))?;
+        descriptions.insert(i, description);
+    }
+    writeln!(w, "#[cfg(feature = \"descriptions\")]")?;
+    writeln!(w, "pub const DESCRIPTIONS: &[(usize, &str)] = &[")?;
+    for (i, description) in descriptions {
+        writeln!(w, "    ({i}, {description:?}),")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_colors_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    writeln!(w, "#[cfg(feature = \"colors\")]")?;
+    writeln!(w, "pub const COLORS: &[(usize, crate::Color)] = &[")?;
+    for (i, color) in &tables.colors {
+        writeln!(w, "    ({i}, crate::Color::{color}),")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_subgroups_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    writeln!(w, "pub const SUBGROUPS: &[Subgroup] = &[")?;
+    for (_, subgroup, _) in &tables.subgroup_ranges {
+        writeln!(w, "    Subgroup::{subgroup},")?;
+    }
+    writeln!(w, "];")?;
+    Ok(())
+}
+
+fn write_layout_slice<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    writeln!(w, "pub const LAYOUT: &[LayoutGroup] = &[")?;
+    let mut subgroups = tables.subgroup_ranges.iter().peekable();
+    for (group, _) in &tables.group_ranges {
+        writeln!(w, "    LayoutGroup {{ group: Group::{group}, subgroups: &[")?;
+        while let Some((_, subgroup, range)) = subgroups.next_if(|(g, _, _)| g == group) {
+            write!(
+                w,
+                "        LayoutSubgroup {{ subgroup: Subgroup::{subgroup}, emojis: &["
+            )?;
+            for (j, i) in tables.default_indexes[range.clone()].iter().enumerate() {
+                if j > 0 {
+                    write!(w, ", ")?;
+                }
+                write!(w, "&EMOJIS[{i}]")?;
+            }
+            writeln!(w, "] }},")?;
         }
+        writeln!(w, "    ] }},")?;
     }
     writeln!(w, "];")?;
     Ok(())
 }
 
+fn write_group_impl<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    writeln!(w, "impl Group {{")?;
+    writeln!(
+        w,
+        "    pub(crate) const fn range(self) -> core::ops::Range<usize> {{"
+    )?;
+    writeln!(w, "        match self {{")?;
+    for (group, range) in &tables.group_ranges {
+        writeln!(w, "            Self::{group} => {range:?},")?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+fn write_subgroup_impl<W: io::Write>(w: &mut W, tables: &Tables) -> Result<()> {
+    writeln!(w, "impl Subgroup {{")?;
+    writeln!(w, "    pub(crate) const fn parent(self) -> Group {{")?;
+    writeln!(w, "        match self {{")?;
+    for (group, subgroup, _) in &tables.subgroup_ranges {
+        writeln!(w, "            Self::{subgroup} => Group::{group},")?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    pub(crate) const fn range(self) -> core::ops::Range<usize> {{"
+    )?;
+    writeln!(w, "        match self {{")?;
+    for (_, subgroup, range) in &tables.subgroup_ranges {
+        writeln!(w, "            Self::{subgroup} => {range:?},")?;
+    }
+    writeln!(w, "        }}")?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
 fn write_phf_map<W: io::Write>(w: &mut W, map: HashMap<String, String>) -> Result<()> {
     write!(w, "pub static MAP: phf::Map<&'static str, usize> = ")?;
     let mut gen = phf_codegen::Map::new();
@@ -106,6 +503,74 @@ fn write_phf_map<W: io::Write>(w: &mut W, map: HashMap<String, String>) -> Resul
         gen.entry(key, value);
     }
     writeln!(w, "{};", gen.build())?;
+
+    writeln!(w)?;
+    writeln!(w, "#[cfg(feature = \"phf-map\")]")?;
+    write!(
+        w,
+        "pub static EMOJI_MAP: phf::Map<&'static str, &'static crate::Emoji> = "
+    )?;
+    let values: Vec<_> = map
+        .values()
+        .map(|i| format!("&crate::gen::EMOJIS[{i}]"))
+        .collect();
+    let mut gen = phf_codegen::Map::new();
+    for (key, value) in map.keys().zip(&values) {
+        gen.entry(key, value);
+    }
+    writeln!(w, "{};", gen.build())?;
+    Ok(())
+}
+
+/// Returns the fingerprint of the emojis, matching
+/// `emojis::dataset_fingerprint()`.
+fn fingerprint(emojis: &[String]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in emojis.iter().flat_map(|e| e.bytes().chain([0xff])) {
+        hash = (hash ^ u64::from(byte)).wrapping_mul(0x100_0000_01b3);
+    }
+    hash
+}
+
+/// Writes the tables mapping the ids of every previous dataset to the
+/// current ones.
+///
+/// Each dataset is recorded in the `datasets` directory as a file named after
+/// its fingerprint containing one emoji per line in id order. The current
+/// dataset is added if it is not already recorded.
+fn write_migrations<W: io::Write>(w: &mut W, emojis: &[String]) -> Result<()> {
+    let dir: PathBuf = [env!("CARGO_MANIFEST_DIR"), "datasets"].iter().collect();
+    let current = fingerprint(emojis);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{current:016x}.txt"));
+    if !path.exists() {
+        fs::write(&path, emojis.join("\n") + "\n")?;
+    }
+
+    let ids: HashMap<_, _> = emojis
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (e.as_str(), i + 1))
+        .collect();
+    let mut paths: Vec<_> = fs::read_dir(&dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<io::Result<_>>()?;
+    paths.sort();
+
+    writeln!(w, "pub const MIGRATIONS: &[(u64, &[u16])] = &[")?;
+    for path in paths {
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap();
+        let old = u64::from_str_radix(stem, 16)?;
+        if old == current {
+            continue;
+        }
+        let new_ids: Vec<_> = fs::read_to_string(&path)?
+            .lines()
+            .map(|e| ids.get(e).copied().unwrap_or(0).to_string())
+            .collect();
+        writeln!(w, "    (0x{old:016x}, &[{}]),", new_ids.join(", "))?;
+    }
+    writeln!(w, "];")?;
     Ok(())
 }
 
@@ -116,33 +581,91 @@ fn main() -> Result<()> {
 
     let unicode_data = unicode::fetch_and_parse_emoji_data()?;
     let github_data = github::fetch_and_parse_emoji_data()?;
-    let mut unicode_map = HashMap::new();
-    let mut shortcode_map = HashMap::new();
+    let cldr_data = cldr::fetch_and_parse_annotations("en")?;
+    let sprite_data = datasource::fetch_and_parse_sprite_data()?;
+    let mut tables = Tables::default();
 
     fs::remove_dir_all(&dir).ok();
     fs::create_dir_all(&dir)?;
 
     let mut f = fs::File::create(dir.join("mod.rs"))?;
     writeln!(f, "#![cfg_attr(rustfmt, rustfmt::skip)]\n")?;
+    writeln!(f, "pub mod migrations;")?;
+    writeln!(f, "#[cfg(feature = \"shortcodes\")]")?;
     writeln!(f, "pub mod shortcode;")?;
     writeln!(f, "pub mod unicode;\n")?;
-    writeln!(f, "use crate::{{Emoji, SkinTone, UnicodeVersion}};\n")?;
+    writeln!(
+        f,
+        "use crate::{{Emoji, LayoutGroup, LayoutSubgroup, SkinTone, UnicodeVersion}};\n"
+    )?;
 
     write_group_enum(&mut f, &unicode_data)?;
     writeln!(f)?;
+    write_subgroup_enum(&mut f, &unicode_data)?;
+    writeln!(f)?;
     write_emojis_slice(
         &mut f,
         &unicode_data,
         &github_data,
-        &mut unicode_map,
-        &mut shortcode_map,
+        &cldr_data,
+        &sprite_data,
+        &mut tables,
     )?;
+    writeln!(f)?;
+    write_default_emojis_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_shortcodes_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_tts_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_sprites_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_source_lines_slice(&mut f, &dir, &tables)?;
+    writeln!(f)?;
+    write_descriptions_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_colors_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_keywords_slice(&mut f, "KEYWORDS", &tables.keywords)?;
+    writeln!(f)?;
+    for locale in cldr::LOCALES {
+        let annotations = cldr::fetch_and_parse_annotations(locale)?;
+        let mut keywords = BTreeMap::<_, BTreeSet<_>>::new();
+        for &i in &tables.default_indexes {
+            let unicode = tables.emojis[i].as_str();
+            if let Some(annotation) = annotations.get(&cldr::key(unicode)) {
+                for keyword in annotation.localized_keywords() {
+                    keywords.entry(keyword).or_default().insert(i);
+                }
+            }
+        }
+        writeln!(f, "#[cfg(feature = \"locales\")]")?;
+        let name = format!("KEYWORDS_{}", locale.to_uppercase());
+        write_keywords_slice(&mut f, &name, &keywords)?;
+        writeln!(f)?;
+    }
+    write_chars_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_first_chars_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_all_chars_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_subgroups_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_layout_slice(&mut f, &tables)?;
+    writeln!(f)?;
+    write_group_impl(&mut f, &tables)?;
+    writeln!(f)?;
+    write_subgroup_impl(&mut f, &tables)?;
 
     let mut f = fs::File::create(dir.join("unicode.rs"))?;
-    write_phf_map(&mut f, unicode_map)?;
+    write_phf_map(&mut f, tables.unicode_map)?;
 
     let mut f = fs::File::create(dir.join("shortcode.rs"))?;
-    write_phf_map(&mut f, shortcode_map)?;
+    write_phf_map(&mut f, tables.shortcode_map)?;
+
+    let mut f = fs::File::create(dir.join("migrations.rs"))?;
+    write_migrations(&mut f, &tables.emojis)?;
 
     Ok(())
 }
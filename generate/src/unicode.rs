@@ -42,6 +42,7 @@ pub struct Emoji {
     status: Status,
     skin_tones: Vec<SkinTone>,
     variations: Vec<String>,
+    line: String,
 }
 
 pub type ParsedData = IndexMap<String, IndexMap<String, Vec<Emoji>>>;
@@ -169,9 +170,7 @@ impl Emoji {
         };
         let skin_tones: Vec<_> = emoji
             .chars()
-            .filter_map(|c| {
-                SkinTone::tones().find_map(|tone| (tone.code_point() == c).then_some(tone))
-            })
+            .filter_map(|c| SkinTone::tones().find(|tone| tone.code_point() == c))
             .collect();
 
         Ok(Self {
@@ -181,6 +180,7 @@ impl Emoji {
             status,
             skin_tones,
             variations: Vec::new(),
+            line: line.to_owned(),
         })
     }
 
@@ -198,12 +198,16 @@ impl Emoji {
 
     pub fn skin_tone(&self) -> Option<SkinTone> {
         assert!(self.skin_tones.len() <= 1);
-        self.skin_tones.get(0).copied()
+        self.skin_tones.first().copied()
     }
 
     pub fn variations(&self) -> &[String] {
         &self.variations
     }
+
+    pub fn line(&self) -> &str {
+        &self.line
+    }
 }
 
 fn parse_emoji_data(data: &str) -> Result<ParsedData> {
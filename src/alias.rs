@@ -0,0 +1,190 @@
+//! Common and scientific names for animals and plants.
+
+use crate::Emoji;
+
+/// Alternative names for emojis in the Animals & Nature group, in Unicode
+/// CLDR order.
+///
+/// These are common names, collective terms, and scientific names that are
+/// not already covered by the emoji name.
+#[rustfmt::skip]
+const ALIASES: &[(&str, &[&str])] = &[
+    ("🐵", &["monkey", "primate", "simian"]),
+    ("🐒", &["primate", "simian", "simiiformes"]),
+    ("🦍", &["ape", "primate", "gorilla gorilla"]),
+    ("🦧", &["ape", "primate", "pongo"]),
+    ("🐶", &["dog", "puppy", "pet", "canine"]),
+    ("🐕", &["canine", "hound", "pet", "canis familiaris"]),
+    ("🦮", &["canine", "guide", "blind", "accessibility", "canis familiaris"]),
+    ("🐕\u{200d}🦺", &["canine", "assistance", "accessibility", "canis familiaris"]),
+    ("🐩", &["dog", "canine", "canis familiaris"]),
+    ("🐺", &["canine", "canis lupus"]),
+    ("🦊", &["canine", "vixen", "vulpes vulpes"]),
+    ("🦝", &["procyon lotor"]),
+    ("🐱", &["cat", "kitten", "pet", "feline"]),
+    ("🐈", &["feline", "kitten", "pet", "felis catus"]),
+    ("🐈\u{200d}⬛", &["feline", "pet", "felis catus"]),
+    ("🦁", &["feline", "big cat", "panthera leo"]),
+    ("🐯", &["tiger", "feline", "big cat"]),
+    ("🐅", &["feline", "big cat", "panthera tigris"]),
+    ("🐆", &["feline", "big cat", "panthera pardus"]),
+    ("🐴", &["horse", "equine"]),
+    ("🫎", &["elk", "cervid", "alces alces"]),
+    ("🫏", &["ass", "burro", "mule", "equine", "equus asinus"]),
+    ("🐎", &["equine", "stallion", "mare", "equus caballus"]),
+    ("🦄", &["equine", "fantasy"]),
+    ("🦓", &["equine", "stripes"]),
+    ("🦌", &["cervid", "stag", "reindeer", "cervidae"]),
+    ("🦬", &["buffalo", "wisent", "bovine", "bison bison"]),
+    ("🐮", &["cow", "cattle", "bovine"]),
+    ("🐂", &["bull", "cattle", "bovine", "bos taurus"]),
+    ("🐃", &["buffalo", "bovine", "bubalus bubalis"]),
+    ("🐄", &["cattle", "bovine", "bos taurus"]),
+    ("🐷", &["pig", "swine", "porcine"]),
+    ("🐖", &["sow", "hog", "swine", "porcine", "sus domesticus"]),
+    ("🐗", &["wild pig", "swine", "sus scrofa"]),
+    ("🐽", &["pig", "snout", "swine"]),
+    ("🐏", &["sheep", "ovine", "ovis aries"]),
+    ("🐑", &["sheep", "lamb", "ovine", "ovis aries"]),
+    ("🐐", &["caprine", "capra hircus"]),
+    ("🐪", &["dromedary", "camelus dromedarius"]),
+    ("🐫", &["bactrian", "camel", "camelus bactrianus"]),
+    ("🦙", &["alpaca", "guanaco", "vicuña", "lama glama"]),
+    ("🦒", &["giraffa"]),
+    ("🐘", &["pachyderm", "elephantidae"]),
+    ("🦣", &["woolly mammoth", "extinct", "mammuthus"]),
+    ("🦏", &["rhino", "rhinocerotidae"]),
+    ("🦛", &["hippo", "hippopotamus amphibius"]),
+    ("🐭", &["mouse", "rodent"]),
+    ("🐁", &["rodent", "mus musculus"]),
+    ("🐀", &["rodent", "rattus"]),
+    ("🐹", &["rodent", "pet", "cricetinae"]),
+    ("🐰", &["rabbit", "bunny", "pet"]),
+    ("🐇", &["bunny", "hare", "leporidae"]),
+    ("🐿\u{fe0f}", &["squirrel", "rodent", "tamias"]),
+    ("🦫", &["rodent", "castor"]),
+    ("🦔", &["spiny", "erinaceinae"]),
+    ("🦇", &["vampire", "chiroptera"]),
+    ("🐻", &["ursine", "ursidae"]),
+    ("🐻\u{200d}❄\u{fe0f}", &["bear", "arctic", "ursine", "ursus maritimus"]),
+    ("🐨", &["marsupial", "phascolarctos cinereus"]),
+    ("🐼", &["giant panda", "bear", "ailuropoda melanoleuca"]),
+    ("🦥", &["slow", "folivora"]),
+    ("🦦", &["lutrinae"]),
+    ("🦨", &["polecat", "mephitidae"]),
+    ("🦘", &["joey", "marsupial", "macropus"]),
+    ("🦡", &["honey badger", "pester", "melinae"]),
+    ("🐾", &["paw", "feet", "footprints", "tracks"]),
+    ("🦃", &["bird", "poultry", "gobbler", "meleagris"]),
+    ("🐔", &["bird", "hen", "poultry", "gallus gallus domesticus"]),
+    ("🐓", &["bird", "cock", "poultry", "gallus gallus domesticus"]),
+    ("🐣", &["bird", "chick", "egg"]),
+    ("🐤", &["bird", "chick"]),
+    ("🐥", &["bird", "chick"]),
+    ("🐦", &["avian", "aves"]),
+    ("🐧", &["bird", "spheniscidae"]),
+    ("🕊\u{fe0f}", &["bird", "pigeon", "peace", "columbidae"]),
+    ("🦅", &["bird", "raptor", "bird of prey", "accipitridae"]),
+    ("🦆", &["bird", "mallard", "anatidae"]),
+    ("🦢", &["bird", "cygnet", "cygnus"]),
+    ("🦉", &["bird", "raptor", "strigiformes"]),
+    ("🦤", &["bird", "extinct", "raphus cucullatus"]),
+    ("🪶", &["plumage", "quill", "bird"]),
+    ("🦩", &["bird", "phoenicopteridae"]),
+    ("🦚", &["peafowl", "peahen", "bird", "pavo"]),
+    ("🦜", &["bird", "macaw", "psittaciformes"]),
+    ("🪽", &["angelic", "flying", "bird"]),
+    ("🐦\u{200d}⬛", &["crow", "raven", "rook", "corvid", "corvus"]),
+    ("🪿", &["bird", "gander", "anser"]),
+    ("🐸", &["toad", "amphibian", "anura"]),
+    ("🐊", &["alligator", "reptile", "crocodylidae"]),
+    ("🐢", &["tortoise", "terrapin", "reptile", "testudines"]),
+    ("🦎", &["gecko", "reptile", "lacertilia"]),
+    ("🐍", &["serpent", "reptile", "serpentes"]),
+    ("🐲", &["dragon", "fantasy"]),
+    ("🐉", &["fantasy", "wyvern"]),
+    ("🦕", &["dinosaur", "brachiosaurus", "brontosaurus", "diplodocus", "sauropoda"]),
+    ("🦖", &["dinosaur", "tyrannosaurus rex"]),
+    ("🐳", &["whale", "cetacean"]),
+    ("🐋", &["cetacean", "cetacea"]),
+    ("🐬", &["flipper", "cetacean", "delphinidae"]),
+    ("🦭", &["sea lion", "pinniped", "pinnipedia"]),
+    ("🐟", &["pisces"]),
+    ("🐠", &["fish", "clownfish"]),
+    ("🐡", &["pufferfish", "fish", "tetraodontidae"]),
+    ("🦈", &["fish", "selachimorpha"]),
+    ("🐙", &["cephalopod", "octopoda"]),
+    ("🐚", &["seashell", "conch", "mollusc"]),
+    ("🪸", &["reef", "anthozoa"]),
+    ("🪼", &["jelly", "medusa", "scyphozoa"]),
+    ("🐌", &["mollusc", "gastropod", "gastropoda"]),
+    ("🦋", &["insect", "moth", "lepidoptera"]),
+    ("🐛", &["caterpillar", "insect", "larva"]),
+    ("🐜", &["insect", "formicidae"]),
+    ("🐝", &["bee", "insect", "apis mellifera"]),
+    ("🪲", &["bug", "insect", "coleoptera"]),
+    ("🐞", &["ladybug", "ladybird", "insect", "coccinellidae"]),
+    ("🦗", &["grasshopper", "insect", "orthoptera"]),
+    ("🪳", &["roach", "insect", "blattodea"]),
+    ("🕷\u{fe0f}", &["arachnid", "araneae"]),
+    ("🕸\u{fe0f}", &["cobweb", "web"]),
+    ("🦂", &["arachnid", "scorpiones"]),
+    ("🦟", &["insect", "culicidae"]),
+    ("🪰", &["insect", "housefly", "musca domestica"]),
+    ("🪱", &["earthworm", "annelid", "lumbricina"]),
+    ("🦠", &["bacteria", "virus", "germ", "amoeba"]),
+    ("💐", &["flowers", "posy"]),
+    ("🌸", &["sakura", "flower", "prunus serrulata"]),
+    ("💮", &["flower"]),
+    ("🪷", &["flower", "nelumbo nucifera"]),
+    ("🏵\u{fe0f}", &["flower"]),
+    ("🌹", &["flower", "rosa"]),
+    ("🥀", &["flower", "dead"]),
+    ("🌺", &["flower", "hibiscus rosa-sinensis"]),
+    ("🌻", &["flower", "helianthus annuus"]),
+    ("🌼", &["flower", "daisy"]),
+    ("🌷", &["flower", "tulipa"]),
+    ("🪻", &["flower", "lavender", "lupine", "hyacinthus"]),
+    ("🌱", &["sprout", "seed", "plant"]),
+    ("🪴", &["houseplant", "plant"]),
+    ("🌲", &["conifer", "pine", "fir", "christmas tree"]),
+    ("🌳", &["broadleaf", "oak", "tree"]),
+    ("🌴", &["tree", "arecaceae"]),
+    ("🌵", &["succulent", "cactaceae"]),
+    ("🌾", &["grain", "rice", "oryza sativa"]),
+    ("🌿", &["leaf", "plant"]),
+    ("☘\u{fe0f}", &["clover", "trifolium", "irish"]),
+    ("🍀", &["clover", "lucky", "trifolium"]),
+    ("🍁", &["leaf", "acer"]),
+    ("🍂", &["leaf", "autumn", "fall"]),
+    ("🍃", &["leaf", "wind"]),
+    ("🪹", &["nest", "nesting"]),
+    ("🪺", &["nest", "eggs", "nesting"]),
+    ("🍄", &["fungus", "toadstool", "fungi"]),
+];
+
+impl Emoji {
+    /// Returns an iterator over alternative names for this emoji.
+    ///
+    /// These are common and scientific names for animals and plants in the
+    /// [Animals & Nature][crate::Group::AnimalsAndNature] group, distinct from
+    /// the [shortcodes][Emoji::shortcodes]. Other emojis have no aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dog = emojis::get("🐕").unwrap();
+    /// assert!(dog.aliases().any(|alias| alias == "canine"));
+    ///
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.aliases().next(), None);
+    /// ```
+    pub fn aliases(&self) -> impl Iterator<Item = &str> {
+        ALIASES
+            .iter()
+            .find(|(emoji, _)| *emoji == self.emoji)
+            .map_or(&[][..], |(_, aliases)| aliases)
+            .iter()
+            .copied()
+    }
+}
@@ -0,0 +1,67 @@
+//! Compile time assertions that emojis are in the dataset.
+
+/// Returns whether the emoji or shortcode surrounded by colons is in the
+/// dataset.
+///
+/// Used by the [`assert_has!`][crate::assert_has] and
+/// [`assert_has_shortcode!`][crate::assert_has_shortcode] macros.
+#[doc(hidden)]
+pub const fn __contains(s: &str) -> bool {
+    crate::set::position(s).is_some()
+}
+
+/// Asserts at compile time that emojis are in the dataset.
+///
+/// Use this to make sure that the emojis a product depends on are known to
+/// the compiled version of this crate, e.g. when upgrading to an older or
+/// trimmed dataset. Unknown emojis are a compile time error and there is no
+/// runtime cost. The macro can be used anywhere an item can.
+///
+/// Emojis must be fully-qualified string literals.
+///
+/// # Examples
+///
+/// ```
+/// emojis::assert_has!("🫠", "👍🏽");
+/// ```
+///
+/// ```compile_fail
+/// emojis::assert_has!("not an emoji");
+/// ```
+#[macro_export]
+macro_rules! assert_has {
+    ($($emoji:literal),+ $(,)?) => {
+        $(
+            const _: () = ::core::assert!(
+                $crate::__contains($emoji),
+                ::core::concat!("emoji `", $emoji, "` is not in the dataset"),
+            );
+        )+
+    };
+}
+
+/// Asserts at compile time that GitHub shortcodes are in the dataset.
+///
+/// This is the same as [`assert_has!`][crate::assert_has] but for shortcodes,
+/// which are given without the surrounding colons.
+///
+/// # Examples
+///
+/// ```
+/// emojis::assert_has_shortcode!("melting_face", "+1");
+/// ```
+///
+/// ```compile_fail
+/// emojis::assert_has_shortcode!("not_a_shortcode");
+/// ```
+#[macro_export]
+macro_rules! assert_has_shortcode {
+    ($($shortcode:literal),+ $(,)?) => {
+        $(
+            const _: () = ::core::assert!(
+                $crate::__contains(::core::concat!(":", $shortcode, ":")),
+                ::core::concat!("shortcode `", $shortcode, "` is not in the dataset"),
+            );
+        )+
+    };
+}
@@ -0,0 +1,48 @@
+//! Const tables of the Unicode scalar values used in emojis.
+//!
+//! Each table is a sorted slice of non-overlapping inclusive ranges. Since the
+//! tables and [`contains()`] are `const` they can be evaluated at compile time,
+//! e.g. by proc-macros and parser generators that embed emoji detection in
+//! generated code.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::codepoint_table::{contains, STARTS};
+//!
+//! const ROCKET: bool = contains(STARTS, '🚀');
+//! assert!(ROCKET);
+//!
+//! let ranges: Vec<_> = STARTS.iter().map(|&(start, end)| start..=end).collect();
+//! assert!(ranges.iter().any(|r| r.contains(&'#')));
+//! ```
+
+/// The ranges of scalar values that an emoji or emoji variation can start
+/// with.
+///
+/// See also [`could_start_emoji()`][crate::could_start_emoji].
+pub const STARTS: &[(char, char)] = crate::gen::FIRST_CHARS;
+
+/// The ranges of scalar values that can appear anywhere in an emoji or emoji
+/// variation, including joiners, variation selectors, skin tone modifiers,
+/// and tags.
+pub const ALL: &[(char, char)] = crate::gen::ALL_CHARS;
+
+/// Returns whether the character is in one of the ranges of the table.
+///
+/// This takes *Ο(log n)* time in the number of ranges.
+pub const fn contains(table: &[(char, char)], c: char) -> bool {
+    let (mut lo, mut hi) = (0, table.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, end) = table[mid];
+        if (end as u32) < c as u32 {
+            lo = mid + 1;
+        } else if (start as u32) > c as u32 {
+            hi = mid;
+        } else {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,75 @@
+//! Colors of emojis.
+
+#[cfg(feature = "colors")]
+use crate::Emoji;
+
+/// The dominant color of an emoji.
+///
+/// This is used to select an emoji from a family of colored emojis, e.g.
+/// [`heart()`][crate::heart], and with the **colors** feature to filter
+/// emojis by color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Color {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Pink,
+    Brown,
+    Black,
+    Grey,
+    White,
+}
+
+#[cfg(feature = "colors")]
+impl Emoji {
+    /// Returns the dominant color of this emoji.
+    ///
+    /// Only emojis whose Unicode CLDR name includes a color have one, such as
+    /// the colored hearts, circles, and squares, or 🍏 green apple. Colors in
+    /// the names of people describe a feature rather than the whole emoji,
+    /// e.g. 🧑‍🦰 person: red hair, so people never have a color. Light blue is
+    /// [`Color::Blue`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Color;
+    ///
+    /// assert_eq!(emojis::get("💙").unwrap().color(), Some(Color::Blue));
+    /// assert_eq!(emojis::get("🟧").unwrap().color(), Some(Color::Orange));
+    /// assert_eq!(emojis::get("🚀").unwrap().color(), None);
+    /// ```
+    pub fn color(&self) -> Option<Color> {
+        let colors = crate::gen::COLORS;
+        let i = colors
+            .binary_search_by_key(&self.index(), |&(i, _)| i)
+            .ok()?;
+        Some(colors[i].1)
+    }
+}
+
+/// Returns an iterator over the emojis with the given dominant color.
+///
+/// Emojis are yielded in Unicode CLDR order. See [`Emoji::color()`] for
+/// which emojis have a color.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Color;
+///
+/// let mut red = emojis::with_color(Color::Red);
+/// assert_eq!(red.next().unwrap(), "❤️");
+/// assert!(red.any(|e| e == "🔴"));
+/// ```
+#[cfg(feature = "colors")]
+pub fn with_color(color: Color) -> impl Iterator<Item = &'static Emoji> {
+    crate::gen::COLORS
+        .iter()
+        .filter(move |&&(_, c)| c == color)
+        .map(|&(i, _)| &crate::gen::EMOJIS[i])
+}
@@ -0,0 +1,47 @@
+//! Grouping emojis that represent the same concept.
+
+use crate::Emoji;
+
+impl Emoji {
+    /// Returns a key that is the same for every variation of this emoji.
+    ///
+    /// Skin tone and gender variations of an emoji, as well as all its
+    /// [qualification variations][Emoji::variations], share the same key.
+    /// The key is the default skin tone, gender-neutral emoji, so it is stable
+    /// across versions of this crate and suitable for storing, e.g. when
+    /// grouping emoji usage for analytics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let farmer = emojis::get("👩🏿‍🌾").unwrap();
+    /// assert_eq!(farmer.concept_id(), "🧑\u{200d}🌾");
+    ///
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.concept_id(), "🚀");
+    /// ```
+    pub fn concept_id(&self) -> &'static str {
+        let default = match self.skin_tone {
+            Some((i, _)) => &crate::gen::EMOJIS[i as usize],
+            None => self,
+        };
+        default.neutral().emoji
+    }
+
+    /// Returns whether this emoji and the other emoji are variations of the
+    /// same concept.
+    ///
+    /// See [`concept_id()`][Emoji::concept_id] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let a = emojis::get("🙋🏻‍♂️").unwrap();
+    /// let b = emojis::get("🙋‍♀️").unwrap();
+    /// assert!(a.same_concept_as(b));
+    /// assert!(!a.same_concept_as(emojis::get("🙆").unwrap()));
+    /// ```
+    pub fn same_concept_as(&self, other: &Emoji) -> bool {
+        self.concept_id() == other.concept_id()
+    }
+}
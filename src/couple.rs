@@ -0,0 +1,93 @@
+//! Building emoji sequences for two people with different skin tones.
+
+use crate::gender::{Buf, MAN, PERSON, WOMAN, ZWJ};
+use crate::{Gender, SkinTone};
+
+const HEART: &str = "\u{2764}\u{fe0f}";
+
+impl Gender {
+    /// Returns the person character for this gender.
+    fn person(self) -> char {
+        match self {
+            Self::Neutral => PERSON,
+            Self::Male => MAN,
+            Self::Female => WOMAN,
+        }
+    }
+}
+
+/// Pushes the character followed by the skin tone modifier, if any.
+fn push_toned(buf: &mut Buf, c: char, tone: SkinTone) {
+    buf.push(c);
+    if let Some(m) = tone.modifier() {
+        buf.push(m);
+    }
+}
+
+/// Returns the handshake emoji with the given skin tones for the right and
+/// the left hand.
+///
+/// If both skin tones are the same this is a toned 🤝, otherwise it is the
+/// sequence of a rightwards and a leftwards hand joined by a zero width
+/// joiner. Returns `None` if there is no such emoji, i.e. when only one of the
+/// skin tones is [`SkinTone::Default`].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::SkinTone;
+///
+/// assert_eq!(emojis::handshake(SkinTone::Default, SkinTone::Default), Some("🤝"));
+/// assert_eq!(emojis::handshake(SkinTone::Dark, SkinTone::Dark), Some("🤝🏿"));
+/// assert_eq!(emojis::handshake(SkinTone::Light, SkinTone::Dark), Some("🫱🏻\u{200d}🫲🏿"));
+/// assert_eq!(emojis::handshake(SkinTone::Default, SkinTone::Dark), None);
+/// ```
+pub fn handshake(right: SkinTone, left: SkinTone) -> Option<&'static str> {
+    let mut buf = Buf::new();
+    if right == left {
+        push_toned(&mut buf, '🤝', right);
+    } else {
+        push_toned(&mut buf, '🫱', right);
+        buf.push(ZWJ);
+        push_toned(&mut buf, '🫲', left);
+    }
+    buf.get_key()
+}
+
+/// Returns the couple with heart emoji for the given people.
+///
+/// Only the combinations recommended by Unicode exist. The skin tones must
+/// either both be [`SkinTone::Default`] or both be set, and a man and a woman
+/// are always in the order woman, man. Returns `None` for any other
+/// combination.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{Gender, SkinTone};
+///
+/// let couple = emojis::couple_with_heart(Gender::Female, SkinTone::Medium, Gender::Male, SkinTone::Dark);
+/// assert_eq!(couple, Some("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿"));
+///
+/// let couple = emojis::couple_with_heart(Gender::Neutral, SkinTone::Light, Gender::Neutral, SkinTone::Light);
+/// assert_eq!(couple, Some("💑🏻"));
+///
+/// let couple = emojis::couple_with_heart(Gender::Male, SkinTone::Default, Gender::Female, SkinTone::Default);
+/// assert_eq!(couple, None);
+/// ```
+pub fn couple_with_heart(
+    g1: Gender,
+    t1: SkinTone,
+    g2: Gender,
+    t2: SkinTone,
+) -> Option<&'static str> {
+    let mut buf = Buf::new();
+    if g1 == Gender::Neutral && g2 == Gender::Neutral && t1 == t2 {
+        push_toned(&mut buf, '💑', t1);
+    } else {
+        push_toned(&mut buf, g1.person(), t1);
+        buf.push(ZWJ).push_str(HEART).push(ZWJ);
+        push_toned(&mut buf, g2.person(), t2);
+    }
+    buf.get_key()
+}
@@ -0,0 +1,793 @@
+//! Pluggable emoji datasets.
+//!
+//! The [`EmojiData`] trait abstracts over a dataset so that the algorithms in
+//! this module, e.g. searching, scanning, and replacing, can be used with data
+//! loaded at runtime, e.g. from a file at startup. The functions at the root
+//! of this crate call the same algorithms with [`Builtin`], the dataset
+//! generated into the crate at compile time. With the **std** feature,
+//! `Dataset` implements the trait for a dataset loaded from bytes.
+//!
+//! Emojis in a dataset are identified by their index, from zero up to
+//! [`EmojiData::len()`].
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::data::{self, Builtin, EmojiData};
+//!
+//! let (range, index) = data::matches(&Builtin, "launch 🚀!").next().unwrap();
+//! assert_eq!(range, 7..11);
+//! assert_eq!(Builtin.name(index), Some("rocket"));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use crate::tokenizer::{Cursor, ShortcodeToken};
+use crate::{Emoji, Group, SkinTone, Subgroup, UnicodeVersion};
+
+/// A dataset of emojis.
+///
+/// Only a few lookups need to be implemented, the algorithms in this module
+/// are built on top of them. The provided methods have simple default
+/// implementations which an implementation can override with faster ones.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, EmojiData};
+/// use emojis::{Group, UnicodeVersion};
+///
+/// /// A dataset with a single emoji.
+/// struct Rocket;
+///
+/// impl EmojiData for Rocket {
+///     fn len(&self) -> usize {
+///         1
+///     }
+///
+///     fn as_str(&self, index: usize) -> Option<&str> {
+///         (index == 0).then(|| "🚀")
+///     }
+///
+///     fn name(&self, index: usize) -> Option<&str> {
+///         (index == 0).then(|| "rocket")
+///     }
+///
+///     fn unicode_version(&self, index: usize) -> Option<UnicodeVersion> {
+///         (index == 0).then(|| UnicodeVersion::new(0, 6))
+///     }
+///
+///     fn group(&self, index: usize) -> Option<Group> {
+///         (index == 0).then(|| Group::TravelAndPlaces)
+///     }
+///
+///     fn get(&self, emoji: &str) -> Option<usize> {
+///         (emoji == "🚀").then(|| 0)
+///     }
+/// }
+///
+/// let found: Vec<_> = data::matches(&Rocket, "🚀 and 🎉").collect();
+/// assert_eq!(found, [(0..4, 0)]);
+/// ```
+pub trait EmojiData {
+    /// Returns the number of emojis in the dataset.
+    fn len(&self) -> usize;
+
+    /// Returns whether the dataset has no emojis.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the emoji at the given index, e.g. "🚀".
+    fn as_str(&self, index: usize) -> Option<&str>;
+
+    /// Returns the CLDR short name of the emoji at the given index, e.g.
+    /// "rocket".
+    fn name(&self, index: usize) -> Option<&str>;
+
+    /// Returns the Unicode version the emoji at the given index was
+    /// introduced in.
+    fn unicode_version(&self, index: usize) -> Option<UnicodeVersion>;
+
+    /// Returns the group of the emoji at the given index.
+    fn group(&self, index: usize) -> Option<Group>;
+
+    /// Returns the subgroup of the emoji at the given index.
+    ///
+    /// The default implementation returns `None`.
+    fn subgroup(&self, index: usize) -> Option<Subgroup> {
+        let _ = index;
+        None
+    }
+
+    /// Returns the skin tone of the emoji at the given index, if it supports
+    /// skin tones.
+    ///
+    /// The default implementation returns `None`.
+    fn skin_tone(&self, index: usize) -> Option<SkinTone> {
+        let _ = index;
+        None
+    }
+
+    /// Returns the index of the given skin tone variation of the emoji at the
+    /// given index.
+    ///
+    /// The default implementation returns `None`.
+    fn with_skin_tone(&self, index: usize, skin_tone: SkinTone) -> Option<usize> {
+        let _ = (index, skin_tone);
+        None
+    }
+
+    /// Returns the primary shortcode of the emoji at the given index, if it
+    /// has one.
+    ///
+    /// The default implementation returns `None`.
+    fn shortcode(&self, index: usize) -> Option<&str> {
+        let _ = index;
+        None
+    }
+
+    /// Lookup an emoji by its exact string.
+    fn get(&self, emoji: &str) -> Option<usize>;
+
+    /// Lookup an emoji by shortcode, excluding the colons.
+    ///
+    /// The default implementation compares the shortcode of every emoji.
+    fn get_by_shortcode(&self, shortcode: &str) -> Option<usize> {
+        (0..self.len()).find(|&i| self.shortcode(i) == Some(shortcode))
+    }
+
+    /// Calls `f` with the index of every emoji with the given keyword, or
+    /// with a keyword that starts with it if `prefix` is `true`.
+    ///
+    /// The keyword is never empty and matching ignores ASCII case. An emoji
+    /// may be visited more than once.
+    ///
+    /// The default implementation uses the words in the name of every emoji
+    /// as its keywords.
+    fn find_keyword(&self, keyword: &str, prefix: bool, f: &mut dyn FnMut(usize)) {
+        let matches = |word: &str| match word.as_bytes().get(..keyword.len()) {
+            Some(start) if prefix => start.eq_ignore_ascii_case(keyword.as_bytes()),
+            _ => word.eq_ignore_ascii_case(keyword),
+        };
+        for i in 0..self.len() {
+            let mut words = self
+                .name(i)
+                .into_iter()
+                .flat_map(str::split_whitespace)
+                .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()));
+            if words.any(matches) {
+                f(i);
+            }
+        }
+    }
+
+    /// Returns the length in bytes of the longest string that
+    /// [`get()`][EmojiData::get] resolves to an emoji.
+    ///
+    /// Scanners only look up prefixes up to this length. The default
+    /// implementation measures every [`as_str()`][EmojiData::as_str], so
+    /// datasets that also resolve longer variations should override it.
+    fn max_len(&self) -> usize {
+        (0..self.len())
+            .filter_map(|i| self.as_str(i))
+            .map(str::len)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns whether an emoji in the dataset could start at the start of
+    /// the given string.
+    ///
+    /// Scanners call this before looking up the prefixes of the string, so an
+    /// implementation can return `false` to cheaply skip text that can not
+    /// contain an emoji. The default implementation returns `true`.
+    fn could_start(&self, s: &str) -> bool {
+        let _ = s;
+        true
+    }
+
+    /// Returns the first character boundary at or after `pos` in the text
+    /// where an emoji in the dataset could start.
+    ///
+    /// The default implementation returns `pos`. Scanning ends if the returned
+    /// offset is before `pos`, past the end of the text, or not on a
+    /// character boundary.
+    fn next_candidate(&self, text: &str, pos: usize) -> usize {
+        let _ = text;
+        pos
+    }
+}
+
+impl<T: EmojiData + ?Sized> EmojiData for &T {
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+
+    fn as_str(&self, index: usize) -> Option<&str> {
+        (**self).as_str(index)
+    }
+
+    fn name(&self, index: usize) -> Option<&str> {
+        (**self).name(index)
+    }
+
+    fn unicode_version(&self, index: usize) -> Option<UnicodeVersion> {
+        (**self).unicode_version(index)
+    }
+
+    fn group(&self, index: usize) -> Option<Group> {
+        (**self).group(index)
+    }
+
+    fn subgroup(&self, index: usize) -> Option<Subgroup> {
+        (**self).subgroup(index)
+    }
+
+    fn skin_tone(&self, index: usize) -> Option<SkinTone> {
+        (**self).skin_tone(index)
+    }
+
+    fn with_skin_tone(&self, index: usize, skin_tone: SkinTone) -> Option<usize> {
+        (**self).with_skin_tone(index, skin_tone)
+    }
+
+    fn shortcode(&self, index: usize) -> Option<&str> {
+        (**self).shortcode(index)
+    }
+
+    fn get(&self, emoji: &str) -> Option<usize> {
+        (**self).get(emoji)
+    }
+
+    fn get_by_shortcode(&self, shortcode: &str) -> Option<usize> {
+        (**self).get_by_shortcode(shortcode)
+    }
+
+    fn find_keyword(&self, keyword: &str, prefix: bool, f: &mut dyn FnMut(usize)) {
+        (**self).find_keyword(keyword, prefix, f)
+    }
+
+    fn max_len(&self) -> usize {
+        (**self).max_len()
+    }
+
+    fn could_start(&self, s: &str) -> bool {
+        (**self).could_start(s)
+    }
+
+    fn next_candidate(&self, text: &str, pos: usize) -> usize {
+        (**self).next_candidate(text, pos)
+    }
+}
+
+/// The dataset generated into this crate.
+///
+/// This includes every emoji and skin tone variation, in the same order as
+/// [`dump()`][crate::dump], and [`EmojiData::get()`] also finds emoji
+/// variations like [`get()`][crate::get].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Builtin;
+
+impl Builtin {
+    /// Returns the emoji at the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::data::{Builtin, EmojiData};
+    ///
+    /// let index = Builtin.get("🚀").unwrap();
+    /// assert_eq!(Builtin::emoji(index).unwrap(), "🚀");
+    /// ```
+    pub fn emoji(index: usize) -> Option<&'static Emoji> {
+        crate::gen::EMOJIS.get(index)
+    }
+}
+
+impl EmojiData for Builtin {
+    fn len(&self) -> usize {
+        crate::gen::EMOJIS.len()
+    }
+
+    fn as_str(&self, index: usize) -> Option<&str> {
+        Self::emoji(index).map(Emoji::as_str)
+    }
+
+    fn name(&self, index: usize) -> Option<&str> {
+        Self::emoji(index).map(Emoji::name)
+    }
+
+    fn unicode_version(&self, index: usize) -> Option<UnicodeVersion> {
+        Self::emoji(index).map(Emoji::unicode_version)
+    }
+
+    fn group(&self, index: usize) -> Option<Group> {
+        Self::emoji(index).map(Emoji::group)
+    }
+
+    fn subgroup(&self, index: usize) -> Option<Subgroup> {
+        let emoji = Self::emoji(index)?;
+        let base = emoji
+            .skin_tones()
+            .and_then(|mut t| t.next())
+            .unwrap_or(emoji);
+        // Subgroups are contiguous and in the same order as the table of all
+        // emojis.
+        let i = Subgroup::ALL
+            .partition_point(|s| s.emojis().last().map_or(true, |e| e.index() < base.index()));
+        Subgroup::ALL
+            .get(i)
+            .copied()
+            .filter(|s| s.emojis().contains(&base))
+    }
+
+    fn skin_tone(&self, index: usize) -> Option<SkinTone> {
+        Self::emoji(index)?.skin_tone()
+    }
+
+    fn with_skin_tone(&self, index: usize, skin_tone: SkinTone) -> Option<usize> {
+        Self::emoji(index)?
+            .with_skin_tone(skin_tone)
+            .map(Emoji::index)
+    }
+
+    fn shortcode(&self, index: usize) -> Option<&str> {
+        Self::emoji(index)?.shortcode()
+    }
+
+    fn get(&self, emoji: &str) -> Option<usize> {
+        crate::get(emoji).map(Emoji::index)
+    }
+
+    fn get_by_shortcode(&self, shortcode: &str) -> Option<usize> {
+        crate::get_by_shortcode(shortcode).map(Emoji::index)
+    }
+
+    fn find_keyword(&self, keyword: &str, prefix: bool, f: &mut dyn FnMut(usize)) {
+        let emojis = if prefix {
+            crate::search::with_prefix(crate::gen::KEYWORDS, keyword)
+        } else {
+            crate::search::with_keyword_entry(crate::gen::KEYWORDS, keyword)
+        };
+        for emoji in emojis.iter().flat_map(|(_, emojis)| emojis.iter()) {
+            f(emoji.index());
+        }
+    }
+
+    fn max_len(&self) -> usize {
+        crate::MAX_BYTES
+    }
+
+    fn could_start(&self, s: &str) -> bool {
+        crate::text::could_start(s)
+    }
+
+    fn next_candidate(&self, text: &str, pos: usize) -> usize {
+        #[cfg(feature = "simd")]
+        {
+            crate::text::skip_ascii(text.as_bytes(), pos)
+        }
+        #[cfg(not(feature = "simd"))]
+        {
+            let _ = text;
+            pos
+        }
+    }
+}
+
+/// Returns whether the skin tone is the default one, i.e. the emoji is not a
+/// skin tone variation.
+fn is_default_skin_tone(skin_tone: Option<SkinTone>) -> bool {
+    matches!(skin_tone, Some(SkinTone::Default) | None)
+}
+
+/// Returns an iterator over the indexes of the emojis whose name contains the
+/// given words.
+///
+/// Matching works like [`find_by_name()`][crate::find_by_name], which calls
+/// this with [`Builtin`]. Only the default skin tone versions are included.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, Builtin, EmojiData};
+///
+/// let index = data::find_by_name(&Builtin, "red heart").next().unwrap();
+/// assert_eq!(Builtin.as_str(index), Some("❤️"));
+/// ```
+pub fn find_by_name<'a, D>(data: &'a D, words: &'a str) -> impl Iterator<Item = usize> + 'a
+where
+    D: EmojiData + ?Sized,
+{
+    let words = words.trim();
+    (0..data.len()).filter(move |&i| {
+        is_default_skin_tone(data.skin_tone(i))
+            && data
+                .name(i)
+                .map_or(false, |name| crate::search::contains_words(name, words))
+    })
+}
+
+/// Returns the indexes of the emojis with the given keyword.
+///
+/// Matching ignores ASCII case and surrounding whitespace, like
+/// [`with_keyword()`][crate::with_keyword]. Emojis are returned once each in
+/// dataset order.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, Builtin, EmojiData};
+///
+/// let rockets = data::with_keyword(&Builtin, "Rocket");
+/// assert!(rockets.iter().any(|&i| Builtin.as_str(i) == Some("🚀")));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn with_keyword<D>(data: &D, keyword: &str) -> Vec<usize>
+where
+    D: EmojiData + ?Sized,
+{
+    keyword_matches(data, keyword.trim(), false)
+}
+
+/// Returns the sorted and deduplicated indexes of the emojis with the given
+/// keyword or a keyword that starts with it.
+#[cfg(feature = "alloc")]
+fn keyword_matches<D>(data: &D, keyword: &str, prefix: bool) -> Vec<usize>
+where
+    D: EmojiData + ?Sized,
+{
+    let mut indexes = Vec::new();
+    if !keyword.is_empty() {
+        data.find_keyword(keyword, prefix, &mut |i| indexes.push(i));
+    }
+    indexes.sort_unstable();
+    indexes.dedup();
+    indexes
+}
+
+/// Searches for emojis and ranks them using the given scorer.
+///
+/// This works like [`search_with()`][crate::search_with], which calls this
+/// with [`Builtin`], except that the scorer is called with the index of each
+/// candidate.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, Builtin, EmojiData};
+///
+/// let results = data::search_with(&Builtin, "rock", |_, index| {
+///     Some((Builtin.as_str(index) == Some("🚀")) as i64)
+/// });
+/// assert_eq!(Builtin.as_str(results[0]), Some("🚀"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn search_with<D, F>(data: &D, query: &str, scorer: F) -> Vec<usize>
+where
+    D: EmojiData + ?Sized,
+    F: Fn(&str, usize) -> Option<i64>,
+{
+    let query = query.trim();
+    let mut results: Vec<_> = keyword_matches(data, query, true)
+        .into_iter()
+        .filter(|&i| is_default_skin_tone(data.skin_tone(i)))
+        .filter_map(|i| Some((scorer(query, i)?, i)))
+        .collect();
+    results.sort_by(|(a, _), (b, _)| b.cmp(a));
+    results.into_iter().map(|(_, i)| i).collect()
+}
+
+/// Returns the length and index of the longest emoji at the start of the
+/// given string.
+pub(crate) fn longest_prefix<D>(data: &D, s: &str, max_len: usize) -> Option<(usize, usize)>
+where
+    D: EmojiData + ?Sized,
+{
+    if !data.could_start(s) {
+        return None;
+    }
+    (1..=s.len().min(max_len))
+        .rev()
+        .filter(|&n| s.is_char_boundary(n))
+        .find_map(|n| data.get(&s[..n]).map(|i| (n, i)))
+}
+
+/// Returns an iterator over the emojis in the text and their byte ranges.
+///
+/// At each position the longest emoji in the dataset is matched. This is the
+/// scanner behind [`EmojiStrExt::emojis()`][crate::EmojiStrExt::emojis] and
+/// the other functions that find emojis in text.
+///
+/// This takes *Ο(n)* time in the length of the text, but performs up to
+/// [`EmojiData::max_len()`] lookups at every position that
+/// [could start][EmojiData::could_start] an emoji.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, Builtin};
+///
+/// let ranges: Vec<_> = data::matches(&Builtin, "go 🇿🇦 team 😀")
+///     .map(|(range, _)| range)
+///     .collect();
+/// assert_eq!(ranges, [3..11, 17..21]);
+/// ```
+pub fn matches<'a, D>(data: &'a D, text: &'a str) -> Matches<'a, D>
+where
+    D: EmojiData + ?Sized,
+{
+    Matches {
+        data,
+        text,
+        pos: 0,
+        max_len: data.max_len(),
+    }
+}
+
+/// An iterator over the emojis in a string and their byte ranges.
+///
+/// Returned by [`matches()`].
+#[derive(Debug, Clone)]
+pub struct Matches<'a, D: ?Sized> {
+    data: &'a D,
+    text: &'a str,
+    pos: usize,
+    max_len: usize,
+}
+
+impl<D> Iterator for Matches<'_, D>
+where
+    D: EmojiData + ?Sized,
+{
+    type Item = (Range<usize>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // The candidate comes from the dataset, so an offset that moves
+            // backwards, is out of range, or is not on a character boundary
+            // ends the scan instead of panicking or looping forever.
+            let pos = self.data.next_candidate(self.text, self.pos);
+            if pos < self.pos {
+                return None;
+            }
+            self.pos = pos;
+            let rest = self.text.get(self.pos..)?;
+            let c = rest.chars().next()?;
+            let start = self.pos;
+            match longest_prefix(self.data, rest, self.max_len) {
+                Some((n, index)) => {
+                    self.pos += n;
+                    return Some((start..self.pos, index));
+                }
+                None => self.pos += c.len_utf8(),
+            }
+        }
+    }
+}
+
+/// Removes the emojis for which the predicate returns `true`.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, Builtin, EmojiData};
+///
+/// let text = data::strip(&Builtin, "go 🇿🇦 team 😀", |i| Builtin.name(i) == Some("grinning face"));
+/// assert_eq!(text, "go 🇿🇦 team ");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn strip<'a, D, F>(data: &D, text: &'a str, mut f: F) -> Cow<'a, str>
+where
+    D: EmojiData + ?Sized,
+    F: FnMut(usize) -> bool,
+{
+    let removed = matches(data, text)
+        .filter(|&(_, i)| f(i))
+        .map(|(range, _)| (range, ""));
+    crate::text::replace_ranges(text, removed)
+}
+
+/// Removes all emojis in the given groups from the text.
+///
+/// This works like [`strip_groups()`][crate::strip_groups], which calls this
+/// with [`Builtin`].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, Builtin};
+/// use emojis::Group;
+///
+/// let text = data::strip_groups(&Builtin, "go 🇿🇦 team 😀", &[Group::Flags]);
+/// assert_eq!(text, "go  team 😀");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn strip_groups<'a, D>(data: &D, text: &'a str, groups: &[Group]) -> Cow<'a, str>
+where
+    D: EmojiData + ?Sized,
+{
+    strip(data, text, |i| {
+        data.group(i).map_or(false, |group| groups.contains(&group))
+    })
+}
+
+/// Replaces shortcodes in the dataset, e.g. `:rocket:`, with emojis.
+///
+/// When a candidate does not resolve, its closing colon can be the start of
+/// the next one. Use a [`ShortcodeTokenizer`] with [`replace_tokens()`] for
+/// more options.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, Builtin};
+///
+/// let text = data::replace_shortcodes(&Builtin, "launch :rocket: at 10:30:00");
+/// assert_eq!(text, "launch 🚀 at 10:30:00");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn replace_shortcodes<'a, D>(data: &D, text: &'a str) -> Cow<'a, str>
+where
+    D: EmojiData + ?Sized,
+{
+    replace_tokens(ShortcodeTokenizer::new(data, text))
+}
+
+/// Replaces shortcodes in the dataset, e.g. `:rocket:`, with emojis in
+/// Markdown text.
+///
+/// This works like
+/// [`replace_shortcodes_markdown()`][crate::replace_shortcodes_markdown],
+/// which calls this with [`Builtin`].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, Builtin};
+///
+/// let text = data::replace_shortcodes_markdown(&Builtin, "launch :rocket: `:rocket:`");
+/// assert_eq!(text, "launch 🚀 `:rocket:`");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn replace_shortcodes_markdown<'a, D>(data: &D, text: &'a str) -> Cow<'a, str>
+where
+    D: EmojiData + ?Sized,
+{
+    let tokens = ShortcodeTokenizer::new(data, text)
+        .skip_code_spans(true)
+        .skip_link_urls(true);
+    replace_tokens(tokens)
+}
+
+/// Replaces the resolved tokens with their emojis.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, Builtin, ShortcodeTokenizer};
+///
+/// let tokens = ShortcodeTokenizer::new(&Builtin, "a:rocket: :rocket:").require_word_boundaries(true);
+/// assert_eq!(data::replace_tokens(tokens), "a:rocket: 🚀");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn replace_tokens<'a, D>(tokens: ShortcodeTokenizer<'a, '_, D>) -> Cow<'a, str>
+where
+    D: EmojiData + ?Sized,
+{
+    let data = tokens.data;
+    let text = tokens.cursor.text;
+    let replacements =
+        tokens.filter_map(|token| Some((token.range(), data.as_str(token.emoji()?)?)));
+    crate::text::replace_ranges(text, replacements)
+}
+
+/// An iterator over the candidate shortcodes in text, resolved using a
+/// dataset.
+///
+/// This works like [`crate::ShortcodeTokenizer`] and has the same options,
+/// except that tokens resolve to the index of an emoji in the dataset.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{Builtin, EmojiData, ShortcodeTokenizer};
+///
+/// let text = "launch :rocket: `:tada:`";
+/// let tokens: Vec<_> = ShortcodeTokenizer::new(&Builtin, text)
+///     .skip_code_spans(true)
+///     .map(|t| t.emoji().and_then(|i| Builtin.as_str(i)))
+///     .collect();
+/// assert_eq!(tokens, [Some("🚀")]);
+/// ```
+pub struct ShortcodeTokenizer<'a, 'd, D: ?Sized> {
+    data: &'d D,
+    cursor: Cursor<'a>,
+}
+
+impl<'a, 'd, D> ShortcodeTokenizer<'a, 'd, D>
+where
+    D: EmojiData + ?Sized,
+{
+    /// Construct a new tokenizer over the given text.
+    pub fn new(data: &'d D, text: &'a str) -> Self {
+        Self {
+            data,
+            cursor: Cursor::new(text, usize::MAX),
+        }
+    }
+
+    /// See [`crate::ShortcodeTokenizer::require_word_boundaries()`].
+    pub fn require_word_boundaries(mut self, yes: bool) -> Self {
+        self.cursor.word_boundaries = yes;
+        self
+    }
+
+    /// See [`crate::ShortcodeTokenizer::skip_code_spans()`].
+    pub fn skip_code_spans(mut self, yes: bool) -> Self {
+        self.cursor.code_spans = yes;
+        self
+    }
+
+    /// See [`crate::ShortcodeTokenizer::skip_link_urls()`].
+    pub fn skip_link_urls(mut self, yes: bool) -> Self {
+        self.cursor.link_urls = yes;
+        self
+    }
+
+    /// See [`crate::ShortcodeTokenizer::discord_custom_emojis()`].
+    ///
+    /// Discord skin tone shortcodes are resolved with
+    /// [`EmojiData::with_skin_tone()`].
+    pub fn discord_custom_emojis(mut self, yes: bool) -> Self {
+        self.cursor.discord = yes;
+        self
+    }
+}
+
+impl<D: ?Sized> Clone for ShortcodeTokenizer<'_, '_, D> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            cursor: self.cursor.clone(),
+        }
+    }
+}
+
+impl<D: ?Sized> fmt::Debug for ShortcodeTokenizer<'_, '_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShortcodeTokenizer")
+            .field("cursor", &self.cursor)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, D> Iterator for ShortcodeTokenizer<'a, '_, D>
+where
+    D: EmojiData + ?Sized,
+{
+    type Item = ShortcodeToken<'a, usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.data;
+        self.cursor.next_token(
+            |shortcode| {
+                data.get_by_shortcode(shortcode).or_else(|| {
+                    let (base, tone) = crate::split_slack_skin_tone(shortcode)?;
+                    data.with_skin_tone(data.get_by_shortcode(base)?, tone)
+                })
+            },
+            |index, tone| data.with_skin_tone(index, tone),
+        )
+    }
+}
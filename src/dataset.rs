@@ -0,0 +1,486 @@
+//! Emoji datasets loaded at runtime.
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::fmt;
+use std::string::String;
+use std::vec::Vec;
+
+use crate::data::{Builtin, EmojiData};
+use crate::{Group, SkinTone, Subgroup, UnicodeVersion};
+
+/// The bytes at the start of every encoded dataset.
+const MAGIC: &[u8; 4] = b"EMDS";
+
+/// The current layout version.
+const FORMAT_VERSION: u8 = 1;
+
+/// The skin tones in the order they are encoded.
+const SKIN_TONES: &[SkinTone] = &[
+    SkinTone::Default,
+    SkinTone::Light,
+    SkinTone::MediumLight,
+    SkinTone::Medium,
+    SkinTone::MediumDark,
+    SkinTone::Dark,
+];
+
+/// A single emoji in a [`Dataset`].
+///
+/// The group, subgroup, and skin tone are stored as encoded so that values
+/// from a newer version of this crate survive a round trip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    emoji: String,
+    name: String,
+    unicode_version: UnicodeVersion,
+    group: u64,
+    subgroup: u64,
+    skin_tone: Option<(u64, usize)>,
+    shortcodes: Vec<String>,
+    keywords: Vec<String>,
+}
+
+/// Returns the encoded form of an optional value, zero for `None` and one
+/// more than the position of the value in `all` otherwise.
+fn encode<T: PartialEq>(value: Option<T>, all: &[T]) -> u64 {
+    value
+        .and_then(|v| all.iter().position(|a| *a == v))
+        .map_or(0, |i| i as u64 + 1)
+}
+
+/// Returns the value for an encoded optional value, `None` if it is zero or
+/// not known to this crate.
+fn decode<T: Copy>(raw: u64, all: &[T]) -> Option<T> {
+    let i = usize::try_from(raw.checked_sub(1)?).ok()?;
+    all.get(i).copied()
+}
+
+/// Returns the entries for the emojis in a dataset that have a name and a
+/// Unicode version.
+fn entries<D>(data: &D) -> Vec<Entry>
+where
+    D: EmojiData + ?Sized,
+{
+    let kept: Vec<usize> = (0..data.len())
+        .filter(|&i| {
+            data.as_str(i).is_some() && data.name(i).is_some() && data.unicode_version(i).is_some()
+        })
+        .collect();
+    kept.iter()
+        .filter_map(|&i| {
+            let name = data.name(i)?;
+            let skin_tone = data.skin_tone(i).and_then(|tone| {
+                let base = data.with_skin_tone(i, SkinTone::Default)?;
+                let base = kept.binary_search(&base).ok()?;
+                Some((encode(Some(tone), SKIN_TONES), base))
+            });
+            Some(Entry {
+                emoji: data.as_str(i)?.into(),
+                name: name.into(),
+                unicode_version: data.unicode_version(i)?,
+                group: encode(data.group(i), Group::ALL),
+                subgroup: encode(data.subgroup(i), Subgroup::ALL),
+                skin_tone,
+                shortcodes: data.shortcode(i).into_iter().map(String::from).collect(),
+                keywords: name
+                    .split_whitespace()
+                    .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+                    .filter(|w| !w.is_empty())
+                    .map(str::to_lowercase)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// An emoji dataset loaded at runtime.
+///
+/// This allows long-lived services to pick up a new Unicode version by
+/// shipping a data file instead of recompiling. The dataset implements
+/// [`EmojiData`] so it can be used with the algorithms in the
+/// [`data`][crate::data] module.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::data::{self, EmojiData};
+/// use emojis::Dataset;
+///
+/// // e.g. `std::fs::read("emojis.bin")?` at startup
+/// let bytes = Dataset::builtin().to_bytes();
+///
+/// let dataset = Dataset::from_bytes(&bytes)?;
+/// let (_, index) = data::matches(&dataset, "launch 🚀").next().unwrap();
+/// assert_eq!(dataset.name(index), Some("rocket"));
+/// # Ok::<(), emojis::DecodeDatasetError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dataset {
+    entries: Vec<Entry>,
+    by_emoji: HashMap<String, usize>,
+    by_shortcode: HashMap<String, usize>,
+    by_keyword: BTreeMap<String, Vec<usize>>,
+    by_skin_tone: HashMap<(usize, u64), usize>,
+    max_len: usize,
+}
+
+impl Dataset {
+    fn new(entries: Vec<Entry>) -> Self {
+        let mut by_emoji = HashMap::with_capacity(entries.len());
+        let mut by_shortcode = HashMap::new();
+        let mut by_keyword = BTreeMap::<_, Vec<_>>::new();
+        let mut by_skin_tone = HashMap::new();
+        for (i, entry) in entries.iter().enumerate() {
+            by_emoji.entry(entry.emoji.clone()).or_insert(i);
+            for shortcode in &entry.shortcodes {
+                by_shortcode.entry(shortcode.clone()).or_insert(i);
+            }
+            for keyword in &entry.keywords {
+                by_keyword
+                    .entry(keyword.to_ascii_lowercase())
+                    .or_default()
+                    .push(i);
+            }
+            if let Some((tone, base)) = entry.skin_tone {
+                by_skin_tone.entry((base, tone)).or_insert(i);
+            }
+        }
+        let max_len = entries.iter().map(|e| e.emoji.len()).max().unwrap_or(0);
+        Self {
+            entries,
+            by_emoji,
+            by_shortcode,
+            by_keyword,
+            by_skin_tone,
+            max_len,
+        }
+    }
+
+    /// Returns a copy of the dataset generated into this crate, including
+    /// every shortcode and keyword.
+    ///
+    /// This is intended for writing a data file with
+    /// [`to_bytes()`][Self::to_bytes] that is later edited or replaced.
+    pub fn builtin() -> Self {
+        let mut entries = entries(&Builtin);
+        for (entry, emoji) in entries.iter_mut().zip(crate::gen::EMOJIS) {
+            entry.shortcodes = emoji.shortcodes().map(String::from).collect();
+            entry.keywords.clear();
+        }
+        for (keyword, emojis) in crate::gen::KEYWORDS {
+            for emoji in emojis.iter() {
+                entries[emoji.index()].keywords.push(String::from(*keyword));
+            }
+        }
+        Self::new(entries)
+    }
+
+    /// Copies another dataset.
+    ///
+    /// Only the primary shortcode of each emoji is available through
+    /// [`EmojiData`] so other shortcodes are not copied, and the keywords of
+    /// each emoji are the words in its name. Emojis without a name or Unicode
+    /// version are skipped.
+    pub fn from_data<D>(data: &D) -> Self
+    where
+        D: EmojiData + ?Sized,
+    {
+        Self::new(entries(data))
+    }
+
+    /// Returns an iterator over the shortcodes of the emoji at the given
+    /// index.
+    pub fn shortcodes(&self, index: usize) -> impl Iterator<Item = &str> {
+        self.entries
+            .get(index)
+            .into_iter()
+            .flat_map(|e| e.shortcodes.iter().map(String::as_str))
+    }
+
+    /// Returns an iterator over the keywords of the emoji at the given index.
+    pub fn keywords(&self, index: usize) -> impl Iterator<Item = &str> {
+        self.entries
+            .get(index)
+            .into_iter()
+            .flat_map(|e| e.keywords.iter().map(String::as_str))
+    }
+
+    /// Returns the dataset encoded as bytes.
+    ///
+    /// The layout is versioned so that [`from_bytes()`][Self::from_bytes]
+    /// can reject data it does not understand. Every integer is unsigned
+    /// LEB128, i.e. seven bits per byte with the high bit set on all but the
+    /// last byte, so nothing is ever truncated:
+    ///
+    /// - bytes 0..4 are the magic bytes `EMDS`.
+    /// - byte 4 is the layout version, currently `1`.
+    /// - then the number of emojis, and for each emoji:
+    ///   - the emoji and its name as strings.
+    ///   - the major and minor Unicode version.
+    ///   - the group and subgroup, zero for none and otherwise one more than
+    ///     the index in [`Group::ALL`] or [`Subgroup::ALL`].
+    ///   - the skin tone, zero for none and otherwise one more than the index
+    ///     in `Default`, `Light`, `MediumLight`, `Medium`, `MediumDark`,
+    ///     `Dark`, followed by the index of the default skin tone emoji if
+    ///     not zero.
+    ///   - the number of shortcodes and the shortcodes as strings.
+    ///   - the number of keywords and the keywords as strings.
+    ///
+    /// Each string is its length in bytes followed by the UTF-8 bytes.
+    /// Groups, subgroups, and skin tones not known to this crate are kept as
+    /// is.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        fn put_int(out: &mut Vec<u8>, mut n: u64) {
+            while n >= 0x80 {
+                out.push(n as u8 | 0x80);
+                n >>= 7;
+            }
+            out.push(n as u8);
+        }
+
+        fn put_str(out: &mut Vec<u8>, s: &str) {
+            put_int(out, s.len() as u64);
+            out.extend_from_slice(s.as_bytes());
+        }
+
+        let mut out = Vec::new();
+        out.extend_from_slice(MAGIC);
+        out.push(FORMAT_VERSION);
+        put_int(&mut out, self.entries.len() as u64);
+        for entry in &self.entries {
+            put_str(&mut out, &entry.emoji);
+            put_str(&mut out, &entry.name);
+            put_int(&mut out, entry.unicode_version.major().into());
+            put_int(&mut out, entry.unicode_version.minor().into());
+            put_int(&mut out, entry.group);
+            put_int(&mut out, entry.subgroup);
+            match entry.skin_tone {
+                Some((tone, base)) => {
+                    put_int(&mut out, tone);
+                    put_int(&mut out, base as u64);
+                }
+                None => put_int(&mut out, 0),
+            }
+            for strings in [&entry.shortcodes, &entry.keywords] {
+                put_int(&mut out, strings.len() as u64);
+                for s in strings {
+                    put_str(&mut out, s);
+                }
+            }
+        }
+        out
+    }
+
+    /// Decodes a dataset from the bytes returned by
+    /// [`to_bytes()`][Self::to_bytes].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeDatasetError> {
+        let mut r = Reader { bytes, pos: 0 };
+        if r.take(MAGIC.len())? != MAGIC {
+            return Err(DecodeDatasetError::InvalidMagic);
+        }
+        let version = r.take(1)?[0];
+        if version != FORMAT_VERSION {
+            return Err(DecodeDatasetError::UnsupportedVersion(version));
+        }
+        let count = r.usize()?;
+        // Every entry takes at least 9 bytes so untrusted counts can not
+        // cause a huge allocation.
+        let mut entries = Vec::with_capacity(count.min(bytes.len() / 9));
+        for _ in 0..count {
+            let emoji = r.string()?;
+            let name = r.string()?;
+            let major = r.u32()?;
+            let minor = r.u32()?;
+            let group = r.int()?;
+            let subgroup = r.int()?;
+            let skin_tone = match r.int()? {
+                0 => None,
+                tone => match r.usize()? {
+                    base if base < count => Some((tone, base)),
+                    _ => return Err(DecodeDatasetError::InvalidIndex),
+                },
+            };
+            let shortcodes = r.strings()?;
+            let keywords = r.strings()?;
+            entries.push(Entry {
+                emoji,
+                name,
+                unicode_version: UnicodeVersion::new(major, minor),
+                group,
+                subgroup,
+                skin_tone,
+                shortcodes,
+                keywords,
+            });
+        }
+        if r.pos != bytes.len() {
+            return Err(DecodeDatasetError::TrailingBytes);
+        }
+        Ok(Self::new(entries))
+    }
+}
+
+/// A cursor over the bytes of an encoded dataset.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeDatasetError> {
+        let bytes = self
+            .bytes
+            .get(self.pos..)
+            .and_then(|rest| rest.get(..n))
+            .ok_or(DecodeDatasetError::UnexpectedEnd)?;
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    fn int(&mut self) -> Result<u64, DecodeDatasetError> {
+        let mut n = 0u64;
+        for shift in (0..64).step_by(7) {
+            let b = self.take(1)?[0];
+            let bits = u64::from(b & 0x7f);
+            if bits << shift >> shift != bits {
+                return Err(DecodeDatasetError::InvalidInteger);
+            }
+            n |= bits << shift;
+            if b & 0x80 == 0 {
+                return Ok(n);
+            }
+        }
+        Err(DecodeDatasetError::InvalidInteger)
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeDatasetError> {
+        u32::try_from(self.int()?).map_err(|_| DecodeDatasetError::InvalidInteger)
+    }
+
+    fn usize(&mut self) -> Result<usize, DecodeDatasetError> {
+        usize::try_from(self.int()?).map_err(|_| DecodeDatasetError::InvalidInteger)
+    }
+
+    fn string(&mut self) -> Result<String, DecodeDatasetError> {
+        let len = self.usize()?;
+        let bytes = self.take(len)?;
+        std::str::from_utf8(bytes)
+            .map(String::from)
+            .map_err(|_| DecodeDatasetError::InvalidUtf8)
+    }
+
+    fn strings(&mut self) -> Result<Vec<String>, DecodeDatasetError> {
+        let n = self.usize()?;
+        (0..n).map(|_| self.string()).collect()
+    }
+}
+
+impl EmojiData for Dataset {
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn as_str(&self, index: usize) -> Option<&str> {
+        self.entries.get(index).map(|e| e.emoji.as_str())
+    }
+
+    fn name(&self, index: usize) -> Option<&str> {
+        self.entries.get(index).map(|e| e.name.as_str())
+    }
+
+    fn unicode_version(&self, index: usize) -> Option<UnicodeVersion> {
+        self.entries.get(index).map(|e| e.unicode_version)
+    }
+
+    fn group(&self, index: usize) -> Option<Group> {
+        decode(self.entries.get(index)?.group, Group::ALL)
+    }
+
+    fn subgroup(&self, index: usize) -> Option<Subgroup> {
+        decode(self.entries.get(index)?.subgroup, Subgroup::ALL)
+    }
+
+    fn skin_tone(&self, index: usize) -> Option<SkinTone> {
+        decode(self.entries.get(index)?.skin_tone?.0, SKIN_TONES)
+    }
+
+    fn with_skin_tone(&self, index: usize, skin_tone: SkinTone) -> Option<usize> {
+        let (_, base) = self.entries.get(index)?.skin_tone?;
+        let tone = encode(Some(skin_tone), SKIN_TONES);
+        self.by_skin_tone.get(&(base, tone)).copied()
+    }
+
+    fn shortcode(&self, index: usize) -> Option<&str> {
+        self.shortcodes(index).next()
+    }
+
+    fn get(&self, emoji: &str) -> Option<usize> {
+        self.by_emoji.get(emoji).copied()
+    }
+
+    fn get_by_shortcode(&self, shortcode: &str) -> Option<usize> {
+        self.by_shortcode.get(shortcode).copied()
+    }
+
+    fn find_keyword(&self, keyword: &str, prefix: bool, f: &mut dyn FnMut(usize)) {
+        let keyword = keyword.to_ascii_lowercase();
+        let indexes = self
+            .by_keyword
+            .range(keyword.clone()..)
+            .take_while(|(k, _)| {
+                if prefix {
+                    k.starts_with(&keyword)
+                } else {
+                    **k == keyword
+                }
+            })
+            .flat_map(|(_, indexes)| indexes);
+        for &i in indexes {
+            f(i);
+        }
+    }
+
+    fn max_len(&self) -> usize {
+        self.max_len
+    }
+}
+
+/// An error returned by [`Dataset::from_bytes()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodeDatasetError {
+    /// The bytes do not start with the dataset magic bytes.
+    InvalidMagic,
+    /// The bytes use a layout version not supported by this crate.
+    UnsupportedVersion(u8),
+    /// The bytes end in the middle of the dataset.
+    UnexpectedEnd,
+    /// There are bytes after the end of the dataset.
+    TrailingBytes,
+    /// A string is not valid UTF-8.
+    InvalidUtf8,
+    /// An integer is too large.
+    InvalidInteger,
+    /// An emoji refers to an emoji that is not in the dataset.
+    InvalidIndex,
+}
+
+impl fmt::Display for DecodeDatasetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => f.write_str("not an emoji dataset"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported emoji dataset version `{}`", v),
+            Self::UnexpectedEnd => f.write_str("unexpected end of emoji dataset"),
+            Self::TrailingBytes => f.write_str("trailing bytes after emoji dataset"),
+            Self::InvalidUtf8 => f.write_str("emoji dataset contains invalid UTF-8"),
+            Self::InvalidInteger => {
+                f.write_str("emoji dataset contains an integer that is too large")
+            }
+            Self::InvalidIndex => {
+                f.write_str("emoji dataset refers to an emoji that does not exist")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeDatasetError {}
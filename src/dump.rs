@@ -0,0 +1,93 @@
+//! Exporting all emoji data at once.
+
+use crate::{Emoji, EmojiId, Group, SkinTone, Subgroup, UnicodeVersion};
+
+/// All the data for a single emoji.
+///
+/// Yielded by [`dump()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Record {
+    /// The id of the emoji, see [`Emoji::id()`].
+    pub id: EmojiId,
+    /// The fully-qualified emoji, see [`Emoji::as_str()`].
+    pub emoji: &'static str,
+    /// The CLDR short name, see [`Emoji::name()`].
+    pub name: &'static str,
+    /// The CLDR text-to-speech annotation, see [`Emoji::tts()`].
+    pub tts: &'static str,
+    /// The group, see [`Emoji::group()`].
+    pub group: Group,
+    /// The subgroup, see [`Subgroup::emojis()`].
+    pub subgroup: Subgroup,
+    /// The Unicode version, see [`Emoji::unicode_version()`].
+    pub unicode_version: UnicodeVersion,
+    /// The GitHub shortcodes, see [`Emoji::shortcodes()`].
+    pub shortcodes: &'static [&'static str],
+    /// The skin tone, see [`Emoji::skin_tone()`].
+    pub skin_tone: Option<SkinTone>,
+    /// The id of the default skin tone version if the emoji supports skin
+    /// tones.
+    pub skin_tone_base: Option<EmojiId>,
+    /// The id of the gender-neutral form, see [`Emoji::neutral()`].
+    pub neutral: EmojiId,
+}
+
+impl Record {
+    fn new(emoji: &'static Emoji, subgroup: Subgroup) -> Self {
+        Self {
+            id: emoji.id(),
+            emoji: emoji.as_str(),
+            name: emoji.name(),
+            tts: emoji.tts(),
+            group: emoji.group(),
+            subgroup,
+            unicode_version: emoji.unicode_version(),
+            shortcodes: emoji.shortcode_slice(),
+            skin_tone: emoji.skin_tone(),
+            skin_tone_base: emoji.skin_tones().and_then(|mut t| t.next()).map(Emoji::id),
+            neutral: emoji.neutral().id(),
+        }
+    }
+
+    /// Returns the emoji for this record.
+    pub fn to_emoji(&self) -> &'static Emoji {
+        self.id.emoji()
+    }
+
+    /// Returns an iterator over the unqualified and minimally-qualified
+    /// variations, see [`Emoji::variations()`].
+    pub fn variations(&self) -> impl Iterator<Item = &'static str> {
+        self.to_emoji().variations()
+    }
+}
+
+/// Returns an iterator over the data for every emoji.
+///
+/// Unlike [`iter()`][crate::iter] this includes all skin tone variations,
+/// which directly follow their default skin tone emoji. This is intended for
+/// exporting the data losslessly, e.g. to a database.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{Group, Subgroup};
+///
+/// let record = emojis::dump().find(|r| r.emoji == "👍🏽").unwrap();
+/// assert_eq!(record.name, "thumbs up: medium skin tone");
+/// assert_eq!(record.group, Group::PeopleAndBody);
+/// assert_eq!(record.subgroup, Subgroup::HandFingersClosed);
+/// assert_eq!(record.shortcodes, [] as [&str; 0]);
+/// assert_eq!(record.skin_tone_base.unwrap().emoji(), "👍");
+/// ```
+pub fn dump() -> impl Iterator<Item = Record> {
+    Subgroup::iter().flat_map(|subgroup| {
+        subgroup.emojis().iter().flat_map(move |&base| {
+            let n = if base.skin_tone.is_some() { 6 } else { 1 };
+            crate::gen::EMOJIS[base.index()..]
+                .iter()
+                .take(n)
+                .map(move |emoji| Record::new(emoji, subgroup))
+        })
+    })
+}
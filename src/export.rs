@@ -0,0 +1,311 @@
+//! Exporting emoji data as delimited text or SQL.
+//!
+//! The delimited text functions write a header row with the column names
+//! followed by one row per emoji in [`dump()`][crate::dump] order, which
+//! includes all skin tone variations.
+
+use std::format;
+use std::io;
+use std::io::Write;
+use std::string::String;
+#[cfg(feature = "rusqlite")]
+use std::vec::Vec;
+
+use crate::{Group, Record, Subgroup};
+
+/// A column of exported emoji data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Column {
+    /// The id of the emoji, see [`Emoji::id()`][crate::Emoji::id].
+    Id,
+    /// The emoji itself, e.g. `🚀`.
+    Emoji,
+    /// The CLDR short name, e.g. `rocket`.
+    Name,
+    /// The CLDR text-to-speech annotation.
+    Tts,
+    /// The group, e.g. `TravelAndPlaces`.
+    Group,
+    /// The subgroup, e.g. `TransportAir`.
+    Subgroup,
+    /// The Unicode version, e.g. `0.6`.
+    UnicodeVersion,
+    /// The GitHub shortcodes separated by spaces, e.g. `rocket`.
+    Shortcodes,
+    /// The skin tone, e.g. `Medium`, or empty if skin tones are not
+    /// applicable.
+    SkinTone,
+}
+
+impl Column {
+    /// Returns the name of the column used in the header row.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Emoji => "emoji",
+            Self::Name => "name",
+            Self::Tts => "tts",
+            Self::Group => "group",
+            Self::Subgroup => "subgroup",
+            Self::UnicodeVersion => "unicode_version",
+            Self::Shortcodes => "shortcodes",
+            Self::SkinTone => "skin_tone",
+        }
+    }
+
+    fn write<W: Write>(
+        self,
+        w: &mut W,
+        record: &Record,
+        escape: fn(&str) -> bool,
+    ) -> io::Result<()> {
+        match self {
+            Self::Id => write!(w, "{}", record.id.get()),
+            Self::Emoji => write_field(w, record.emoji, escape),
+            Self::Name => write_field(w, record.name, escape),
+            Self::Tts => write_field(w, record.tts, escape),
+            Self::Group => write!(w, "{:?}", record.group),
+            Self::Subgroup => write!(w, "{:?}", record.subgroup),
+            Self::UnicodeVersion => {
+                let v = record.unicode_version;
+                write!(w, "{}.{}", v.major(), v.minor())
+            }
+            Self::Shortcodes => write_field(w, &record.shortcodes.join(" "), escape),
+            Self::SkinTone => match record.skin_tone {
+                Some(tone) => write!(w, "{:?}", tone),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+/// Writes the field, quoting it if necessary.
+fn write_field<W: Write>(w: &mut W, field: &str, quote: fn(&str) -> bool) -> io::Result<()> {
+    if quote(field) {
+        write!(w, "\"{}\"", field.replace('"', "\"\""))
+    } else {
+        w.write_all(field.as_bytes())
+    }
+}
+
+fn write_delimited<W: Write>(
+    mut w: W,
+    columns: &[Column],
+    delimiter: u8,
+    quote: fn(&str) -> bool,
+) -> io::Result<()> {
+    for (i, column) in columns.iter().enumerate() {
+        if i > 0 {
+            w.write_all(&[delimiter])?;
+        }
+        w.write_all(column.name().as_bytes())?;
+    }
+    w.write_all(b"\n")?;
+    for record in crate::dump() {
+        for (i, column) in columns.iter().enumerate() {
+            if i > 0 {
+                w.write_all(&[delimiter])?;
+            }
+            column.write(&mut w, &record, quote)?;
+        }
+        w.write_all(b"\n")?;
+    }
+    w.flush()
+}
+
+/// Writes the given columns for every emoji as comma-separated values.
+///
+/// Fields are quoted as described in [RFC 4180] when they contain a comma or
+/// a double quote.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::export::{to_csv, Column};
+///
+/// let mut buf = Vec::new();
+/// to_csv(&mut buf, &[Column::Emoji, Column::Name, Column::Group])?;
+/// let csv = String::from_utf8(buf).unwrap();
+/// let mut lines = csv.lines();
+/// assert_eq!(lines.next(), Some("emoji,name,group"));
+/// assert_eq!(lines.next(), Some("😀,grinning face,SmileysAndEmotion"));
+/// assert!(csv.contains("\n👩‍❤️‍💋‍👨,\"kiss: woman, man\",PeopleAndBody\n"));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// [RFC 4180]: https://www.rfc-editor.org/rfc/rfc4180
+pub fn to_csv<W: Write>(w: W, columns: &[Column]) -> io::Result<()> {
+    write_delimited(w, columns, b',', |field| field.contains([',', '"']))
+}
+
+/// Writes the given columns for every emoji as tab-separated values.
+///
+/// No field contains a tab or newline so fields are never quoted.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::export::{to_tsv, Column};
+///
+/// let mut buf = Vec::new();
+/// to_tsv(&mut buf, &[Column::Emoji, Column::Shortcodes])?;
+/// let tsv = String::from_utf8(buf).unwrap();
+/// assert!(tsv.starts_with("emoji\tshortcodes\n😀\tgrinning\n"));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn to_tsv<W: Write>(w: W, columns: &[Column]) -> io::Result<()> {
+    write_delimited(w, columns, b'\t', |_| false)
+}
+
+/// The schema written by [`to_sql()`].
+const SCHEMA: &str = "\
+CREATE TABLE groups (id INTEGER PRIMARY KEY, name TEXT NOT NULL UNIQUE);
+CREATE TABLE subgroups (
+    id INTEGER PRIMARY KEY,
+    group_id INTEGER NOT NULL REFERENCES groups (id),
+    name TEXT NOT NULL UNIQUE
+);
+CREATE TABLE emojis (
+    id INTEGER PRIMARY KEY,
+    emoji TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    tts TEXT NOT NULL,
+    subgroup_id INTEGER NOT NULL REFERENCES subgroups (id),
+    unicode_version TEXT NOT NULL,
+    skin_tone TEXT,
+    skin_tone_base_id INTEGER REFERENCES emojis (id)
+);
+CREATE TABLE shortcodes (
+    shortcode TEXT PRIMARY KEY,
+    emoji_id INTEGER NOT NULL REFERENCES emojis (id)
+);
+CREATE TABLE keywords (
+    keyword TEXT NOT NULL,
+    emoji_id INTEGER NOT NULL REFERENCES emojis (id),
+    PRIMARY KEY (keyword, emoji_id)
+);
+";
+
+/// Writes SQL statements that create and populate normalized tables of the
+/// emoji data.
+///
+/// The script creates the `groups`, `subgroups`, `emojis`, `shortcodes`, and
+/// `keywords` tables and is compatible with SQLite, so a database can be
+/// bootstrapped by executing it, see also [`to_sqlite()`] with the **rusqlite**
+/// feature.
+/// The statements are wrapped in a transaction. Ids in the `emojis` table are
+/// [`EmojiId`][crate::EmojiId]s.
+///
+/// # Examples
+///
+/// ```
+/// let mut buf = Vec::new();
+/// emojis::export::to_sql(&mut buf)?;
+/// let sql = String::from_utf8(buf).unwrap();
+/// assert!(sql.starts_with("BEGIN;\nCREATE TABLE groups"));
+/// assert!(sql.contains("INSERT INTO shortcodes VALUES ('rocket', "));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn to_sql<W: Write>(mut w: W) -> io::Result<()> {
+    writeln!(w, "BEGIN;")?;
+    w.write_all(SCHEMA.as_bytes())?;
+    for (i, group) in Group::iter().enumerate() {
+        writeln!(w, "INSERT INTO groups VALUES ({}, '{:?}');", i, group)?;
+    }
+    for (i, subgroup) in Subgroup::iter().enumerate() {
+        let group = Group::iter()
+            .position(|g| g == subgroup.group())
+            .unwrap_or(0);
+        writeln!(
+            w,
+            "INSERT INTO subgroups VALUES ({}, {}, '{:?}');",
+            i, group, subgroup
+        )?;
+    }
+    for record in crate::dump() {
+        let subgroup = Subgroup::iter()
+            .position(|s| s == record.subgroup)
+            .unwrap_or(0);
+        let v = record.unicode_version;
+        write!(
+            w,
+            "INSERT INTO emojis VALUES ({}, {}, {}, {}, {}, '{}.{}', ",
+            record.id.get(),
+            sql_str(record.emoji),
+            sql_str(record.name),
+            sql_str(record.tts),
+            subgroup,
+            v.major(),
+            v.minor(),
+        )?;
+        match record.skin_tone {
+            Some(tone) => write!(w, "'{:?}', ", tone)?,
+            None => write!(w, "NULL, ")?,
+        }
+        match record.skin_tone_base {
+            Some(id) => writeln!(w, "{});", id.get())?,
+            None => writeln!(w, "NULL);")?,
+        }
+        for shortcode in record.shortcodes {
+            writeln!(
+                w,
+                "INSERT INTO shortcodes VALUES ({}, {});",
+                sql_str(shortcode),
+                record.id.get()
+            )?;
+        }
+    }
+    for (keyword, emojis) in crate::gen::KEYWORDS {
+        for emoji in *emojis {
+            writeln!(
+                w,
+                "INSERT INTO keywords VALUES ({}, {});",
+                sql_str(keyword),
+                emoji.id().get()
+            )?;
+        }
+    }
+    writeln!(w, "COMMIT;")?;
+    w.flush()
+}
+
+/// Creates and populates normalized tables of the emoji data in the given
+/// SQLite database.
+///
+/// This executes the script written by [`to_sql()`], so the tables and the
+/// transaction are the same. If a statement fails, e.g. because the tables
+/// already exist, the transaction is rolled back and the error is returned.
+///
+/// # Examples
+///
+/// ```
+/// let conn = rusqlite::Connection::open_in_memory()?;
+/// emojis::export::to_sqlite(&conn)?;
+/// let name: String = conn.query_row(
+///     "SELECT name FROM emojis JOIN shortcodes ON emoji_id = id WHERE shortcode = 'rocket'",
+///     [],
+///     |row| row.get(0),
+/// )?;
+/// assert_eq!(name, "rocket");
+/// # Ok::<(), rusqlite::Error>(())
+/// ```
+#[cfg(feature = "rusqlite")]
+pub fn to_sqlite(conn: &rusqlite::Connection) -> rusqlite::Result<()> {
+    let mut buf = Vec::new();
+    // Writing to a `Vec` never fails and the script is valid UTF-8 so the
+    // fallbacks are never used.
+    to_sql(&mut buf).ok();
+    let autocommit = conn.is_autocommit();
+    let result = conn.execute_batch(core::str::from_utf8(&buf).unwrap_or(""));
+    if result.is_err() && autocommit && !conn.is_autocommit() {
+        // The error is more useful than a failure to roll back.
+        conn.execute_batch("ROLLBACK").ok();
+    }
+    result
+}
+
+/// Returns the string as a SQL string literal.
+fn sql_str(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
@@ -0,0 +1,85 @@
+//! An extension trait for emoji operations on strings.
+
+use crate::text::Matches;
+use crate::{Emoji, Replaced};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for str {}
+}
+
+/// An extension trait that adds emoji methods to [`str`].
+///
+/// Each method is a shorthand for a function elsewhere in this crate. This
+/// trait is sealed and can not be implemented outside of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::EmojiStrExt;
+///
+/// assert!("launch 🚀".contains_emoji());
+/// assert_eq!("launch :rocket:".expand_shortcodes().to_string(), "launch 🚀");
+/// assert_eq!("launch 🚀".strip_emojis().to_string(), "launch ");
+/// ```
+pub trait EmojiStrExt: private::Sealed {
+    /// Returns whether the string contains an emoji.
+    fn contains_emoji(&self) -> bool;
+
+    /// Returns an iterator over the emojis in the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::EmojiStrExt;
+    ///
+    /// let found: Vec<_> = "a 👍🏽 b 🚀".emojis().collect();
+    /// assert_eq!(found, ["👍🏽", "🚀"]);
+    /// ```
+    fn emojis(&self) -> TextEmojis<'_>;
+
+    /// Returns a value that writes the string with all emojis removed, see
+    /// [`Replaced::strip_emojis()`].
+    fn strip_emojis(&self) -> Replaced<'_>;
+
+    /// Returns a value that writes the string with GitHub shortcodes replaced
+    /// with emojis, see [`Replaced::expand_shortcodes()`].
+    fn expand_shortcodes(&self) -> Replaced<'_>;
+}
+
+impl EmojiStrExt for str {
+    fn contains_emoji(&self) -> bool {
+        crate::first(self).is_some()
+    }
+
+    fn emojis(&self) -> TextEmojis<'_> {
+        TextEmojis {
+            matches: Matches::new(self),
+        }
+    }
+
+    fn strip_emojis(&self) -> Replaced<'_> {
+        Replaced::strip_emojis(self)
+    }
+
+    fn expand_shortcodes(&self) -> Replaced<'_> {
+        Replaced::expand_shortcodes(self)
+    }
+}
+
+/// An iterator over the emojis in a string.
+///
+/// Returned by [`EmojiStrExt::emojis()`].
+#[derive(Debug, Clone)]
+pub struct TextEmojis<'a> {
+    matches: Matches<'a>,
+}
+
+impl Iterator for TextEmojis<'_> {
+    type Item = &'static Emoji;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.matches.next().map(|(_, emoji)| emoji)
+    }
+}
@@ -0,0 +1,106 @@
+//! Filtering emojis out of text.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use crate::text::Matches;
+use crate::{Emoji, EmojiSet};
+
+/// What a [`Filter`] does with the emojis it matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterPolicy {
+    /// Remove the emoji.
+    Remove,
+    /// Replace the emoji with its [name][Emoji::name].
+    ReplaceWithName,
+    /// Replace the emoji with its [shortcode][Emoji::shortcode] surrounded by
+    /// colons, e.g. `:rocket:`. Emojis without a shortcode are replaced with
+    /// their name.
+    ReplaceWithShortcode,
+    /// Replace the emoji with the given character.
+    ReplaceWith(char),
+}
+
+/// A filter that applies a policy to every emoji in a set.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{EmojiSet, Filter, FilterPolicy};
+///
+/// let deny: EmojiSet = ["🍆", "🍑"].iter().filter_map(|s| emojis::get(s)).collect();
+/// let filter = Filter::new(deny, FilterPolicy::ReplaceWith('*'));
+/// assert_eq!(filter.apply("nice 🍑 and 🚀"), "nice * and 🚀");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Filter {
+    set: EmojiSet,
+    policy: FilterPolicy,
+}
+
+impl Filter {
+    /// Construct a new filter that applies the policy to the emojis in the
+    /// given set.
+    ///
+    /// Emojis with a skin tone are only matched if that exact skin tone is in
+    /// the set.
+    pub fn new(set: EmojiSet, policy: FilterPolicy) -> Self {
+        Self { set, policy }
+    }
+
+    /// Returns the set of emojis this filter matches.
+    pub fn set(&self) -> &EmojiSet {
+        &self.set
+    }
+
+    /// Returns the policy this filter applies.
+    pub fn policy(&self) -> FilterPolicy {
+        self.policy
+    }
+
+    /// Applies this filter to the text in a single pass.
+    ///
+    /// If no emojis are matched the text is returned unchanged. This takes
+    /// *Ο(n)* time in the length of the text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{EmojiSet, Filter, FilterPolicy};
+    ///
+    /// let deny: EmojiSet = emojis::get("🚀").into_iter().collect();
+    /// let filter = Filter::new(deny, FilterPolicy::ReplaceWithShortcode);
+    /// assert_eq!(filter.apply("launch 🚀"), "launch :rocket:");
+    /// ```
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut out = String::new();
+        let mut last = 0;
+        let matches = Matches::new(text).filter(|(_, emoji)| self.set.contains(emoji));
+        for (range, emoji) in matches {
+            out.push_str(&text[last..range.start]);
+            self.replace(&mut out, emoji);
+            last = range.end;
+        }
+        if last == 0 {
+            return Cow::Borrowed(text);
+        }
+        out.push_str(&text[last..]);
+        Cow::Owned(out)
+    }
+
+    fn replace(&self, out: &mut String, emoji: &Emoji) {
+        match self.policy {
+            FilterPolicy::Remove => {}
+            FilterPolicy::ReplaceWithName => out.push_str(emoji.name()),
+            FilterPolicy::ReplaceWithShortcode => match emoji.shortcode() {
+                Some(shortcode) => {
+                    out.push(':');
+                    out.push_str(shortcode);
+                    out.push(':');
+                }
+                None => out.push_str(emoji.name()),
+            },
+            FilterPolicy::ReplaceWith(c) => out.push(c),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Converting between letters and regional indicator flags.
+
+use core::fmt;
+use core::str::Chars;
+
+use crate::Emoji;
+
+const REGIONAL_INDICATOR_A: u32 = 0x1f1e6;
+const ZERO_WIDTH_SPACE: char = '\u{200b}';
+
+/// Returns the regional indicator symbol for the given ASCII letter.
+///
+/// The letter is case-insensitive. A regional indicator on its own is not an
+/// emoji, but two of them form a flag, see [`letters_to_flag()`].
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::regional_indicator('A'), Some('🇦'));
+/// assert_eq!(emojis::regional_indicator('z'), Some('🇿'));
+/// assert_eq!(emojis::regional_indicator('1'), None);
+/// ```
+pub fn regional_indicator(letter: char) -> Option<char> {
+    if !letter.is_ascii_alphabetic() {
+        return None;
+    }
+    let offset = letter.to_ascii_uppercase() as u32 - 'A' as u32;
+    char::from_u32(REGIONAL_INDICATOR_A + offset)
+}
+
+/// Returns the ASCII uppercase letter for the given regional indicator
+/// symbol.
+fn letter(c: char) -> Option<char> {
+    let offset = (c as u32).checked_sub(REGIONAL_INDICATOR_A)?;
+    if offset >= 26 {
+        return None;
+    }
+    char::from_u32('A' as u32 + offset)
+}
+
+/// Returns the flag emoji for the given two letter region code.
+///
+/// The code is case-insensitive. Returns `None` if the code is not two ASCII
+/// letters or there is no flag for the region.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::letters_to_flag("JP").unwrap(), "🇯🇵");
+/// assert_eq!(emojis::letters_to_flag("za").unwrap(), "🇿🇦");
+/// assert_eq!(emojis::letters_to_flag("XX"), None);
+/// ```
+pub fn letters_to_flag(code: &str) -> Option<&'static Emoji> {
+    let mut chars = code.chars();
+    let (a, b) = (chars.next()?, chars.next()?);
+    if chars.next().is_some() {
+        return None;
+    }
+    let mut buf = [0; 8];
+    let n = regional_indicator(a)?.encode_utf8(&mut buf).len();
+    let m = regional_indicator(b)?.encode_utf8(&mut buf[n..]).len();
+    crate::get(core::str::from_utf8(&buf[..n + m]).ok()?)
+}
+
+/// Returns the two ASCII uppercase letters of the given regional indicator
+/// pair.
+///
+/// This is the inverse of [`letters_to_flag()`], except that any pair of
+/// regional indicators is accepted even if it is not a known flag.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::flag_to_letters("🇯🇵"), Some(['J', 'P']));
+/// assert_eq!(emojis::flag_to_letters("🇽🇽"), Some(['X', 'X']));
+/// assert_eq!(emojis::flag_to_letters("🏴‍☠️"), None);
+/// ```
+pub fn flag_to_letters(flag: &str) -> Option<[char; 2]> {
+    let mut chars = flag.chars();
+    let letters = [letter(chars.next()?)?, letter(chars.next()?)?];
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(letters)
+}
+
+/// Returns an iterator over the characters of the text with ASCII letters
+/// replaced by regional indicator symbols.
+///
+/// All other characters are passed through unchanged. Adjacent regional
+/// indicators are displayed as a flag when they form a region code, e.g.
+/// spelling "US" would show 🇺🇸, so by default a zero width space is inserted
+/// between any two letters that would form a flag. Use
+/// [`flag_separator()`][Spell::flag_separator] to change the separator. The
+/// iterator also implements [`Display`][fmt::Display].
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::spell("OK").to_string(), "🇴🇰");
+/// assert_eq!(emojis::spell("us").to_string(), "🇺\u{200b}🇸");
+/// assert_eq!(emojis::spell("us").flag_separator(None).to_string(), "🇺🇸");
+/// assert_eq!(emojis::spell("Hi!").to_string(), "🇭🇮!");
+/// ```
+pub fn spell(text: &str) -> Spell<'_> {
+    Spell {
+        chars: text.chars(),
+        separator: Some(ZERO_WIDTH_SPACE),
+        open: None,
+        pending: None,
+    }
+}
+
+/// An iterator over text spelled with regional indicator symbols.
+///
+/// Returned by [`spell()`].
+#[derive(Debug, Clone)]
+pub struct Spell<'a> {
+    chars: Chars<'a>,
+    separator: Option<char>,
+    /// The letter of the previous regional indicator if it is the first of a
+    /// pair that is displayed together.
+    open: Option<char>,
+    /// A regional indicator to yield after a separator.
+    pending: Option<char>,
+}
+
+impl Spell<'_> {
+    /// Sets the character inserted between letters that would otherwise be
+    /// displayed as a flag, or `None` to never insert one.
+    ///
+    /// Defaults to a zero width space, `U+200B`.
+    pub fn flag_separator(mut self, separator: Option<char>) -> Self {
+        self.separator = separator;
+        self
+    }
+}
+
+/// Returns whether the two ASCII letters form a flag.
+fn is_flag(a: char, b: char) -> bool {
+    let mut buf = [0; 8];
+    let n = a.encode_utf8(&mut buf).len();
+    let m = b.encode_utf8(&mut buf[n..]).len();
+    core::str::from_utf8(&buf[..n + m]).map_or(false, |code| letters_to_flag(code).is_some())
+}
+
+impl Iterator for Spell<'_> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(c) = self.pending.take() {
+            return Some(c);
+        }
+        let c = self.chars.next()?;
+        let ri = match regional_indicator(c) {
+            Some(ri) => ri,
+            None => {
+                self.open = None;
+                return Some(c);
+            }
+        };
+        match (self.open.take(), self.separator) {
+            (Some(a), Some(separator)) if is_flag(a, c) => {
+                // The separator ends the pair, so this starts a new one.
+                self.open = Some(c);
+                self.pending = Some(ri);
+                Some(separator)
+            }
+            (Some(_), _) => Some(ri),
+            (None, _) => {
+                self.open = Some(c);
+                Some(ri)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Spell<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        for c in self.clone() {
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+//! Properties of this crate for use in fuzz and property tests.
+//!
+//! Each function checks a property that holds for any input and panics if it
+//! does not, so they can be called directly from a fuzz target or a property
+//! test.
+//!
+//! # Examples
+//!
+//! With [`cargo fuzz`](https://github.com/rust-fuzz/cargo-fuzz) a fuzz target
+//! that checks every property looks like this.
+//!
+//! ```ignore
+//! #![no_main]
+//!
+//! libfuzzer_sys::fuzz_target!(|text: &str| {
+//!     emojis::fuzz::check_all(text);
+//! });
+//! ```
+
+use crate::text::longest_prefix;
+
+/// Checks every property in this module.
+///
+/// # Panics
+///
+/// Panics if any property does not hold for the input.
+pub fn check_all(text: &str) {
+    lookup_roundtrip(text);
+    shortcode_roundtrip(text);
+    scanner_matches_are_maximal(text);
+}
+
+/// Checks that looking up the input and then looking up the emoji's
+/// [`as_str()`][crate::Emoji::as_str] returns the same emoji.
+///
+/// # Panics
+///
+/// Panics if the property does not hold for the input.
+pub fn lookup_roundtrip(text: &str) {
+    if let Some(emoji) = crate::get(text) {
+        assert_eq!(crate::get(emoji.as_str()), Some(emoji));
+        assert_eq!(crate::get_normalized(text), Some(emoji));
+        assert_eq!(emoji.id().emoji(), emoji);
+    }
+}
+
+/// Checks that if the input is a shortcode then every shortcode of the emoji
+/// resolves to the same emoji, and that the tokenizer resolves every token it
+/// yields to the emoji the shortcode resolves to.
+///
+/// # Panics
+///
+/// Panics if the property does not hold for the input.
+pub fn shortcode_roundtrip(text: &str) {
+    if let Some(emoji) = crate::get_by_shortcode(text) {
+        for shortcode in emoji.shortcodes() {
+            assert_eq!(crate::get_by_shortcode(shortcode), Some(emoji));
+        }
+    }
+    for token in crate::ShortcodeTokenizer::new(text) {
+        assert!(text[token.range()].contains(token.shortcode()));
+        if let Some(emoji) = token.emoji() {
+            assert_eq!(crate::get_by_shortcode(token.shortcode()), Some(emoji));
+        }
+    }
+}
+
+/// Checks that the emoji scanner yields non-overlapping matches in order,
+/// that each match is an emoji, and that each match is the longest emoji at
+/// its position so that an emoji sequence is never split.
+///
+/// # Panics
+///
+/// Panics if the property does not hold for the input.
+pub fn scanner_matches_are_maximal(text: &str) {
+    let mut last = 0;
+    for (start, s) in crate::pattern().find_in(text) {
+        assert!(start >= last);
+        let emoji = crate::get(s).expect("match is not an emoji");
+        let longest = longest_prefix(&text[start..]).map(|(n, _)| n);
+        assert_eq!(longest, Some(s.len()), "match {:?} is not maximal", emoji);
+        last = start + s.len();
+    }
+}
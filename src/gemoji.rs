@@ -0,0 +1,93 @@
+//! GitHub custom emojis that are not part of Unicode.
+//!
+//! GitHub supports a few shortcodes, such as `:octocat:` and `:shipit:`, that
+//! are rendered as images instead of Unicode emojis. These are the custom
+//! emojis defined by [gemoji].
+//!
+//! [gemoji]: https://github.com/github/gemoji
+
+macro_rules! custom {
+    ($($name:literal),* $(,)?) => {
+        &[$(CustomShortcode {
+            name: $name,
+            image_url: concat!("https://github.githubassets.com/images/icons/emoji/", $name, ".png"),
+        }),*]
+    };
+}
+
+/// A GitHub shortcode that is rendered as a custom image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomShortcode {
+    name: &'static str,
+    image_url: &'static str,
+}
+
+/// The custom emojis in alphabetical order.
+const CUSTOM: &[CustomShortcode] = custom![
+    "atom",
+    "basecamp",
+    "basecampy",
+    "bowtie",
+    "electron",
+    "feelsgood",
+    "finnadie",
+    "fishsticks",
+    "goberserk",
+    "godmode",
+    "hurtrealbad",
+    "neckbeard",
+    "octocat",
+    "rage1",
+    "rage2",
+    "rage3",
+    "rage4",
+    "shipit",
+    "suspect",
+    "trollface",
+];
+
+impl CustomShortcode {
+    /// Returns the shortcode, excluding the colons.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the URL of the image GitHub renders for this shortcode.
+    pub fn image_url(&self) -> &'static str {
+        self.image_url
+    }
+}
+
+/// Lookup a custom emoji by GitHub shortcode.
+///
+/// This only includes shortcodes that are not Unicode emojis, use
+/// [`get_by_shortcode()`][crate::get_by_shortcode] for the rest.
+///
+/// # Examples
+///
+/// ```
+/// let octocat = emojis::gemoji::get("octocat").unwrap();
+/// assert_eq!(
+///     octocat.image_url(),
+///     "https://github.githubassets.com/images/icons/emoji/octocat.png"
+/// );
+/// assert!(emojis::gemoji::get("rocket").is_none());
+/// ```
+pub fn get(shortcode: &str) -> Option<&'static CustomShortcode> {
+    CUSTOM
+        .binary_search_by(|custom| custom.name.cmp(shortcode))
+        .ok()
+        .map(|i| &CUSTOM[i])
+}
+
+/// Returns an iterator over all custom emojis in alphabetical order.
+///
+/// # Examples
+///
+/// ```
+/// let first = emojis::gemoji::iter().next().unwrap();
+/// assert_eq!(first.name(), "atom");
+/// ```
+pub fn iter() -> impl Iterator<Item = &'static CustomShortcode> {
+    CUSTOM.iter()
+}
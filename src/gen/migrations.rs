@@ -0,0 +1,2 @@
+pub const MIGRATIONS: &[(u64, &[u16])] = &[
+];
@@ -2276,3 +2276,2283 @@ pub static MAP: phf::Map<&'static str, usize> = ::phf::Map {
         ("clock6", 2463),
     ],
 };
+
+#[cfg(feature = "phf-map")]
+pub static EMOJI_MAP: phf::Map<&'static str, &'static crate::Emoji> = ::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 22),
+        (0, 43),
+        (0, 13),
+        (0, 24),
+        (0, 882),
+        (0, 193),
+        (0, 256),
+        (0, 3),
+        (0, 3),
+        (0, 34),
+        (0, 221),
+        (0, 479),
+        (0, 330),
+        (0, 37),
+        (0, 78),
+        (0, 1384),
+        (0, 122),
+        (0, 1),
+        (0, 0),
+        (0, 9),
+        (0, 623),
+        (0, 11),
+        (0, 174),
+        (0, 4),
+        (0, 87),
+        (0, 0),
+        (0, 3),
+        (0, 101),
+        (1, 651),
+        (0, 3),
+        (0, 71),
+        (0, 0),
+        (0, 239),
+        (0, 229),
+        (0, 303),
+        (0, 29),
+        (0, 3),
+        (0, 0),
+        (0, 67),
+        (0, 30),
+        (0, 276),
+        (0, 555),
+        (0, 1),
+        (0, 1518),
+        (0, 1),
+        (0, 288),
+        (0, 31),
+        (0, 2),
+        (0, 0),
+        (0, 34),
+        (0, 0),
+        (0, 38),
+        (0, 693),
+        (0, 1091),
+        (0, 46),
+        (0, 0),
+        (0, 0),
+        (0, 1),
+        (1, 334),
+        (1, 1368),
+        (0, 61),
+        (0, 1),
+        (0, 815),
+        (0, 1873),
+        (0, 8),
+        (0, 3),
+        (0, 28),
+        (0, 6),
+        (0, 0),
+        (0, 33),
+        (0, 2),
+        (0, 58),
+        (0, 0),
+        (0, 510),
+        (0, 78),
+        (0, 0),
+        (0, 9),
+        (0, 29),
+        (0, 172),
+        (0, 15),
+        (0, 144),
+        (0, 1118),
+        (0, 173),
+        (0, 28),
+        (0, 1),
+        (0, 21),
+        (0, 581),
+        (0, 226),
+        (0, 0),
+        (0, 13),
+        (0, 1002),
+        (0, 268),
+        (0, 19),
+        (0, 31),
+        (0, 99),
+        (0, 49),
+        (0, 698),
+        (0, 92),
+        (0, 14),
+        (0, 0),
+        (0, 227),
+        (0, 153),
+        (0, 13),
+        (0, 1),
+        (0, 72),
+        (0, 2),
+        (0, 332),
+        (0, 3),
+        (0, 1769),
+        (0, 128),
+        (0, 289),
+        (0, 225),
+        (0, 82),
+        (0, 39),
+        (0, 193),
+        (0, 44),
+        (0, 27),
+        (0, 4),
+        (0, 211),
+        (0, 145),
+        (0, 197),
+        (0, 1),
+        (0, 519),
+        (0, 108),
+        (0, 1),
+        (0, 6),
+        (0, 0),
+        (0, 9),
+        (1, 63),
+        (0, 1312),
+        (0, 98),
+        (0, 90),
+        (0, 48),
+        (0, 45),
+        (0, 257),
+        (0, 6),
+        (0, 18),
+        (0, 493),
+        (0, 1),
+        (0, 0),
+        (0, 355),
+        (0, 0),
+        (0, 23),
+        (0, 48),
+        (0, 12),
+        (0, 2),
+        (0, 3),
+        (0, 1061),
+        (0, 439),
+        (0, 15),
+        (0, 1068),
+        (0, 187),
+        (0, 148),
+        (0, 0),
+        (0, 1),
+        (0, 151),
+        (1, 1039),
+        (0, 367),
+        (0, 0),
+        (0, 80),
+        (0, 64),
+        (0, 136),
+        (1, 874),
+        (0, 29),
+        (0, 49),
+        (0, 171),
+        (0, 478),
+        (0, 0),
+        (0, 20),
+        (0, 112),
+        (0, 6),
+        (0, 467),
+        (0, 14),
+        (0, 10),
+        (0, 66),
+        (0, 414),
+        (0, 945),
+        (0, 0),
+        (0, 1109),
+        (0, 692),
+        (0, 92),
+        (0, 2),
+        (1, 1251),
+        (0, 4),
+        (0, 2),
+        (0, 109),
+        (0, 1270),
+        (0, 352),
+        (0, 4),
+        (0, 91),
+        (0, 83),
+        (0, 598),
+        (0, 5),
+        (0, 148),
+        (0, 80),
+        (0, 0),
+        (0, 40),
+        (0, 191),
+        (2, 1113),
+        (0, 1168),
+        (1, 572),
+        (0, 1179),
+        (0, 71),
+        (1, 527),
+        (0, 1197),
+        (0, 109),
+        (0, 0),
+        (1, 405),
+        (0, 72),
+        (0, 58),
+        (0, 124),
+        (0, 19),
+        (1, 36),
+        (0, 63),
+        (0, 6),
+        (0, 120),
+        (0, 628),
+        (0, 545),
+        (0, 18),
+        (0, 40),
+        (0, 186),
+        (0, 179),
+        (1, 252),
+        (0, 25),
+        (0, 13),
+        (0, 9),
+        (0, 121),
+        (0, 1419),
+        (0, 475),
+        (0, 141),
+        (0, 17),
+        (0, 290),
+        (0, 30),
+        (0, 259),
+        (0, 53),
+        (0, 3),
+        (0, 84),
+        (0, 10),
+        (0, 1068),
+        (0, 148),
+        (0, 18),
+        (1, 279),
+        (0, 171),
+        (0, 28),
+        (0, 329),
+        (0, 797),
+        (5, 1192),
+        (0, 419),
+        (0, 69),
+        (0, 767),
+        (0, 18),
+        (1, 101),
+        (3, 1488),
+        (0, 1178),
+        (3, 1099),
+        (0, 8),
+        (0, 176),
+        (0, 72),
+        (0, 190),
+        (4, 832),
+        (0, 10),
+        (0, 0),
+        (0, 63),
+        (0, 301),
+        (0, 136),
+        (0, 35),
+        (0, 9),
+        (0, 0),
+        (1, 1612),
+        (0, 115),
+        (0, 138),
+        (0, 325),
+        (0, 22),
+        (0, 1238),
+        (2, 1725),
+        (2, 683),
+        (0, 594),
+        (0, 119),
+        (0, 8),
+        (0, 145),
+        (0, 368),
+        (0, 65),
+        (0, 93),
+        (0, 1),
+        (0, 154),
+        (0, 26),
+        (0, 356),
+        (0, 389),
+        (0, 17),
+        (0, 48),
+        (0, 909),
+        (0, 55),
+        (0, 38),
+        (0, 792),
+        (2, 201),
+        (1, 111),
+        (0, 507),
+        (0, 91),
+        (0, 1702),
+        (4, 714),
+        (3, 1084),
+        (0, 3),
+        (0, 652),
+        (0, 167),
+        (0, 1561),
+        (2, 1677),
+        (0, 53),
+        (0, 144),
+        (0, 0),
+        (0, 2),
+        (0, 1549),
+        (0, 237),
+        (0, 455),
+        (0, 12),
+        (0, 261),
+        (0, 56),
+        (0, 5),
+        (0, 149),
+        (0, 801),
+        (0, 83),
+        (0, 74),
+        (0, 392),
+        (0, 83),
+        (0, 46),
+        (0, 505),
+        (0, 10),
+        (0, 17),
+        (0, 0),
+        (0, 161),
+        (0, 4),
+        (0, 836),
+        (0, 3),
+        (0, 2),
+        (2, 1371),
+        (1, 1513),
+        (2, 1494),
+        (0, 1472),
+        (1, 1551),
+        (0, 21),
+        (0, 431),
+        (0, 766),
+        (0, 1159),
+        (0, 733),
+        (0, 848),
+        (0, 2),
+        (0, 4),
+        (0, 174),
+        (0, 6),
+        (0, 1171),
+        (2, 1820),
+        (0, 2),
+        (0, 223),
+        (0, 826),
+        (0, 0),
+        (0, 1461),
+        (10, 750),
+        (5, 895),
+        (0, 7),
+        (0, 1450),
+        (0, 11),
+        (1, 211),
+        (4, 14),
+        (0, 970),
+        (0, 1868),
+        (0, 4),
+        (0, 1),
+        (0, 3),
+        (18, 851),
+        (0, 236),
+        (0, 459),
+        (0, 1546),
+        (4, 1783),
+        (10, 719),
+        (7, 1399),
+        (1, 1589),
+        (0, 283),
+        (0, 6),
+        (0, 5),
+        (0, 5),
+    ],
+    entries: &[
+        ("st_helena", &crate::gen::EMOJIS[3303]),
+        ("small_red_triangle_down", &crate::gen::EMOJIS[3086]),
+        ("hocho", &crate::gen::EMOJIS[2301]),
+        ("gun", &crate::gen::EMOJIS[2579]),
+        ("rice_cracker", &crate::gen::EMOJIS[2242]),
+        ("socks", &crate::gen::EMOJIS[2618]),
+        ("milk_glass", &crate::gen::EMOJIS[2278]),
+        ("menorah", &crate::gen::EMOJIS[2925]),
+        ("broken_heart", &crate::gen::EMOJIS[137]),
+        ("articulated_lorry", &crate::gen::EMOJIS[2397]),
+        ("angola", &crate::gen::EMOJIS[3107]),
+        ("shirt", &crate::gen::EMOJIS[2613]),
+        ("spades", &crate::gen::EMOJIS[2592]),
+        ("camel", &crate::gen::EMOJIS[2058]),
+        ("oncoming_police_car", &crate::gen::EMOJIS[2389]),
+        ("tiger", &crate::gen::EMOJIS[2035]),
+        ("handball_person", &crate::gen::EMOJIS[1885]),
+        ("walking", &crate::gen::EMOJIS[1505]),
+        ("high_brightness", &crate::gen::EMOJIS[2961]),
+        ("swaziland", &crate::gen::EMOJIS[3317]),
+        ("snowman_with_snow", &crate::gen::EMOJIS[2516]),
+        ("u7a7a", &crate::gen::EMOJIS[3052]),
+        ("plate_with_cutlery", &crate::gen::EMOJIS[2298]),
+        ("truck", &crate::gen::EMOJIS[2396]),
+        ("south_sudan", &crate::gen::EMOJIS[3312]),
+        ("hankey", &crate::gen::EMOJIS[107]),
+        ("receipt", &crate::gen::EMOJIS[2744]),
+        ("skunk", &crate::gen::EMOJIS[2081]),
+        ("brunei", &crate::gen::EMOJIS[3127]),
+        ("supervillain_woman", &crate::gen::EMOJIS[1366]),
+        ("gorilla", &crate::gen::EMOJIS[2021]),
+        ("flight_departure", &crate::gen::EMOJIS[2431]),
+        ("koko", &crate::gen::EMOJIS[3040]),
+        ("french_polynesia", &crate::gen::EMOJIS[3279]),
+        ("greece", &crate::gen::EMOJIS[3192]),
+        ("card_index", &crate::gen::EMOJIS[2774]),
+        ("thumbsdown", &crate::gen::EMOJIS[334]),
+        ("bear", &crate::gen::EMOJIS[2075]),
+        ("man_with_probing_cane", &crate::gen::EMOJIS[1565]),
+        ("arrow_down", &crate::gen::EMOJIS[2898]),
+        ("+1", &crate::gen::EMOJIS[328]),
+        ("toothbrush", &crate::gen::EMOJIS[2855]),
+        ("ab", &crate::gen::EMOJIS[3024]),
+        ("family_woman_girl_girl", &crate::gen::EMOJIS[2013]),
+        ("onion", &crate::gen::EMOJIS[2201]),
+        ("christmas_island", &crate::gen::EMOJIS[3154]),
+        ("cucumber", &crate::gen::EMOJIS[2197]),
+        ("woman_playing_water_polo", &crate::gen::EMOJIS[1879]),
+        ("liechtenstein", &crate::gen::EMOJIS[3233]),
+        ("-1", &crate::gen::EMOJIS[334]),
+        ("speak_no_evil", &crate::gen::EMOJIS[126]),
+        ("construction_worker", &crate::gen::EMOJIS[1168]),
+        ("malawi", &crate::gen::EMOJIS[3260]),
+        ("skateboard", &crate::gen::EMOJIS[2407]),
+        ("bread", &crate::gen::EMOJIS[2207]),
+        ("pregnant_man", &crate::gen::EMOJIS[1276]),
+        ("tokyo_tower", &crate::gen::EMOJIS[2345]),
+        ("chad", &crate::gen::EMOJIS[3320]),
+        ("tristan_da_cunha", &crate::gen::EMOJIS[3318]),
+        ("man_artist", &crate::gen::EMOJIS[1042]),
+        ("badger", &crate::gen::EMOJIS[2083]),
+        ("busstop", &crate::gen::EMOJIS[2409]),
+        ("mountain_snow", &crate::gen::EMOJIS[2311]),
+        ("email", &crate::gen::EMOJIS[2747]),
+        ("couplekiss_man_woman", &crate::gen::EMOJIS[1976]),
+        ("id", &crate::gen::EMOJIS[3030]),
+        ("helicopter", &crate::gen::EMOJIS[2435]),
+        ("old_key", &crate::gen::EMOJIS[2794]),
+        ("surfer", &crate::gen::EMOJIS[1720]),
+        ("secret", &crate::gen::EMOJIS[3054]),
+        ("bookmark", &crate::gen::EMOJIS[2734]),
+        ("sob", &crate::gen::EMOJIS[90]),
+        ("snowflake", &crate::gen::EMOJIS[2515]),
+        ("latin_cross", &crate::gen::EMOJIS[2921]),
+        ("clock430", &crate::gen::EMOJIS[2460]),
+        ("ocean", &crate::gen::EMOJIS[2521]),
+        ("hotsprings", &crate::gen::EMOJIS[2363]),
+        ("dromedary_camel", &crate::gen::EMOJIS[2057]),
+        ("tonga", &crate::gen::EMOJIS[3329]),
+        ("partly_sunny", &crate::gen::EMOJIS[2497]),
+        ("botswana", &crate::gen::EMOJIS[3134]),
+        ("blonde_woman", &crate::gen::EMOJIS[610]),
+        ("family_man_woman_girl_boy", &crate::gen::EMOJIS[1991]),
+        ("clock3", &crate::gen::EMOJIS[2457]),
+        ("tunisia", &crate::gen::EMOJIS[3328]),
+        ("toolbox", &crate::gen::EMOJIS[2817]),
+        ("x", &crate::gen::EMOJIS[2994]),
+        ("ribbon", &crate::gen::EMOJIS[2538]),
+        ("point_up_2", &crate::gen::EMOJIS[298]),
+        ("lotus_position_woman", &crate::gen::EMOJIS[1933]),
+        ("flying_disc", &crate::gen::EMOJIS[2557]),
+        ("meat_on_bone", &crate::gen::EMOJIS[2216]),
+        ("collision", &crate::gen::EMOJIS[155]),
+        ("parrot", &crate::gen::EMOJIS[2102]),
+        ("knot", &crate::gen::EMOJIS[2606]),
+        ("thread", &crate::gen::EMOJIS[2603]),
+        ("man_technologist", &crate::gen::EMOJIS[1006]),
+        ("vulcan_salute", &crate::gen::EMOJIS[190]),
+        ("juggling_person", &crate::gen::EMOJIS[1903]),
+        ("heart_eyes", &crate::gen::EMOJIS[15]),
+        ("clock4", &crate::gen::EMOJIS[2459]),
+        ("eight_spoked_asterisk", &crate::gen::EMOJIS[2999]),
+        ("exclamation", &crate::gen::EMOJIS[2980]),
+        ("family_woman_girl", &crate::gen::EMOJIS[2011]),
+        ("love_letter", &crate::gen::EMOJIS[127]),
+        ("arrow_upper_right", &crate::gen::EMOJIS[2895]),
+        ("rainbow", &crate::gen::EMOJIS[2509]),
+        ("sari", &crate::gen::EMOJIS[2621]),
+        ("sweden", &crate::gen::EMOJIS[3301]),
+        ("nerd_face", &crate::gen::EMOJIS[71]),
+        ("city_sunrise", &crate::gen::EMOJIS[2361]),
+        ("carpentry_saw", &crate::gen::EMOJIS[2806]),
+        ("stop_button", &crate::gen::EMOJIS[2956]),
+        ("man_juggling", &crate::gen::EMOJIS[1909]),
+        ("red_circle", &crate::gen::EMOJIS[3057]),
+        ("santa", &crate::gen::EMOJIS[1318]),
+        ("guitar", &crate::gen::EMOJIS[2674]),
+        ("doughnut", &crate::gen::EMOJIS[2266]),
+        ("dizzy_face", &crate::gen::EMOJIS[64]),
+        ("o2", &crate::gen::EMOJIS[3034]),
+        ("woman", &crate::gen::EMOJIS[556]),
+        ("memo", &crate::gen::EMOJIS[2765]),
+        ("umbrella", &crate::gen::EMOJIS[2512]),
+        ("pig_nose", &crate::gen::EMOJIS[2053]),
+        ("cook_islands", &crate::gen::EMOJIS[3144]),
+        ("niger", &crate::gen::EMOJIS[3266]),
+        ("cool", &crate::gen::EMOJIS[3027]),
+        ("white_medium_square", &crate::gen::EMOJIS[3076]),
+        ("cherry_blossom", &crate::gen::EMOJIS[2144]),
+        ("no_bell", &crate::gen::EMOJIS[2662]),
+        ("fax", &crate::gen::EMOJIS[2688]),
+        ("mending_heart", &crate::gen::EMOJIS[139]),
+        ("safety_pin", &crate::gen::EMOJIS[2848]),
+        ("garlic", &crate::gen::EMOJIS[2200]),
+        ("couplekiss_man_man", &crate::gen::EMOJIS[1977]),
+        ("transgender_symbol", &crate::gen::EMOJIS[2968]),
+        ("genie", &crate::gen::EMOJIS[1462]),
+        ("congratulations", &crate::gen::EMOJIS[3053]),
+        ("congo_kinshasa", &crate::gen::EMOJIS[3139]),
+        ("mandarin", &crate::gen::EMOJIS[2174]),
+        ("british_virgin_islands", &crate::gen::EMOJIS[3345]),
+        ("closed_lock_with_key", &crate::gen::EMOJIS[2792]),
+        ("dizzy", &crate::gen::EMOJIS[156]),
+        ("mega", &crate::gen::EMOJIS[2659]),
+        ("rewind", &crate::gen::EMOJIS[2949]),
+        ("zombie_woman", &crate::gen::EMOJIS[1467]),
+        ("uruguay", &crate::gen::EMOJIS[3340]),
+        ("pinata", &crate::gen::EMOJIS[2589]),
+        ("chicken", &crate::gen::EMOJIS[2086]),
+        ("blue_book", &crate::gen::EMOJIS[2723]),
+        ("fire", &crate::gen::EMOJIS[2519]),
+        ("dotted_line_face", &crate::gen::EMOJIS[41]),
+        ("balloon", &crate::gen::EMOJIS[2528]),
+        ("ski", &crate::gen::EMOJIS[2573]),
+        ("woman_cartwheeling", &crate::gen::EMOJIS[1858]),
+        ("jack_o_lantern", &crate::gen::EMOJIS[2522]),
+        ("martinique", &crate::gen::EMOJIS[3254]),
+        ("dolls", &crate::gen::EMOJIS[2533]),
+        ("clock9", &crate::gen::EMOJIS[2469]),
+        ("arrow_right", &crate::gen::EMOJIS[2896]),
+        ("three", &crate::gen::EMOJIS[3010]),
+        ("man_shrugging", &crate::gen::EMOJIS[808]),
+        ("small_blue_diamond", &crate::gen::EMOJIS[3084]),
+        ("golfing_woman", &crate::gen::EMOJIS[1714]),
+        ("european_union", &crate::gen::EMOJIS[3172]),
+        ("madagascar", &crate::gen::EMOJIS[3246]),
+        ("nine", &crate::gen::EMOJIS[3016]),
+        ("lungs", &crate::gen::EMOJIS[464]),
+        ("cote_divoire", &crate::gen::EMOJIS[3143]),
+        ("sushi", &crate::gen::EMOJIS[2250]),
+        ("turkey", &crate::gen::EMOJIS[2085]),
+        ("person_with_probing_cane", &crate::gen::EMOJIS[1559]),
+        ("wind_chime", &crate::gen::EMOJIS[2535]),
+        ("leafy_green", &crate::gen::EMOJIS[2198]),
+        ("mirror", &crate::gen::EMOJIS[2836]),
+        ("face_with_open_eyes_and_hand_over_mouth", &crate::gen::EMOJIS[31]),
+        ("six", &crate::gen::EMOJIS[3013]),
+        ("ringed_planet", &crate::gen::EMOJIS[2491]),
+        ("new_moon", &crate::gen::EMOJIS[2475]),
+        ("climbing", &crate::gen::EMOJIS[1670]),
+        ("beetle", &crate::gen::EMOJIS[2132]),
+        ("merman", &crate::gen::EMOJIS[1432]),
+        ("flat_shoe", &crate::gen::EMOJIS[2637]),
+        ("no_good_woman", &crate::gen::EMOJIS[688]),
+        ("pizza", &crate::gen::EMOJIS[2222]),
+        ("arrow_up", &crate::gen::EMOJIS[2894]),
+        ("person_bald", &crate::gen::EMOJIS[604]),
+        ("heavy_check_mark", &crate::gen::EMOJIS[2993]),
+        ("ballot_box_with_check", &crate::gen::EMOJIS[2992]),
+        ("cameroon", &crate::gen::EMOJIS[3146]),
+        ("female_detective", &crate::gen::EMOJIS[1138]),
+        ("estonia", &crate::gen::EMOJIS[3166]),
+        ("biohazard", &crate::gen::EMOJIS[2893]),
+        ("woman_with_veil", &crate::gen::EMOJIS[1264]),
+        ("supervillain_man", &crate::gen::EMOJIS[1360]),
+        ("paraguay", &crate::gen::EMOJIS[3290]),
+        ("back", &crate::gen::EMOJIS[2910]),
+        ("monocle_face", &crate::gen::EMOJIS[72]),
+        ("page_with_curl", &crate::gen::EMOJIS[2728]),
+        ("abc", &crate::gen::EMOJIS[3022]),
+        ("breast_feeding", &crate::gen::EMOJIS[1288]),
+        ("black_small_square", &crate::gen::EMOJIS[3079]),
+        ("bison", &crate::gen::EMOJIS[2045]),
+        ("face_with_spiral_eyes", &crate::gen::EMOJIS[65]),
+        ("newspaper", &crate::gen::EMOJIS[2731]),
+        ("kneeling_woman", &crate::gen::EMOJIS[1553]),
+        ("new_moon_with_face", &crate::gen::EMOJIS[2484]),
+        ("cloud_with_rain", &crate::gen::EMOJIS[2502]),
+        ("place_of_worship", &crate::gen::EMOJIS[2915]),
+        ("disappointed_relieved", &crate::gen::EMOJIS[88]),
+        ("north_korea", &crate::gen::EMOJIS[3225]),
+        ("eritrea", &crate::gen::EMOJIS[3169]),
+        ("magnet", &crate::gen::EMOJIS[2818]),
+        ("woman_factory_worker", &crate::gen::EMOJIS[958]),
+        ("cheese", &crate::gen::EMOJIS[2215]),
+        ("vatican_city", &crate::gen::EMOJIS[3342]),
+        ("somalia", &crate::gen::EMOJIS[3310]),
+        ("new_caledonia", &crate::gen::EMOJIS[3265]),
+        ("pause_button", &crate::gen::EMOJIS[2955]),
+        ("mobile_phone_off", &crate::gen::EMOJIS[2965]),
+        ("tractor", &crate::gen::EMOJIS[2398]),
+        ("flushed", &crate::gen::EMOJIS[81]),
+        ("blowfish", &crate::gen::EMOJIS[2121]),
+        ("ng_woman", &crate::gen::EMOJIS[688]),
+        ("six_pointed_star", &crate::gen::EMOJIS[2926]),
+        ("post_office", &crate::gen::EMOJIS[2332]),
+        ("older_adult", &crate::gen::EMOJIS[622]),
+        ("malta", &crate::gen::EMOJIS[3257]),
+        ("waffle", &crate::gen::EMOJIS[2214]),
+        ("el_salvador", &crate::gen::EMOJIS[3314]),
+        ("bouvet_island", &crate::gen::EMOJIS[3133]),
+        ("belgium", &crate::gen::EMOJIS[3119]),
+        ("star_and_crescent", &crate::gen::EMOJIS[2923]),
+        ("loop", &crate::gen::EMOJIS[2997]),
+        ("white_square_button", &crate::gen::EMOJIS[3089]),
+        ("record_button", &crate::gen::EMOJIS[2957]),
+        ("paw_prints", &crate::gen::EMOJIS[2084]),
+        ("droplet", &crate::gen::EMOJIS[2520]),
+        ("black_heart", &crate::gen::EMOJIS[149]),
+        ("djibouti", &crate::gen::EMOJIS[3159]),
+        ("bento", &crate::gen::EMOJIS[2241]),
+        ("notes", &crate::gen::EMOJIS[2665]),
+        ("wheel", &crate::gen::EMOJIS[2414]),
+        ("kiwi_fruit", &crate::gen::EMOJIS[2186]),
+        ("cupid", &crate::gen::EMOJIS[128]),
+        ("ear", &crate::gen::EMOJIS[444]),
+        ("turtle", &crate::gen::EMOJIS[2108]),
+        ("princess", &crate::gen::EMOJIS[1198]),
+        ("clock12", &crate::gen::EMOJIS[2451]),
+        ("mouse2", &crate::gen::EMOJIS[2066]),
+        ("woman_facepalming", &crate::gen::EMOJIS[796]),
+        ("iphone", &crate::gen::EMOJIS[2683]),
+        ("dominican_republic", &crate::gen::EMOJIS[3162]),
+        ("green_square", &crate::gen::EMOJIS[3069]),
+        ("man_in_tuxedo", &crate::gen::EMOJIS[1240]),
+        ("baseball", &crate::gen::EMOJIS[2550]),
+        ("anger", &crate::gen::EMOJIS[154]),
+        ("arrow_up_small", &crate::gen::EMOJIS[2951]),
+        ("ambulance", &crate::gen::EMOJIS[2386]),
+        ("tea", &crate::gen::EMOJIS[2281]),
+        ("lips", &crate::gen::EMOJIS[470]),
+        ("information_source", &crate::gen::EMOJIS[3029]),
+        ("adhesive_bandage", &crate::gen::EMOJIS[2830]),
+        ("deaf_woman", &crate::gen::EMOJIS[760]),
+        ("coat", &crate::gen::EMOJIS[2617]),
+        ("boat", &crate::gen::EMOJIS[2422]),
+        ("radio_button", &crate::gen::EMOJIS[3088]),
+        ("mountain", &crate::gen::EMOJIS[2312]),
+        ("sun_with_face", &crate::gen::EMOJIS[2490]),
+        ("mute", &crate::gen::EMOJIS[2654]),
+        ("pie", &crate::gen::EMOJIS[2271]),
+        ("jigsaw", &crate::gen::EMOJIS[2587]),
+        ("crossed_swords", &crate::gen::EMOJIS[2801]),
+        ("melon", &crate::gen::EMOJIS[2172]),
+        ("baby_chick", &crate::gen::EMOJIS[2089]),
+        ("guadeloupe", &crate::gen::EMOJIS[3190]),
+        ("left_right_arrow", &crate::gen::EMOJIS[2903]),
+        ("poop", &crate::gen::EMOJIS[107]),
+        ("underage", &crate::gen::EMOJIS[2891]),
+        ("falafel", &crate::gen::EMOJIS[2229]),
+        ("bath", &crate::gen::EMOJIS[1939]),
+        ("french_guiana", &crate::gen::EMOJIS[3183]),
+        ("bulb", &crate::gen::EMOJIS[2715]),
+        ("technologist", &crate::gen::EMOJIS[1000]),
+        ("blond_haired_woman", &crate::gen::EMOJIS[610]),
+        ("motorcycle", &crate::gen::EMOJIS[2400]),
+        ("left_speech_bubble", &crate::gen::EMOJIS[162]),
+        ("micronesia", &crate::gen::EMOJIS[3176]),
+        ("guernsey", &crate::gen::EMOJIS[3184]),
+        ("fish_cake", &crate::gen::EMOJIS[2252]),
+        ("kosovo", &crate::gen::EMOJIS[3351]),
+        ("school", &crate::gen::EMOJIS[2339]),
+        ("pretzel", &crate::gen::EMOJIS[2211]),
+        ("policewoman", &crate::gen::EMOJIS[1120]),
+        ("crab", &crate::gen::EMOJIS[2258]),
+        ("flight_arrival", &crate::gen::EMOJIS[2432]),
+        ("headphones", &crate::gen::EMOJIS[2670]),
+        ("rosette", &crate::gen::EMOJIS[2147]),
+        ("construction_worker_man", &crate::gen::EMOJIS[1174]),
+        ("woman_technologist", &crate::gen::EMOJIS[1012]),
+        ("person_in_tuxedo", &crate::gen::EMOJIS[1234]),
+        ("interrobang", &crate::gen::EMOJIS[2976]),
+        ("statue_of_liberty", &crate::gen::EMOJIS[2346]),
+        ("railway_car", &crate::gen::EMOJIS[2371]),
+        ("mechanical_arm", &crate::gen::EMOJIS[430]),
+        ("south_georgia_south_sandwich_islands", &crate::gen::EMOJIS[3193]),
+        ("leftwards_arrow_with_hook", &crate::gen::EMOJIS[2904]),
+        ("hippopotamus", &crate::gen::EMOJIS[2064]),
+        ("left_luggage", &crate::gen::EMOJIS[2880]),
+        ("grinning", &crate::gen::EMOJIS[0]),
+        ("shield", &crate::gen::EMOJIS[2805]),
+        ("clock730", &crate::gen::EMOJIS[2466]),
+        ("moneybag", &crate::gen::EMOJIS[2736]),
+        ("empty_nest", &crate::gen::EMOJIS[2168]),
+        ("raising_hand", &crate::gen::EMOJIS[730]),
+        ("shaved_ice", &crate::gen::EMOJIS[2264]),
+        ("black_cat", &crate::gen::EMOJIS[2033]),
+        ("frowning_man", &crate::gen::EMOJIS[646]),
+        ("tickets", &crate::gen::EMOJIS[2541]),
+        ("postbox", &crate::gen::EMOJIS[2757]),
+        ("mango", &crate::gen::EMOJIS[2178]),
+        ("aruba", &crate::gen::EMOJIS[3113]),
+        ("red_haired_man", &crate::gen::EMOJIS[532]),
+        ("woman_student", &crate::gen::EMOJIS[850]),
+        ("woman_with_turban", &crate::gen::EMOJIS[1216]),
+        ("beaver", &crate::gen::EMOJIS[2072]),
+        ("arrow_double_down", &crate::gen::EMOJIS[2954]),
+        ("family_man_woman_boy", &crate::gen::EMOJIS[1989]),
+        ("ice_skate", &crate::gen::EMOJIS[2569]),
+        ("stuck_out_tongue_closed_eyes", &crate::gen::EMOJIS[27]),
+        ("telescope", &crate::gen::EMOJIS[2825]),
+        ("baby_symbol", &crate::gen::EMOJIS[2875]),
+        ("credit_card", &crate::gen::EMOJIS[2743]),
+        ("new", &crate::gen::EMOJIS[3032]),
+        ("firecracker", &crate::gen::EMOJIS[2526]),
+        ("file_folder", &crate::gen::EMOJIS[2767]),
+        ("nest_with_eggs", &crate::gen::EMOJIS[2169]),
+        ("bee", &crate::gen::EMOJIS[2131]),
+        ("afghanistan", &crate::gen::EMOJIS[3102]),
+        ("sauna_person", &crate::gen::EMOJIS[1652]),
+        ("knife", &crate::gen::EMOJIS[2301]),
+        ("nauseated_face", &crate::gen::EMOJIS[58]),
+        ("brown_square", &crate::gen::EMOJIS[3072]),
+        ("hugs", &crate::gen::EMOJIS[29]),
+        ("palau", &crate::gen::EMOJIS[3289]),
+        ("horse", &crate::gen::EMOJIS[2038]),
+        ("fist_left", &crate::gen::EMOJIS[352]),
+        ("trophy", &crate::gen::EMOJIS[2544]),
+        ("u6708", &crate::gen::EMOJIS[3042]),
+        ("sunflower", &crate::gen::EMOJIS[2151]),
+        ("flashlight", &crate::gen::EMOJIS[2716]),
+        ("tv", &crate::gen::EMOJIS[2707]),
+        ("family_man_girl", &crate::gen::EMOJIS[2006]),
+        ("100", &crate::gen::EMOJIS[153]),
+        ("swimmer", &crate::gen::EMOJIS[1756]),
+        ("sound", &crate::gen::EMOJIS[2656]),
+        ("telephone", &crate::gen::EMOJIS[2685]),
+        ("arrows_counterclockwise", &crate::gen::EMOJIS[2909]),
+        ("cockroach", &crate::gen::EMOJIS[2135]),
+        ("climbing_woman", &crate::gen::EMOJIS[1682]),
+        ("unicorn", &crate::gen::EMOJIS[2042]),
+        ("vietnam", &crate::gen::EMOJIS[3347]),
+        ("kiss", &crate::gen::EMOJIS[152]),
+        ("older_woman", &crate::gen::EMOJIS[634]),
+        ("bouncing_ball_woman", &crate::gen::EMOJIS[1786]),
+        ("bouquet", &crate::gen::EMOJIS[2143]),
+        ("hibiscus", &crate::gen::EMOJIS[2150]),
+        ("mailbox_with_no_mail", &crate::gen::EMOJIS[2756]),
+        ("trolleybus", &crate::gen::EMOJIS[2384]),
+        ("clock230", &crate::gen::EMOJIS[2456]),
+        ("rice_scene", &crate::gen::EMOJIS[2536]),
+        ("bellhop_bell", &crate::gen::EMOJIS[2442]),
+        ("spiral_calendar", &crate::gen::EMOJIS[2773]),
+        ("expressionless", &crate::gen::EMOJIS[39]),
+        ("heart", &crate::gen::EMOJIS[140]),
+        ("blue_square", &crate::gen::EMOJIS[3070]),
+        ("zap", &crate::gen::EMOJIS[2514]),
+        ("clock930", &crate::gen::EMOJIS[2470]),
+        ("yum", &crate::gen::EMOJIS[23]),
+        ("roller_coaster", &crate::gen::EMOJIS[2367]),
+        ("burundi", &crate::gen::EMOJIS[3123]),
+        ("black_flag", &crate::gen::EMOJIS[3094]),
+        ("hammer_and_wrench", &crate::gen::EMOJIS[2799]),
+        ("seat", &crate::gen::EMOJIS[2434]),
+        ("burrito", &crate::gen::EMOJIS[2226]),
+        ("grimacing", &crate::gen::EMOJIS[46]),
+        ("funeral_urn", &crate::gen::EMOJIS[2862]),
+        ("blond_haired_man", &crate::gen::EMOJIS[616]),
+        ("sunrise_over_mountains", &crate::gen::EMOJIS[2358]),
+        ("shamrock", &crate::gen::EMOJIS[2163]),
+        ("indonesia", &crate::gen::EMOJIS[3205]),
+        ("previous_track_button", &crate::gen::EMOJIS[2950]),
+        ("spoon", &crate::gen::EMOJIS[2300]),
+        ("envelope_with_arrow", &crate::gen::EMOJIS[2749]),
+        ("hear_no_evil", &crate::gen::EMOJIS[125]),
+        ("boar", &crate::gen::EMOJIS[2052]),
+        ("vs", &crate::gen::EMOJIS[3039]),
+        ("arrows_clockwise", &crate::gen::EMOJIS[2908]),
+        ("austria", &crate::gen::EMOJIS[3111]),
+        ("heartpulse", &crate::gen::EMOJIS[131]),
+        ("movie_camera", &crate::gen::EMOJIS[2703]),
+        ("crayon", &crate::gen::EMOJIS[2764]),
+        ("seychelles", &crate::gen::EMOJIS[3299]),
+        ("baggage_claim", &crate::gen::EMOJIS[2879]),
+        ("water_polo", &crate::gen::EMOJIS[1867]),
+        ("cape_verde", &crate::gen::EMOJIS[3152]),
+        ("canned_food", &crate::gen::EMOJIS[2240]),
+        ("fortune_cookie", &crate::gen::EMOJIS[2256]),
+        ("women_wrestling", &crate::gen::EMOJIS[1866]),
+        ("pray", &crate::gen::EMOJIS[400]),
+        ("bone", &crate::gen::EMOJIS[466]),
+        ("dash", &crate::gen::EMOJIS[158]),
+        ("whale2", &crate::gen::EMOJIS[2116]),
+        ("taxi", &crate::gen::EMOJIS[2390]),
+        ("office", &crate::gen::EMOJIS[2331]),
+        ("shopping", &crate::gen::EMOJIS[2631]),
+        ("japanese_ogre", &crate::gen::EMOJIS[109]),
+        ("hand_over_mouth", &crate::gen::EMOJIS[30]),
+        ("ecuador", &crate::gen::EMOJIS[3165]),
+        ("ascension_island", &crate::gen::EMOJIS[3099]),
+        ("white_medium_small_square", &crate::gen::EMOJIS[3078]),
+        ("teacher", &crate::gen::EMOJIS[856]),
+        ("name_badge", &crate::gen::EMOJIS[2988]),
+        ("parasol_on_ground", &crate::gen::EMOJIS[2513]),
+        ("greenland", &crate::gen::EMOJIS[3187]),
+        ("tulip", &crate::gen::EMOJIS[2153]),
+        ("hamster", &crate::gen::EMOJIS[2068]),
+        ("canary_islands", &crate::gen::EMOJIS[3204]),
+        ("raised_hand_with_fingers_splayed", &crate::gen::EMOJIS[178]),
+        ("maldives", &crate::gen::EMOJIS[3259]),
+        ("feet", &crate::gen::EMOJIS[2084]),
+        ("information_desk_person", &crate::gen::EMOJIS[712]),
+        ("burkina_faso", &crate::gen::EMOJIS[3120]),
+        ("open_book", &crate::gen::EMOJIS[2721]),
+        ("sheep", &crate::gen::EMOJIS[2055]),
+        ("togo", &crate::gen::EMOJIS[3322]),
+        ("tipping_hand_woman", &crate::gen::EMOJIS[724]),
+        ("cake", &crate::gen::EMOJIS[2269]),
+        ("medal_military", &crate::gen::EMOJIS[2543]),
+        ("ghana", &crate::gen::EMOJIS[3185]),
+        ("traffic_light", &crate::gen::EMOJIS[2416]),
+        ("asterisk", &crate::gen::EMOJIS[3006]),
+        ("earth_americas", &crate::gen::EMOJIS[2305]),
+        ("fu", &crate::gen::EMOJIS[304]),
+        ("couple", &crate::gen::EMOJIS[1958]),
+        ("abcd", &crate::gen::EMOJIS[3019]),
+        ("no_mouth", &crate::gen::EMOJIS[40]),
+        ("bermuda", &crate::gen::EMOJIS[3126]),
+        ("frowning_face", &crate::gen::EMOJIS[77]),
+        ("star_struck", &crate::gen::EMOJIS[16]),
+        ("flags", &crate::gen::EMOJIS[2534]),
+        ("luggage", &crate::gen::EMOJIS[2443]),
+        ("cactus", &crate::gen::EMOJIS[2160]),
+        ("sunglasses", &crate::gen::EMOJIS[70]),
+        ("end", &crate::gen::EMOJIS[2911]),
+        ("boot", &crate::gen::EMOJIS[2641]),
+        ("slot_machine", &crate::gen::EMOJIS[2585]),
+        ("honduras", &crate::gen::EMOJIS[3200]),
+        ("orthodox_cross", &crate::gen::EMOJIS[2922]),
+        ("purple_circle", &crate::gen::EMOJIS[3062]),
+        ("hand_with_index_finger_and_thumb_crossed", &crate::gen::EMOJIS[262]),
+        ("clapper", &crate::gen::EMOJIS[2706]),
+        ("india", &crate::gen::EMOJIS[3209]),
+        ("blue_heart", &crate::gen::EMOJIS[145]),
+        ("mahjong", &crate::gen::EMOJIS[2598]),
+        ("potato", &crate::gen::EMOJIS[2192]),
+        ("dark_sunglasses", &crate::gen::EMOJIS[2608]),
+        ("card_index_dividers", &crate::gen::EMOJIS[2769]),
+        ("ping_pong", &crate::gen::EMOJIS[2563]),
+        ("lotus_position", &crate::gen::EMOJIS[1921]),
+        ("lotion_bottle", &crate::gen::EMOJIS[2847]),
+        ("speedboat", &crate::gen::EMOJIS[2424]),
+        ("cambodia", &crate::gen::EMOJIS[3221]),
+        ("ram", &crate::gen::EMOJIS[2054]),
+        ("niue", &crate::gen::EMOJIS[3274]),
+        ("mag", &crate::gen::EMOJIS[2712]),
+        ("cupcake", &crate::gen::EMOJIS[2270]),
+        ("business_suit_levitating", &crate::gen::EMOJIS[1643]),
+        ("person_in_motorized_wheelchair", &crate::gen::EMOJIS[1577]),
+        ("pancakes", &crate::gen::EMOJIS[2213]),
+        ("tanzania", &crate::gen::EMOJIS[3334]),
+        ("poodle", &crate::gen::EMOJIS[2027]),
+        ("family_man_man_boy", &crate::gen::EMOJIS[1994]),
+        ("orangutan", &crate::gen::EMOJIS[2022]),
+        ("mauritania", &crate::gen::EMOJIS[3255]),
+        ("chopsticks", &crate::gen::EMOJIS[2297]),
+        ("pinched_fingers", &crate::gen::EMOJIS[238]),
+        ("curly_haired_woman", &crate::gen::EMOJIS[574]),
+        ("european_post_office", &crate::gen::EMOJIS[2333]),
+        ("mechanic", &crate::gen::EMOJIS[928]),
+        ("selfie", &crate::gen::EMOJIS[418]),
+        ("watch", &crate::gen::EMOJIS[2446]),
+        ("orange_square", &crate::gen::EMOJIS[3067]),
+        ("rabbit", &crate::gen::EMOJIS[2069]),
+        ("trumpet", &crate::gen::EMOJIS[2676]),
+        ("girl", &crate::gen::EMOJIS[490]),
+        ("solomon_islands", &crate::gen::EMOJIS[3298]),
+        ("checkered_flag", &crate::gen::EMOJIS[3091]),
+        ("hole", &crate::gen::EMOJIS[159]),
+        ("turks_caicos_islands", &crate::gen::EMOJIS[3319]),
+        ("fist_raised", &crate::gen::EMOJIS[340]),
+        ("racing_car", &crate::gen::EMOJIS[2399]),
+        ("aquarius", &crate::gen::EMOJIS[2938]),
+        ("man_playing_handball", &crate::gen::EMOJIS[1891]),
+        ("railway_track", &crate::gen::EMOJIS[2411]),
+        ("aries", &crate::gen::EMOJIS[2928]),
+        ("innocent", &crate::gen::EMOJIS[13]),
+        ("pig", &crate::gen::EMOJIS[2050]),
+        ("man_student", &crate::gen::EMOJIS[844]),
+        ("spiral_notepad", &crate::gen::EMOJIS[2772]),
+        ("stethoscope", &crate::gen::EMOJIS[2832]),
+        ("lebanon", &crate::gen::EMOJIS[3231]),
+        ("jeans", &crate::gen::EMOJIS[2614]),
+        ("georgia", &crate::gen::EMOJIS[3182]),
+        ("bullettrain_side", &crate::gen::EMOJIS[2372]),
+        ("mosque", &crate::gen::EMOJIS[2348]),
+        ("fairy_man", &crate::gen::EMOJIS[1396]),
+        ("iran", &crate::gen::EMOJIS[3212]),
+        ("palms_up_together", &crate::gen::EMOJIS[388]),
+        ("punch", &crate::gen::EMOJIS[346]),
+        ("bowing_man", &crate::gen::EMOJIS[772]),
+        ("stuck_out_tongue", &crate::gen::EMOJIS[24]),
+        ("soon", &crate::gen::EMOJIS[2913]),
+        ("liberia", &crate::gen::EMOJIS[3235]),
+        ("woman_singer", &crate::gen::EMOJIS[1030]),
+        ("ballet_shoes", &crate::gen::EMOJIS[2640]),
+        ("toilet", &crate::gen::EMOJIS[2841]),
+        ("open_mouth", &crate::gen::EMOJIS[78]),
+        ("eye", &crate::gen::EMOJIS[468]),
+        ("shinto_shrine", &crate::gen::EMOJIS[2351]),
+        ("male_detective", &crate::gen::EMOJIS[1132]),
+        ("scorpius", &crate::gen::EMOJIS[2935]),
+        ("white_haired_woman", &crate::gen::EMOJIS[586]),
+        ("dancers", &crate::gen::EMOJIS[1649]),
+        ("amphora", &crate::gen::EMOJIS[2303]),
+        ("lobster", &crate::gen::EMOJIS[2259]),
+        ("pitcairn_islands", &crate::gen::EMOJIS[3285]),
+        ("mermaid", &crate::gen::EMOJIS[1438]),
+        ("japan", &crate::gen::EMOJIS[2309]),
+        ("anatomical_heart", &crate::gen::EMOJIS[463]),
+        ("eject_button", &crate::gen::EMOJIS[2958]),
+        ("scotland", &crate::gen::EMOJIS[3358]),
+        ("orange", &crate::gen::EMOJIS[2174]),
+        ("genie_man", &crate::gen::EMOJIS[1463]),
+        ("see_no_evil", &crate::gen::EMOJIS[124]),
+        ("brown_heart", &crate::gen::EMOJIS[148]),
+        ("pirate_flag", &crate::gen::EMOJIS[3098]),
+        ("accept", &crate::gen::EMOJIS[3049]),
+        ("fried_shrimp", &crate::gen::EMOJIS[2251]),
+        ("basketball_man", &crate::gen::EMOJIS[1780]),
+        ("bubble_tea", &crate::gen::EMOJIS[2293]),
+        ("train2", &crate::gen::EMOJIS[2374]),
+        ("construction", &crate::gen::EMOJIS[2419]),
+        ("gabon", &crate::gen::EMOJIS[3179]),
+        ("ok_woman", &crate::gen::EMOJIS[706]),
+        ("arrow_down_small", &crate::gen::EMOJIS[2953]),
+        ("kimono", &crate::gen::EMOJIS[2620]),
+        ("mount_fuji", &crate::gen::EMOJIS[2314]),
+        ("satisfied", &crate::gen::EMOJIS[4]),
+        ("united_arab_emirates", &crate::gen::EMOJIS[3101]),
+        ("family_woman_woman_girl_boy", &crate::gen::EMOJIS[2001]),
+        ("heavy_minus_sign", &crate::gen::EMOJIS[2971]),
+        ("vhs", &crate::gen::EMOJIS[2711]),
+        ("maple_leaf", &crate::gen::EMOJIS[2165]),
+        ("camping", &crate::gen::EMOJIS[2315]),
+        ("marshall_islands", &crate::gen::EMOJIS[3247]),
+        ("canoe", &crate::gen::EMOJIS[2423]),
+        ("man_factory_worker", &crate::gen::EMOJIS[952]),
+        ("zebra", &crate::gen::EMOJIS[2043]),
+        ("rabbit2", &crate::gen::EMOJIS[2070]),
+        ("weight_lifting", &crate::gen::EMOJIS[1792]),
+        ("ice_cream", &crate::gen::EMOJIS[2265]),
+        ("ring_buoy", &crate::gen::EMOJIS[2421]),
+        ("honeybee", &crate::gen::EMOJIS[2131]),
+        ("kazakhstan", &crate::gen::EMOJIS[3229]),
+        ("sierra_leone", &crate::gen::EMOJIS[3307]),
+        ("broccoli", &crate::gen::EMOJIS[2199]),
+        ("armenia", &crate::gen::EMOJIS[3106]),
+        ("star2", &crate::gen::EMOJIS[2493]),
+        ("musical_note", &crate::gen::EMOJIS[2664]),
+        ("b", &crate::gen::EMOJIS[3025]),
+        ("atm", &crate::gen::EMOJIS[2868]),
+        ("panda_face", &crate::gen::EMOJIS[2078]),
+        ("open_umbrella", &crate::gen::EMOJIS[2511]),
+        ("wedding", &crate::gen::EMOJIS[2344]),
+        ("pound", &crate::gen::EMOJIS[2741]),
+        ("deer", &crate::gen::EMOJIS[2044]),
+        ("synagogue", &crate::gen::EMOJIS[2350]),
+        ("tropical_drink", &crate::gen::EMOJIS[2286]),
+        ("yellow_heart", &crate::gen::EMOJIS[143]),
+        ("people_hugging", &crate::gen::EMOJIS[2017]),
+        ("stopwatch", &crate::gen::EMOJIS[2448]),
+        ("worried", &crate::gen::EMOJIS[75]),
+        ("penguin", &crate::gen::EMOJIS[2092]),
+        ("tuvalu", &crate::gen::EMOJIS[3332]),
+        ("love_you_gesture", &crate::gen::EMOJIS[268]),
+        ("pig2", &crate::gen::EMOJIS[2051]),
+        ("rainbow_flag", &crate::gen::EMOJIS[3096]),
+        ("wastebasket", &crate::gen::EMOJIS[2788]),
+        ("mens", &crate::gen::EMOJIS[2872]),
+        ("white_heart", &crate::gen::EMOJIS[151]),
+        ("u6307", &crate::gen::EMOJIS[3044]),
+        ("no_bicycles", &crate::gen::EMOJIS[2885]),
+        ("arrow_double_up", &crate::gen::EMOJIS[2952]),
+        ("paintbrush", &crate::gen::EMOJIS[2763]),
+        ("smiling_imp", &crate::gen::EMOJIS[103]),
+        ("wine_glass", &crate::gen::EMOJIS[2284]),
+        ("people_holding_hands", &crate::gen::EMOJIS[1951]),
+        ("grin", &crate::gen::EMOJIS[3]),
+        ("kissing_closed_eyes", &crate::gen::EMOJIS[20]),
+        ("lock_with_ink_pen", &crate::gen::EMOJIS[2791]),
+        ("mosquito", &crate::gen::EMOJIS[2139]),
+        ("smiling_face_with_tear", &crate::gen::EMOJIS[22]),
+        ("orange_book", &crate::gen::EMOJIS[2724]),
+        ("netherlands", &crate::gen::EMOJIS[3270]),
+        ("1st_place_medal", &crate::gen::EMOJIS[2546]),
+        ("skull_and_crossbones", &crate::gen::EMOJIS[106]),
+        ("san_marino", &crate::gen::EMOJIS[3308]),
+        ("department_store", &crate::gen::EMOJIS[2340]),
+        ("green_circle", &crate::gen::EMOJIS[3060]),
+        ("oncoming_taxi", &crate::gen::EMOJIS[2391]),
+        ("haircut_woman", &crate::gen::EMOJIS[1499]),
+        ("peru", &crate::gen::EMOJIS[3278]),
+        ("auto_rickshaw", &crate::gen::EMOJIS[2404]),
+        ("koala", &crate::gen::EMOJIS[2077]),
+        ("lotus_position_man", &crate::gen::EMOJIS[1927]),
+        ("couplekiss_woman_woman", &crate::gen::EMOJIS[1978]),
+        ("station", &crate::gen::EMOJIS[2377]),
+        ("st_martin", &crate::gen::EMOJIS[3245]),
+        ("upside_down_face", &crate::gen::EMOJIS[9]),
+        ("mammoth", &crate::gen::EMOJIS[2062]),
+        ("cinema", &crate::gen::EMOJIS[2959]),
+        ("kite", &crate::gen::EMOJIS[2578]),
+        ("fountain_pen", &crate::gen::EMOJIS[2761]),
+        ("dog2", &crate::gen::EMOJIS[2024]),
+        ("massage_man", &crate::gen::EMOJIS[1475]),
+        ("suspension_railway", &crate::gen::EMOJIS[2436]),
+        ("sweat_drops", &crate::gen::EMOJIS[157]),
+        ("arrow_right_hook", &crate::gen::EMOJIS[2905]),
+        ("us_outlying_islands", &crate::gen::EMOJIS[3337]),
+        ("hong_kong", &crate::gen::EMOJIS[3198]),
+        ("dna", &crate::gen::EMOJIS[2823]),
+        ("mexico", &crate::gen::EMOJIS[3261]),
+        ("bat", &crate::gen::EMOJIS[2074]),
+        ("slovenia", &crate::gen::EMOJIS[3304]),
+        ("eu", &crate::gen::EMOJIS[3172]),
+        ("leftwards_hand", &crate::gen::EMOJIS[202]),
+        ("pakistan", &crate::gen::EMOJIS[3282]),
+        ("western_sahara", &crate::gen::EMOJIS[3168]),
+        ("earth_africa", &crate::gen::EMOJIS[2304]),
+        ("ok_man", &crate::gen::EMOJIS[700]),
+        ("sauropod", &crate::gen::EMOJIS[2113]),
+        ("black_square_button", &crate::gen::EMOJIS[3090]),
+        ("benin", &crate::gen::EMOJIS[3124]),
+        ("sleeping_bed", &crate::gen::EMOJIS[1945]),
+        ("bar_chart", &crate::gen::EMOJIS[2777]),
+        ("hushed", &crate::gen::EMOJIS[79]),
+        ("hammer_and_pick", &crate::gen::EMOJIS[2798]),
+        ("x_ray", &crate::gen::EMOJIS[2833]),
+        ("haiti", &crate::gen::EMOJIS[3202]),
+        ("weight_lifting_man", &crate::gen::EMOJIS[1798]),
+        ("tada", &crate::gen::EMOJIS[2529]),
+        ("family", &crate::gen::EMOJIS[1988]),
+        ("flower_playing_cards", &crate::gen::EMOJIS[2599]),
+        ("curly_haired_man", &crate::gen::EMOJIS[538]),
+        ("bow", &crate::gen::EMOJIS[766]),
+        ("children_crossing", &crate::gen::EMOJIS[2882]),
+        ("kissing_heart", &crate::gen::EMOJIS[17]),
+        ("flying_saucer", &crate::gen::EMOJIS[2441]),
+        ("barbados", &crate::gen::EMOJIS[3117]),
+        ("heart_decoration", &crate::gen::EMOJIS[135]),
+        ("woman_teacher", &crate::gen::EMOJIS[868]),
+        ("lacrosse", &crate::gen::EMOJIS[2562]),
+        ("small_airplane", &crate::gen::EMOJIS[2430]),
+        ("barber", &crate::gen::EMOJIS[2368]),
+        ("pencil2", &crate::gen::EMOJIS[2759]),
+        ("grapes", &crate::gen::EMOJIS[2171]),
+        ("syria", &crate::gen::EMOJIS[3316]),
+        ("school_satchel", &crate::gen::EMOJIS[2632]),
+        ("spaghetti", &crate::gen::EMOJIS[2247]),
+        ("pushpin", &crate::gen::EMOJIS[2779]),
+        ("military_helmet", &crate::gen::EMOJIS[2648]),
+        ("superhero", &crate::gen::EMOJIS[1336]),
+        ("sauna_man", &crate::gen::EMOJIS[1658]),
+        ("biting_lip", &crate::gen::EMOJIS[471]),
+        ("shopping_cart", &crate::gen::EMOJIS[2858]),
+        ("tr", &crate::gen::EMOJIS[3330]),
+        ("large_blue_diamond", &crate::gen::EMOJIS[3082]),
+        ("dart", &crate::gen::EMOJIS[2576]),
+        ("white_small_square", &crate::gen::EMOJIS[3080]),
+        ("cyprus", &crate::gen::EMOJIS[3155]),
+        ("sparkles", &crate::gen::EMOJIS[2527]),
+        ("singer", &crate::gen::EMOJIS[1018]),
+        ("croissant", &crate::gen::EMOJIS[2208]),
+        ("strawberry", &crate::gen::EMOJIS[2184]),
+        ("running", &crate::gen::EMOJIS[1613]),
+        ("full_moon", &crate::gen::EMOJIS[2479]),
+        ("woman_judge", &crate::gen::EMOJIS[886]),
+        ("scream", &crate::gen::EMOJIS[91]),
+        ("office_worker", &crate::gen::EMOJIS[964]),
+        ("red_square", &crate::gen::EMOJIS[3066]),
+        ("infinity", &crate::gen::EMOJIS[2974]),
+        ("frowning_person", &crate::gen::EMOJIS[640]),
+        ("door", &crate::gen::EMOJIS[2834]),
+        ("rage", &crate::gen::EMOJIS[100]),
+        ("taco", &crate::gen::EMOJIS[2225]),
+        ("steam_locomotive", &crate::gen::EMOJIS[2370]),
+        ("wavy_dash", &crate::gen::EMOJIS[2981]),
+        ("person_fencing", &crate::gen::EMOJIS[1688]),
+        ("closed_umbrella", &crate::gen::EMOJIS[2510]),
+        ("switzerland", &crate::gen::EMOJIS[3142]),
+        ("gemini", &crate::gen::EMOJIS[2930]),
+        ("champagne", &crate::gen::EMOJIS[2283]),
+        ("green_apple", &crate::gen::EMOJIS[2180]),
+        ("city_sunset", &crate::gen::EMOJIS[2360]),
+        ("snail", &crate::gen::EMOJIS[2127]),
+        ("desert", &crate::gen::EMOJIS[2317]),
+        ("ng", &crate::gen::EMOJIS[3033]),
+        ("index_pointing_at_the_viewer", &crate::gen::EMOJIS[322]),
+        ("bow_and_arrow", &crate::gen::EMOJIS[2804]),
+        ("scientist", &crate::gen::EMOJIS[982]),
+        ("baby_bottle", &crate::gen::EMOJIS[2277]),
+        ("kaaba", &crate::gen::EMOJIS[2352]),
+        ("heavy_division_sign", &crate::gen::EMOJIS[2972]),
+        ("compass", &crate::gen::EMOJIS[2310]),
+        ("hand", &crate::gen::EMOJIS[184]),
+        ("tram", &crate::gen::EMOJIS[2378]),
+        ("popcorn", &crate::gen::EMOJIS[2237]),
+        ("wind_face", &crate::gen::EMOJIS[2507]),
+        ("basketball_woman", &crate::gen::EMOJIS[1786]),
+        ("minibus", &crate::gen::EMOJIS[2385]),
+        ("trinidad_tobago", &crate::gen::EMOJIS[3331]),
+        ("wc", &crate::gen::EMOJIS[2876]),
+        ("south_africa", &crate::gen::EMOJIS[3354]),
+        ("mirror_ball", &crate::gen::EMOJIS[2590]),
+        ("link", &crate::gen::EMOJIS[2814]),
+        ("eyes", &crate::gen::EMOJIS[467]),
+        ("dvd", &crate::gen::EMOJIS[2701]),
+        ("mailbox_with_mail", &crate::gen::EMOJIS[2755]),
+        ("coin", &crate::gen::EMOJIS[2737]),
+        ("man_with_turban", &crate::gen::EMOJIS[1210]),
+        ("medical_symbol", &crate::gen::EMOJIS[2984]),
+        ("page_facing_up", &crate::gen::EMOJIS[2730]),
+        ("wolf", &crate::gen::EMOJIS[2028]),
+        ("goggles", &crate::gen::EMOJIS[2609]),
+        ("keycap_ten", &crate::gen::EMOJIS[3017]),
+        ("cocos_islands", &crate::gen::EMOJIS[3138]),
+        ("atom_symbol", &crate::gen::EMOJIS[2916]),
+        ("gibraltar", &crate::gen::EMOJIS[3186]),
+        ("placard", &crate::gen::EMOJIS[2866]),
+        ("crossed_flags", &crate::gen::EMOJIS[3093]),
+        ("pisces", &crate::gen::EMOJIS[2939]),
+        ("hourglass", &crate::gen::EMOJIS[2444]),
+        ("mouse_trap", &crate::gen::EMOJIS[2845]),
+        ("fairy", &crate::gen::EMOJIS[1390]),
+        ("avocado", &crate::gen::EMOJIS[2190]),
+        ("white_large_square", &crate::gen::EMOJIS[3074]),
+        ("haircut_man", &crate::gen::EMOJIS[1493]),
+        ("superhero_man", &crate::gen::EMOJIS[1342]),
+        ("whale", &crate::gen::EMOJIS[2115]),
+        ("chess_pawn", &crate::gen::EMOJIS[2596]),
+        ("face_holding_back_tears", &crate::gen::EMOJIS[83]),
+        ("samoa", &crate::gen::EMOJIS[3350]),
+        ("pouting_cat", &crate::gen::EMOJIS[123]),
+        ("capital_abcd", &crate::gen::EMOJIS[3018]),
+        ("sassy_woman", &crate::gen::EMOJIS[724]),
+        ("woman_office_worker", &crate::gen::EMOJIS[976]),
+        ("goal_net", &crate::gen::EMOJIS[2567]),
+        ("mx_claus", &crate::gen::EMOJIS[1330]),
+        ("bullettrain_front", &crate::gen::EMOJIS[2373]),
+        ("train", &crate::gen::EMOJIS[2381]),
+        ("running_man", &crate::gen::EMOJIS[1619]),
+        ("dodo", &crate::gen::EMOJIS[2098]),
+        ("pouting_face", &crate::gen::EMOJIS[658]),
+        ("radioactive", &crate::gen::EMOJIS[2892]),
+        ("face_with_peeking_eye", &crate::gen::EMOJIS[32]),
+        ("crystal_ball", &crate::gen::EMOJIS[2581]),
+        ("woman_mechanic", &crate::gen::EMOJIS[940]),
+        ("cocktail", &crate::gen::EMOJIS[2285]),
+        ("mantelpiece_clock", &crate::gen::EMOJIS[2450]),
+        ("standing_person", &crate::gen::EMOJIS[1523]),
+        ("joy", &crate::gen::EMOJIS[7]),
+        ("mozambique", &crate::gen::EMOJIS[3263]),
+        ("passport_control", &crate::gen::EMOJIS[2877]),
+        ("stadium", &crate::gen::EMOJIS[2320]),
+        ("factory_worker", &crate::gen::EMOJIS[946]),
+        ("triangular_flag_on_post", &crate::gen::EMOJIS[3092]),
+        ("u6709", &crate::gen::EMOJIS[3043]),
+        ("wheel_of_dharma", &crate::gen::EMOJIS[2919]),
+        ("woman_juggling", &crate::gen::EMOJIS[1915]),
+        ("pill", &crate::gen::EMOJIS[2829]),
+        ("hourglass_flowing_sand", &crate::gen::EMOJIS[2445]),
+        ("reminder_ribbon", &crate::gen::EMOJIS[2540]),
+        ("motor_boat", &crate::gen::EMOJIS[2427]),
+        ("fries", &crate::gen::EMOJIS[2221]),
+        ("crown", &crate::gen::EMOJIS[2643]),
+        ("sun_behind_rain_cloud", &crate::gen::EMOJIS[2501]),
+        ("exploding_head", &crate::gen::EMOJIS[66]),
+        ("arrow_lower_left", &crate::gen::EMOJIS[2899]),
+        ("timor_leste", &crate::gen::EMOJIS[3326]),
+        ("clock130", &crate::gen::EMOJIS[2454]),
+        ("straight_ruler", &crate::gen::EMOJIS[2783]),
+        ("cow", &crate::gen::EMOJIS[2046]),
+        ("rat", &crate::gen::EMOJIS[2067]),
+        ("oncoming_bus", &crate::gen::EMOJIS[2383]),
+        ("nose", &crate::gen::EMOJIS[456]),
+        ("art", &crate::gen::EMOJIS[2602]),
+        ("t-rex", &crate::gen::EMOJIS[2114]),
+        ("package", &crate::gen::EMOJIS[2752]),
+        ("clock1030", &crate::gen::EMOJIS[2472]),
+        ("walking_woman", &crate::gen::EMOJIS[1517]),
+        ("diya_lamp", &crate::gen::EMOJIS[2718]),
+        ("us", &crate::gen::EMOJIS[3339]),
+        ("tshirt", &crate::gen::EMOJIS[2613]),
+        ("elf_woman", &crate::gen::EMOJIS[1456]),
+        ("speaker", &crate::gen::EMOJIS[2655]),
+        ("church", &crate::gen::EMOJIS[2347]),
+        ("giraffe", &crate::gen::EMOJIS[2060]),
+        ("mountain_biking_man", &crate::gen::EMOJIS[1834]),
+        ("neutral_face", &crate::gen::EMOJIS[38]),
+        ("volcano", &crate::gen::EMOJIS[2313]),
+        ("sauna_woman", &crate::gen::EMOJIS[1664]),
+        ("laughing", &crate::gen::EMOJIS[4]),
+        ("zambia", &crate::gen::EMOJIS[3355]),
+        ("scroll", &crate::gen::EMOJIS[2729]),
+        ("star", &crate::gen::EMOJIS[2492]),
+        ("cricket", &crate::gen::EMOJIS[2134]),
+        ("montenegro", &crate::gen::EMOJIS[3244]),
+        ("om", &crate::gen::EMOJIS[2917]),
+        ("repeat_one", &crate::gen::EMOJIS[2943]),
+        ("low_battery", &crate::gen::EMOJIS[2690]),
+        ("handbag", &crate::gen::EMOJIS[2629]),
+        ("hot_face", &crate::gen::EMOJIS[61]),
+        ("kneeling_person", &crate::gen::EMOJIS[1541]),
+        ("mayotte", &crate::gen::EMOJIS[3353]),
+        ("superhero_woman", &crate::gen::EMOJIS[1348]),
+        ("french_southern_territories", &crate::gen::EMOJIS[3321]),
+        ("heavy_exclamation_mark", &crate::gen::EMOJIS[2980]),
+        ("low_brightness", &crate::gen::EMOJIS[2960]),
+        ("timer_clock", &crate::gen::EMOJIS[2449]),
+        ("tipping_hand_person", &crate::gen::EMOJIS[712]),
+        ("two_hearts", &crate::gen::EMOJIS[134]),
+        ("person_with_turban", &crate::gen::EMOJIS[1204]),
+        ("man_feeding_baby", &crate::gen::EMOJIS[1300]),
+        ("thailand", &crate::gen::EMOJIS[3323]),
+        ("bulgaria", &crate::gen::EMOJIS[3121]),
+        ("mechanical_leg", &crate::gen::EMOJIS[431]),
+        ("hammer", &crate::gen::EMOJIS[2795]),
+        ("diego_garcia", &crate::gen::EMOJIS[3158]),
+        ("dove", &crate::gen::EMOJIS[2093]),
+        ("orange_circle", &crate::gen::EMOJIS[3058]),
+        ("dancing_men", &crate::gen::EMOJIS[1650]),
+        ("revolving_hearts", &crate::gen::EMOJIS[133]),
+        ("swimming_woman", &crate::gen::EMOJIS[1768]),
+        ("bahamas", &crate::gen::EMOJIS[3131]),
+        ("andorra", &crate::gen::EMOJIS[3100]),
+        ("ballot_box", &crate::gen::EMOJIS[2758]),
+        ("cat", &crate::gen::EMOJIS[2031]),
+        ("octopus", &crate::gen::EMOJIS[2123]),
+        ("golfing", &crate::gen::EMOJIS[1702]),
+        ("es", &crate::gen::EMOJIS[3170]),
+        ("couch_and_lamp", &crate::gen::EMOJIS[2839]),
+        ("transgender_flag", &crate::gen::EMOJIS[3097]),
+        ("uganda", &crate::gen::EMOJIS[3336]),
+        ("fly", &crate::gen::EMOJIS[2140]),
+        ("detective", &crate::gen::EMOJIS[1126]),
+        ("united_nations", &crate::gen::EMOJIS[3338]),
+        ("family_man_girl_boy", &crate::gen::EMOJIS[2007]),
+        ("chart_with_downwards_trend", &crate::gen::EMOJIS[2776]),
+        ("leo", &crate::gen::EMOJIS[2932]),
+        ("raccoon", &crate::gen::EMOJIS[2030]),
+        ("canada", &crate::gen::EMOJIS[3137]),
+        ("chart_with_upwards_trend", &crate::gen::EMOJIS[2775]),
+        ("bahrain", &crate::gen::EMOJIS[3122]),
+        ("evergreen_tree", &crate::gen::EMOJIS[2157]),
+        ("sparkling_heart", &crate::gen::EMOJIS[130]),
+        ("incoming_envelope", &crate::gen::EMOJIS[2748]),
+        ("smiley_cat", &crate::gen::EMOJIS[115]),
+        ("woman_artist", &crate::gen::EMOJIS[1048]),
+        ("biking_man", &crate::gen::EMOJIS[1816]),
+        ("tropical_fish", &crate::gen::EMOJIS[2120]),
+        ("playground_slide", &crate::gen::EMOJIS[2365]),
+        ("belize", &crate::gen::EMOJIS[3136]),
+        ("relaxed", &crate::gen::EMOJIS[19]),
+        ("parachute", &crate::gen::EMOJIS[2433]),
+        ("finland", &crate::gen::EMOJIS[3173]),
+        ("moldova", &crate::gen::EMOJIS[3243]),
+        ("ninja", &crate::gen::EMOJIS[1162]),
+        ("clock1130", &crate::gen::EMOJIS[2474]),
+        ("sweat_smile", &crate::gen::EMOJIS[5]),
+        ("orange_heart", &crate::gen::EMOJIS[142]),
+        ("faroe_islands", &crate::gen::EMOJIS[3177]),
+        ("mountain_railway", &crate::gen::EMOJIS[2380]),
+        ("qatar", &crate::gen::EMOJIS[3291]),
+        ("u5408", &crate::gen::EMOJIS[3051]),
+        ("chains", &crate::gen::EMOJIS[2815]),
+        ("senegal", &crate::gen::EMOJIS[3309]),
+        ("point_left", &crate::gen::EMOJIS[286]),
+        ("sweet_potato", &crate::gen::EMOJIS[2248]),
+        ("rocket", &crate::gen::EMOJIS[2440]),
+        ("yo_yo", &crate::gen::EMOJIS[2577]),
+        ("man_playing_water_polo", &crate::gen::EMOJIS[1873]),
+        ("clamp", &crate::gen::EMOJIS[2811]),
+        ("swim_brief", &crate::gen::EMOJIS[2623]),
+        ("u6e80", &crate::gen::EMOJIS[3056]),
+        ("waxing_crescent_moon", &crate::gen::EMOJIS[2476]),
+        ("iceland", &crate::gen::EMOJIS[3213]),
+        ("lemon", &crate::gen::EMOJIS[2175]),
+        ("trident", &crate::gen::EMOJIS[2987]),
+        ("axe", &crate::gen::EMOJIS[2796]),
+        ("dolphin", &crate::gen::EMOJIS[2117]),
+        ("pick", &crate::gen::EMOJIS[2797]),
+        ("snake", &crate::gen::EMOJIS[2110]),
+        ("boom", &crate::gen::EMOJIS[155]),
+        ("owl", &crate::gen::EMOJIS[2097]),
+        ("dragon", &crate::gen::EMOJIS[2112]),
+        ("up", &crate::gen::EMOJIS[3038]),
+        ("monorail", &crate::gen::EMOJIS[2379]),
+        ("radio", &crate::gen::EMOJIS[2671]),
+        ("negative_squared_cross_mark", &crate::gen::EMOJIS[2995]),
+        ("tophat", &crate::gen::EMOJIS[2645]),
+        ("person_white_hair", &crate::gen::EMOJIS[592]),
+        ("bolivia", &crate::gen::EMOJIS[3128]),
+        ("running_woman", &crate::gen::EMOJIS[1625]),
+        ("heavy_equals_sign", &crate::gen::EMOJIS[2973]),
+        ("tired_face", &crate::gen::EMOJIS[97]),
+        ("diamond_shape_with_a_dot_inside", &crate::gen::EMOJIS[3087]),
+        ("man_with_veil", &crate::gen::EMOJIS[1258]),
+        ("houses", &crate::gen::EMOJIS[2327]),
+        ("potable_water", &crate::gen::EMOJIS[2870]),
+        ("rowing_man", &crate::gen::EMOJIS[1744]),
+        ("yemen", &crate::gen::EMOJIS[3352]),
+        ("papua_new_guinea", &crate::gen::EMOJIS[3280]),
+        ("sri_lanka", &crate::gen::EMOJIS[3234]),
+        ("czech_republic", &crate::gen::EMOJIS[3156]),
+        ("zombie", &crate::gen::EMOJIS[1465]),
+        ("peace_symbol", &crate::gen::EMOJIS[2924]),
+        ("mauritius", &crate::gen::EMOJIS[3258]),
+        ("face_with_thermometer", &crate::gen::EMOJIS[56]),
+        ("soccer", &crate::gen::EMOJIS[2549]),
+        ("man_teacher", &crate::gen::EMOJIS[862]),
+        ("sunrise", &crate::gen::EMOJIS[2359]),
+        ("smoking", &crate::gen::EMOJIS[2859]),
+        ("snowman", &crate::gen::EMOJIS[2517]),
+        ("isle_of_man", &crate::gen::EMOJIS[3208]),
+        ("probing_cane", &crate::gen::EMOJIS[2813]),
+        ("moon_cake", &crate::gen::EMOJIS[2253]),
+        ("speech_balloon", &crate::gen::EMOJIS[160]),
+        ("sa", &crate::gen::EMOJIS[3041]),
+        ("yen", &crate::gen::EMOJIS[2738]),
+        ("sudan", &crate::gen::EMOJIS[3300]),
+        ("romania", &crate::gen::EMOJIS[3293]),
+        ("triangular_ruler", &crate::gen::EMOJIS[2784]),
+        ("microscope", &crate::gen::EMOJIS[2824]),
+        ("brown_circle", &crate::gen::EMOJIS[3063]),
+        ("singapore", &crate::gen::EMOJIS[3302]),
+        ("computer_mouse", &crate::gen::EMOJIS[2696]),
+        ("smiling_face_with_three_hearts", &crate::gen::EMOJIS[14]),
+        ("world_map", &crate::gen::EMOJIS[2308]),
+        ("clock7", &crate::gen::EMOJIS[2465]),
+        ("bank", &crate::gen::EMOJIS[2335]),
+        ("vampire_woman", &crate::gen::EMOJIS[1420]),
+        ("service_dog", &crate::gen::EMOJIS[2026]),
+        ("card_file_box", &crate::gen::EMOJIS[2786]),
+        ("kangaroo", &crate::gen::EMOJIS[2082]),
+        ("golfing_man", &crate::gen::EMOJIS[1708]),
+        ("palm_up_hand", &crate::gen::EMOJIS[214]),
+        ("birthday", &crate::gen::EMOJIS[2268]),
+        ("nigeria", &crate::gen::EMOJIS[3268]),
+        ("brazil", &crate::gen::EMOJIS[3130]),
+        ("hotel", &crate::gen::EMOJIS[2336]),
+        ("outbox_tray", &crate::gen::EMOJIS[2750]),
+        ("confetti_ball", &crate::gen::EMOJIS[2530]),
+        ("soap", &crate::gen::EMOJIS[2853]),
+        ("person_feeding_baby", &crate::gen::EMOJIS[1306]),
+        ("clinking_glasses", &crate::gen::EMOJIS[2289]),
+        ("battery", &crate::gen::EMOJIS[2689]),
+        ("derelict_house", &crate::gen::EMOJIS[2328]),
+        ("studio_microphone", &crate::gen::EMOJIS[2666]),
+        ("england", &crate::gen::EMOJIS[3357]),
+        ("gift", &crate::gen::EMOJIS[2539]),
+        ("seven", &crate::gen::EMOJIS[3014]),
+        ("globe_with_meridians", &crate::gen::EMOJIS[2307]),
+        ("ledger", &crate::gen::EMOJIS[2727]),
+        ("woozy_face", &crate::gen::EMOJIS[63]),
+        ("raised_back_of_hand", &crate::gen::EMOJIS[172]),
+        ("ru", &crate::gen::EMOJIS[3295]),
+        ("passenger_ship", &crate::gen::EMOJIS[2425]),
+        ("cl", &crate::gen::EMOJIS[3026]),
+        ("new_zealand", &crate::gen::EMOJIS[3275]),
+        ("israel", &crate::gen::EMOJIS[3207]),
+        ("grey_question", &crate::gen::EMOJIS[2978]),
+        ("rock", &crate::gen::EMOJIS[2324]),
+        ("five", &crate::gen::EMOJIS[3012]),
+        ("smile", &crate::gen::EMOJIS[2]),
+        ("face_in_clouds", &crate::gen::EMOJIS[42]),
+        ("cat2", &crate::gen::EMOJIS[2032]),
+        ("shrug", &crate::gen::EMOJIS[802]),
+        ("australia", &crate::gen::EMOJIS[3112]),
+        ("kissing_cat", &crate::gen::EMOJIS[120]),
+        ("desert_island", &crate::gen::EMOJIS[2318]),
+        ("merperson", &crate::gen::EMOJIS[1426]),
+        ("man_in_manual_wheelchair", &crate::gen::EMOJIS[1601]),
+        ("paperclips", &crate::gen::EMOJIS[2782]),
+        ("tooth", &crate::gen::EMOJIS[465]),
+        ("sunny", &crate::gen::EMOJIS[2488]),
+        ("martial_arts_uniform", &crate::gen::EMOJIS[2566]),
+        ("date", &crate::gen::EMOJIS[2770]),
+        ("gear", &crate::gen::EMOJIS[2810]),
+        ("artificial_satellite", &crate::gen::EMOJIS[2439]),
+        ("ox", &crate::gen::EMOJIS[2047]),
+        ("slightly_smiling_face", &crate::gen::EMOJIS[8]),
+        ("antigua_barbuda", &crate::gen::EMOJIS[3103]),
+        ("hatched_chick", &crate::gen::EMOJIS[2090]),
+        ("deaf_man", &crate::gen::EMOJIS[754]),
+        ("black_joker", &crate::gen::EMOJIS[2597]),
+        ("motorway", &crate::gen::EMOJIS[2410]),
+        ("cold_sweat", &crate::gen::EMOJIS[87]),
+        ("guard", &crate::gen::EMOJIS[1144]),
+        ("person_in_manual_wheelchair", &crate::gen::EMOJIS[1595]),
+        ("musical_keyboard", &crate::gen::EMOJIS[2675]),
+        ("last_quarter_moon_with_face", &crate::gen::EMOJIS[2486]),
+        ("purse", &crate::gen::EMOJIS[2628]),
+        ("waxing_gibbous_moon", &crate::gen::EMOJIS[2478]),
+        ("smile_cat", &crate::gen::EMOJIS[116]),
+        ("mrs_claus", &crate::gen::EMOJIS[1324]),
+        ("sparkle", &crate::gen::EMOJIS[3001]),
+        ("walking_man", &crate::gen::EMOJIS[1511]),
+        ("costa_rica", &crate::gen::EMOJIS[3150]),
+        ("mountain_bicyclist", &crate::gen::EMOJIS[1828]),
+        ("man_dancing", &crate::gen::EMOJIS[1637]),
+        ("pouting_man", &crate::gen::EMOJIS[664]),
+        ("luxembourg", &crate::gen::EMOJIS[3238]),
+        ("microbe", &crate::gen::EMOJIS[2142]),
+        ("sandwich", &crate::gen::EMOJIS[2224]),
+        ("eagle", &crate::gen::EMOJIS[2094]),
+        ("white_check_mark", &crate::gen::EMOJIS[2991]),
+        ("diving_mask", &crate::gen::EMOJIS[2571]),
+        ("calling", &crate::gen::EMOJIS[2684]),
+        ("no_entry", &crate::gen::EMOJIS[2883]),
+        ("scarf", &crate::gen::EMOJIS[2615]),
+        ("dominica", &crate::gen::EMOJIS[3161]),
+        ("field_hockey", &crate::gen::EMOJIS[2560]),
+        ("egypt", &crate::gen::EMOJIS[3167]),
+        ("roller_skate", &crate::gen::EMOJIS[2408]),
+        ("sint_maarten", &crate::gen::EMOJIS[3315]),
+        ("argentina", &crate::gen::EMOJIS[3109]),
+        ("airplane", &crate::gen::EMOJIS[2429]),
+        ("framed_picture", &crate::gen::EMOJIS[2601]),
+        ("surfing_man", &crate::gen::EMOJIS[1726]),
+        ("blue_car", &crate::gen::EMOJIS[2394]),
+        ("prayer_beads", &crate::gen::EMOJIS[2650]),
+        ("man_pilot", &crate::gen::EMOJIS[1060]),
+        ("brain", &crate::gen::EMOJIS[462]),
+        ("clock1", &crate::gen::EMOJIS[2453]),
+        ("inbox_tray", &crate::gen::EMOJIS[2751]),
+        ("guardswoman", &crate::gen::EMOJIS[1156]),
+        ("zero", &crate::gen::EMOJIS[3007]),
+        ("partying_face", &crate::gen::EMOJIS[68]),
+        ("corn", &crate::gen::EMOJIS[2194]),
+        ("woman_dancing", &crate::gen::EMOJIS[1631]),
+        ("one", &crate::gen::EMOJIS[3008]),
+        ("pleading_face", &crate::gen::EMOJIS[82]),
+        ("farmer", &crate::gen::EMOJIS[892]),
+        ("shower", &crate::gen::EMOJIS[2843]),
+        ("badminton", &crate::gen::EMOJIS[2564]),
+        ("recycle", &crate::gen::EMOJIS[2985]),
+        ("cityscape", &crate::gen::EMOJIS[2357]),
+        ("ethiopia", &crate::gen::EMOJIS[3171]),
+        ("kneeling_man", &crate::gen::EMOJIS[1547]),
+        ("leaves", &crate::gen::EMOJIS[2167]),
+        ("violin", &crate::gen::EMOJIS[2677]),
+        ("fearful", &crate::gen::EMOJIS[86]),
+        ("womens", &crate::gen::EMOJIS[2873]),
+        ("high_heel", &crate::gen::EMOJIS[2638]),
+        ("sos", &crate::gen::EMOJIS[3037]),
+        ("bucket", &crate::gen::EMOJIS[2852]),
+        ("stew", &crate::gen::EMOJIS[2233]),
+        ("family_man_boy", &crate::gen::EMOJIS[2004]),
+        ("red_envelope", &crate::gen::EMOJIS[2537]),
+        ("wood", &crate::gen::EMOJIS[2325]),
+        ("ok_person", &crate::gen::EMOJIS[694]),
+        ("family_woman_woman_girl_girl", &crate::gen::EMOJIS[2003]),
+        ("grey_exclamation", &crate::gen::EMOJIS[2979]),
+        ("sailboat", &crate::gen::EMOJIS[2422]),
+        ("antarctica", &crate::gen::EMOJIS[3108]),
+        ("curly_loop", &crate::gen::EMOJIS[2996]),
+        ("wales", &crate::gen::EMOJIS[3359]),
+        ("couplekiss", &crate::gen::EMOJIS[1970]),
+        ("heart_hands", &crate::gen::EMOJIS[376]),
+        ("astonished", &crate::gen::EMOJIS[80]),
+        ("bhutan", &crate::gen::EMOJIS[3132]),
+        ("point_down", &crate::gen::EMOJIS[310]),
+        ("fishing_pole_and_fish", &crate::gen::EMOJIS[2570]),
+        ("mans_shoe", &crate::gen::EMOJIS[2634]),
+        ("handshake", &crate::gen::EMOJIS[394]),
+        ("bikini", &crate::gen::EMOJIS[2625]),
+        ("money_with_wings", &crate::gen::EMOJIS[2742]),
+        ("man_facepalming", &crate::gen::EMOJIS[790]),
+        ("fire_extinguisher", &crate::gen::EMOJIS[2857]),
+        ("jersey", &crate::gen::EMOJIS[3215]),
+        ("jp", &crate::gen::EMOJIS[3218]),
+        ("coconut", &crate::gen::EMOJIS[2189]),
+        ("angry", &crate::gen::EMOJIS[101]),
+        ("ring", &crate::gen::EMOJIS[2652]),
+        ("alembic", &crate::gen::EMOJIS[2820]),
+        ("man_cook", &crate::gen::EMOJIS[916]),
+        ("stuffed_flatbread", &crate::gen::EMOJIS[2228]),
+        ("cop", &crate::gen::EMOJIS[1108]),
+        ("potted_plant", &crate::gen::EMOJIS[2156]),
+        ("portugal", &crate::gen::EMOJIS[3288]),
+        ("cloud", &crate::gen::EMOJIS[2496]),
+        ("japanese_goblin", &crate::gen::EMOJIS[110]),
+        ("muscle", &crate::gen::EMOJIS[424]),
+        ("latvia", &crate::gen::EMOJIS[3239]),
+        ("cartwheeling", &crate::gen::EMOJIS[1846]),
+        ("beers", &crate::gen::EMOJIS[2288]),
+        ("u5272", &crate::gen::EMOJIS[3046]),
+        ("oncoming_automobile", &crate::gen::EMOJIS[2393]),
+        ("love_hotel", &crate::gen::EMOJIS[2337]),
+        ("fast_forward", &crate::gen::EMOJIS[2945]),
+        ("oman", &crate::gen::EMOJIS[3276]),
+        ("green_salad", &crate::gen::EMOJIS[2236]),
+        ("chipmunk", &crate::gen::EMOJIS[2071]),
+        ("serbia", &crate::gen::EMOJIS[3294]),
+        ("zimbabwe", &crate::gen::EMOJIS[3356]),
+        ("bouncing_ball_man", &crate::gen::EMOJIS[1780]),
+        ("peacock", &crate::gen::EMOJIS[2101]),
+        ("massage", &crate::gen::EMOJIS[1469]),
+        ("bricks", &crate::gen::EMOJIS[2323]),
+        ("hot_pepper", &crate::gen::EMOJIS[2195]),
+        ("earth_asia", &crate::gen::EMOJIS[2306]),
+        ("lantern", &crate::gen::EMOJIS[2717]),
+        ("taiwan", &crate::gen::EMOJIS[3333]),
+        ("twisted_rightwards_arrows", &crate::gen::EMOJIS[2941]),
+        ("registered", &crate::gen::EMOJIS[3003]),
+        ("boy", &crate::gen::EMOJIS[484]),
+        ("cuba", &crate::gen::EMOJIS[3151]),
+        ("biking_woman", &crate::gen::EMOJIS[1822]),
+        ("cup_with_straw", &crate::gen::EMOJIS[2292]),
+        ("skier", &crate::gen::EMOJIS[1695]),
+        ("bubbles", &crate::gen::EMOJIS[2854]),
+        ("chair", &crate::gen::EMOJIS[2840]),
+        ("reunion", &crate::gen::EMOJIS[3292]),
+        ("dango", &crate::gen::EMOJIS[2254]),
+        ("crutch", &crate::gen::EMOJIS[2831]),
+        ("white_haired_man", &crate::gen::EMOJIS[544]),
+        ("red_haired_woman", &crate::gen::EMOJIS[562]),
+        ("olive", &crate::gen::EMOJIS[2188]),
+        ("u7121", &crate::gen::EMOJIS[3047]),
+        ("pregnant_person", &crate::gen::EMOJIS[1282]),
+        ("fr", &crate::gen::EMOJIS[3178]),
+        ("no_smoking", &crate::gen::EMOJIS[2886]),
+        ("next_track_button", &crate::gen::EMOJIS[2946]),
+        ("scream_cat", &crate::gen::EMOJIS[121]),
+        ("yawning_face", &crate::gen::EMOJIS[98]),
+        ("car", &crate::gen::EMOJIS[2392]),
+        ("turkmenistan", &crate::gen::EMOJIS[3327]),
+        ("envelope", &crate::gen::EMOJIS[2746]),
+        ("mushroom", &crate::gen::EMOJIS[2170]),
+        ("elf_man", &crate::gen::EMOJIS[1450]),
+        ("fuelpump", &crate::gen::EMOJIS[2413]),
+        ("golf", &crate::gen::EMOJIS[2568]),
+        ("construction_worker_woman", &crate::gen::EMOJIS[1180]),
+        ("fiji", &crate::gen::EMOJIS[3174]),
+        ("tajikistan", &crate::gen::EMOJIS[3324]),
+        ("pear", &crate::gen::EMOJIS[2181]),
+        ("runner", &crate::gen::EMOJIS[1613]),
+        ("yellow_circle", &crate::gen::EMOJIS[3059]),
+        ("family_man_woman_girl_girl", &crate::gen::EMOJIS[1993]),
+        ("clock830", &crate::gen::EMOJIS[2468]),
+        ("national_park", &crate::gen::EMOJIS[2319]),
+        ("raised_hands", &crate::gen::EMOJIS[370]),
+        ("one_piece_swimsuit", &crate::gen::EMOJIS[2622]),
+        ("fire_engine", &crate::gen::EMOJIS[2387]),
+        ("crying_cat_face", &crate::gen::EMOJIS[122]),
+        ("bowl_with_spoon", &crate::gen::EMOJIS[2235]),
+        ("apple", &crate::gen::EMOJIS[2179]),
+        ("colombia", &crate::gen::EMOJIS[3148]),
+        ("elephant", &crate::gen::EMOJIS[2061]),
+        ("family_woman_girl_boy", &crate::gen::EMOJIS[2012]),
+        ("game_die", &crate::gen::EMOJIS[2586]),
+        ("parking", &crate::gen::EMOJIS[3036]),
+        ("zzz", &crate::gen::EMOJIS[165]),
+        ("woman_in_manual_wheelchair", &crate::gen::EMOJIS[1607]),
+        ("man_singer", &crate::gen::EMOJIS[1024]),
+        ("arrow_heading_up", &crate::gen::EMOJIS[2906]),
+        ("seal", &crate::gen::EMOJIS[2118]),
+        ("drum", &crate::gen::EMOJIS[2679]),
+        ("leopard", &crate::gen::EMOJIS[2037]),
+        ("ice_hockey", &crate::gen::EMOJIS[2561]),
+        ("comet", &crate::gen::EMOJIS[2518]),
+        ("mage_woman", &crate::gen::EMOJIS[1384]),
+        ("stop_sign", &crate::gen::EMOJIS[2418]),
+        ("vampire", &crate::gen::EMOJIS[1408]),
+        ("boxing_glove", &crate::gen::EMOJIS[2565]),
+        ("notebook", &crate::gen::EMOJIS[2726]),
+        ("bell", &crate::gen::EMOJIS[2661]),
+        ("raising_hand_woman", &crate::gen::EMOJIS[742]),
+        ("genie_woman", &crate::gen::EMOJIS[1464]),
+        ("four", &crate::gen::EMOJIS[3011]),
+        ("metal", &crate::gen::EMOJIS[274]),
+        ("eye_speech_bubble", &crate::gen::EMOJIS[161]),
+        ("polar_bear", &crate::gen::EMOJIS[2076]),
+        ("fist_oncoming", &crate::gen::EMOJIS[346]),
+        ("abacus", &crate::gen::EMOJIS[2702]),
+        ("man_mechanic", &crate::gen::EMOJIS[934]),
+        ("softball", &crate::gen::EMOJIS[2551]),
+        ("clipperton_island", &crate::gen::EMOJIS[3149]),
+        ("bride_with_veil", &crate::gen::EMOJIS[1264]),
+        ("de", &crate::gen::EMOJIS[3157]),
+        ("teapot", &crate::gen::EMOJIS[2280]),
+        ("jar", &crate::gen::EMOJIS[2302]),
+        ("woman_in_tuxedo", &crate::gen::EMOJIS[1246]),
+        ("sun_behind_large_cloud", &crate::gen::EMOJIS[2500]),
+        ("northern_mariana_islands", &crate::gen::EMOJIS[3253]),
+        ("spider", &crate::gen::EMOJIS[2136]),
+        ("family_man_boy_boy", &crate::gen::EMOJIS[2005]),
+        ("cloud_with_snow", &crate::gen::EMOJIS[2503]),
+        ("identification_card", &crate::gen::EMOJIS[2867]),
+        ("thinking", &crate::gen::EMOJIS[34]),
+        ("no_good_man", &crate::gen::EMOJIS[682]),
+        ("adult", &crate::gen::EMOJIS[496]),
+        ("butter", &crate::gen::EMOJIS[2238]),
+        ("accordion", &crate::gen::EMOJIS[2673]),
+        ("woman_scientist", &crate::gen::EMOJIS[994]),
+        ("face_with_diagonal_mouth", &crate::gen::EMOJIS[74]),
+        ("ok", &crate::gen::EMOJIS[3035]),
+        ("robot", &crate::gen::EMOJIS[114]),
+        ("last_quarter_moon", &crate::gen::EMOJIS[2481]),
+        ("play_or_pause_button", &crate::gen::EMOJIS[2947]),
+        ("lock", &crate::gen::EMOJIS[2789]),
+        ("tm", &crate::gen::EMOJIS[3004]),
+        ("rowboat", &crate::gen::EMOJIS[1738]),
+        ("kiribati", &crate::gen::EMOJIS[3222]),
+        ("equatorial_guinea", &crate::gen::EMOJIS[3191]),
+        ("vanuatu", &crate::gen::EMOJIS[3348]),
+        ("electric_plug", &crate::gen::EMOJIS[2691]),
+        ("u7533", &crate::gen::EMOJIS[3050]),
+        ("kenya", &crate::gen::EMOJIS[3219]),
+        ("wave", &crate::gen::EMOJIS[166]),
+        ("footprints", &crate::gen::EMOJIS[2018]),
+        ("foggy", &crate::gen::EMOJIS[2355]),
+        ("woman_with_headscarf", &crate::gen::EMOJIS[1228]),
+        ("calendar", &crate::gen::EMOJIS[2771]),
+        ("family_man_man_girl_boy", &crate::gen::EMOJIS[1996]),
+        ("sleeping", &crate::gen::EMOJIS[54]),
+        ("black_medium_small_square", &crate::gen::EMOJIS[3077]),
+        ("uzbekistan", &crate::gen::EMOJIS[3341]),
+        ("hamburger", &crate::gen::EMOJIS[2220]),
+        ("sagittarius", &crate::gen::EMOJIS[2936]),
+        ("telephone_receiver", &crate::gen::EMOJIS[2686]),
+        ("swimming_man", &crate::gen::EMOJIS[1762]),
+        ("man", &crate::gen::EMOJIS[508]),
+        ("british_indian_ocean_territory", &crate::gen::EMOJIS[3210]),
+        ("mailbox", &crate::gen::EMOJIS[2753]),
+        ("peach", &crate::gen::EMOJIS[2182]),
+        ("custard", &crate::gen::EMOJIS[2275]),
+        ("european_castle", &crate::gen::EMOJIS[2343]),
+        ("basketball", &crate::gen::EMOJIS[2552]),
+        ("fleur_de_lis", &crate::gen::EMOJIS[2986]),
+        ("couple_with_heart", &crate::gen::EMOJIS[1979]),
+        ("eight_pointed_black_star", &crate::gen::EMOJIS[3000]),
+        ("cloud_with_lightning_and_rain", &crate::gen::EMOJIS[2498]),
+        ("kick_scooter", &crate::gen::EMOJIS[2406]),
+        ("do_not_litter", &crate::gen::EMOJIS[2887]),
+        ("magic_wand", &crate::gen::EMOJIS[2582]),
+        ("house_with_garden", &crate::gen::EMOJIS[2330]),
+        ("chocolate_bar", &crate::gen::EMOJIS[2272]),
+        ("cry", &crate::gen::EMOJIS[89]),
+        ("albania", &crate::gen::EMOJIS[3105]),
+        ("white_flag", &crate::gen::EMOJIS[3095]),
+        ("currency_exchange", &crate::gen::EMOJIS[2982]),
+        ("syringe", &crate::gen::EMOJIS[2827]),
+        ("ticket", &crate::gen::EMOJIS[2542]),
+        ("alien", &crate::gen::EMOJIS[112]),
+        ("rofl", &crate::gen::EMOJIS[6]),
+        ("woman_firefighter", &crate::gen::EMOJIS[1102]),
+        ("ophiuchus", &crate::gen::EMOJIS[2940]),
+        ("mortar_board", &crate::gen::EMOJIS[2646]),
+        ("copyright", &crate::gen::EMOJIS[3002]),
+        ("leg", &crate::gen::EMOJIS[432]),
+        ("man_firefighter", &crate::gen::EMOJIS[1096]),
+        ("sneezing_face", &crate::gen::EMOJIS[60]),
+        ("herb", &crate::gen::EMOJIS[2162]),
+        ("duck", &crate::gen::EMOJIS[2095]),
+        ("alarm_clock", &crate::gen::EMOJIS[2447]),
+        ("nauru", &crate::gen::EMOJIS[3273]),
+        ("no_good", &crate::gen::EMOJIS[676]),
+        ("mountain_cableway", &crate::gen::EMOJIS[2437]),
+        ("bridge_at_night", &crate::gen::EMOJIS[2362]),
+        ("standing_man", &crate::gen::EMOJIS[1529]),
+        ("clubs", &crate::gen::EMOJIS[2595]),
+        ("safety_vest", &crate::gen::EMOJIS[2611]),
+        ("house", &crate::gen::EMOJIS[2329]),
+        ("running_shirt_with_sash", &crate::gen::EMOJIS[2572]),
+        ("artist", &crate::gen::EMOJIS[1036]),
+        ("guinea_bissau", &crate::gen::EMOJIS[3196]),
+        ("frog", &crate::gen::EMOJIS[2106]),
+        ("broom", &crate::gen::EMOJIS[2849]),
+        ("desktop_computer", &crate::gen::EMOJIS[2693]),
+        ("woman_farmer", &crate::gen::EMOJIS[904]),
+        ("fireworks", &crate::gen::EMOJIS[2524]),
+        ("mongolia", &crate::gen::EMOJIS[3251]),
+        ("closed_book", &crate::gen::EMOJIS[2720]),
+        ("no_entry_sign", &crate::gen::EMOJIS[2884]),
+        ("blush", &crate::gen::EMOJIS[12]),
+        ("massage_woman", &crate::gen::EMOJIS[1481]),
+        ("man_office_worker", &crate::gen::EMOJIS[970]),
+        ("libra", &crate::gen::EMOJIS[2934]),
+        ("central_african_republic", &crate::gen::EMOJIS[3140]),
+        ("arrow_upper_left", &crate::gen::EMOJIS[2901]),
+        ("blond_haired_person", &crate::gen::EMOJIS[502]),
+        ("pregnant_woman", &crate::gen::EMOJIS[1270]),
+        ("firefighter", &crate::gen::EMOJIS[1090]),
+        ("1234", &crate::gen::EMOJIS[3020]),
+        ("convenience_store", &crate::gen::EMOJIS[2338]),
+        ("sassy_man", &crate::gen::EMOJIS[718]),
+        ("scissors", &crate::gen::EMOJIS[2785]),
+        ("frowning", &crate::gen::EMOJIS[84]),
+        ("flatbread", &crate::gen::EMOJIS[2210]),
+        ("sloth", &crate::gen::EMOJIS[2079]),
+        ("weight_lifting_woman", &crate::gen::EMOJIS[1804]),
+        ("clock1230", &crate::gen::EMOJIS[2452]),
+        ("tipping_hand_man", &crate::gen::EMOJIS[718]),
+        ("tiger2", &crate::gen::EMOJIS[2036]),
+        ("question", &crate::gen::EMOJIS[2977]),
+        ("mage_man", &crate::gen::EMOJIS[1378]),
+        ("belarus", &crate::gen::EMOJIS[3135]),
+        ("christmas_tree", &crate::gen::EMOJIS[2523]),
+        ("man_farmer", &crate::gen::EMOJIS[898]),
+        ("chart", &crate::gen::EMOJIS[2745]),
+        ("saudi_arabia", &crate::gen::EMOJIS[3297]),
+        ("saxophone", &crate::gen::EMOJIS[2672]),
+        ("morocco", &crate::gen::EMOJIS[3241]),
+        ("bearded_person", &crate::gen::EMOJIS[514]),
+        ("person_curly_hair", &crate::gen::EMOJIS[580]),
+        ("couple_with_heart_woman_man", &crate::gen::EMOJIS[1985]),
+        ("metro", &crate::gen::EMOJIS[2375]),
+        ("manual_wheelchair", &crate::gen::EMOJIS[2402]),
+        ("oil_drum", &crate::gen::EMOJIS[2412]),
+        ("american_samoa", &crate::gen::EMOJIS[3110]),
+        ("small_orange_diamond", &crate::gen::EMOJIS[3083]),
+        ("blueberries", &crate::gen::EMOJIS[2185]),
+        ("zipper_mouth_face", &crate::gen::EMOJIS[36]),
+        ("zombie_man", &crate::gen::EMOJIS[1466]),
+        ("roll_eyes", &crate::gen::EMOJIS[45]),
+        ("black_medium_square", &crate::gen::EMOJIS[3075]),
+        ("unamused", &crate::gen::EMOJIS[44]),
+        ("lion", &crate::gen::EMOJIS[2034]),
+        ("shit", &crate::gen::EMOJIS[107]),
+        ("green_heart", &crate::gen::EMOJIS[144]),
+        ("balance_scale", &crate::gen::EMOJIS[2812]),
+        ("diamonds", &crate::gen::EMOJIS[2594]),
+        ("video_camera", &crate::gen::EMOJIS[2710]),
+        ("salt", &crate::gen::EMOJIS[2239]),
+        ("kissing_smiling_eyes", &crate::gen::EMOJIS[21]),
+        ("child", &crate::gen::EMOJIS[478]),
+        ("volleyball", &crate::gen::EMOJIS[2553]),
+        ("rotating_light", &crate::gen::EMOJIS[2415]),
+        ("rooster", &crate::gen::EMOJIS[2087]),
+        ("cayman_islands", &crate::gen::EMOJIS[3228]),
+        ("bouncing_ball_person", &crate::gen::EMOJIS[1774]),
+        ("lotus", &crate::gen::EMOJIS[2146]),
+        ("hamsa", &crate::gen::EMOJIS[2864]),
+        ("loudspeaker", &crate::gen::EMOJIS[2658]),
+        ("ferris_wheel", &crate::gen::EMOJIS[2366]),
+        ("couple_with_heart_man_man", &crate::gen::EMOJIS[1986]),
+        ("warning", &crate::gen::EMOJIS[2881]),
+        ("bald_man", &crate::gen::EMOJIS[550]),
+        ("man_cartwheeling", &crate::gen::EMOJIS[1852]),
+        ("tamale", &crate::gen::EMOJIS[2227]),
+        ("foot", &crate::gen::EMOJIS[438]),
+        ("woman_in_motorized_wheelchair", &crate::gen::EMOJIS[1589]),
+        ("fallen_leaf", &crate::gen::EMOJIS[2166]),
+        ("beverage_box", &crate::gen::EMOJIS[2294]),
+        ("mage", &crate::gen::EMOJIS[1372]),
+        ("vibration_mode", &crate::gen::EMOJIS[2964]),
+        ("man_beard", &crate::gen::EMOJIS[520]),
+        ("woman_feeding_baby", &crate::gen::EMOJIS[1294]),
+        ("thought_balloon", &crate::gen::EMOJIS[164]),
+        ("pouting_woman", &crate::gen::EMOJIS[670]),
+        ("keyboard", &crate::gen::EMOJIS[2695]),
+        ("police_car", &crate::gen::EMOJIS[2388]),
+        ("lying_face", &crate::gen::EMOJIS[48]),
+        ("books", &crate::gen::EMOJIS[2725]),
+        ("camera_flash", &crate::gen::EMOJIS[2709]),
+        ("ghost", &crate::gen::EMOJIS[111]),
+        ("men_wrestling", &crate::gen::EMOJIS[1865]),
+        ("purple_heart", &crate::gen::EMOJIS[147]),
+        ("bus", &crate::gen::EMOJIS[2382]),
+        ("denmark", &crate::gen::EMOJIS[3160]),
+        ("falkland_islands", &crate::gen::EMOJIS[3175]),
+        ("guardsman", &crate::gen::EMOJIS[1150]),
+        ("kissing", &crate::gen::EMOJIS[18]),
+        ("pager", &crate::gen::EMOJIS[2687]),
+        ("loud_sound", &crate::gen::EMOJIS[2657]),
+        ("slightly_frowning_face", &crate::gen::EMOJIS[76]),
+        ("cherries", &crate::gen::EMOJIS[2183]),
+        ("point_right", &crate::gen::EMOJIS[292]),
+        ("heavy_multiplication_x", &crate::gen::EMOJIS[2969]),
+        ("no_mobile_phones", &crate::gen::EMOJIS[2890]),
+        ("joystick", &crate::gen::EMOJIS[2584]),
+        ("anguilla", &crate::gen::EMOJIS[3104]),
+        ("purple_square", &crate::gen::EMOJIS[3071]),
+        ("montserrat", &crate::gen::EMOJIS[3256]),
+        ("smirk_cat", &crate::gen::EMOJIS[119]),
+        ("clock11", &crate::gen::EMOJIS[2473]),
+        ("put_litter_in_its_place", &crate::gen::EMOJIS[2869]),
+        ("beans", &crate::gen::EMOJIS[2203]),
+        ("hospital", &crate::gen::EMOJIS[2334]),
+        ("coral", &crate::gen::EMOJIS[2125]),
+        ("yellow_square", &crate::gen::EMOJIS[3068]),
+        ("ireland", &crate::gen::EMOJIS[3206]),
+        ("bangbang", &crate::gen::EMOJIS[2975]),
+        ("cook", &crate::gen::EMOJIS[910]),
+        ("kyrgyzstan", &crate::gen::EMOJIS[3220]),
+        ("egg", &crate::gen::EMOJIS[2230]),
+        ("monkey", &crate::gen::EMOJIS[2020]),
+        ("ideograph_advantage", &crate::gen::EMOJIS[3045]),
+        ("beer", &crate::gen::EMOJIS[2287]),
+        ("cn", &crate::gen::EMOJIS[3147]),
+        ("ear_of_rice", &crate::gen::EMOJIS[2161]),
+        ("cd", &crate::gen::EMOJIS[2700]),
+        ("gloves", &crate::gen::EMOJIS[2616]),
+        ("sao_tome_principe", &crate::gen::EMOJIS[3313]),
+        ("lipstick", &crate::gen::EMOJIS[2651]),
+        ("basket", &crate::gen::EMOJIS[2850]),
+        ("red_car", &crate::gen::EMOJIS[2392]),
+        ("bird", &crate::gen::EMOJIS[2091]),
+        ("person_with_veil", &crate::gen::EMOJIS[1252]),
+        ("clock8", &crate::gen::EMOJIS[2467]),
+        ("hotdog", &crate::gen::EMOJIS[2223]),
+        ("eggplant", &crate::gen::EMOJIS[2191]),
+        ("rwanda", &crate::gen::EMOJIS[3296]),
+        ("watermelon", &crate::gen::EMOJIS[2173]),
+        ("ng_man", &crate::gen::EMOJIS[682]),
+        ("dagger", &crate::gen::EMOJIS[2800]),
+        ("relieved", &crate::gen::EMOJIS[50]),
+        ("cut_of_meat", &crate::gen::EMOJIS[2218]),
+        ("window", &crate::gen::EMOJIS[2837]),
+        ("suriname", &crate::gen::EMOJIS[3311]),
+        ("moyai", &crate::gen::EMOJIS[2865]),
+        ("supervillain", &crate::gen::EMOJIS[1354]),
+        ("necktie", &crate::gen::EMOJIS[2612]),
+        ("womans_clothes", &crate::gen::EMOJIS[2626]),
+        ("control_knobs", &crate::gen::EMOJIS[2668]),
+        ("round_pushpin", &crate::gen::EMOJIS[2780]),
+        ("gift_heart", &crate::gen::EMOJIS[129]),
+        ("film_strip", &crate::gen::EMOJIS[2704]),
+        ("woman_health_worker", &crate::gen::EMOJIS[832]),
+        ("moon", &crate::gen::EMOJIS[2478]),
+        ("long_drum", &crate::gen::EMOJIS[2680]),
+        ("newspaper_roll", &crate::gen::EMOJIS[2732]),
+        ("pen", &crate::gen::EMOJIS[2762]),
+        ("euro", &crate::gen::EMOJIS[2740]),
+        ("writing_hand", &crate::gen::EMOJIS[406]),
+        ("couple_with_heart_woman_woman", &crate::gen::EMOJIS[1987]),
+        ("large_orange_diamond", &crate::gen::EMOJIS[3081]),
+        ("curacao", &crate::gen::EMOJIS[3153]),
+        ("chile", &crate::gen::EMOJIS[3145]),
+        ("arrow_lower_right", &crate::gen::EMOJIS[2897]),
+        ("triumph", &crate::gen::EMOJIS[99]),
+        ("myanmar", &crate::gen::EMOJIS[3250]),
+        ("monaco", &crate::gen::EMOJIS[3242]),
+        ("guide_dog", &crate::gen::EMOJIS[2025]),
+        ("arrow_forward", &crate::gen::EMOJIS[2944]),
+        ("lady_beetle", &crate::gen::EMOJIS[2133]),
+        ("guyana", &crate::gen::EMOJIS[3197]),
+        ("sweat", &crate::gen::EMOJIS[95]),
+        ("clock630", &crate::gen::EMOJIS[2464]),
+        ("star_of_david", &crate::gen::EMOJIS[2918]),
+        ("student", &crate::gen::EMOJIS[838]),
+        ("candy", &crate::gen::EMOJIS[2273]),
+        ("busts_in_silhouette", &crate::gen::EMOJIS[2016]),
+        ("hatching_chick", &crate::gen::EMOJIS[2088]),
+        ("bamboo", &crate::gen::EMOJIS[2532]),
+        ("st_pierre_miquelon", &crate::gen::EMOJIS[3284]),
+        ("monkey_face", &crate::gen::EMOJIS[2019]),
+        ("black_nib", &crate::gen::EMOJIS[2760]),
+        ("imp", &crate::gen::EMOJIS[104]),
+        ("vampire_man", &crate::gen::EMOJIS[1414]),
+        ("llama", &crate::gen::EMOJIS[2059]),
+        ("weary", &crate::gen::EMOJIS[96]),
+        ("bowing_woman", &crate::gen::EMOJIS[778]),
+        ("large_blue_circle", &crate::gen::EMOJIS[3061]),
+        ("dollar", &crate::gen::EMOJIS[2739]),
+        ("tomato", &crate::gen::EMOJIS[2187]),
+        ("bed", &crate::gen::EMOJIS[2838]),
+        ("phone", &crate::gen::EMOJIS[2685]),
+        ("open_file_folder", &crate::gen::EMOJIS[2768]),
+        ("file_cabinet", &crate::gen::EMOJIS[2787]),
+        ("cloud_with_lightning", &crate::gen::EMOJIS[2504]),
+        ("on", &crate::gen::EMOJIS[2912]),
+        ("jamaica", &crate::gen::EMOJIS[3216]),
+        ("rose", &crate::gen::EMOJIS[2148]),
+        ("swan", &crate::gen::EMOJIS[2096]),
+        ("man_astronaut", &crate::gen::EMOJIS[1078]),
+        ("butterfly", &crate::gen::EMOJIS[2128]),
+        ("snowboarder", &crate::gen::EMOJIS[1696]),
+        ("water_buffalo", &crate::gen::EMOJIS[2048]),
+        ("shoe", &crate::gen::EMOJIS[2634]),
+        ("fist", &crate::gen::EMOJIS[340]),
+        ("st_kitts_nevis", &crate::gen::EMOJIS[3224]),
+        ("guam", &crate::gen::EMOJIS[3195]),
+        ("3rd_place_medal", &crate::gen::EMOJIS[2548]),
+        ("microphone", &crate::gen::EMOJIS[2669]),
+        ("fist_right", &crate::gen::EMOJIS[358]),
+        ("svalbard_jan_mayen", &crate::gen::EMOJIS[3305]),
+        ("hash", &crate::gen::EMOJIS[3005]),
+        ("arrow_backward", &crate::gen::EMOJIS[2948]),
+        ("lithuania", &crate::gen::EMOJIS[3237]),
+        ("rice", &crate::gen::EMOJIS[2244]),
+        ("a", &crate::gen::EMOJIS[3023]),
+        ("poultry_leg", &crate::gen::EMOJIS[2217]),
+        ("sleepy", &crate::gen::EMOJIS[52]),
+        ("two_women_holding_hands", &crate::gen::EMOJIS[1952]),
+        ("woman_cook", &crate::gen::EMOJIS[922]),
+        ("palm_tree", &crate::gen::EMOJIS[2159]),
+        ("video_game", &crate::gen::EMOJIS[2583]),
+        ("bathtub", &crate::gen::EMOJIS[2844]),
+        ("pensive", &crate::gen::EMOJIS[51]),
+        ("heavy_dollar_sign", &crate::gen::EMOJIS[2983]),
+        ("rowing_woman", &crate::gen::EMOJIS[1750]),
+        ("comoros", &crate::gen::EMOJIS[3223]),
+        ("mate", &crate::gen::EMOJIS[2295]),
+        ("bike", &crate::gen::EMOJIS[2405]),
+        ("printer", &crate::gen::EMOJIS[2694]),
+        ("otter", &crate::gen::EMOJIS[2080]),
+        ("man_in_motorized_wheelchair", &crate::gen::EMOJIS[1583]),
+        ("arrow_up_down", &crate::gen::EMOJIS[2902]),
+        ("confused", &crate::gen::EMOJIS[73]),
+        ("curling_stone", &crate::gen::EMOJIS[2575]),
+        ("level_slider", &crate::gen::EMOJIS[2667]),
+        ("fried_egg", &crate::gen::EMOJIS[2231]),
+        ("thumbsup", &crate::gen::EMOJIS[328]),
+        ("nut_and_bolt", &crate::gen::EMOJIS[2809]),
+        ("eight", &crate::gen::EMOJIS[3015]),
+        ("drop_of_blood", &crate::gen::EMOJIS[2828]),
+        ("sun_behind_small_cloud", &crate::gen::EMOJIS[2499]),
+        ("rescue_worker_helmet", &crate::gen::EMOJIS[2649]),
+        ("boomerang", &crate::gen::EMOJIS[2803]),
+        ("oyster", &crate::gen::EMOJIS[2262]),
+        ("family_man_man_boy_boy", &crate::gen::EMOJIS[1997]),
+        ("banjo", &crate::gen::EMOJIS[2678]),
+        ("bosnia_herzegovina", &crate::gen::EMOJIS[3116]),
+        ("woman_with_probing_cane", &crate::gen::EMOJIS[1571]),
+        ("slovakia", &crate::gen::EMOJIS[3306]),
+        ("hook", &crate::gen::EMOJIS[2816]),
+        ("point_up", &crate::gen::EMOJIS[316]),
+        ("ukraine", &crate::gen::EMOJIS[3335]),
+        ("fountain", &crate::gen::EMOJIS[2353]),
+        ("rhinoceros", &crate::gen::EMOJIS[2063]),
+        ("first_quarter_moon", &crate::gen::EMOJIS[2477]),
+        ("older_man", &crate::gen::EMOJIS[628]),
+        ("heavy_heart_exclamation", &crate::gen::EMOJIS[136]),
+        ("nicaragua", &crate::gen::EMOJIS[3269]),
+        ("factory", &crate::gen::EMOJIS[2341]),
+        ("raising_hand_man", &crate::gen::EMOJIS[736]),
+        ("cursing_face", &crate::gen::EMOJIS[102]),
+        ("family_woman_woman_girl", &crate::gen::EMOJIS[2000]),
+        ("minidisc", &crate::gen::EMOJIS[2698]),
+        ("nazar_amulet", &crate::gen::EMOJIS[2863]),
+        ("persevere", &crate::gen::EMOJIS[93]),
+        ("rice_ball", &crate::gen::EMOJIS[2243]),
+        ("pencil", &crate::gen::EMOJIS[2765]),
+        ("woman_shrugging", &crate::gen::EMOJIS[814]),
+        ("gambia", &crate::gen::EMOJIS[3188]),
+        ("bell_pepper", &crate::gen::EMOJIS[2196]),
+        ("clock10", &crate::gen::EMOJIS[2471]),
+        ("o", &crate::gen::EMOJIS[2990]),
+        ("prince", &crate::gen::EMOJIS[1192]),
+        ("oden", &crate::gen::EMOJIS[2249]),
+        ("bowling", &crate::gen::EMOJIS[2558]),
+        ("blossom", &crate::gen::EMOJIS[2152]),
+        ("heartbeat", &crate::gen::EMOJIS[132]),
+        ("camera", &crate::gen::EMOJIS[2708]),
+        ("customs", &crate::gen::EMOJIS[2878]),
+        ("athletic_shoe", &crate::gen::EMOJIS[2635]),
+        ("honey_pot", &crate::gen::EMOJIS[2276]),
+        ("top", &crate::gen::EMOJIS[2914]),
+        ("azerbaijan", &crate::gen::EMOJIS[3115]),
+        ("sled", &crate::gen::EMOJIS[2574]),
+        ("ferry", &crate::gen::EMOJIS[2426]),
+        ("clipboard", &crate::gen::EMOJIS[2778]),
+        ("person_red_hair", &crate::gen::EMOJIS[568]),
+        ("cancer", &crate::gen::EMOJIS[2931]),
+        ("nesting_dolls", &crate::gen::EMOJIS[2591]),
+        ("key", &crate::gen::EMOJIS[2793]),
+        ("hedgehog", &crate::gen::EMOJIS[2073]),
+        ("crescent_moon", &crate::gen::EMOJIS[2483]),
+        ("man_scientist", &crate::gen::EMOJIS[988]),
+        ("bagel", &crate::gen::EMOJIS[2212]),
+        ("mouse", &crate::gen::EMOJIS[2065]),
+        ("taurus", &crate::gen::EMOJIS[2929]),
+        ("unlock", &crate::gen::EMOJIS[2790]),
+        ("person_with_crown", &crate::gen::EMOJIS[1186]),
+        ("palestinian_territories", &crate::gen::EMOJIS[3287]),
+        ("puerto_rico", &crate::gen::EMOJIS[3286]),
+        ("palm_down_hand", &crate::gen::EMOJIS[208]),
+        ("chestnut", &crate::gen::EMOJIS[2204]),
+        ("guatemala", &crate::gen::EMOJIS[3194]),
+        ("woman_beard", &crate::gen::EMOJIS[526]),
+        ("smirk", &crate::gen::EMOJIS[43]),
+        ("philippines", &crate::gen::EMOJIS[3281]),
+        ("frowning_woman", &crate::gen::EMOJIS[652]),
+        ("tent", &crate::gen::EMOJIS[2354]),
+        ("flipper", &crate::gen::EMOJIS[2117]),
+        ("waning_crescent_moon", &crate::gen::EMOJIS[2482]),
+        ("no_pedestrians", &crate::gen::EMOJIS[2889]),
+        ("billed_cap", &crate::gen::EMOJIS[2647]),
+        ("hungary", &crate::gen::EMOJIS[3203]),
+        ("wallis_futuna", &crate::gen::EMOJIS[3349]),
+        ("takeout_box", &crate::gen::EMOJIS[2257]),
+        ("aland_islands", &crate::gen::EMOJIS[3114]),
+        ("ladder", &crate::gen::EMOJIS[2819]),
+        ("baby", &crate::gen::EMOJIS[472]),
+        ("pickup_truck", &crate::gen::EMOJIS[2395]),
+        ("hut", &crate::gen::EMOJIS[2326]),
+        ("bacon", &crate::gen::EMOJIS[2219]),
+        ("wheelchair", &crate::gen::EMOJIS[2871]),
+        ("beach_umbrella", &crate::gen::EMOJIS[2316]),
+        ("candle", &crate::gen::EMOJIS[2714]),
+        ("tongue", &crate::gen::EMOJIS[469]),
+        ("ship", &crate::gen::EMOJIS[2428]),
+        ("carrot", &crate::gen::EMOJIS[2193]),
+        ("right_anger_bubble", &crate::gen::EMOJIS[163]),
+        ("venezuela", &crate::gen::EMOJIS[3344]),
+        ("haircut", &crate::gen::EMOJIS[1487]),
+        ("arrow_left", &crate::gen::EMOJIS[2900]),
+        ("free", &crate::gen::EMOJIS[3028]),
+        ("pouring_liquid", &crate::gen::EMOJIS[2291]),
+        ("feather", &crate::gen::EMOJIS[2099]),
+        ("deaf_person", &crate::gen::EMOJIS[748]),
+        ("womans_hat", &crate::gen::EMOJIS[2644]),
+        ("man_health_worker", &crate::gen::EMOJIS[826]),
+        ("fish", &crate::gen::EMOJIS[2119]),
+        ("coffee", &crate::gen::EMOJIS[2279]),
+        ("peanuts", &crate::gen::EMOJIS[2202]),
+        ("clown_face", &crate::gen::EMOJIS[108]),
+        ("label", &crate::gen::EMOJIS[2735]),
+        ("carousel_horse", &crate::gen::EMOJIS[2364]),
+        ("man_with_gua_pi_mao", &crate::gen::EMOJIS[1222]),
+        ("racehorse", &crate::gen::EMOJIS[2041]),
+        ("razor", &crate::gen::EMOJIS[2846]),
+        ("part_alternation_mark", &crate::gen::EMOJIS[2998]),
+        ("motorized_wheelchair", &crate::gen::EMOJIS[2403]),
+        ("heavy_plus_sign", &crate::gen::EMOJIS[2970]),
+        ("nail_care", &crate::gen::EMOJIS[412]),
+        ("norfolk_island", &crate::gen::EMOJIS[3267]),
+        ("macedonia", &crate::gen::EMOJIS[3248]),
+        ("vomiting_face", &crate::gen::EMOJIS[59]),
+        ("shrimp", &crate::gen::EMOJIS[2260]),
+        ("clock2", &crate::gen::EMOJIS[2455]),
+        ("m", &crate::gen::EMOJIS[3031]),
+        ("panama", &crate::gen::EMOJIS[3277]),
+        ("tumbler_glass", &crate::gen::EMOJIS[2290]),
+        ("curry", &crate::gen::EMOJIS[2245]),
+        ("8ball", &crate::gen::EMOJIS[2580]),
+        ("middle_finger", &crate::gen::EMOJIS[304]),
+        ("symbols", &crate::gen::EMOJIS[3021]),
+        ("open_hands", &crate::gen::EMOJIS[382]),
+        ("judge", &crate::gen::EMOJIS[874]),
+        ("rightwards_hand", &crate::gen::EMOJIS[196]),
+        ("u55b6", &crate::gen::EMOJIS[3055]),
+        ("floppy_disk", &crate::gen::EMOJIS[2699]),
+        ("space_invader", &crate::gen::EMOJIS[113]),
+        ("anchor", &crate::gen::EMOJIS[2420]),
+        ("croatia", &crate::gen::EMOJIS[3201]),
+        ("japanese_castle", &crate::gen::EMOJIS[2342]),
+        ("surfing_woman", &crate::gen::EMOJIS[1732]),
+        ("white_flower", &crate::gen::EMOJIS[2145]),
+        ("elf", &crate::gen::EMOJIS[1444]),
+        ("family_woman_boy_boy", &crate::gen::EMOJIS[2010]),
+        ("mag_right", &crate::gen::EMOJIS[2713]),
+        ("dress", &crate::gen::EMOJIS[2619]),
+        ("motor_scooter", &crate::gen::EMOJIS[2401]),
+        ("money_mouth_face", &crate::gen::EMOJIS[28]),
+        ("performing_arts", &crate::gen::EMOJIS[2600]),
+        ("st_vincent_grenadines", &crate::gen::EMOJIS[3343]),
+        ("deciduous_tree", &crate::gen::EMOJIS[2158]),
+        ("crossed_fingers", &crate::gen::EMOJIS[256]),
+        ("pilot", &crate::gen::EMOJIS[1054]),
+        ("woman_playing_handball", &crate::gen::EMOJIS[1897]),
+        ("arrow_heading_down", &crate::gen::EMOJIS[2907]),
+        ("four_leaf_clover", &crate::gen::EMOJIS[2164]),
+        ("shark", &crate::gen::EMOJIS[2122]),
+        ("iraq", &crate::gen::EMOJIS[3211]),
+        ("ramen", &crate::gen::EMOJIS[2246]),
+        ("clock330", &crate::gen::EMOJIS[2458]),
+        ("medal_sports", &crate::gen::EMOJIS[2545]),
+        ("laos", &crate::gen::EMOJIS[3230]),
+        ("circus_tent", &crate::gen::EMOJIS[2369]),
+        ("screwdriver", &crate::gen::EMOJIS[2808]),
+        ("night_with_stars", &crate::gen::EMOJIS[2356]),
+        ("full_moon_with_face", &crate::gen::EMOJIS[2489]),
+        ("teddy_bear", &crate::gen::EMOJIS[2588]),
+        ("tangerine", &crate::gen::EMOJIS[2174]),
+        ("melting_face", &crate::gen::EMOJIS[10]),
+        ("sparkler", &crate::gen::EMOJIS[2525]),
+        ("astronaut", &crate::gen::EMOJIS[1072]),
+        ("bicyclist", &crate::gen::EMOJIS[1810]),
+        ("caribbean_netherlands", &crate::gen::EMOJIS[3129]),
+        ("scorpion", &crate::gen::EMOJIS[2138]),
+        ("virgo", &crate::gen::EMOJIS[2933]),
+        ("family_woman_woman_boy", &crate::gen::EMOJIS[1999]),
+        ("speaking_head", &crate::gen::EMOJIS[2014]),
+        ("cowboy_hat_face", &crate::gen::EMOJIS[67]),
+        ("st_barthelemy", &crate::gen::EMOJIS[3125]),
+        ("anguished", &crate::gen::EMOJIS[85]),
+        ("milky_way", &crate::gen::EMOJIS[2495]),
+        ("policeman", &crate::gen::EMOJIS[1114]),
+        ("wilted_flower", &crate::gen::EMOJIS[2149]),
+        ("ant", &crate::gen::EMOJIS[2130]),
+        ("worm", &crate::gen::EMOJIS[2141]),
+        ("squid", &crate::gen::EMOJIS[2261]),
+        ("ice_cube", &crate::gen::EMOJIS[2296]),
+        ("banana", &crate::gen::EMOJIS[2176]),
+        ("family_man_woman_boy_boy", &crate::gen::EMOJIS[1992]),
+        ("bald_woman", &crate::gen::EMOJIS[598]),
+        ("troll", &crate::gen::EMOJIS[1468]),
+        ("seedling", &crate::gen::EMOJIS[2155]),
+        ("tornado", &crate::gen::EMOJIS[2505]),
+        ("norway", &crate::gen::EMOJIS[3271]),
+        ("waning_gibbous_moon", &crate::gen::EMOJIS[2480]),
+        ("shushing_face", &crate::gen::EMOJIS[33]),
+        ("ear_with_hearing_aid", &crate::gen::EMOJIS[450]),
+        ("malaysia", &crate::gen::EMOJIS[3262]),
+        ("cold_face", &crate::gen::EMOJIS[62]),
+        ("zany_face", &crate::gen::EMOJIS[26]),
+        ("izakaya_lantern", &crate::gen::EMOJIS[2717]),
+        ("yin_yang", &crate::gen::EMOJIS[2920]),
+        ("lizard", &crate::gen::EMOJIS[2109]),
+        ("family_woman_boy", &crate::gen::EMOJIS[2009]),
+        ("film_projector", &crate::gen::EMOJIS[2705]),
+        ("health_worker", &crate::gen::EMOJIS[820]),
+        ("gem", &crate::gen::EMOJIS[2653]),
+        ("facepalm", &crate::gen::EMOJIS[784]),
+        ("goat", &crate::gen::EMOJIS[2056]),
+        ("bomb", &crate::gen::EMOJIS[2802]),
+        ("libya", &crate::gen::EMOJIS[3240]),
+        ("algeria", &crate::gen::EMOJIS[3163]),
+        ("book", &crate::gen::EMOJIS[2721]),
+        ("sewing_needle", &crate::gen::EMOJIS[2604]),
+        ("family_man_girl_girl", &crate::gen::EMOJIS[2008]),
+        ("clock5", &crate::gen::EMOJIS[2461]),
+        ("grenada", &crate::gen::EMOJIS[3181]),
+        ("bust_in_silhouette", &crate::gen::EMOJIS[2015]),
+        ("headstone", &crate::gen::EMOJIS[2861]),
+        ("two_men_holding_hands", &crate::gen::EMOJIS[1964]),
+        ("postal_horn", &crate::gen::EMOJIS[2660]),
+        ("test_tube", &crate::gen::EMOJIS[2821]),
+        ("police_officer", &crate::gen::EMOJIS[1108]),
+        ("uk", &crate::gen::EMOJIS[3180]),
+        ("stars", &crate::gen::EMOJIS[2494]),
+        ("gb", &crate::gen::EMOJIS[3180]),
+        ("namibia", &crate::gen::EMOJIS[3264]),
+        ("classical_building", &crate::gen::EMOJIS[2321]),
+        ("facepunch", &crate::gen::EMOJIS[346]),
+        ("mask", &crate::gen::EMOJIS[55]),
+        ("trackball", &crate::gen::EMOJIS[2697]),
+        ("black_circle", &crate::gen::EMOJIS[3064]),
+        ("raised_hand", &crate::gen::EMOJIS[184]),
+        ("cookie", &crate::gen::EMOJIS[2267]),
+        ("dancing_women", &crate::gen::EMOJIS[1651]),
+        ("mailbox_closed", &crate::gen::EMOJIS[2754]),
+        ("wrench", &crate::gen::EMOJIS[2807]),
+        ("restroom", &crate::gen::EMOJIS[2874]),
+        ("ok_hand", &crate::gen::EMOJIS[232]),
+        ("disappointed", &crate::gen::EMOJIS[94]),
+        ("bug", &crate::gen::EMOJIS[2129]),
+        ("football", &crate::gen::EMOJIS[2554]),
+        ("lesotho", &crate::gen::EMOJIS[3236]),
+        ("call_me_hand", &crate::gen::EMOJIS[280]),
+        ("angel", &crate::gen::EMOJIS[1312]),
+        ("black_large_square", &crate::gen::EMOJIS[3073]),
+        ("tokelau", &crate::gen::EMOJIS[3325]),
+        ("satellite", &crate::gen::EMOJIS[2826]),
+        ("drooling_face", &crate::gen::EMOJIS[53]),
+        ("green_book", &crate::gen::EMOJIS[2722]),
+        ("woman_pilot", &crate::gen::EMOJIS[1066]),
+        ("spider_web", &crate::gen::EMOJIS[2137]),
+        ("family_man_man_girl_girl", &crate::gen::EMOJIS[1998]),
+        ("poland", &crate::gen::EMOJIS[3283]),
+        ("smiley", &crate::gen::EMOJIS[1]),
+        ("saluting_face", &crate::gen::EMOJIS[35]),
+        ("light_rail", &crate::gen::EMOJIS[2376]),
+        ("crocodile", &crate::gen::EMOJIS[2107]),
+        ("fondue", &crate::gen::EMOJIS[2234]),
+        ("v", &crate::gen::EMOJIS[250]),
+        ("musical_score", &crate::gen::EMOJIS[2663]),
+        ("st_lucia", &crate::gen::EMOJIS[3232]),
+        ("heard_mcdonald_islands", &crate::gen::EMOJIS[3199]),
+        ("mountain_biking_woman", &crate::gen::EMOJIS[1840]),
+        ("eyeglasses", &crate::gen::EMOJIS[2607]),
+        ("fog", &crate::gen::EMOJIS[2506]),
+        ("thong_sandal", &crate::gen::EMOJIS[2633]),
+        ("bangladesh", &crate::gen::EMOJIS[3118]),
+        ("vertical_traffic_light", &crate::gen::EMOJIS[2417]),
+        ("wrestling", &crate::gen::EMOJIS[1864]),
+        ("e-mail", &crate::gen::EMOJIS[2747]),
+        ("confounded", &crate::gen::EMOJIS[92]),
+        ("shorts", &crate::gen::EMOJIS[2624]),
+        ("sake", &crate::gen::EMOJIS[2282]),
+        ("face_exhaling", &crate::gen::EMOJIS[47]),
+        ("two", &crate::gen::EMOJIS[3009]),
+        ("building_construction", &crate::gen::EMOJIS[2322]),
+        ("stuck_out_tongue_winking_eye", &crate::gen::EMOJIS[25]),
+        ("tennis", &crate::gen::EMOJIS[2556]),
+        ("dancer", &crate::gen::EMOJIS[1631]),
+        ("sponge", &crate::gen::EMOJIS[2856]),
+        ("raised_eyebrow", &crate::gen::EMOJIS[37]),
+        ("paperclip", &crate::gen::EMOJIS[2781]),
+        ("fairy_woman", &crate::gen::EMOJIS[1402]),
+        ("sandal", &crate::gen::EMOJIS[2639]),
+        ("horse_racing", &crate::gen::EMOJIS[1689]),
+        ("nepal", &crate::gen::EMOJIS[3272]),
+        ("family_man_man_girl", &crate::gen::EMOJIS[1995]),
+        ("cyclone", &crate::gen::EMOJIS[2508]),
+        ("u7981", &crate::gen::EMOJIS[3048]),
+        ("capricorn", &crate::gen::EMOJIS[2937]),
+        ("woman_astronaut", &crate::gen::EMOJIS[1084]),
+        ("male_sign", &crate::gen::EMOJIS[2967]),
+        ("petri_dish", &crate::gen::EMOJIS[2822]),
+        ("dragon_face", &crate::gen::EMOJIS[2111]),
+        ("lab_coat", &crate::gen::EMOJIS[2610]),
+        ("kuwait", &crate::gen::EMOJIS[3227]),
+        ("cow2", &crate::gen::EMOJIS[2049]),
+        ("2nd_place_medal", &crate::gen::EMOJIS[2547]),
+        ("hiking_boot", &crate::gen::EMOJIS[2636]),
+        ("flamingo", &crate::gen::EMOJIS[2100]),
+        ("joy_cat", &crate::gen::EMOJIS[117]),
+        ("dog", &crate::gen::EMOJIS[2023]),
+        ("jordan", &crate::gen::EMOJIS[3217]),
+        ("bookmark_tabs", &crate::gen::EMOJIS[2733]),
+        ("shallow_pan_of_food", &crate::gen::EMOJIS[2232]),
+        ("heart_on_fire", &crate::gen::EMOJIS[138]),
+        ("tanabata_tree", &crate::gen::EMOJIS[2531]),
+        ("pinching_hand", &crate::gen::EMOJIS[244]),
+        ("guinea", &crate::gen::EMOJIS[3189]),
+        ("elevator", &crate::gen::EMOJIS[2835]),
+        ("fork_and_knife", &crate::gen::EMOJIS[2299]),
+        ("clock530", &crate::gen::EMOJIS[2462]),
+        ("pout", &crate::gen::EMOJIS[100]),
+        ("hearts", &crate::gen::EMOJIS[2593]),
+        ("shell", &crate::gen::EMOJIS[2124]),
+        ("climbing_man", &crate::gen::EMOJIS[1676]),
+        ("mali", &crate::gen::EMOJIS[3249]),
+        ("clap", &crate::gen::EMOJIS[364]),
+        ("family_woman_woman_boy_boy", &crate::gen::EMOJIS[2002]),
+        ("disguised_face", &crate::gen::EMOJIS[69]),
+        ("us_virgin_islands", &crate::gen::EMOJIS[3346]),
+        ("plunger", &crate::gen::EMOJIS[2842]),
+        ("ceuta_melilla", &crate::gen::EMOJIS[3164]),
+        ("female_sign", &crate::gen::EMOJIS[2966]),
+        ("icecream", &crate::gen::EMOJIS[2263]),
+        ("congo_brazzaville", &crate::gen::EMOJIS[3141]),
+        ("repeat", &crate::gen::EMOJIS[2942]),
+        ("dumpling", &crate::gen::EMOJIS[2255]),
+        ("coffin", &crate::gen::EMOJIS[2860]),
+        ("notebook_with_decorative_cover", &crate::gen::EMOJIS[2719]),
+        ("white_circle", &crate::gen::EMOJIS[3065]),
+        ("fox_face", &crate::gen::EMOJIS[2029]),
+        ("beginner", &crate::gen::EMOJIS[2989]),
+        ("family_man_woman_girl", &crate::gen::EMOJIS[1990]),
+        ("face_with_head_bandage", &crate::gen::EMOJIS[57]),
+        ("computer", &crate::gen::EMOJIS[2692]),
+        ("non-potable_water", &crate::gen::EMOJIS[2888]),
+        ("skull", &crate::gen::EMOJIS[105]),
+        ("cricket_game", &crate::gen::EMOJIS[2559]),
+        ("yarn", &crate::gen::EMOJIS[2605]),
+        ("standing_woman", &crate::gen::EMOJIS[1535]),
+        ("man_judge", &crate::gen::EMOJIS[880]),
+        ("rugby_football", &crate::gen::EMOJIS[2555]),
+        ("pineapple", &crate::gen::EMOJIS[2177]),
+        ("hindu_temple", &crate::gen::EMOJIS[2349]),
+        ("aerial_tramway", &crate::gen::EMOJIS[2438]),
+        ("signal_strength", &crate::gen::EMOJIS[2962]),
+        ("it", &crate::gen::EMOJIS[3214]),
+        ("baguette_bread", &crate::gen::EMOJIS[2209]),
+        ("macau", &crate::gen::EMOJIS[3252]),
+        ("wink", &crate::gen::EMOJIS[11]),
+        ("thermometer", &crate::gen::EMOJIS[2487]),
+        ("heart_eyes_cat", &crate::gen::EMOJIS[118]),
+        ("lollipop", &crate::gen::EMOJIS[2274]),
+        ("briefcase", &crate::gen::EMOJIS[2766]),
+        ("small_red_triangle", &crate::gen::EMOJIS[3085]),
+        ("pouch", &crate::gen::EMOJIS[2630]),
+        ("kr", &crate::gen::EMOJIS[3226]),
+        ("first_quarter_moon_with_face", &crate::gen::EMOJIS[2485]),
+        ("roll_of_paper", &crate::gen::EMOJIS[2851]),
+        ("clock6", &crate::gen::EMOJIS[2463]),
+    ],
+};
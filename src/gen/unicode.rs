@@ -5674,3 +5674,5681 @@ pub static MAP: phf::Map<&'static str, usize> = ::phf::Map {
         ("👰\u{200d}♂\u{fe0f}", 1258),
     ],
 };
+
+#[cfg(feature = "phf-map")]
+pub static EMOJI_MAP: phf::Map<&'static str, &'static crate::Emoji> = ::phf::Map {
+    key: 12913932095322966823,
+    disps: &[
+        (0, 50),
+        (0, 469),
+        (0, 54),
+        (0, 3),
+        (0, 106),
+        (0, 48),
+        (0, 125),
+        (0, 593),
+        (0, 338),
+        (0, 42),
+        (0, 607),
+        (0, 141),
+        (0, 80),
+        (0, 6),
+        (0, 0),
+        (0, 168),
+        (0, 1032),
+        (0, 8),
+        (0, 28),
+        (0, 112),
+        (0, 0),
+        (0, 4),
+        (0, 202),
+        (0, 2),
+        (0, 232),
+        (0, 952),
+        (0, 480),
+        (0, 25),
+        (0, 827),
+        (0, 10),
+        (0, 197),
+        (0, 305),
+        (0, 0),
+        (0, 552),
+        (0, 817),
+        (0, 383),
+        (0, 109),
+        (0, 0),
+        (0, 68),
+        (0, 141),
+        (0, 47),
+        (0, 4),
+        (0, 1490),
+        (0, 118),
+        (0, 151),
+        (0, 74),
+        (0, 19),
+        (0, 5),
+        (0, 11),
+        (0, 1),
+        (0, 64),
+        (0, 23),
+        (0, 2350),
+        (0, 151),
+        (0, 1451),
+        (0, 380),
+        (0, 8),
+        (0, 69),
+        (0, 7),
+        (0, 26),
+        (0, 0),
+        (0, 85),
+        (0, 5),
+        (0, 2),
+        (0, 1006),
+        (0, 248),
+        (0, 30),
+        (0, 299),
+        (0, 3755),
+        (0, 32),
+        (0, 28),
+        (0, 257),
+        (0, 394),
+        (0, 8),
+        (0, 275),
+        (0, 8),
+        (0, 24),
+        (0, 2),
+        (0, 0),
+        (0, 0),
+        (0, 9),
+        (0, 0),
+        (0, 2288),
+        (0, 36),
+        (0, 2),
+        (0, 11),
+        (0, 22),
+        (0, 0),
+        (0, 2225),
+        (0, 15),
+        (0, 8),
+        (0, 223),
+        (0, 26),
+        (0, 82),
+        (0, 4),
+        (0, 9),
+        (0, 131),
+        (0, 0),
+        (0, 6),
+        (0, 1),
+        (0, 1),
+        (0, 19),
+        (0, 21),
+        (0, 2),
+        (0, 195),
+        (0, 0),
+        (0, 269),
+        (0, 0),
+        (0, 1884),
+        (0, 1),
+        (0, 978),
+        (0, 283),
+        (0, 415),
+        (0, 160),
+        (0, 0),
+        (0, 110),
+        (0, 384),
+        (0, 321),
+        (0, 93),
+        (0, 11),
+        (0, 0),
+        (0, 368),
+        (0, 0),
+        (0, 0),
+        (0, 0),
+        (0, 113),
+        (0, 321),
+        (0, 603),
+        (0, 2868),
+        (0, 0),
+        (0, 53),
+        (0, 36),
+        (0, 11),
+        (0, 862),
+        (0, 1795),
+        (0, 34),
+        (0, 600),
+        (0, 0),
+        (0, 0),
+        (0, 11),
+        (0, 0),
+        (0, 53),
+        (0, 18),
+        (0, 201),
+        (0, 98),
+        (0, 99),
+        (0, 52),
+        (0, 627),
+        (0, 1183),
+        (0, 113),
+        (0, 11),
+        (0, 9),
+        (0, 190),
+        (0, 46),
+        (0, 245),
+        (0, 143),
+        (0, 28),
+        (0, 587),
+        (0, 160),
+        (0, 1182),
+        (0, 58),
+        (0, 33),
+        (0, 408),
+        (0, 30),
+        (0, 32),
+        (0, 0),
+        (0, 0),
+        (0, 474),
+        (0, 5),
+        (0, 0),
+        (0, 503),
+        (0, 0),
+        (0, 2),
+        (0, 0),
+        (0, 12),
+        (0, 906),
+        (0, 3),
+        (0, 29),
+        (0, 76),
+        (0, 3),
+        (0, 67),
+        (0, 23),
+        (0, 17),
+        (0, 60),
+        (0, 985),
+        (0, 55),
+        (0, 13),
+        (0, 62),
+        (0, 209),
+        (0, 161),
+        (0, 250),
+        (0, 1735),
+        (0, 3440),
+        (0, 240),
+        (0, 636),
+        (0, 2),
+        (0, 48),
+        (0, 17),
+        (0, 20),
+        (0, 7),
+        (0, 28),
+        (0, 7),
+        (0, 801),
+        (0, 8),
+        (0, 1),
+        (0, 83),
+        (0, 65),
+        (0, 1),
+        (0, 462),
+        (0, 1),
+        (0, 1142),
+        (0, 10),
+        (0, 720),
+        (0, 2304),
+        (0, 0),
+        (0, 86),
+        (0, 22),
+        (0, 843),
+        (0, 3),
+        (0, 17),
+        (0, 1),
+        (0, 487),
+        (0, 7),
+        (0, 25),
+        (0, 10),
+        (0, 921),
+        (0, 14),
+        (0, 37),
+        (0, 705),
+        (0, 1278),
+        (0, 1272),
+        (0, 2),
+        (0, 2),
+        (0, 346),
+        (0, 27),
+        (0, 105),
+        (0, 532),
+        (0, 1),
+        (0, 80),
+        (0, 3),
+        (0, 16),
+        (0, 4),
+        (0, 0),
+        (0, 11),
+        (0, 949),
+        (0, 2),
+        (0, 35),
+        (0, 9),
+        (0, 1),
+        (0, 16),
+        (0, 393),
+        (0, 411),
+        (0, 12),
+        (0, 570),
+        (0, 3),
+        (0, 550),
+        (0, 5),
+        (0, 68),
+        (0, 8),
+        (0, 392),
+        (0, 1),
+        (0, 5),
+        (0, 1619),
+        (0, 89),
+        (0, 1),
+        (0, 2),
+        (0, 1208),
+        (0, 2092),
+        (0, 116),
+        (0, 260),
+        (0, 101),
+        (0, 146),
+        (0, 0),
+        (0, 641),
+        (0, 128),
+        (0, 96),
+        (0, 6),
+        (0, 1651),
+        (0, 321),
+        (0, 623),
+        (0, 88),
+        (0, 27),
+        (0, 40),
+        (0, 0),
+        (0, 446),
+        (0, 14),
+        (0, 71),
+        (0, 194),
+        (0, 198),
+        (0, 276),
+        (0, 15),
+        (0, 137),
+        (0, 241),
+        (0, 0),
+        (0, 87),
+        (0, 53),
+        (0, 63),
+        (0, 485),
+        (0, 17),
+        (0, 10),
+        (0, 581),
+        (0, 9),
+        (0, 209),
+        (0, 0),
+        (0, 1),
+        (0, 957),
+        (0, 84),
+        (0, 402),
+        (0, 75),
+        (0, 130),
+        (0, 838),
+        (0, 816),
+        (0, 1),
+        (0, 9),
+        (0, 76),
+        (0, 463),
+        (0, 522),
+        (0, 118),
+        (0, 171),
+        (0, 31),
+        (0, 847),
+        (0, 861),
+        (0, 592),
+        (0, 37),
+        (0, 803),
+        (0, 8),
+        (0, 23),
+        (0, 4),
+        (0, 231),
+        (0, 1383),
+        (0, 1122),
+        (0, 5),
+        (0, 533),
+        (0, 2),
+        (0, 450),
+        (0, 1737),
+        (0, 1068),
+        (0, 1),
+        (0, 22),
+        (0, 2656),
+        (0, 297),
+        (0, 51),
+        (0, 70),
+        (0, 1),
+        (0, 3),
+        (0, 7),
+        (0, 257),
+        (0, 35),
+        (0, 207),
+        (0, 12),
+        (0, 691),
+        (0, 517),
+        (0, 38),
+        (0, 3266),
+        (0, 2084),
+        (0, 26),
+        (0, 4406),
+        (0, 28),
+        (0, 38),
+        (0, 380),
+        (0, 689),
+        (0, 1),
+        (0, 239),
+        (0, 3),
+        (0, 73),
+        (0, 896),
+        (0, 88),
+        (0, 1521),
+        (0, 2352),
+        (0, 19),
+        (0, 6),
+        (0, 55),
+        (0, 281),
+        (0, 203),
+        (0, 60),
+        (0, 7),
+        (0, 94),
+        (0, 329),
+        (0, 2392),
+        (0, 0),
+        (0, 1456),
+        (0, 32),
+        (0, 186),
+        (0, 8),
+        (0, 477),
+        (0, 1035),
+        (0, 197),
+        (0, 7),
+        (0, 1),
+        (0, 0),
+        (0, 1507),
+        (0, 0),
+        (0, 228),
+        (0, 577),
+        (0, 2022),
+        (0, 474),
+        (0, 11),
+        (0, 392),
+        (0, 133),
+        (0, 18),
+        (0, 229),
+        (0, 838),
+        (0, 155),
+        (0, 1229),
+        (0, 19),
+        (0, 27),
+        (0, 109),
+        (0, 1018),
+        (0, 812),
+        (0, 1032),
+        (0, 13),
+        (0, 91),
+        (0, 2918),
+        (0, 721),
+        (0, 303),
+        (0, 32),
+        (0, 504),
+        (0, 1062),
+        (0, 13),
+        (0, 12),
+        (0, 1311),
+        (0, 7),
+        (0, 5),
+        (0, 193),
+        (0, 1),
+        (0, 3),
+        (0, 76),
+        (0, 8),
+        (0, 51),
+        (0, 9),
+        (0, 1179),
+        (0, 187),
+        (0, 133),
+        (0, 1151),
+        (0, 8),
+        (0, 942),
+        (0, 346),
+        (0, 4),
+        (0, 307),
+        (0, 16),
+        (0, 11),
+        (0, 342),
+        (0, 141),
+        (0, 234),
+        (0, 55),
+        (0, 63),
+        (0, 2),
+        (0, 176),
+        (0, 56),
+        (0, 156),
+        (0, 855),
+        (0, 550),
+        (0, 5),
+        (0, 114),
+        (0, 6),
+        (0, 73),
+        (0, 2465),
+        (0, 4),
+        (0, 1465),
+        (0, 573),
+        (0, 18),
+        (0, 1),
+        (0, 1526),
+        (0, 88),
+        (0, 13),
+        (0, 7),
+        (0, 36),
+        (0, 2),
+        (0, 0),
+        (0, 3251),
+        (0, 104),
+        (0, 111),
+        (0, 84),
+        (0, 7),
+        (0, 0),
+        (0, 204),
+        (0, 547),
+        (0, 1),
+        (0, 0),
+        (0, 107),
+        (0, 1063),
+        (0, 1693),
+        (0, 77),
+        (0, 4),
+        (0, 12),
+        (0, 106),
+        (0, 263),
+        (0, 1),
+        (0, 172),
+        (0, 3125),
+        (0, 10),
+        (0, 10),
+        (0, 1),
+        (0, 95),
+        (0, 296),
+        (0, 40),
+        (0, 3080),
+        (0, 88),
+        (0, 1362),
+        (0, 7),
+        (0, 443),
+        (0, 273),
+        (0, 0),
+        (0, 1),
+        (0, 56),
+        (0, 119),
+        (0, 1441),
+        (0, 100),
+        (0, 19),
+        (0, 723),
+        (0, 46),
+        (0, 532),
+        (0, 16),
+        (0, 0),
+        (0, 1014),
+        (0, 1026),
+        (0, 61),
+        (0, 31),
+        (0, 3466),
+        (0, 24),
+        (0, 6),
+        (0, 18),
+        (0, 17),
+        (0, 2068),
+        (0, 2467),
+        (0, 2250),
+        (0, 5),
+        (0, 1019),
+        (0, 2597),
+        (0, 1791),
+        (0, 22),
+        (0, 2211),
+        (0, 119),
+        (0, 7),
+        (0, 118),
+        (0, 80),
+        (0, 2286),
+        (0, 583),
+        (0, 0),
+        (0, 198),
+        (0, 4088),
+        (0, 2261),
+        (0, 496),
+        (0, 52),
+        (0, 274),
+        (0, 507),
+        (0, 440),
+        (0, 59),
+        (0, 0),
+        (0, 201),
+        (0, 143),
+        (0, 904),
+        (0, 1399),
+        (1, 2561),
+        (0, 289),
+        (0, 1449),
+        (0, 1548),
+        (0, 3),
+        (0, 3),
+        (0, 430),
+        (0, 2430),
+        (0, 5),
+        (0, 257),
+        (0, 0),
+        (0, 13),
+        (0, 186),
+        (0, 3155),
+        (0, 91),
+        (0, 616),
+        (0, 397),
+        (0, 175),
+        (0, 294),
+        (0, 56),
+        (0, 1797),
+        (0, 440),
+        (0, 1074),
+        (0, 2),
+        (0, 4655),
+        (0, 1486),
+        (1, 590),
+        (0, 1224),
+        (0, 410),
+        (1, 130),
+        (0, 142),
+        (0, 4480),
+        (0, 64),
+        (0, 2),
+        (0, 1573),
+        (0, 27),
+        (0, 4),
+        (0, 157),
+        (0, 226),
+        (0, 1136),
+        (0, 0),
+        (0, 0),
+        (0, 57),
+        (0, 34),
+        (1, 5),
+        (0, 1551),
+        (0, 161),
+        (0, 1118),
+        (0, 2),
+        (0, 2),
+        (0, 1),
+        (0, 943),
+        (0, 0),
+        (0, 701),
+        (0, 26),
+        (0, 12),
+        (0, 22),
+        (0, 4),
+        (0, 546),
+        (0, 1436),
+        (0, 1),
+        (0, 7),
+        (0, 551),
+        (0, 1347),
+        (0, 530),
+        (0, 347),
+        (0, 2617),
+        (0, 257),
+        (0, 12),
+        (0, 10),
+        (0, 1314),
+        (0, 31),
+        (0, 1346),
+        (0, 179),
+        (0, 55),
+        (0, 216),
+        (0, 0),
+        (0, 119),
+        (3, 1911),
+        (0, 10),
+        (0, 735),
+        (0, 240),
+        (0, 36),
+        (0, 19),
+        (0, 258),
+        (0, 156),
+        (0, 19),
+        (0, 5),
+        (0, 552),
+        (0, 7),
+        (0, 11),
+        (0, 968),
+        (0, 104),
+        (0, 251),
+        (0, 1092),
+        (0, 123),
+        (0, 0),
+        (0, 125),
+        (0, 123),
+        (0, 8),
+        (0, 2856),
+        (0, 1),
+        (0, 6),
+        (0, 654),
+        (0, 161),
+        (0, 411),
+        (0, 881),
+        (0, 2),
+        (1, 3534),
+        (0, 1),
+        (0, 804),
+        (0, 1081),
+        (0, 240),
+        (0, 33),
+        (0, 406),
+        (0, 21),
+        (0, 35),
+        (0, 622),
+        (0, 388),
+        (0, 758),
+        (0, 31),
+        (0, 722),
+        (0, 336),
+        (0, 1899),
+        (0, 0),
+        (0, 0),
+        (0, 121),
+        (0, 152),
+        (0, 5),
+        (0, 68),
+        (0, 3757),
+        (1, 3406),
+        (0, 655),
+        (0, 3451),
+        (0, 12),
+        (1, 1485),
+        (0, 355),
+        (1, 1502),
+        (0, 39),
+        (0, 58),
+        (0, 16),
+        (0, 1964),
+        (0, 557),
+        (0, 2231),
+        (0, 1155),
+        (0, 9),
+        (0, 37),
+        (0, 1),
+        (0, 479),
+        (0, 510),
+        (0, 174),
+        (0, 5),
+        (0, 779),
+        (0, 762),
+        (0, 650),
+        (0, 3),
+        (0, 162),
+        (0, 6),
+        (0, 0),
+        (0, 1048),
+        (0, 1764),
+        (0, 1),
+        (0, 22),
+        (0, 6),
+        (0, 638),
+        (0, 95),
+        (1, 2756),
+        (0, 202),
+        (0, 103),
+        (0, 4),
+        (0, 196),
+        (0, 1076),
+        (2, 777),
+        (0, 738),
+        (0, 247),
+        (0, 224),
+        (0, 1374),
+        (0, 66),
+        (0, 605),
+        (0, 1),
+        (0, 0),
+        (0, 31),
+        (0, 204),
+        (0, 1607),
+        (0, 45),
+        (0, 0),
+        (0, 0),
+        (1, 1133),
+        (1, 3000),
+        (0, 1958),
+        (0, 1271),
+        (0, 0),
+        (0, 2),
+        (0, 346),
+        (1, 1348),
+        (0, 1122),
+        (0, 42),
+        (0, 2122),
+        (0, 2117),
+        (0, 1993),
+        (0, 31),
+        (1, 4589),
+        (0, 199),
+        (0, 3459),
+        (0, 268),
+        (0, 1533),
+        (0, 562),
+        (1, 56),
+        (0, 5),
+        (0, 784),
+        (1, 274),
+        (0, 13),
+        (1, 2429),
+        (0, 0),
+        (1, 1994),
+        (0, 47),
+        (1, 3451),
+        (0, 359),
+        (0, 214),
+        (3, 3182),
+        (0, 1831),
+        (0, 919),
+        (0, 266),
+        (0, 1543),
+        (0, 2406),
+        (0, 5),
+        (0, 10),
+        (0, 1),
+        (0, 3),
+        (0, 360),
+        (0, 1744),
+        (0, 148),
+        (1, 1177),
+        (0, 865),
+        (0, 894),
+        (0, 4),
+        (0, 6),
+        (0, 238),
+        (2, 4476),
+        (0, 298),
+        (0, 1348),
+        (0, 3573),
+        (1, 2382),
+        (0, 1102),
+        (1, 2447),
+        (0, 841),
+        (0, 4477),
+        (0, 443),
+        (0, 1237),
+        (0, 32),
+        (2, 422),
+        (0, 76),
+        (0, 58),
+        (0, 0),
+        (0, 12),
+        (0, 3),
+        (0, 10),
+        (0, 822),
+        (0, 4),
+        (0, 14),
+        (0, 20),
+        (2, 4629),
+        (0, 53),
+        (0, 29),
+        (0, 1317),
+        (0, 3743),
+        (0, 2),
+        (0, 2680),
+        (0, 103),
+        (0, 669),
+        (0, 567),
+        (0, 2583),
+        (0, 55),
+        (0, 4632),
+        (8, 1152),
+        (0, 3),
+        (0, 1529),
+        (0, 181),
+        (0, 574),
+        (0, 25),
+        (0, 165),
+        (1, 2614),
+        (0, 314),
+        (0, 6),
+        (0, 1),
+        (0, 80),
+        (0, 641),
+        (0, 1),
+        (0, 13),
+        (2, 758),
+        (0, 17),
+        (0, 207),
+        (0, 53),
+        (0, 3),
+        (0, 0),
+        (0, 4),
+        (0, 449),
+        (0, 98),
+        (0, 14),
+        (0, 1754),
+        (0, 13),
+        (0, 20),
+        (0, 32),
+        (0, 1594),
+        (0, 5),
+        (0, 3199),
+        (0, 51),
+        (0, 2474),
+        (9, 3417),
+        (0, 708),
+        (0, 28),
+        (0, 3747),
+        (0, 5),
+        (1, 3),
+        (0, 1),
+        (4, 3615),
+        (0, 12),
+        (0, 20),
+        (0, 1140),
+        (0, 3343),
+        (0, 32),
+        (0, 3444),
+        (0, 8),
+        (0, 289),
+        (1, 3747),
+        (0, 85),
+        (0, 175),
+        (0, 3),
+        (0, 7),
+        (0, 241),
+        (0, 4695),
+        (0, 0),
+        (0, 2057),
+        (0, 1),
+        (0, 21),
+        (0, 39),
+        (0, 1598),
+        (0, 1069),
+        (0, 4),
+        (0, 16),
+        (5, 2133),
+        (0, 1442),
+        (0, 1501),
+        (0, 68),
+        (2, 1029),
+        (0, 1546),
+        (0, 166),
+        (0, 60),
+        (0, 154),
+        (0, 1685),
+        (0, 7),
+        (0, 506),
+        (0, 1286),
+        (0, 4),
+        (0, 44),
+        (0, 0),
+        (0, 1214),
+        (0, 1),
+        (0, 32),
+        (1, 2273),
+        (3, 2117),
+        (0, 7),
+        (0, 496),
+        (0, 370),
+        (0, 4680),
+        (17, 4718),
+        (0, 256),
+        (0, 0),
+        (0, 4551),
+        (0, 1231),
+        (0, 665),
+        (1, 755),
+        (0, 171),
+        (0, 21),
+        (0, 8),
+        (0, 78),
+        (2, 943),
+        (0, 375),
+        (0, 28),
+        (0, 69),
+        (1, 1475),
+        (2, 1526),
+        (0, 592),
+        (0, 737),
+        (0, 0),
+        (0, 24),
+        (0, 5),
+        (0, 4569),
+        (0, 15),
+        (2, 12),
+        (0, 83),
+        (0, 3943),
+    ],
+    entries: &[
+        ("👵🏽", &crate::gen::EMOJIS[637]),
+        ("🤸🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1861]),
+        ("🧜🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1437]),
+        ("✉\u{fe0f}", &crate::gen::EMOJIS[2746]),
+        ("🐥", &crate::gen::EMOJIS[2090]),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1976]),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏼", &crate::gen::EMOJIS[1975]),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏾", &crate::gen::EMOJIS[1951]),
+        ("🤫", &crate::gen::EMOJIS[33]),
+        ("👨🏿\u{200d}❤\u{200d}👨🏽", &crate::gen::EMOJIS[1986]),
+        ("♠", &crate::gen::EMOJIS[2592]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", &crate::gen::EMOJIS[1978]),
+        ("🤱🏿", &crate::gen::EMOJIS[1293]),
+        ("🫰🏼", &crate::gen::EMOJIS[264]),
+        ("5\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3012]),
+        ("🟣", &crate::gen::EMOJIS[3062]),
+        ("🦽", &crate::gen::EMOJIS[2402]),
+        ("👩🏾\u{200d}🚒", &crate::gen::EMOJIS[1106]),
+        ("👳🏾\u{200d}♂", &crate::gen::EMOJIS[1214]),
+        ("🙇🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[777]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏿", &crate::gen::EMOJIS[1987]),
+        ("⛈", &crate::gen::EMOJIS[2498]),
+        ("🤷🏼", &crate::gen::EMOJIS[804]),
+        ("💁🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[728]),
+        ("👾", &crate::gen::EMOJIS[113]),
+        ("🧍🏾\u{200d}♀", &crate::gen::EMOJIS[1539]),
+        ("🇪🇭", &crate::gen::EMOJIS[3168]),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏿", &crate::gen::EMOJIS[1975]),
+        ("🫅🏿", &crate::gen::EMOJIS[1191]),
+        ("🙆🏾\u{200d}♀", &crate::gen::EMOJIS[710]),
+        ("🗂", &crate::gen::EMOJIS[2769]),
+        ("👉🏾", &crate::gen::EMOJIS[296]),
+        ("🪻", &crate::gen::EMOJIS[2154]),
+        ("🙍🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[648]),
+        ("🙍🏾", &crate::gen::EMOJIS[644]),
+        ("🤼\u{200d}♂", &crate::gen::EMOJIS[1865]),
+        ("📲", &crate::gen::EMOJIS[2684]),
+        ("👁\u{200d}🗨\u{fe0f}", &crate::gen::EMOJIS[161]),
+        ("⏸\u{fe0f}", &crate::gen::EMOJIS[2955]),
+        ("2\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3009]),
+        ("🫰🏾", &crate::gen::EMOJIS[266]),
+        ("🤾🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1893]),
+        ("👨\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[880]),
+        ("🏄🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1728]),
+        ("🎟", &crate::gen::EMOJIS[2541]),
+        ("👏", &crate::gen::EMOJIS[364]),
+        ("🏃🏽\u{200d}♀", &crate::gen::EMOJIS[1628]),
+        ("🐈", &crate::gen::EMOJIS[2032]),
+        ("💪🏻", &crate::gen::EMOJIS[425]),
+        ("🚴🏻\u{200d}♀", &crate::gen::EMOJIS[1823]),
+        ("🔋", &crate::gen::EMOJIS[2689]),
+        ("👩🏽\u{200d}🚀", &crate::gen::EMOJIS[1087]),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1977]),
+        ("🏃\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1619]),
+        ("🧏🏽\u{200d}♂", &crate::gen::EMOJIS[757]),
+        ("🛕", &crate::gen::EMOJIS[2349]),
+        ("🦸🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1351]),
+        ("🌵", &crate::gen::EMOJIS[2160]),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏾", &crate::gen::EMOJIS[1975]),
+        ("🈁", &crate::gen::EMOJIS[3040]),
+        ("👩🏽\u{200d}❤\u{200d}👨🏿", &crate::gen::EMOJIS[1985]),
+        ("💁🏻\u{200d}♀", &crate::gen::EMOJIS[725]),
+        ("🥽", &crate::gen::EMOJIS[2609]),
+        ("🥅", &crate::gen::EMOJIS[2567]),
+        ("💑🏿", &crate::gen::EMOJIS[1984]),
+        ("🥧", &crate::gen::EMOJIS[2271]),
+        ("🇯🇲", &crate::gen::EMOJIS[3216]),
+        ("🔃", &crate::gen::EMOJIS[2908]),
+        ("💁🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[723]),
+        ("🌥\u{fe0f}", &crate::gen::EMOJIS[2500]),
+        ("❤\u{200d}🔥", &crate::gen::EMOJIS[138]),
+        ("👨🏼\u{200d}❤\u{200d}👨🏻", &crate::gen::EMOJIS[1986]),
+        ("🙎🏿\u{200d}♀", &crate::gen::EMOJIS[675]),
+        ("🙇🏻", &crate::gen::EMOJIS[767]),
+        ("🏋🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1805]),
+        ("💚", &crate::gen::EMOJIS[144]),
+        ("🇸🇭", &crate::gen::EMOJIS[3303]),
+        ("🕵\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1132]),
+        ("🏋🏼", &crate::gen::EMOJIS[1794]),
+        ("🐭", &crate::gen::EMOJIS[2065]),
+        ("🚶🏽", &crate::gen::EMOJIS[1508]),
+        ("👩🏼\u{200d}🏭", &crate::gen::EMOJIS[960]),
+        ("🫶🏾", &crate::gen::EMOJIS[380]),
+        ("👩\u{200d}❤\u{200d}💋\u{200d}👨", &crate::gen::EMOJIS[1976]),
+        ("🥖", &crate::gen::EMOJIS[2209]),
+        ("🛍", &crate::gen::EMOJIS[2631]),
+        ("🔁", &crate::gen::EMOJIS[2942]),
+        ("⌚", &crate::gen::EMOJIS[2446]),
+        ("🐨", &crate::gen::EMOJIS[2077]),
+        ("👷", &crate::gen::EMOJIS[1168]),
+        ("👲🏿", &crate::gen::EMOJIS[1227]),
+        ("🏃\u{200d}♀", &crate::gen::EMOJIS[1625]),
+        ("🔫", &crate::gen::EMOJIS[2579]),
+        ("🎶", &crate::gen::EMOJIS[2665]),
+        ("🦇", &crate::gen::EMOJIS[2074]),
+        ("🇶🇦", &crate::gen::EMOJIS[3291]),
+        ("🌗", &crate::gen::EMOJIS[2481]),
+        ("🦸🏾\u{200d}♂", &crate::gen::EMOJIS[1346]),
+        ("🌄", &crate::gen::EMOJIS[2358]),
+        ("🌆", &crate::gen::EMOJIS[2360]),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏿", &crate::gen::EMOJIS[1958]),
+        ("🤘", &crate::gen::EMOJIS[274]),
+        ("🧎🏿\u{200d}♂", &crate::gen::EMOJIS[1552]),
+        ("🦫", &crate::gen::EMOJIS[2072]),
+        ("👨🏼\u{200d}❤\u{200d}👨🏽", &crate::gen::EMOJIS[1986]),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏿", &crate::gen::EMOJIS[1984]),
+        ("🧔🏻\u{200d}♀", &crate::gen::EMOJIS[527]),
+        ("〽", &crate::gen::EMOJIS[2998]),
+        ("🇮🇴", &crate::gen::EMOJIS[3210]),
+        ("🍳", &crate::gen::EMOJIS[2231]),
+        ("🤾🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1892]),
+        ("🤷🏼\u{200d}♀", &crate::gen::EMOJIS[816]),
+        ("👸🏿", &crate::gen::EMOJIS[1203]),
+        ("👨🏻\u{200d}🦱", &crate::gen::EMOJIS[539]),
+        ("🧑🏿\u{200d}💼", &crate::gen::EMOJIS[969]),
+        ("👩🏿\u{200d}🦯", &crate::gen::EMOJIS[1576]),
+        ("🙋🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[743]),
+        ("🧍🏿\u{200d}♀", &crate::gen::EMOJIS[1540]),
+        ("🏃", &crate::gen::EMOJIS[1613]),
+        ("🤹🏽", &crate::gen::EMOJIS[1906]),
+        ("🈴", &crate::gen::EMOJIS[3051]),
+        ("✋🏻", &crate::gen::EMOJIS[185]),
+        ("🇽🇰", &crate::gen::EMOJIS[3351]),
+        ("👍🏼", &crate::gen::EMOJIS[330]),
+        ("💮", &crate::gen::EMOJIS[2145]),
+        ("👩🏽\u{200d}🎨", &crate::gen::EMOJIS[1051]),
+        ("🐗", &crate::gen::EMOJIS[2052]),
+        ("👨🏽\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1063]),
+        ("🧑🏾\u{200d}🌾", &crate::gen::EMOJIS[896]),
+        ("🤵🏻", &crate::gen::EMOJIS[1235]),
+        ("🧑🏼\u{200d}🔬", &crate::gen::EMOJIS[984]),
+        ("👩🏻\u{200d}🦰", &crate::gen::EMOJIS[563]),
+        ("▪\u{fe0f}", &crate::gen::EMOJIS[3079]),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1977]),
+        ("🍬", &crate::gen::EMOJIS[2273]),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏾", &crate::gen::EMOJIS[1979]),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1977]),
+        ("🧑🏻\u{200d}🎄", &crate::gen::EMOJIS[1331]),
+        ("🏃🏾\u{200d}♀", &crate::gen::EMOJIS[1629]),
+        ("🧘🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1930]),
+        ("📿", &crate::gen::EMOJIS[2650]),
+        ("🏌🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1713]),
+        ("🙏", &crate::gen::EMOJIS[400]),
+        ("👨🏽\u{200d}🦯", &crate::gen::EMOJIS[1568]),
+        ("🛂", &crate::gen::EMOJIS[2877]),
+        ("📫", &crate::gen::EMOJIS[2753]),
+        ("🇳🇷", &crate::gen::EMOJIS[3273]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", &crate::gen::EMOJIS[1985]),
+        ("🫴🏿", &crate::gen::EMOJIS[219]),
+        ("🧑\u{200d}💻", &crate::gen::EMOJIS[1000]),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏾", &crate::gen::EMOJIS[1979]),
+        ("🫲🏿", &crate::gen::EMOJIS[207]),
+        ("🧜🏾\u{200d}♂", &crate::gen::EMOJIS[1436]),
+        ("🙌🏽", &crate::gen::EMOJIS[373]),
+        ("🧑\u{200d}⚖", &crate::gen::EMOJIS[874]),
+        ("👴🏼", &crate::gen::EMOJIS[630]),
+        ("📞", &crate::gen::EMOJIS[2686]),
+        ("👱\u{200d}♂", &crate::gen::EMOJIS[616]),
+        ("🏄🏽\u{200d}♀", &crate::gen::EMOJIS[1735]),
+        ("🚚", &crate::gen::EMOJIS[2396]),
+        ("🏌🏼\u{200d}♂", &crate::gen::EMOJIS[1710]),
+        ("⛹🏾\u{200d}♀", &crate::gen::EMOJIS[1790]),
+        ("🙇🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[774]),
+        ("💣", &crate::gen::EMOJIS[2802]),
+        ("🔟", &crate::gen::EMOJIS[3017]),
+        ("🧘🏾\u{200d}♂", &crate::gen::EMOJIS[1931]),
+        ("🧑🏻\u{200d}🔧", &crate::gen::EMOJIS[929]),
+        ("🩹", &crate::gen::EMOJIS[2830]),
+        ("🤘🏾", &crate::gen::EMOJIS[278]),
+        ("🏝\u{fe0f}", &crate::gen::EMOJIS[2318]),
+        ("🫃🏻", &crate::gen::EMOJIS[1277]),
+        ("🙇🏻\u{200d}♂", &crate::gen::EMOJIS[773]),
+        ("👌🏾", &crate::gen::EMOJIS[236]),
+        ("⛽", &crate::gen::EMOJIS[2413]),
+        ("🧛🏾\u{200d}♀", &crate::gen::EMOJIS[1424]),
+        ("💅🏽", &crate::gen::EMOJIS[415]),
+        ("👶🏼", &crate::gen::EMOJIS[474]),
+        ("🦵🏼", &crate::gen::EMOJIS[434]),
+        ("🧗🏽", &crate::gen::EMOJIS[1673]),
+        ("🤲", &crate::gen::EMOJIS[388]),
+        ("🏋🏿\u{200d}♀", &crate::gen::EMOJIS[1809]),
+        ("💙", &crate::gen::EMOJIS[145]),
+        ("🩴", &crate::gen::EMOJIS[2633]),
+        ("🧛\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1420]),
+        ("👨\u{200d}❤\u{200d}💋\u{200d}👨", &crate::gen::EMOJIS[1977]),
+        ("🚣🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1752]),
+        ("⛰", &crate::gen::EMOJIS[2312]),
+        ("☝🏻", &crate::gen::EMOJIS[317]),
+        ("☸\u{fe0f}", &crate::gen::EMOJIS[2919]),
+        ("🫒", &crate::gen::EMOJIS[2188]),
+        ("🧖🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1662]),
+        ("🇨🇭", &crate::gen::EMOJIS[3142]),
+        ("🪨", &crate::gen::EMOJIS[2324]),
+        ("🦪", &crate::gen::EMOJIS[2262]),
+        ("💻", &crate::gen::EMOJIS[2692]),
+        ("🫄🏿", &crate::gen::EMOJIS[1287]),
+        ("🥉", &crate::gen::EMOJIS[2548]),
+        ("🧯", &crate::gen::EMOJIS[2857]),
+        ("🙆🏼\u{200d}♂", &crate::gen::EMOJIS[702]),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏿", &crate::gen::EMOJIS[1979]),
+        ("🙍🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[651]),
+        ("🚣\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1750]),
+        ("🧗\u{200d}♂", &crate::gen::EMOJIS[1676]),
+        ("🧑🏽\u{200d}🎨", &crate::gen::EMOJIS[1039]),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1977]),
+        ("👩🏿\u{200d}🎤", &crate::gen::EMOJIS[1035]),
+        ("🤹🏽\u{200d}♀", &crate::gen::EMOJIS[1918]),
+        ("🪣", &crate::gen::EMOJIS[2852]),
+        ("👱🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[620]),
+        ("🇲🇸", &crate::gen::EMOJIS[3256]),
+        ("🧔🏼", &crate::gen::EMOJIS[516]),
+        ("🧑🏾\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[824]),
+        ("🫷🏾", &crate::gen::EMOJIS[224]),
+        ("🕧", &crate::gen::EMOJIS[2452]),
+        ("🧑🏿\u{200d}🔧", &crate::gen::EMOJIS[933]),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", &crate::gen::EMOJIS[1970]),
+        ("👩🏿\u{200d}🔬", &crate::gen::EMOJIS[999]),
+        ("🧚\u{200d}♂", &crate::gen::EMOJIS[1396]),
+        ("🔚", &crate::gen::EMOJIS[2911]),
+        ("🧔🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[522]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏾", &crate::gen::EMOJIS[1987]),
+        ("🧑🏽\u{200d}🌾", &crate::gen::EMOJIS[895]),
+        ("👨🏼\u{200d}🚀", &crate::gen::EMOJIS[1080]),
+        ("🚸", &crate::gen::EMOJIS[2882]),
+        ("🙅\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[682]),
+        ("🥡", &crate::gen::EMOJIS[2257]),
+        ("🥢", &crate::gen::EMOJIS[2297]),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1976]),
+        ("🏌🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1709]),
+        ("❤\u{fe0f}\u{200d}🩹", &crate::gen::EMOJIS[139]),
+        ("🎛", &crate::gen::EMOJIS[2668]),
+        ("🕟", &crate::gen::EMOJIS[2460]),
+        ("💠", &crate::gen::EMOJIS[3087]),
+        ("🐦", &crate::gen::EMOJIS[2091]),
+        ("🇨🇰", &crate::gen::EMOJIS[3144]),
+        ("🧏🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[761]),
+        ("🧝🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1453]),
+        ("🇦🇲", &crate::gen::EMOJIS[3106]),
+        ("🚣🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1753]),
+        ("🚵\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1840]),
+        ("🧎🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1552]),
+        ("9\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3016]),
+        ("👨\u{200d}💼", &crate::gen::EMOJIS[970]),
+        ("🫱🏼\u{200d}🫲🏽", &crate::gen::EMOJIS[394]),
+        ("🅿", &crate::gen::EMOJIS[3036]),
+        ("🚅", &crate::gen::EMOJIS[2373]),
+        ("🛌", &crate::gen::EMOJIS[1945]),
+        ("🌐", &crate::gen::EMOJIS[2307]),
+        ("🏋🏽\u{200d}♀", &crate::gen::EMOJIS[1807]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1976]),
+        ("🙅\u{200d}♂", &crate::gen::EMOJIS[682]),
+        ("🛢\u{fe0f}", &crate::gen::EMOJIS[2412]),
+        ("🏄🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1737]),
+        ("🧑🏿\u{200d}🦼", &crate::gen::EMOJIS[1582]),
+        ("🧻", &crate::gen::EMOJIS[2851]),
+        ("👨🏿\u{200d}❤\u{200d}👨🏼", &crate::gen::EMOJIS[1986]),
+        ("🕵\u{fe0f}\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1138]),
+        ("🧑🏼\u{200d}🦳", &crate::gen::EMOJIS[594]),
+        ("👩🏽\u{200d}❤\u{200d}👨🏼", &crate::gen::EMOJIS[1985]),
+        ("🧘🏼", &crate::gen::EMOJIS[1923]),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1977]),
+        ("🧎🏾\u{200d}♀", &crate::gen::EMOJIS[1557]),
+        ("👨🏾\u{200d}✈", &crate::gen::EMOJIS[1064]),
+        ("🧑", &crate::gen::EMOJIS[496]),
+        ("🍞", &crate::gen::EMOJIS[2207]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏿", &crate::gen::EMOJIS[1987]),
+        ("🧙🏾\u{200d}♀", &crate::gen::EMOJIS[1388]),
+        ("💱", &crate::gen::EMOJIS[2982]),
+        ("🖖🏾", &crate::gen::EMOJIS[194]),
+        ("🐉", &crate::gen::EMOJIS[2112]),
+        ("🧑🏼\u{200d}🎄", &crate::gen::EMOJIS[1332]),
+        ("🧒🏼", &crate::gen::EMOJIS[480]),
+        ("🙎\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[664]),
+        ("👨🏽\u{200d}🏫", &crate::gen::EMOJIS[865]),
+        ("🥰", &crate::gen::EMOJIS[14]),
+        ("🚶🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1516]),
+        ("🧎🏻\u{200d}♀", &crate::gen::EMOJIS[1554]),
+        ("🧎🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1557]),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1976]),
+        ("📰", &crate::gen::EMOJIS[2731]),
+        ("🧛", &crate::gen::EMOJIS[1408]),
+        ("🧏🏾", &crate::gen::EMOJIS[752]),
+        ("👨\u{200d}🦲", &crate::gen::EMOJIS[550]),
+        ("🕝", &crate::gen::EMOJIS[2456]),
+        ("⏹", &crate::gen::EMOJIS[2956]),
+        ("😮", &crate::gen::EMOJIS[78]),
+        ("🤵🏼\u{200d}♂", &crate::gen::EMOJIS[1242]),
+        ("🧑🏾\u{200d}💻", &crate::gen::EMOJIS[1004]),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1977]),
+        ("🇸🇪", &crate::gen::EMOJIS[3301]),
+        ("🧑🏿\u{200d}🏭", &crate::gen::EMOJIS[951]),
+        ("🧚🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1406]),
+        ("👨🏽", &crate::gen::EMOJIS[511]),
+        ("🏌🏻\u{200d}♀", &crate::gen::EMOJIS[1715]),
+        ("🚐", &crate::gen::EMOJIS[2385]),
+        ("🏃🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1629]),
+        ("🤷\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[814]),
+        ("🧑🏾\u{200d}🎓", &crate::gen::EMOJIS[842]),
+        ("🧑🏾\u{200d}🏫", &crate::gen::EMOJIS[860]),
+        ("🙌🏻", &crate::gen::EMOJIS[371]),
+        ("🇨🇻", &crate::gen::EMOJIS[3152]),
+        ("🤦🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[792]),
+        ("⚖", &crate::gen::EMOJIS[2812]),
+        ("🧚", &crate::gen::EMOJIS[1390]),
+        ("🦹\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1366]),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏿", &crate::gen::EMOJIS[1978]),
+        ("🔖", &crate::gen::EMOJIS[2734]),
+        ("🇻🇦", &crate::gen::EMOJIS[3342]),
+        ("🇸🇨", &crate::gen::EMOJIS[3299]),
+        ("🧙🏼\u{200d}♂", &crate::gen::EMOJIS[1380]),
+        ("🧚\u{200d}♀", &crate::gen::EMOJIS[1402]),
+        ("👩\u{200d}🔬", &crate::gen::EMOJIS[994]),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏼", &crate::gen::EMOJIS[1951]),
+        ("👐", &crate::gen::EMOJIS[382]),
+        ("🕵🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1143]),
+        ("🎀", &crate::gen::EMOJIS[2538]),
+        ("🚶", &crate::gen::EMOJIS[1505]),
+        ("👨🏼\u{200d}🌾", &crate::gen::EMOJIS[900]),
+        ("🇻🇺", &crate::gen::EMOJIS[3348]),
+        ("🤸", &crate::gen::EMOJIS[1846]),
+        ("👰🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1267]),
+        ("😿", &crate::gen::EMOJIS[122]),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", &crate::gen::EMOJIS[1986]),
+        ("🤚🏽", &crate::gen::EMOJIS[175]),
+        ("😀", &crate::gen::EMOJIS[0]),
+        ("🤹🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1914]),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏼", &crate::gen::EMOJIS[1978]),
+        ("🧛🏼\u{200d}♂", &crate::gen::EMOJIS[1416]),
+        ("⛹🏼\u{200d}♂", &crate::gen::EMOJIS[1782]),
+        ("🦹🏿", &crate::gen::EMOJIS[1359]),
+        ("👦🏿", &crate::gen::EMOJIS[489]),
+        ("⚪", &crate::gen::EMOJIS[3065]),
+        ("*\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3006]),
+        ("🌬", &crate::gen::EMOJIS[2507]),
+        ("👷\u{200d}♀", &crate::gen::EMOJIS[1180]),
+        ("🫸🏻", &crate::gen::EMOJIS[227]),
+        ("🧘🏻\u{200d}♂", &crate::gen::EMOJIS[1928]),
+        ("🤷🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[812]),
+        ("🗂\u{fe0f}", &crate::gen::EMOJIS[2769]),
+        ("👱🏼", &crate::gen::EMOJIS[504]),
+        ("💕", &crate::gen::EMOJIS[134]),
+        ("🏌\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1708]),
+        ("🧛🏿", &crate::gen::EMOJIS[1413]),
+        ("🍐", &crate::gen::EMOJIS[2181]),
+        ("👨🏽\u{200d}🍳", &crate::gen::EMOJIS[919]),
+        ("👨🏽\u{200d}🦳", &crate::gen::EMOJIS[547]),
+        ("💆🏽\u{200d}♀", &crate::gen::EMOJIS[1484]),
+        ("👎🏿", &crate::gen::EMOJIS[339]),
+        ("💷", &crate::gen::EMOJIS[2741]),
+        ("🤐", &crate::gen::EMOJIS[36]),
+        ("👰🏿", &crate::gen::EMOJIS[1257]),
+        ("🏊🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1772]),
+        ("🦸🏿\u{200d}♂", &crate::gen::EMOJIS[1347]),
+        ("🙆\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[706]),
+        ("🙇🏾", &crate::gen::EMOJIS[770]),
+        ("🧖🏻\u{200d}♀", &crate::gen::EMOJIS[1665]),
+        ("🧑🏻\u{200d}🦼", &crate::gen::EMOJIS[1578]),
+        ("👊🏿", &crate::gen::EMOJIS[351]),
+        ("🇪🇹", &crate::gen::EMOJIS[3171]),
+        ("☂\u{fe0f}", &crate::gen::EMOJIS[2511]),
+        ("♟", &crate::gen::EMOJIS[2596]),
+        ("🧍🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1533]),
+        ("🇩🇬", &crate::gen::EMOJIS[3158]),
+        ("🇱🇧", &crate::gen::EMOJIS[3231]),
+        ("👨🏽\u{200d}❤\u{200d}👨🏿", &crate::gen::EMOJIS[1986]),
+        ("🙍🏼\u{200d}♀", &crate::gen::EMOJIS[654]),
+        ("🌺", &crate::gen::EMOJIS[2150]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", &crate::gen::EMOJIS[1978]),
+        ("👐🏼", &crate::gen::EMOJIS[384]),
+        ("⛩\u{fe0f}", &crate::gen::EMOJIS[2351]),
+        ("🇧🇻", &crate::gen::EMOJIS[3133]),
+        ("⏰", &crate::gen::EMOJIS[2447]),
+        ("🙅🏽\u{200d}♀", &crate::gen::EMOJIS[691]),
+        ("🇳🇵", &crate::gen::EMOJIS[3272]),
+        ("🪓", &crate::gen::EMOJIS[2796]),
+        ("🌠", &crate::gen::EMOJIS[2494]),
+        ("🐟", &crate::gen::EMOJIS[2119]),
+        ("💨", &crate::gen::EMOJIS[158]),
+        ("🧗🏼\u{200d}♂", &crate::gen::EMOJIS[1678]),
+        ("🧔🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[525]),
+        ("🇰🇼", &crate::gen::EMOJIS[3227]),
+        ("3\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3010]),
+        ("🤶🏾", &crate::gen::EMOJIS[1328]),
+        ("👩🏾\u{200d}🍳", &crate::gen::EMOJIS[926]),
+        ("🏌\u{fe0f}\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1708]),
+        ("🕳\u{fe0f}", &crate::gen::EMOJIS[159]),
+        ("🧑\u{200d}🍼", &crate::gen::EMOJIS[1306]),
+        ("🧛🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1425]),
+        ("🛌🏼", &crate::gen::EMOJIS[1947]),
+        ("🫃🏾", &crate::gen::EMOJIS[1280]),
+        ("🤵🏿", &crate::gen::EMOJIS[1239]),
+        ("🧜🏻\u{200d}♂", &crate::gen::EMOJIS[1433]),
+        ("🤸🏿\u{200d}♂", &crate::gen::EMOJIS[1857]),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", &crate::gen::EMOJIS[1986]),
+        ("🙋🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[741]),
+        ("🙆🏿\u{200d}♂", &crate::gen::EMOJIS[705]),
+        ("👨🏻\u{200d}🔧", &crate::gen::EMOJIS[935]),
+        ("📋", &crate::gen::EMOJIS[2778]),
+        ("🧑🏾\u{200d}🦯", &crate::gen::EMOJIS[1563]),
+        ("😅", &crate::gen::EMOJIS[5]),
+        ("🇬🇶", &crate::gen::EMOJIS[3191]),
+        ("👨🏿\u{200d}💼", &crate::gen::EMOJIS[975]),
+        ("🗺\u{fe0f}", &crate::gen::EMOJIS[2308]),
+        ("✊", &crate::gen::EMOJIS[340]),
+        ("🙆🏻\u{200d}♀", &crate::gen::EMOJIS[707]),
+        ("🧜🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1443]),
+        ("🤹\u{200d}♀", &crate::gen::EMOJIS[1915]),
+        ("🙆🏼\u{200d}♀", &crate::gen::EMOJIS[708]),
+        ("🧗🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1681]),
+        ("⛷\u{fe0f}", &crate::gen::EMOJIS[1695]),
+        ("🧙🏽\u{200d}♀", &crate::gen::EMOJIS[1387]),
+        ("🧏🏼\u{200d}♂", &crate::gen::EMOJIS[756]),
+        ("🚣\u{200d}♀", &crate::gen::EMOJIS[1750]),
+        ("📄", &crate::gen::EMOJIS[2730]),
+        ("👬🏻", &crate::gen::EMOJIS[1965]),
+        ("🧑🏻\u{200d}🦳", &crate::gen::EMOJIS[593]),
+        ("👨🏼\u{200d}❤\u{200d}👨🏼", &crate::gen::EMOJIS[1986]),
+        ("🚶🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1513]),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏽", &crate::gen::EMOJIS[1984]),
+        ("🚵🏼\u{200d}♂", &crate::gen::EMOJIS[1836]),
+        ("🇵🇸", &crate::gen::EMOJIS[3287]),
+        ("🏋🏽\u{200d}♂", &crate::gen::EMOJIS[1801]),
+        ("🇧🇲", &crate::gen::EMOJIS[3126]),
+        ("🧑🏽\u{200d}🚀", &crate::gen::EMOJIS[1075]),
+        ("🇫🇲", &crate::gen::EMOJIS[3176]),
+        ("🏄🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1734]),
+        ("🕷", &crate::gen::EMOJIS[2136]),
+        ("⛹🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1785]),
+        ("👶", &crate::gen::EMOJIS[472]),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1977]),
+        ("🍗", &crate::gen::EMOJIS[2217]),
+        ("👶🏽", &crate::gen::EMOJIS[475]),
+        ("🧑🏾\u{200d}🦰", &crate::gen::EMOJIS[572]),
+        ("🧏🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[763]),
+        ("👩🏿\u{200d}🎓", &crate::gen::EMOJIS[855]),
+        ("🕠", &crate::gen::EMOJIS[2462]),
+        ("➿", &crate::gen::EMOJIS[2997]),
+        ("🏍\u{fe0f}", &crate::gen::EMOJIS[2400]),
+        ("🤜🏾", &crate::gen::EMOJIS[362]),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1976]),
+        ("🇵🇲", &crate::gen::EMOJIS[3284]),
+        ("▫\u{fe0f}", &crate::gen::EMOJIS[3080]),
+        ("🚏", &crate::gen::EMOJIS[2409]),
+        ("🫧", &crate::gen::EMOJIS[2854]),
+        ("🧑\u{200d}⚕", &crate::gen::EMOJIS[820]),
+        ("🖐🏽", &crate::gen::EMOJIS[181]),
+        ("👨\u{200d}✈", &crate::gen::EMOJIS[1060]),
+        ("🏳\u{200d}⚧\u{fe0f}", &crate::gen::EMOJIS[3097]),
+        ("🤰🏻", &crate::gen::EMOJIS[1271]),
+        ("🎈", &crate::gen::EMOJIS[2528]),
+        ("🏊\u{200d}♂", &crate::gen::EMOJIS[1762]),
+        ("🇹🇲", &crate::gen::EMOJIS[3327]),
+        ("💇🏽\u{200d}♂", &crate::gen::EMOJIS[1496]),
+        ("🧑🏻\u{200d}🍳", &crate::gen::EMOJIS[911]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", &crate::gen::EMOJIS[1978]),
+        ("🔭", &crate::gen::EMOJIS[2825]),
+        ("🇪🇨", &crate::gen::EMOJIS[3165]),
+        ("💰", &crate::gen::EMOJIS[2736]),
+        ("🧍🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1534]),
+        ("👩🏼\u{200d}🦽", &crate::gen::EMOJIS[1609]),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", &crate::gen::EMOJIS[1986]),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏾", &crate::gen::EMOJIS[1978]),
+        ("🧗🏼\u{200d}♀", &crate::gen::EMOJIS[1684]),
+        ("🎗\u{fe0f}", &crate::gen::EMOJIS[2540]),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏾", &crate::gen::EMOJIS[1952]),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏻", &crate::gen::EMOJIS[1952]),
+        ("🚜", &crate::gen::EMOJIS[2398]),
+        ("👷🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1179]),
+        ("🩺", &crate::gen::EMOJIS[2832]),
+        ("🙅🏾\u{200d}♂", &crate::gen::EMOJIS[686]),
+        ("😖", &crate::gen::EMOJIS[92]),
+        ("👨🏽\u{200d}⚕", &crate::gen::EMOJIS[829]),
+        ("👨🏿\u{200d}🎓", &crate::gen::EMOJIS[849]),
+        ("🔵", &crate::gen::EMOJIS[3061]),
+        ("🧖🏽", &crate::gen::EMOJIS[1655]),
+        ("🇩🇯", &crate::gen::EMOJIS[3159]),
+        ("⚛", &crate::gen::EMOJIS[2916]),
+        ("🇨🇾", &crate::gen::EMOJIS[3155]),
+        ("🚶🏿\u{200d}♂", &crate::gen::EMOJIS[1516]),
+        ("🇯🇴", &crate::gen::EMOJIS[3217]),
+        ("🏊🏼\u{200d}♂", &crate::gen::EMOJIS[1764]),
+        ("👩\u{200d}🦲", &crate::gen::EMOJIS[598]),
+        ("🙎🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[671]),
+        ("🇧🇼", &crate::gen::EMOJIS[3134]),
+        ("🧝🏼\u{200d}♂", &crate::gen::EMOJIS[1452]),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏿", &crate::gen::EMOJIS[1952]),
+        ("🫱🏾\u{200d}🫲🏽", &crate::gen::EMOJIS[394]),
+        ("🎄", &crate::gen::EMOJIS[2523]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", &crate::gen::EMOJIS[1978]),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏿", &crate::gen::EMOJIS[1984]),
+        ("🧙🏿\u{200d}♀", &crate::gen::EMOJIS[1389]),
+        ("💏🏽", &crate::gen::EMOJIS[1973]),
+        ("🏌🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1718]),
+        ("🤵🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1245]),
+        ("🦹🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1370]),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏾", &crate::gen::EMOJIS[1958]),
+        ("🧏🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[757]),
+        ("🏡", &crate::gen::EMOJIS[2330]),
+        ("👩🏾\u{200d}🏫", &crate::gen::EMOJIS[872]),
+        ("🏇🏼", &crate::gen::EMOJIS[1691]),
+        ("🐶", &crate::gen::EMOJIS[2023]),
+        ("🇰🇭", &crate::gen::EMOJIS[3221]),
+        ("🛝", &crate::gen::EMOJIS[2365]),
+        ("🇪🇬", &crate::gen::EMOJIS[3167]),
+        ("🏊", &crate::gen::EMOJIS[1756]),
+        ("🛀🏿", &crate::gen::EMOJIS[1944]),
+        ("🇲🇬", &crate::gen::EMOJIS[3246]),
+        ("🪘", &crate::gen::EMOJIS[2680]),
+        ("🧔🏽\u{200d}♀", &crate::gen::EMOJIS[529]),
+        ("🤹🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1911]),
+        ("🙅🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[686]),
+        ("⛹🏿", &crate::gen::EMOJIS[1779]),
+        ("👒", &crate::gen::EMOJIS[2644]),
+        ("🅰\u{fe0f}", &crate::gen::EMOJIS[3023]),
+        ("↕", &crate::gen::EMOJIS[2902]),
+        ("🧜🏻", &crate::gen::EMOJIS[1427]),
+        ("🙆", &crate::gen::EMOJIS[694]),
+        ("🧣", &crate::gen::EMOJIS[2615]),
+        ("🌊", &crate::gen::EMOJIS[2521]),
+        ("🐡", &crate::gen::EMOJIS[2121]),
+        ("🇬🇦", &crate::gen::EMOJIS[3179]),
+        ("🧝🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1455]),
+        ("💆🏽\u{200d}♂", &crate::gen::EMOJIS[1478]),
+        ("🤹🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1917]),
+        ("🙍🏿\u{200d}♀", &crate::gen::EMOJIS[657]),
+        ("🧑🏼\u{200d}💼", &crate::gen::EMOJIS[966]),
+        ("🏃🏿", &crate::gen::EMOJIS[1618]),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏽", &crate::gen::EMOJIS[1975]),
+        ("🙇🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[775]),
+        ("🏊🏿", &crate::gen::EMOJIS[1761]),
+        ("🎫", &crate::gen::EMOJIS[2542]),
+        ("🌈", &crate::gen::EMOJIS[2509]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", &crate::gen::EMOJIS[1985]),
+        ("🙆🏾", &crate::gen::EMOJIS[698]),
+        ("👨🏿\u{200d}🍼", &crate::gen::EMOJIS[1305]),
+        ("🇦🇫", &crate::gen::EMOJIS[3102]),
+        ("🚴🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1825]),
+        ("🈳", &crate::gen::EMOJIS[3052]),
+        ("⚗\u{fe0f}", &crate::gen::EMOJIS[2820]),
+        ("🫵🏼", &crate::gen::EMOJIS[324]),
+        ("🖖🏼", &crate::gen::EMOJIS[192]),
+        ("🧑🏻\u{200d}🚀", &crate::gen::EMOJIS[1073]),
+        ("🚣🏼", &crate::gen::EMOJIS[1740]),
+        ("🚟", &crate::gen::EMOJIS[2436]),
+        ("🥿", &crate::gen::EMOJIS[2637]),
+        ("🤹🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1910]),
+        ("🧛\u{200d}♂", &crate::gen::EMOJIS[1414]),
+        ("🥑", &crate::gen::EMOJIS[2190]),
+        ("🙋🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[746]),
+        ("🆓", &crate::gen::EMOJIS[3028]),
+        ("🔴", &crate::gen::EMOJIS[3057]),
+        ("👩🏼\u{200d}❤\u{200d}👨🏻", &crate::gen::EMOJIS[1985]),
+        ("🦂", &crate::gen::EMOJIS[2138]),
+        ("🤭", &crate::gen::EMOJIS[30]),
+        ("⛹🏾\u{200d}♂", &crate::gen::EMOJIS[1784]),
+        ("🚝", &crate::gen::EMOJIS[2379]),
+        ("📪", &crate::gen::EMOJIS[2754]),
+        ("🙅🏿", &crate::gen::EMOJIS[681]),
+        ("👩🏾\u{200d}❤\u{200d}👩🏽", &crate::gen::EMOJIS[1987]),
+        ("🎖\u{fe0f}", &crate::gen::EMOJIS[2543]),
+        ("🧖🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1666]),
+        ("👷\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1174]),
+        ("🌷", &crate::gen::EMOJIS[2153]),
+        ("✌🏿", &crate::gen::EMOJIS[255]),
+        ("🫱🏾\u{200d}🫲🏿", &crate::gen::EMOJIS[394]),
+        ("🚶\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1517]),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏿", &crate::gen::EMOJIS[1951]),
+        ("🤯", &crate::gen::EMOJIS[66]),
+        ("👇🏻", &crate::gen::EMOJIS[311]),
+        ("🏍", &crate::gen::EMOJIS[2400]),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏾", &crate::gen::EMOJIS[1978]),
+        ("👷🏼", &crate::gen::EMOJIS[1170]),
+        ("🤵🏽", &crate::gen::EMOJIS[1237]),
+        ("📒", &crate::gen::EMOJIS[2727]),
+        ("🔧", &crate::gen::EMOJIS[2807]),
+        ("🍧", &crate::gen::EMOJIS[2264]),
+        ("🙆🏽\u{200d}♂", &crate::gen::EMOJIS[703]),
+        ("👳🏽", &crate::gen::EMOJIS[1207]),
+        ("🎒", &crate::gen::EMOJIS[2632]),
+        ("👩🏼\u{200d}🦼", &crate::gen::EMOJIS[1591]),
+        ("🇸🇴", &crate::gen::EMOJIS[3310]),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1976]),
+        ("⛹\u{fe0f}\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1780]),
+        ("🧖🏻", &crate::gen::EMOJIS[1653]),
+        ("🕵🏻", &crate::gen::EMOJIS[1127]),
+        ("🍭", &crate::gen::EMOJIS[2274]),
+        ("🇱🇾", &crate::gen::EMOJIS[3240]),
+        ("📂", &crate::gen::EMOJIS[2768]),
+        ("🧛🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1419]),
+        ("🚶🏾\u{200d}♂", &crate::gen::EMOJIS[1515]),
+        ("🍊", &crate::gen::EMOJIS[2174]),
+        ("🔑", &crate::gen::EMOJIS[2793]),
+        ("🧚🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1398]),
+        ("💁🏼\u{200d}♀", &crate::gen::EMOJIS[726]),
+        ("🧑🏻\u{200d}🏫", &crate::gen::EMOJIS[857]),
+        ("🇬🇼", &crate::gen::EMOJIS[3196]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏽", &crate::gen::EMOJIS[1987]),
+        ("🌚", &crate::gen::EMOJIS[2484]),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1977]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", &crate::gen::EMOJIS[1978]),
+        ("👨🏿\u{200d}🍳", &crate::gen::EMOJIS[921]),
+        ("🧚\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1402]),
+        ("🇮🇱", &crate::gen::EMOJIS[3207]),
+        ("🔣", &crate::gen::EMOJIS[3021]),
+        ("👩🏻\u{200d}❤\u{200d}👨🏾", &crate::gen::EMOJIS[1985]),
+        ("🧑🏿\u{200d}🍼", &crate::gen::EMOJIS[1311]),
+        ("🚴🏾\u{200d}♂", &crate::gen::EMOJIS[1820]),
+        ("❤", &crate::gen::EMOJIS[140]),
+        ("🇦🇮", &crate::gen::EMOJIS[3104]),
+        ("💪🏾", &crate::gen::EMOJIS[428]),
+        ("🧜\u{200d}♀", &crate::gen::EMOJIS[1438]),
+        ("🚣\u{200d}♂", &crate::gen::EMOJIS[1744]),
+        ("🙋🏽\u{200d}♀", &crate::gen::EMOJIS[745]),
+        ("💈", &crate::gen::EMOJIS[2368]),
+        ("™", &crate::gen::EMOJIS[3004]),
+        ("🤌🏻", &crate::gen::EMOJIS[239]),
+        ("👩🏽\u{200d}⚖", &crate::gen::EMOJIS[889]),
+        ("🧜🏼", &crate::gen::EMOJIS[1428]),
+        ("🫴🏻", &crate::gen::EMOJIS[215]),
+        ("🧟\u{200d}♂", &crate::gen::EMOJIS[1466]),
+        ("🤾\u{200d}♂", &crate::gen::EMOJIS[1891]),
+        ("🙅🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[692]),
+        ("😂", &crate::gen::EMOJIS[7]),
+        ("👱\u{200d}♀", &crate::gen::EMOJIS[610]),
+        ("🧔🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[529]),
+        ("🕵🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1140]),
+        ("💇🏼\u{200d}♂", &crate::gen::EMOJIS[1495]),
+        ("🗜\u{fe0f}", &crate::gen::EMOJIS[2811]),
+        ("✂\u{fe0f}", &crate::gen::EMOJIS[2785]),
+        ("🧗🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1680]),
+        ("🤒", &crate::gen::EMOJIS[56]),
+        ("👳🏿\u{200d}♀", &crate::gen::EMOJIS[1221]),
+        ("🧛\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1414]),
+        ("🥸", &crate::gen::EMOJIS[69]),
+        ("🧙🏻", &crate::gen::EMOJIS[1373]),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏿", &crate::gen::EMOJIS[1979]),
+        ("🥎", &crate::gen::EMOJIS[2551]),
+        ("🐂", &crate::gen::EMOJIS[2047]),
+        ("🏌🏿\u{200d}♂", &crate::gen::EMOJIS[1713]),
+        ("🧔🏾", &crate::gen::EMOJIS[518]),
+        ("❇", &crate::gen::EMOJIS[3001]),
+        ("🥌", &crate::gen::EMOJIS[2575]),
+        ("🎗", &crate::gen::EMOJIS[2540]),
+        ("👩🏿\u{200d}🍳", &crate::gen::EMOJIS[927]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1976]),
+        ("🤹\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1915]),
+        ("🤷🏻\u{200d}♂", &crate::gen::EMOJIS[809]),
+        ("📷", &crate::gen::EMOJIS[2708]),
+        ("✳\u{fe0f}", &crate::gen::EMOJIS[2999]),
+        ("🏑", &crate::gen::EMOJIS[2560]),
+        ("👯", &crate::gen::EMOJIS[1649]),
+        ("👱🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[617]),
+        ("🫳", &crate::gen::EMOJIS[208]),
+        ("🍤", &crate::gen::EMOJIS[2251]),
+        ("😆", &crate::gen::EMOJIS[4]),
+        ("🧑🏿\u{200d}✈", &crate::gen::EMOJIS[1059]),
+        ("🤨", &crate::gen::EMOJIS[37]),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1977]),
+        ("🥳", &crate::gen::EMOJIS[68]),
+        ("🧑🏽\u{200d}🔧", &crate::gen::EMOJIS[931]),
+        ("🖖🏿", &crate::gen::EMOJIS[195]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", &crate::gen::EMOJIS[1978]),
+        ("🆔", &crate::gen::EMOJIS[3030]),
+        ("🚵🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1842]),
+        ("🧑🏿\u{200d}🦯", &crate::gen::EMOJIS[1564]),
+        ("📘", &crate::gen::EMOJIS[2723]),
+        ("🇲🇫", &crate::gen::EMOJIS[3245]),
+        ("👮🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1121]),
+        ("🇮🇹", &crate::gen::EMOJIS[3214]),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1977]),
+        ("🍀", &crate::gen::EMOJIS[2164]),
+        ("🕵", &crate::gen::EMOJIS[1126]),
+        ("🫅🏾", &crate::gen::EMOJIS[1190]),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏾", &crate::gen::EMOJIS[1975]),
+        ("🇸🇧", &crate::gen::EMOJIS[3298]),
+        ("🙅🏿\u{200d}♀", &crate::gen::EMOJIS[693]),
+        ("👨🏽\u{200d}🦲", &crate::gen::EMOJIS[553]),
+        ("⛹🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1787]),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏿", &crate::gen::EMOJIS[1975]),
+        ("🏌🏻\u{200d}♂", &crate::gen::EMOJIS[1709]),
+        ("🏳\u{fe0f}", &crate::gen::EMOJIS[3095]),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏽", &crate::gen::EMOJIS[1964]),
+        ("👭🏽", &crate::gen::EMOJIS[1955]),
+        ("🧑🏽\u{200d}🦯", &crate::gen::EMOJIS[1562]),
+        ("🧞\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1463]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏼", &crate::gen::EMOJIS[1987]),
+        ("💂\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1156]),
+        ("🧑🏼\u{200d}🏭", &crate::gen::EMOJIS[948]),
+        ("🤳🏽", &crate::gen::EMOJIS[421]),
+        ("🚴\u{200d}♂", &crate::gen::EMOJIS[1816]),
+        ("🇹🇱", &crate::gen::EMOJIS[3326]),
+        ("🖕🏿", &crate::gen::EMOJIS[309]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1976]),
+        ("#\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3005]),
+        ("🛶", &crate::gen::EMOJIS[2423]),
+        ("🧜🏾\u{200d}♀", &crate::gen::EMOJIS[1442]),
+        ("🙍🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[653]),
+        ("🙍🏻\u{200d}♀", &crate::gen::EMOJIS[653]),
+        ("✍🏿", &crate::gen::EMOJIS[411]),
+        ("🤦🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[798]),
+        ("🕉", &crate::gen::EMOJIS[2917]),
+        ("👨\u{200d}🏭", &crate::gen::EMOJIS[952]),
+        ("🇬🇫", &crate::gen::EMOJIS[3183]),
+        ("🧛\u{200d}♀", &crate::gen::EMOJIS[1420]),
+        ("👯\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1651]),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏻", &crate::gen::EMOJIS[1951]),
+        ("🦸\u{200d}♀", &crate::gen::EMOJIS[1348]),
+        ("🧎\u{200d}♂", &crate::gen::EMOJIS[1547]),
+        ("🚖", &crate::gen::EMOJIS[2391]),
+        ("🌪", &crate::gen::EMOJIS[2505]),
+        ("🧜🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1439]),
+        ("🩱", &crate::gen::EMOJIS[2622]),
+        ("👏🏾", &crate::gen::EMOJIS[368]),
+        ("🚿", &crate::gen::EMOJIS[2843]),
+        ("✡\u{fe0f}", &crate::gen::EMOJIS[2918]),
+        ("🧑🏾\u{200d}⚕", &crate::gen::EMOJIS[824]),
+        ("🤬", &crate::gen::EMOJIS[102]),
+        ("🍹", &crate::gen::EMOJIS[2286]),
+        ("💿", &crate::gen::EMOJIS[2700]),
+        ("🗿", &crate::gen::EMOJIS[2865]),
+        ("🤦🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[794]),
+        ("🧛🏽\u{200d}♀", &crate::gen::EMOJIS[1423]),
+        ("👵🏾", &crate::gen::EMOJIS[638]),
+        ("🥣", &crate::gen::EMOJIS[2235]),
+        ("🇵🇰", &crate::gen::EMOJIS[3282]),
+        ("👰🏻\u{200d}♂", &crate::gen::EMOJIS[1259]),
+        ("🧎🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1550]),
+        ("🦻🏻", &crate::gen::EMOJIS[451]),
+        ("👨🏼\u{200d}💼", &crate::gen::EMOJIS[972]),
+        ("👱🏼\u{200d}♀", &crate::gen::EMOJIS[612]),
+        ("🧖🏿", &crate::gen::EMOJIS[1657]),
+        ("🤸🏼", &crate::gen::EMOJIS[1848]),
+        ("🧍🏽\u{200d}♀", &crate::gen::EMOJIS[1538]),
+        ("2\u{20e3}", &crate::gen::EMOJIS[3009]),
+        ("🪦", &crate::gen::EMOJIS[2861]),
+        ("🧜🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1433]),
+        ("👎🏽", &crate::gen::EMOJIS[337]),
+        ("👳🏾", &crate::gen::EMOJIS[1208]),
+        ("🪃", &crate::gen::EMOJIS[2803]),
+        ("🦋", &crate::gen::EMOJIS[2128]),
+        ("⛴", &crate::gen::EMOJIS[2426]),
+        ("💁🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[726]),
+        ("👩\u{200d}🎤", &crate::gen::EMOJIS[1030]),
+        ("👩🏿\u{200d}🦼", &crate::gen::EMOJIS[1594]),
+        ("🧲", &crate::gen::EMOJIS[2818]),
+        ("🤾🏼\u{200d}♀", &crate::gen::EMOJIS[1899]),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏽", &crate::gen::EMOJIS[1979]),
+        ("🌔", &crate::gen::EMOJIS[2478]),
+        ("🤾\u{200d}♀", &crate::gen::EMOJIS[1897]),
+        ("🏄🏽", &crate::gen::EMOJIS[1723]),
+        ("🧷", &crate::gen::EMOJIS[2848]),
+        ("🏞", &crate::gen::EMOJIS[2319]),
+        ("🧪", &crate::gen::EMOJIS[2821]),
+        ("🔍", &crate::gen::EMOJIS[2712]),
+        ("🏛", &crate::gen::EMOJIS[2321]),
+        ("🇮🇨", &crate::gen::EMOJIS[3204]),
+        ("🏊🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1766]),
+        ("👨🏾\u{200d}❤\u{200d}👨🏻", &crate::gen::EMOJIS[1986]),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏽", &crate::gen::EMOJIS[1978]),
+        ("🙅🏿\u{200d}♂", &crate::gen::EMOJIS[687]),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", &crate::gen::EMOJIS[1986]),
+        ("6\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3013]),
+        ("💆🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1478]),
+        ("🚵🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1844]),
+        ("👩🏽\u{200d}🍳", &crate::gen::EMOJIS[925]),
+        ("⚽", &crate::gen::EMOJIS[2549]),
+        ("🧑🏾\u{200d}🦽", &crate::gen::EMOJIS[1599]),
+        ("💁🏼\u{200d}♂", &crate::gen::EMOJIS[720]),
+        ("🧁", &crate::gen::EMOJIS[2270]),
+        ("🇧🇾", &crate::gen::EMOJIS[3135]),
+        ("👳\u{200d}♀", &crate::gen::EMOJIS[1216]),
+        ("♾\u{fe0f}", &crate::gen::EMOJIS[2974]),
+        ("🤶🏿", &crate::gen::EMOJIS[1329]),
+        ("💵", &crate::gen::EMOJIS[2739]),
+        ("🏋🏽", &crate::gen::EMOJIS[1795]),
+        ("👩🏾\u{200d}🦯", &crate::gen::EMOJIS[1575]),
+        ("🔛", &crate::gen::EMOJIS[2912]),
+        ("💆🏼", &crate::gen::EMOJIS[1471]),
+        ("💑🏼", &crate::gen::EMOJIS[1981]),
+        ("🥐", &crate::gen::EMOJIS[2208]),
+        ("👱🏾", &crate::gen::EMOJIS[506]),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", &crate::gen::EMOJIS[1986]),
+        ("🧑\u{200d}✈", &crate::gen::EMOJIS[1054]),
+        ("☀\u{fe0f}", &crate::gen::EMOJIS[2488]),
+        ("🤪", &crate::gen::EMOJIS[26]),
+        ("😩", &crate::gen::EMOJIS[96]),
+        ("🇦🇪", &crate::gen::EMOJIS[3101]),
+        ("⛹", &crate::gen::EMOJIS[1774]),
+        ("🧗🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1677]),
+        ("📓", &crate::gen::EMOJIS[2726]),
+        ("🕺🏽", &crate::gen::EMOJIS[1640]),
+        ("🩲", &crate::gen::EMOJIS[2623]),
+        ("🦹🏻\u{200d}♂", &crate::gen::EMOJIS[1361]),
+        ("👩🏼\u{200d}❤\u{200d}👨🏾", &crate::gen::EMOJIS[1985]),
+        ("🦢", &crate::gen::EMOJIS[2096]),
+        ("🧇", &crate::gen::EMOJIS[2214]),
+        ("🚩", &crate::gen::EMOJIS[3092]),
+        ("🛳\u{fe0f}", &crate::gen::EMOJIS[2425]),
+        ("🤸🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1860]),
+        ("🍛", &crate::gen::EMOJIS[2245]),
+        ("🕹\u{fe0f}", &crate::gen::EMOJIS[2584]),
+        ("👨\u{200d}👩\u{200d}👧\u{200d}👦", &crate::gen::EMOJIS[1991]),
+        ("🧑🏾\u{200d}🍼", &crate::gen::EMOJIS[1310]),
+        ("👨🏿\u{200d}🦱", &crate::gen::EMOJIS[543]),
+        ("🕵🏻\u{200d}♂", &crate::gen::EMOJIS[1133]),
+        ("🏘", &crate::gen::EMOJIS[2327]),
+        ("🧑🏿\u{200d}🦲", &crate::gen::EMOJIS[609]),
+        ("🎖", &crate::gen::EMOJIS[2543]),
+        ("🤶🏼", &crate::gen::EMOJIS[1326]),
+        ("🌡", &crate::gen::EMOJIS[2487]),
+        ("👩🏿\u{200d}❤\u{200d}👨🏿", &crate::gen::EMOJIS[1985]),
+        ("🇳🇪", &crate::gen::EMOJIS[3266]),
+        ("✏", &crate::gen::EMOJIS[2759]),
+        ("🚴", &crate::gen::EMOJIS[1810]),
+        ("🫴🏾", &crate::gen::EMOJIS[218]),
+        ("🤼", &crate::gen::EMOJIS[1864]),
+        ("🎡", &crate::gen::EMOJIS[2366]),
+        ("👨🏿\u{200d}❤\u{200d}👨🏿", &crate::gen::EMOJIS[1986]),
+        ("🪄", &crate::gen::EMOJIS[2582]),
+        ("🚣", &crate::gen::EMOJIS[1738]),
+        ("🤩", &crate::gen::EMOJIS[16]),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1977]),
+        ("💪", &crate::gen::EMOJIS[424]),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏼", &crate::gen::EMOJIS[1984]),
+        ("💇🏾\u{200d}♂", &crate::gen::EMOJIS[1497]),
+        ("🔒", &crate::gen::EMOJIS[2789]),
+        ("🙅🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[684]),
+        ("🧑\u{200d}🦰", &crate::gen::EMOJIS[568]),
+        ("💅🏿", &crate::gen::EMOJIS[417]),
+        ("👭🏾", &crate::gen::EMOJIS[1956]),
+        ("🥷🏿", &crate::gen::EMOJIS[1167]),
+        ("🧑🏼\u{200d}🎨", &crate::gen::EMOJIS[1038]),
+        ("🇬🇩", &crate::gen::EMOJIS[3181]),
+        ("🧘🏽\u{200d}♀", &crate::gen::EMOJIS[1936]),
+        ("🌤\u{fe0f}", &crate::gen::EMOJIS[2499]),
+        ("🌏", &crate::gen::EMOJIS[2306]),
+        ("🧑\u{200d}🎨", &crate::gen::EMOJIS[1036]),
+        ("💁\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[724]),
+        ("🈂", &crate::gen::EMOJIS[3041]),
+        ("🤙", &crate::gen::EMOJIS[280]),
+        ("👩🏻\u{200d}🦽", &crate::gen::EMOJIS[1608]),
+        ("🤟", &crate::gen::EMOJIS[268]),
+        ("🚲", &crate::gen::EMOJIS[2405]),
+        ("🦀", &crate::gen::EMOJIS[2258]),
+        ("🇨🇫", &crate::gen::EMOJIS[3140]),
+        ("🦖", &crate::gen::EMOJIS[2114]),
+        ("👳🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1219]),
+        ("🤸🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1857]),
+        ("🥴", &crate::gen::EMOJIS[63]),
+        ("📜", &crate::gen::EMOJIS[2729]),
+        ("🇧🇭", &crate::gen::EMOJIS[3122]),
+        ("👩🏻\u{200d}🦲", &crate::gen::EMOJIS[599]),
+        ("🧙🏼", &crate::gen::EMOJIS[1374]),
+        ("🏋🏾\u{200d}♂", &crate::gen::EMOJIS[1802]),
+        ("🕉\u{fe0f}", &crate::gen::EMOJIS[2917]),
+        ("👻", &crate::gen::EMOJIS[111]),
+        ("🧑🏿\u{200d}🍳", &crate::gen::EMOJIS[915]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", &crate::gen::EMOJIS[1985]),
+        ("🇺🇬", &crate::gen::EMOJIS[3336]),
+        ("🧑🏽\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1057]),
+        ("🌶", &crate::gen::EMOJIS[2195]),
+        ("🙌🏿", &crate::gen::EMOJIS[375]),
+        ("🇵🇼", &crate::gen::EMOJIS[3289]),
+        ("🦮", &crate::gen::EMOJIS[2025]),
+        ("🇻🇨", &crate::gen::EMOJIS[3343]),
+        ("⤵", &crate::gen::EMOJIS[2907]),
+        ("⚱", &crate::gen::EMOJIS[2862]),
+        ("👩🏻\u{200d}🦳", &crate::gen::EMOJIS[587]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1976]),
+        ("🎧", &crate::gen::EMOJIS[2670]),
+        ("🧝🏿\u{200d}♂", &crate::gen::EMOJIS[1455]),
+        ("♥", &crate::gen::EMOJIS[2593]),
+        ("🧢", &crate::gen::EMOJIS[2647]),
+        ("😓", &crate::gen::EMOJIS[95]),
+        ("🦹🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1362]),
+        ("🇵🇬", &crate::gen::EMOJIS[3280]),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1976]),
+        ("🥃", &crate::gen::EMOJIS[2290]),
+        ("✝", &crate::gen::EMOJIS[2921]),
+        ("🆗", &crate::gen::EMOJIS[3035]),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏿", &crate::gen::EMOJIS[1952]),
+        ("🛑", &crate::gen::EMOJIS[2418]),
+        ("👧🏽", &crate::gen::EMOJIS[493]),
+        ("🌿", &crate::gen::EMOJIS[2162]),
+        ("🧎🏼\u{200d}♀", &crate::gen::EMOJIS[1555]),
+        ("🤾🏽", &crate::gen::EMOJIS[1888]),
+        ("🧑🏼\u{200d}🦱", &crate::gen::EMOJIS[582]),
+        ("⁉", &crate::gen::EMOJIS[2976]),
+        ("🇰🇾", &crate::gen::EMOJIS[3228]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏼", &crate::gen::EMOJIS[1987]),
+        ("👮\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1114]),
+        ("🏌🏾\u{200d}♀", &crate::gen::EMOJIS[1718]),
+        ("🗯\u{fe0f}", &crate::gen::EMOJIS[163]),
+        ("🎉", &crate::gen::EMOJIS[2529]),
+        ("💁🏼", &crate::gen::EMOJIS[714]),
+        ("🤵🏼", &crate::gen::EMOJIS[1236]),
+        ("🤏", &crate::gen::EMOJIS[244]),
+        ("🪠", &crate::gen::EMOJIS[2842]),
+        ("🕵🏾\u{200d}♂", &crate::gen::EMOJIS[1136]),
+        ("🕖", &crate::gen::EMOJIS[2465]),
+        ("👭🏻", &crate::gen::EMOJIS[1953]),
+        ("😁", &crate::gen::EMOJIS[3]),
+        ("❗", &crate::gen::EMOJIS[2980]),
+        ("🇲🇭", &crate::gen::EMOJIS[3247]),
+        ("🤸🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1853]),
+        ("🧑🏾\u{200d}🍳", &crate::gen::EMOJIS[914]),
+        ("🛰\u{fe0f}", &crate::gen::EMOJIS[2439]),
+        ("🤹🏿", &crate::gen::EMOJIS[1908]),
+        ("♣\u{fe0f}", &crate::gen::EMOJIS[2595]),
+        ("⛩", &crate::gen::EMOJIS[2351]),
+        ("✅", &crate::gen::EMOJIS[2991]),
+        ("🗨\u{fe0f}", &crate::gen::EMOJIS[162]),
+        ("🚣🏽", &crate::gen::EMOJIS[1741]),
+        ("🇦🇱", &crate::gen::EMOJIS[3105]),
+        ("❄\u{fe0f}", &crate::gen::EMOJIS[2515]),
+        ("🙆\u{200d}♀", &crate::gen::EMOJIS[706]),
+        ("💂", &crate::gen::EMOJIS[1144]),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏿", &crate::gen::EMOJIS[1986]),
+        ("🇮🇩", &crate::gen::EMOJIS[3205]),
+        ("💆\u{200d}♂", &crate::gen::EMOJIS[1475]),
+        ("🎅🏿", &crate::gen::EMOJIS[1323]),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", &crate::gen::EMOJIS[1986]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", &crate::gen::EMOJIS[1978]),
+        ("🇸🇩", &crate::gen::EMOJIS[3300]),
+        ("🥦", &crate::gen::EMOJIS[2199]),
+        ("🔱", &crate::gen::EMOJIS[2987]),
+        ("🇸🇻", &crate::gen::EMOJIS[3314]),
+        ("👩🏼\u{200d}❤\u{200d}👩🏻", &crate::gen::EMOJIS[1987]),
+        ("👩🏾\u{200d}🎤", &crate::gen::EMOJIS[1034]),
+        ("🧚🏾\u{200d}♂", &crate::gen::EMOJIS[1400]),
+        ("👨\u{200d}🚀", &crate::gen::EMOJIS[1078]),
+        ("🇨🇼", &crate::gen::EMOJIS[3153]),
+        ("🤾🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1901]),
+        ("🚴\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1822]),
+        ("💘", &crate::gen::EMOJIS[128]),
+        ("👼🏾", &crate::gen::EMOJIS[1316]),
+        ("🛌🏿", &crate::gen::EMOJIS[1950]),
+        ("😽", &crate::gen::EMOJIS[120]),
+        ("🔞", &crate::gen::EMOJIS[2891]),
+        ("🏈", &crate::gen::EMOJIS[2554]),
+        ("🤱🏽", &crate::gen::EMOJIS[1291]),
+        ("🎅🏾", &crate::gen::EMOJIS[1322]),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1977]),
+        ("🖊", &crate::gen::EMOJIS[2762]),
+        ("🙆🏻\u{200d}♂", &crate::gen::EMOJIS[701]),
+        ("👳🏿\u{200d}♂", &crate::gen::EMOJIS[1215]),
+        ("🧑🏼", &crate::gen::EMOJIS[498]),
+        ("🏊🏻\u{200d}♂", &crate::gen::EMOJIS[1763]),
+        ("✈", &crate::gen::EMOJIS[2429]),
+        ("👨🏾\u{200d}⚕", &crate::gen::EMOJIS[830]),
+        ("🫁", &crate::gen::EMOJIS[464]),
+        ("🪈", &crate::gen::EMOJIS[2682]),
+        ("🏊🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1764]),
+        ("🤵\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1246]),
+        ("🤶🏻", &crate::gen::EMOJIS[1325]),
+        ("🤦🏾\u{200d}♀", &crate::gen::EMOJIS[800]),
+        ("🕵🏽", &crate::gen::EMOJIS[1129]),
+        ("🏴\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}", &crate::gen::EMOJIS[3357]),
+        ("🐖", &crate::gen::EMOJIS[2051]),
+        ("🪼", &crate::gen::EMOJIS[2126]),
+        ("🤰", &crate::gen::EMOJIS[1270]),
+        ("🅱", &crate::gen::EMOJIS[3025]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏽", &crate::gen::EMOJIS[1987]),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", &crate::gen::EMOJIS[1986]),
+        ("🎇", &crate::gen::EMOJIS[2525]),
+        ("🏊🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1769]),
+        ("🇷🇪", &crate::gen::EMOJIS[3292]),
+        ("🔡", &crate::gen::EMOJIS[3019]),
+        ("📃", &crate::gen::EMOJIS[2728]),
+        ("🤹🏼", &crate::gen::EMOJIS[1905]),
+        ("👳🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1214]),
+        ("💁🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[727]),
+        ("🖲\u{fe0f}", &crate::gen::EMOJIS[2697]),
+        ("👧🏾", &crate::gen::EMOJIS[494]),
+        ("🪤", &crate::gen::EMOJIS[2845]),
+        ("🧖🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1659]),
+        ("♨", &crate::gen::EMOJIS[2363]),
+        ("🧕🏼", &crate::gen::EMOJIS[1230]),
+        ("♐", &crate::gen::EMOJIS[2936]),
+        ("🤱🏻", &crate::gen::EMOJIS[1289]),
+        ("🧑🏼\u{200d}🚒", &crate::gen::EMOJIS[1092]),
+        ("🎏", &crate::gen::EMOJIS[2534]),
+        ("🧒🏽", &crate::gen::EMOJIS[481]),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏽", &crate::gen::EMOJIS[1952]),
+        ("👩🏾\u{200d}🎨", &crate::gen::EMOJIS[1052]),
+        ("🫴🏽", &crate::gen::EMOJIS[217]),
+        ("🧑\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1054]),
+        ("🧏🏼", &crate::gen::EMOJIS[750]),
+        ("🇭🇷", &crate::gen::EMOJIS[3201]),
+        ("🇩🇿", &crate::gen::EMOJIS[3163]),
+        ("🧑🏾\u{200d}🦳", &crate::gen::EMOJIS[596]),
+        ("🇾🇪", &crate::gen::EMOJIS[3352]),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏾", &crate::gen::EMOJIS[1958]),
+        ("🦕", &crate::gen::EMOJIS[2113]),
+        ("💽", &crate::gen::EMOJIS[2698]),
+        ("⚛\u{fe0f}", &crate::gen::EMOJIS[2916]),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1977]),
+        ("🌎", &crate::gen::EMOJIS[2305]),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏼", &crate::gen::EMOJIS[1979]),
+        ("🚴🏿\u{200d}♀", &crate::gen::EMOJIS[1827]),
+        ("👷🏻\u{200d}♀", &crate::gen::EMOJIS[1181]),
+        ("👩🏼\u{200d}🦯", &crate::gen::EMOJIS[1573]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", &crate::gen::EMOJIS[1978]),
+        ("🧑🏼\u{200d}✈", &crate::gen::EMOJIS[1056]),
+        ("🧝🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1451]),
+        ("👎🏾", &crate::gen::EMOJIS[338]),
+        ("💤", &crate::gen::EMOJIS[165]),
+        ("🙎🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[665]),
+        ("👝", &crate::gen::EMOJIS[2630]),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏻", &crate::gen::EMOJIS[1951]),
+        ("👱🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[613]),
+        ("🦶", &crate::gen::EMOJIS[438]),
+        ("🪩", &crate::gen::EMOJIS[2590]),
+        ("✔\u{fe0f}", &crate::gen::EMOJIS[2993]),
+        ("🧞\u{200d}♀", &crate::gen::EMOJIS[1464]),
+        ("✊🏾", &crate::gen::EMOJIS[344]),
+        ("🤰🏿", &crate::gen::EMOJIS[1275]),
+        ("🅾\u{fe0f}", &crate::gen::EMOJIS[3034]),
+        ("🐯", &crate::gen::EMOJIS[2035]),
+        ("🙆🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[711]),
+        ("🎅", &crate::gen::EMOJIS[1318]),
+        ("👩🏼\u{200d}🎓", &crate::gen::EMOJIS[852]),
+        ("🤷🏾\u{200d}♂", &crate::gen::EMOJIS[812]),
+        ("☺\u{fe0f}", &crate::gen::EMOJIS[19]),
+        ("⚕\u{fe0f}", &crate::gen::EMOJIS[2984]),
+        ("🤸🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1859]),
+        ("🩶", &crate::gen::EMOJIS[150]),
+        ("🧛🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1415]),
+        ("🙎🏾", &crate::gen::EMOJIS[662]),
+        ("🐼", &crate::gen::EMOJIS[2078]),
+        ("🧑🏻\u{200d}🚒", &crate::gen::EMOJIS[1091]),
+        ("👩\u{200d}👧\u{200d}👧", &crate::gen::EMOJIS[2013]),
+        ("🦶🏾", &crate::gen::EMOJIS[442]),
+        ("🚵🏽\u{200d}♀", &crate::gen::EMOJIS[1843]),
+        ("⛸", &crate::gen::EMOJIS[2569]),
+        ("🧑🏼\u{200d}🦲", &crate::gen::EMOJIS[606]),
+        ("👙", &crate::gen::EMOJIS[2625]),
+        ("🧎🏼\u{200d}♂", &crate::gen::EMOJIS[1549]),
+        ("🇳🇦", &crate::gen::EMOJIS[3264]),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", &crate::gen::EMOJIS[1986]),
+        ("🏄", &crate::gen::EMOJIS[1720]),
+        ("🧘🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1928]),
+        ("📵", &crate::gen::EMOJIS[2890]),
+        ("👰🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1268]),
+        ("🕯", &crate::gen::EMOJIS[2714]),
+        ("🧑🏽\u{200d}🎓", &crate::gen::EMOJIS[841]),
+        ("👰🏾\u{200d}♂", &crate::gen::EMOJIS[1262]),
+        ("🇵🇫", &crate::gen::EMOJIS[3279]),
+        ("🚵🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1839]),
+        ("🧛🏻\u{200d}♂", &crate::gen::EMOJIS[1415]),
+        ("🔅", &crate::gen::EMOJIS[2960]),
+        ("🙎🏿\u{200d}♂", &crate::gen::EMOJIS[669]),
+        ("🏋🏾", &crate::gen::EMOJIS[1796]),
+        ("🗃\u{fe0f}", &crate::gen::EMOJIS[2786]),
+        ("Ⓜ", &crate::gen::EMOJIS[3031]),
+        ("☂", &crate::gen::EMOJIS[2511]),
+        ("🧕", &crate::gen::EMOJIS[1228]),
+        ("🧜🏾", &crate::gen::EMOJIS[1430]),
+        ("🆙", &crate::gen::EMOJIS[3038]),
+        ("🤸🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1863]),
+        ("💇🏻\u{200d}♀", &crate::gen::EMOJIS[1500]),
+        ("👨🏾\u{200d}🔧", &crate::gen::EMOJIS[938]),
+        ("🚶🏻\u{200d}♂", &crate::gen::EMOJIS[1512]),
+        ("👨🏽\u{200d}🦽", &crate::gen::EMOJIS[1604]),
+        ("👩🏽\u{200d}❤\u{200d}👩🏿", &crate::gen::EMOJIS[1987]),
+        ("🈷", &crate::gen::EMOJIS[3042]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1976]),
+        ("🖲", &crate::gen::EMOJIS[2697]),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", &crate::gen::EMOJIS[1970]),
+        ("🌑", &crate::gen::EMOJIS[2475]),
+        ("🧗🏾\u{200d}♂", &crate::gen::EMOJIS[1680]),
+        ("🦻🏾", &crate::gen::EMOJIS[454]),
+        ("🌌", &crate::gen::EMOJIS[2495]),
+        ("👰🏽\u{200d}♂", &crate::gen::EMOJIS[1261]),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1977]),
+        ("👨🏾\u{200d}🦽", &crate::gen::EMOJIS[1605]),
+        ("👨🏽\u{200d}🏭", &crate::gen::EMOJIS[955]),
+        ("🏋🏻\u{200d}♀", &crate::gen::EMOJIS[1805]),
+        ("👩🏽\u{200d}🎓", &crate::gen::EMOJIS[853]),
+        ("🤴", &crate::gen::EMOJIS[1192]),
+        ("😌", &crate::gen::EMOJIS[50]),
+        ("🕥", &crate::gen::EMOJIS[2472]),
+        ("📡", &crate::gen::EMOJIS[2826]),
+        ("🏋\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1804]),
+        ("🚽", &crate::gen::EMOJIS[2841]),
+        ("🤱", &crate::gen::EMOJIS[1288]),
+        ("🙋🏽\u{200d}♂", &crate::gen::EMOJIS[739]),
+        ("👩🏿\u{200d}🦰", &crate::gen::EMOJIS[567]),
+        ("☘\u{fe0f}", &crate::gen::EMOJIS[2163]),
+        ("🧜\u{200d}♂", &crate::gen::EMOJIS[1432]),
+        ("🧎\u{200d}♀", &crate::gen::EMOJIS[1553]),
+        ("👩🏼\u{200d}🎨", &crate::gen::EMOJIS[1050]),
+        ("🛢", &crate::gen::EMOJIS[2412]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1976]),
+        ("☺", &crate::gen::EMOJIS[19]),
+        ("🙆🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[701]),
+        ("🇸🇷", &crate::gen::EMOJIS[3311]),
+        ("🛁", &crate::gen::EMOJIS[2844]),
+        ("🛖", &crate::gen::EMOJIS[2326]),
+        ("🎽", &crate::gen::EMOJIS[2572]),
+        ("😯", &crate::gen::EMOJIS[79]),
+        ("✊🏻", &crate::gen::EMOJIS[341]),
+        ("🌪\u{fe0f}", &crate::gen::EMOJIS[2505]),
+        ("💁🏻", &crate::gen::EMOJIS[713]),
+        ("🔀", &crate::gen::EMOJIS[2941]),
+        ("👱🏾\u{200d}♀", &crate::gen::EMOJIS[614]),
+        ("🕞", &crate::gen::EMOJIS[2458]),
+        ("👷\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1180]),
+        ("👩🏿\u{200d}🦱", &crate::gen::EMOJIS[579]),
+        ("🙋🏿", &crate::gen::EMOJIS[735]),
+        ("🧝\u{200d}♀", &crate::gen::EMOJIS[1456]),
+        ("🚴\u{200d}♀", &crate::gen::EMOJIS[1822]),
+        ("🤾🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1896]),
+        ("👨🏼\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[882]),
+        ("🏊🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1763]),
+        ("🏬", &crate::gen::EMOJIS[2340]),
+        ("🏊🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1770]),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", &crate::gen::EMOJIS[1970]),
+        ("😬", &crate::gen::EMOJIS[46]),
+        ("🇱🇻", &crate::gen::EMOJIS[3239]),
+        ("🧜🏽\u{200d}♂", &crate::gen::EMOJIS[1435]),
+        ("🟢", &crate::gen::EMOJIS[3060]),
+        ("🙇🏽\u{200d}♀", &crate::gen::EMOJIS[781]),
+        ("👃🏾", &crate::gen::EMOJIS[460]),
+        ("🏄\u{200d}♀", &crate::gen::EMOJIS[1732]),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏼", &crate::gen::EMOJIS[1975]),
+        ("🙅🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[683]),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏽", &crate::gen::EMOJIS[1975]),
+        ("👩🏿\u{200d}❤\u{200d}👩🏿", &crate::gen::EMOJIS[1987]),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏿", &crate::gen::EMOJIS[1952]),
+        ("🇱🇮", &crate::gen::EMOJIS[3233]),
+        ("🔆", &crate::gen::EMOJIS[2961]),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1977]),
+        ("🏌🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1710]),
+        ("🇰🇲", &crate::gen::EMOJIS[3223]),
+        ("🤽🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1882]),
+        ("❤\u{200d}🩹", &crate::gen::EMOJIS[139]),
+        ("🉑", &crate::gen::EMOJIS[3049]),
+        ("💇\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1499]),
+        ("🧘\u{200d}♀", &crate::gen::EMOJIS[1933]),
+        ("😳", &crate::gen::EMOJIS[81]),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1977]),
+        ("🤾🏾\u{200d}♂", &crate::gen::EMOJIS[1895]),
+        ("🏌🏻", &crate::gen::EMOJIS[1703]),
+        ("🇰🇪", &crate::gen::EMOJIS[3219]),
+        ("🖥\u{fe0f}", &crate::gen::EMOJIS[2693]),
+        ("👨🏿\u{200d}🦯", &crate::gen::EMOJIS[1570]),
+        ("🧃", &crate::gen::EMOJIS[2294]),
+        ("👰🏼", &crate::gen::EMOJIS[1254]),
+        ("🫷🏼", &crate::gen::EMOJIS[222]),
+        ("🧖🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1665]),
+        ("🤡", &crate::gen::EMOJIS[108]),
+        ("🕜", &crate::gen::EMOJIS[2454]),
+        ("😧", &crate::gen::EMOJIS[85]),
+        ("🤷🏽\u{200d}♀", &crate::gen::EMOJIS[817]),
+        ("🧑🏽\u{200d}🎤", &crate::gen::EMOJIS[1021]),
+        ("🚶🏼\u{200d}♂", &crate::gen::EMOJIS[1513]),
+        ("🤥", &crate::gen::EMOJIS[48]),
+        ("⚱\u{fe0f}", &crate::gen::EMOJIS[2862]),
+        ("🏄🏼", &crate::gen::EMOJIS[1722]),
+        ("🥁", &crate::gen::EMOJIS[2679]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", &crate::gen::EMOJIS[1978]),
+        ("🥋", &crate::gen::EMOJIS[2566]),
+        ("🤽🏽\u{200d}♀", &crate::gen::EMOJIS[1882]),
+        ("🎨", &crate::gen::EMOJIS[2602]),
+        ("🖍\u{fe0f}", &crate::gen::EMOJIS[2764]),
+        ("🚵🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1845]),
+        ("🫱🏽\u{200d}🫲🏼", &crate::gen::EMOJIS[394]),
+        ("👅", &crate::gen::EMOJIS[469]),
+        ("🏋🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1809]),
+        ("🩳", &crate::gen::EMOJIS[2624]),
+        ("💐", &crate::gen::EMOJIS[2143]),
+        ("🧙\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1384]),
+        ("🧑🏽\u{200d}🎄", &crate::gen::EMOJIS[1333]),
+        ("🚵🏾\u{200d}♀", &crate::gen::EMOJIS[1844]),
+        ("👩🏻\u{200d}🦱", &crate::gen::EMOJIS[575]),
+        ("👨🏿\u{200d}🦲", &crate::gen::EMOJIS[555]),
+        ("🤵🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1244]),
+        ("🖐🏿", &crate::gen::EMOJIS[183]),
+        ("🇨🇵", &crate::gen::EMOJIS[3149]),
+        ("↖\u{fe0f}", &crate::gen::EMOJIS[2901]),
+        ("🕵🏾", &crate::gen::EMOJIS[1130]),
+        ("🤝🏼", &crate::gen::EMOJIS[396]),
+        ("🧍🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1539]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", &crate::gen::EMOJIS[1985]),
+        ("🐬", &crate::gen::EMOJIS[2117]),
+        ("👊🏻", &crate::gen::EMOJIS[347]),
+        ("🧗🏻", &crate::gen::EMOJIS[1671]),
+        ("🙆🏽", &crate::gen::EMOJIS[697]),
+        ("🇵🇦", &crate::gen::EMOJIS[3277]),
+        ("💂🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1151]),
+        ("®", &crate::gen::EMOJIS[3003]),
+        ("🧆", &crate::gen::EMOJIS[2229]),
+        ("🏄🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1733]),
+        ("💶", &crate::gen::EMOJIS[2740]),
+        ("🧔🏻", &crate::gen::EMOJIS[515]),
+        ("🫂", &crate::gen::EMOJIS[2017]),
+        ("🇱🇰", &crate::gen::EMOJIS[3234]),
+        ("🤹🏻\u{200d}♀", &crate::gen::EMOJIS[1916]),
+        ("👶🏻", &crate::gen::EMOJIS[473]),
+        ("👨🏽\u{200d}🚀", &crate::gen::EMOJIS[1081]),
+        ("♠\u{fe0f}", &crate::gen::EMOJIS[2592]),
+        ("🚊", &crate::gen::EMOJIS[2378]),
+        ("🫱🏻\u{200d}🫲🏽", &crate::gen::EMOJIS[394]),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏾", &crate::gen::EMOJIS[1952]),
+        ("🏄🏾", &crate::gen::EMOJIS[1724]),
+        ("◻", &crate::gen::EMOJIS[3076]),
+        ("🕵🏼\u{200d}♂", &crate::gen::EMOJIS[1134]),
+        ("🪴", &crate::gen::EMOJIS[2156]),
+        ("🤷🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[815]),
+        ("👳🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1213]),
+        ("🐕\u{200d}🦺", &crate::gen::EMOJIS[2026]),
+        ("💂\u{200d}♂", &crate::gen::EMOJIS[1150]),
+        ("⛓\u{fe0f}", &crate::gen::EMOJIS[2815]),
+        ("♊", &crate::gen::EMOJIS[2930]),
+        ("🤹🏾\u{200d}♀", &crate::gen::EMOJIS[1919]),
+        ("🔢", &crate::gen::EMOJIS[3020]),
+        ("💆🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1482]),
+        ("👨🏼\u{200d}⚕", &crate::gen::EMOJIS[828]),
+        ("👨🏾\u{200d}🍼", &crate::gen::EMOJIS[1304]),
+        ("🦸🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1349]),
+        ("📖", &crate::gen::EMOJIS[2721]),
+        ("🤦", &crate::gen::EMOJIS[784]),
+        ("🧙🏿", &crate::gen::EMOJIS[1377]),
+        ("👩🏾\u{200d}❤\u{200d}👩🏿", &crate::gen::EMOJIS[1987]),
+        ("👳🏼\u{200d}♀", &crate::gen::EMOJIS[1218]),
+        ("🤦🏾", &crate::gen::EMOJIS[788]),
+        ("🧟", &crate::gen::EMOJIS[1465]),
+        ("🟦", &crate::gen::EMOJIS[3070]),
+        ("🤙🏼", &crate::gen::EMOJIS[282]),
+        ("👨🏽\u{200d}❤\u{200d}👨🏼", &crate::gen::EMOJIS[1986]),
+        ("👩🏻\u{200d}❤\u{200d}👨🏼", &crate::gen::EMOJIS[1985]),
+        ("🤳🏻", &crate::gen::EMOJIS[419]),
+        ("👩\u{200d}🏭", &crate::gen::EMOJIS[958]),
+        ("🎟\u{fe0f}", &crate::gen::EMOJIS[2541]),
+        ("🛀🏽", &crate::gen::EMOJIS[1942]),
+        ("🤍", &crate::gen::EMOJIS[151]),
+        ("🤸🏾\u{200d}♂", &crate::gen::EMOJIS[1856]),
+        ("🤦🏼", &crate::gen::EMOJIS[786]),
+        ("👗", &crate::gen::EMOJIS[2619]),
+        ("⛳", &crate::gen::EMOJIS[2568]),
+        ("👩🏽\u{200d}❤\u{200d}👨🏻", &crate::gen::EMOJIS[1985]),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏻", &crate::gen::EMOJIS[1975]),
+        ("🧑🏼\u{200d}❤\u{200d}💋\u{200d}🧑🏾", &crate::gen::EMOJIS[1975]),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏼", &crate::gen::EMOJIS[1951]),
+        ("🇦🇺", &crate::gen::EMOJIS[3112]),
+        ("🔄", &crate::gen::EMOJIS[2909]),
+        ("💁\u{200d}♀", &crate::gen::EMOJIS[724]),
+        ("👦🏼", &crate::gen::EMOJIS[486]),
+        ("8\u{20e3}", &crate::gen::EMOJIS[3015]),
+        ("🇳🇬", &crate::gen::EMOJIS[3268]),
+        ("🪆", &crate::gen::EMOJIS[2591]),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1976]),
+        ("↘", &crate::gen::EMOJIS[2897]),
+        ("➗", &crate::gen::EMOJIS[2972]),
+        ("👳\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1210]),
+        ("🤘🏽", &crate::gen::EMOJIS[277]),
+        ("🧏🏼\u{200d}♀", &crate::gen::EMOJIS[762]),
+        ("🚣🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1745]),
+        ("👨🏾\u{200d}🦼", &crate::gen::EMOJIS[1587]),
+        ("🧛🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1417]),
+        ("🙎", &crate::gen::EMOJIS[658]),
+        ("🧙\u{200d}♂", &crate::gen::EMOJIS[1378]),
+        ("🫷", &crate::gen::EMOJIS[220]),
+        ("👶🏾", &crate::gen::EMOJIS[476]),
+        ("🏊🏿\u{200d}♀", &crate::gen::EMOJIS[1773]),
+        ("🤾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1891]),
+        ("🕡", &crate::gen::EMOJIS[2464]),
+        ("🧧", &crate::gen::EMOJIS[2537]),
+        ("⛺", &crate::gen::EMOJIS[2354]),
+        ("✍🏽", &crate::gen::EMOJIS[409]),
+        ("🕓", &crate::gen::EMOJIS[2459]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏾", &crate::gen::EMOJIS[1987]),
+        ("🕵🏽\u{200d}♂", &crate::gen::EMOJIS[1135]),
+        ("🤴🏽", &crate::gen::EMOJIS[1195]),
+        ("🇰🇵", &crate::gen::EMOJIS[3225]),
+        ("🌞", &crate::gen::EMOJIS[2490]),
+        ("🏊🏾", &crate::gen::EMOJIS[1760]),
+        ("🙋🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[744]),
+        ("🤵\u{200d}♀", &crate::gen::EMOJIS[1246]),
+        ("🏊🏿\u{200d}♂", &crate::gen::EMOJIS[1767]),
+        ("👰🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1265]),
+        ("🧉", &crate::gen::EMOJIS[2295]),
+        ("㊙", &crate::gen::EMOJIS[3054]),
+        ("🎰", &crate::gen::EMOJIS[2585]),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏻", &crate::gen::EMOJIS[1978]),
+        ("👷🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1175]),
+        ("🦹🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1367]),
+        ("🎓", &crate::gen::EMOJIS[2646]),
+        ("💞", &crate::gen::EMOJIS[133]),
+        ("👨🏾\u{200d}🌾", &crate::gen::EMOJIS[902]),
+        ("🚣🏿", &crate::gen::EMOJIS[1743]),
+        ("🔻", &crate::gen::EMOJIS[3086]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏿", &crate::gen::EMOJIS[1985]),
+        ("🧜🏽\u{200d}♀", &crate::gen::EMOJIS[1441]),
+        ("💆🏻\u{200d}♀", &crate::gen::EMOJIS[1482]),
+        ("🧔🏾\u{200d}♂", &crate::gen::EMOJIS[524]),
+        ("🔠", &crate::gen::EMOJIS[3018]),
+        ("🏕\u{fe0f}", &crate::gen::EMOJIS[2315]),
+        ("👨\u{200d}❤\u{fe0f}\u{200d}👨", &crate::gen::EMOJIS[1986]),
+        ("👮\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1120]),
+        ("⚧", &crate::gen::EMOJIS[2968]),
+        ("💇🏽", &crate::gen::EMOJIS[1490]),
+        ("⛹\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1780]),
+        ("🇧🇿", &crate::gen::EMOJIS[3136]),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏾", &crate::gen::EMOJIS[1958]),
+        ("🧍🏿\u{200d}♂", &crate::gen::EMOJIS[1534]),
+        ("🥚", &crate::gen::EMOJIS[2230]),
+        ("🧍🏻\u{200d}♀", &crate::gen::EMOJIS[1536]),
+        ("🍁", &crate::gen::EMOJIS[2165]),
+        ("🧑🏽\u{200d}🏭", &crate::gen::EMOJIS[949]),
+        ("⛅", &crate::gen::EMOJIS[2497]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏻", &crate::gen::EMOJIS[1987]),
+        ("🏳\u{200d}🌈", &crate::gen::EMOJIS[3096]),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏽", &crate::gen::EMOJIS[1986]),
+        ("🇩🇪", &crate::gen::EMOJIS[3157]),
+        ("👭", &crate::gen::EMOJIS[1952]),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏼", &crate::gen::EMOJIS[1978]),
+        ("🐎", &crate::gen::EMOJIS[2041]),
+        ("🤹🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1912]),
+        ("👡", &crate::gen::EMOJIS[2639]),
+        ("💂🏿", &crate::gen::EMOJIS[1149]),
+        ("👨\u{200d}👦", &crate::gen::EMOJIS[2004]),
+        ("🤷🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[817]),
+        ("🦸🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1347]),
+        ("⛹🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1783]),
+        ("🛺", &crate::gen::EMOJIS[2404]),
+        ("🚋", &crate::gen::EMOJIS[2381]),
+        ("🏟", &crate::gen::EMOJIS[2320]),
+        ("⏏", &crate::gen::EMOJIS[2958]),
+        ("👩🏾\u{200d}💼", &crate::gen::EMOJIS[980]),
+        ("🧑\u{200d}🌾", &crate::gen::EMOJIS[892]),
+        ("🏊🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1767]),
+        ("🆕", &crate::gen::EMOJIS[3032]),
+        ("🤵🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1248]),
+        ("🇮🇶", &crate::gen::EMOJIS[3211]),
+        ("💴", &crate::gen::EMOJIS[2738]),
+        ("👨🏾\u{200d}🍳", &crate::gen::EMOJIS[920]),
+        ("🧍🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1537]),
+        ("🧝🏻\u{200d}♀", &crate::gen::EMOJIS[1457]),
+        ("🌸", &crate::gen::EMOJIS[2144]),
+        ("👩🏽\u{200d}🦽", &crate::gen::EMOJIS[1610]),
+        ("🙎🏻\u{200d}♂", &crate::gen::EMOJIS[665]),
+        ("🧘🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1929]),
+        ("🫸🏽", &crate::gen::EMOJIS[229]),
+        ("🌩\u{fe0f}", &crate::gen::EMOJIS[2504]),
+        ("👮🏻", &crate::gen::EMOJIS[1109]),
+        ("🐐", &crate::gen::EMOJIS[2056]),
+        ("👩🏿\u{200d}❤\u{200d}👩🏽", &crate::gen::EMOJIS[1987]),
+        ("🙅🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[691]),
+        ("💒", &crate::gen::EMOJIS[2344]),
+        ("🧐", &crate::gen::EMOJIS[72]),
+        ("♌", &crate::gen::EMOJIS[2932]),
+        ("㊗\u{fe0f}", &crate::gen::EMOJIS[3053]),
+        ("👩🏾\u{200d}🏭", &crate::gen::EMOJIS[962]),
+        ("🔎", &crate::gen::EMOJIS[2713]),
+        ("👨🏼\u{200d}🔧", &crate::gen::EMOJIS[936]),
+        ("⛱", &crate::gen::EMOJIS[2513]),
+        ("👁\u{fe0f}\u{200d}🗨\u{fe0f}", &crate::gen::EMOJIS[161]),
+        ("👆🏽", &crate::gen::EMOJIS[301]),
+        ("👩🏿\u{200d}❤\u{200d}👨🏽", &crate::gen::EMOJIS[1985]),
+        ("🛣\u{fe0f}", &crate::gen::EMOJIS[2410]),
+        ("👩🏾\u{200d}🦽", &crate::gen::EMOJIS[1611]),
+        ("👱🏻\u{200d}♀", &crate::gen::EMOJIS[611]),
+        ("🧍🏼\u{200d}♀", &crate::gen::EMOJIS[1537]),
+        ("🧑🏿\u{200d}💻", &crate::gen::EMOJIS[1005]),
+        ("⛹🏽\u{200d}♂", &crate::gen::EMOJIS[1783]),
+        ("🤝", &crate::gen::EMOJIS[394]),
+        ("🏄🏿\u{200d}♂", &crate::gen::EMOJIS[1731]),
+        ("🏃🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1628]),
+        ("🦸🏾", &crate::gen::EMOJIS[1340]),
+        ("🙅\u{200d}♀", &crate::gen::EMOJIS[688]),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1977]),
+        ("🙍🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[657]),
+        ("🪖", &crate::gen::EMOJIS[2648]),
+        ("🖐🏻", &crate::gen::EMOJIS[179]),
+        ("👮\u{200d}♂", &crate::gen::EMOJIS[1114]),
+        ("👩🏾\u{200d}🚀", &crate::gen::EMOJIS[1088]),
+        ("👳🏽\u{200d}♂", &crate::gen::EMOJIS[1213]),
+        ("🙋🏻\u{200d}♀", &crate::gen::EMOJIS[743]),
+        ("👱\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[610]),
+        ("#\u{20e3}", &crate::gen::EMOJIS[3005]),
+        ("🫃", &crate::gen::EMOJIS[1276]),
+        ("👩🏻\u{200d}🎓", &crate::gen::EMOJIS[851]),
+        ("⚖\u{fe0f}", &crate::gen::EMOJIS[2812]),
+        ("🏌🏽\u{200d}♀", &crate::gen::EMOJIS[1717]),
+        ("💆\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1475]),
+        ("🙆🏻", &crate::gen::EMOJIS[695]),
+        ("⛹🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1781]),
+        ("👨🏻\u{200d}🦳", &crate::gen::EMOJIS[545]),
+        ("🗑\u{fe0f}", &crate::gen::EMOJIS[2788]),
+        ("☯", &crate::gen::EMOJIS[2920]),
+        ("👟", &crate::gen::EMOJIS[2635]),
+        ("🐈\u{200d}⬛", &crate::gen::EMOJIS[2033]),
+        ("✔", &crate::gen::EMOJIS[2993]),
+        ("🚵🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1837]),
+        ("🚣🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1746]),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏽", &crate::gen::EMOJIS[1964]),
+        ("🏎\u{fe0f}", &crate::gen::EMOJIS[2399]),
+        ("🏇🏽", &crate::gen::EMOJIS[1692]),
+        ("🤰🏽", &crate::gen::EMOJIS[1273]),
+        ("🖇", &crate::gen::EMOJIS[2782]),
+        ("🧑🏼\u{200d}🍼", &crate::gen::EMOJIS[1308]),
+        ("👦🏻", &crate::gen::EMOJIS[485]),
+        ("👩🏽\u{200d}🦼", &crate::gen::EMOJIS[1592]),
+        ("👮🏾", &crate::gen::EMOJIS[1112]),
+        ("🌰", &crate::gen::EMOJIS[2204]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", &crate::gen::EMOJIS[1978]),
+        ("❔", &crate::gen::EMOJIS[2978]),
+        ("🌙", &crate::gen::EMOJIS[2483]),
+        ("👱🏻", &crate::gen::EMOJIS[503]),
+        ("🤦🏿\u{200d}♂", &crate::gen::EMOJIS[795]),
+        ("🛄", &crate::gen::EMOJIS[2879]),
+        ("🏌🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1717]),
+        ("🇧🇬", &crate::gen::EMOJIS[3121]),
+        ("♾", &crate::gen::EMOJIS[2974]),
+        ("🤵🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1249]),
+        ("🧍🏻", &crate::gen::EMOJIS[1524]),
+        ("🤽🏼\u{200d}♂", &crate::gen::EMOJIS[1875]),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", &crate::gen::EMOJIS[1970]),
+        ("🧛🏼\u{200d}♀", &crate::gen::EMOJIS[1422]),
+        ("👰", &crate::gen::EMOJIS[1252]),
+        ("3\u{20e3}", &crate::gen::EMOJIS[3010]),
+        ("🫱🏽\u{200d}🫲🏻", &crate::gen::EMOJIS[394]),
+        ("🪭", &crate::gen::EMOJIS[2627]),
+        ("⏭\u{fe0f}", &crate::gen::EMOJIS[2946]),
+        ("⏯\u{fe0f}", &crate::gen::EMOJIS[2947]),
+        ("👨🏾\u{200d}🎤", &crate::gen::EMOJIS[1028]),
+        ("🏸", &crate::gen::EMOJIS[2564]),
+        ("🏋🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1800]),
+        ("🧓🏼", &crate::gen::EMOJIS[624]),
+        ("👼🏽", &crate::gen::EMOJIS[1315]),
+        ("👮🏼\u{200d}♂", &crate::gen::EMOJIS[1116]),
+        ("⭐", &crate::gen::EMOJIS[2492]),
+        ("🇭🇺", &crate::gen::EMOJIS[3203]),
+        ("😞", &crate::gen::EMOJIS[94]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", &crate::gen::EMOJIS[1985]),
+        ("💉", &crate::gen::EMOJIS[2827]),
+        ("💇🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1504]),
+        ("🛣", &crate::gen::EMOJIS[2410]),
+        ("🧎\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1547]),
+        ("🦗", &crate::gen::EMOJIS[2134]),
+        ("🐛", &crate::gen::EMOJIS[2129]),
+        ("👩🏼", &crate::gen::EMOJIS[558]),
+        ("🤘🏿", &crate::gen::EMOJIS[279]),
+        ("💇🏿", &crate::gen::EMOJIS[1492]),
+        ("🤛🏿", &crate::gen::EMOJIS[357]),
+        ("🪂", &crate::gen::EMOJIS[2433]),
+        ("👱🏿\u{200d}♀", &crate::gen::EMOJIS[615]),
+        ("👨🏾\u{200d}🎓", &crate::gen::EMOJIS[848]),
+        ("🚇", &crate::gen::EMOJIS[2375]),
+        ("✡", &crate::gen::EMOJIS[2918]),
+        ("🙍🏿\u{200d}♂", &crate::gen::EMOJIS[651]),
+        ("🇱🇦", &crate::gen::EMOJIS[3230]),
+        ("🧘🏻\u{200d}♀", &crate::gen::EMOJIS[1934]),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏻", &crate::gen::EMOJIS[1958]),
+        ("🫖", &crate::gen::EMOJIS[2280]),
+        ("♥\u{fe0f}", &crate::gen::EMOJIS[2593]),
+        ("🤾🏿", &crate::gen::EMOJIS[1890]),
+        ("🦹🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1369]),
+        ("🍅", &crate::gen::EMOJIS[2187]),
+        ("🤽🏾\u{200d}♂", &crate::gen::EMOJIS[1877]),
+        ("📩", &crate::gen::EMOJIS[2749]),
+        ("🤑", &crate::gen::EMOJIS[28]),
+        ("➡", &crate::gen::EMOJIS[2896]),
+        ("👨\u{200d}👨\u{200d}👧\u{200d}👧", &crate::gen::EMOJIS[1998]),
+        ("🪪", &crate::gen::EMOJIS[2867]),
+        ("🤳🏼", &crate::gen::EMOJIS[420]),
+        ("⛹🏽\u{200d}♀", &crate::gen::EMOJIS[1789]),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1976]),
+        ("🧔\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[526]),
+        ("👩🏻\u{200d}❤\u{200d}👩🏿", &crate::gen::EMOJIS[1987]),
+        ("🛩\u{fe0f}", &crate::gen::EMOJIS[2430]),
+        ("♻", &crate::gen::EMOJIS[2985]),
+        ("🧑\u{200d}🦱", &crate::gen::EMOJIS[580]),
+        ("🧏🏽", &crate::gen::EMOJIS[751]),
+        ("🇦🇸", &crate::gen::EMOJIS[3110]),
+        ("🍯", &crate::gen::EMOJIS[2276]),
+        ("👏🏽", &crate::gen::EMOJIS[367]),
+        ("🫱🏻", &crate::gen::EMOJIS[197]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", &crate::gen::EMOJIS[1985]),
+        ("🇬🇺", &crate::gen::EMOJIS[3195]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", &crate::gen::EMOJIS[1978]),
+        ("➕", &crate::gen::EMOJIS[2970]),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", &crate::gen::EMOJIS[1970]),
+        ("🎊", &crate::gen::EMOJIS[2530]),
+        ("⛹\u{fe0f}\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1786]),
+        ("🖋", &crate::gen::EMOJIS[2761]),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏼", &crate::gen::EMOJIS[1984]),
+        ("⏏\u{fe0f}", &crate::gen::EMOJIS[2958]),
+        ("🚻", &crate::gen::EMOJIS[2874]),
+        ("🧘🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1935]),
+        ("🦹🏾\u{200d}♀", &crate::gen::EMOJIS[1370]),
+        ("🧘🏾", &crate::gen::EMOJIS[1925]),
+        ("👩\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1066]),
+        ("🇸🇯", &crate::gen::EMOJIS[3305]),
+        ("💆🏻", &crate::gen::EMOJIS[1470]),
+        ("◽", &crate::gen::EMOJIS[3078]),
+        ("🔗", &crate::gen::EMOJIS[2814]),
+        ("🤽🏿", &crate::gen::EMOJIS[1872]),
+        ("🤹🏻\u{200d}♂", &crate::gen::EMOJIS[1910]),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏿", &crate::gen::EMOJIS[1958]),
+        ("💾", &crate::gen::EMOJIS[2699]),
+        ("🐁", &crate::gen::EMOJIS[2066]),
+        ("♟\u{fe0f}", &crate::gen::EMOJIS[2596]),
+        ("🇳🇫", &crate::gen::EMOJIS[3267]),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏼", &crate::gen::EMOJIS[1986]),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏿", &crate::gen::EMOJIS[1958]),
+        ("👱🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[612]),
+        ("👷🏽", &crate::gen::EMOJIS[1171]),
+        ("🪮", &crate::gen::EMOJIS[2642]),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1977]),
+        ("🧖", &crate::gen::EMOJIS[1652]),
+        ("🚣🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1754]),
+        ("⏲", &crate::gen::EMOJIS[2449]),
+        ("👩🏽\u{200d}🏫", &crate::gen::EMOJIS[871]),
+        ("🧰", &crate::gen::EMOJIS[2817]),
+        ("🌅", &crate::gen::EMOJIS[2359]),
+        ("🧏🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[765]),
+        ("🤌🏾", &crate::gen::EMOJIS[242]),
+        ("🦓", &crate::gen::EMOJIS[2043]),
+        ("🏄🏻\u{200d}♀", &crate::gen::EMOJIS[1733]),
+        ("🕊", &crate::gen::EMOJIS[2093]),
+        ("🧏\u{200d}♀", &crate::gen::EMOJIS[760]),
+        ("🤦🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[797]),
+        ("👨🏿\u{200d}🦽", &crate::gen::EMOJIS[1606]),
+        ("🥇", &crate::gen::EMOJIS[2546]),
+        ("☹", &crate::gen::EMOJIS[77]),
+        ("🦸🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1352]),
+        ("🧘🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1936]),
+        ("🪔", &crate::gen::EMOJIS[2718]),
+        ("🕵🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1142]),
+        ("👳🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1217]),
+        ("🎵", &crate::gen::EMOJIS[2664]),
+        ("👨🏾\u{200d}💼", &crate::gen::EMOJIS[974]),
+        ("🙅🏻\u{200d}♀", &crate::gen::EMOJIS[689]),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1977]),
+        ("🤽🏼\u{200d}♀", &crate::gen::EMOJIS[1881]),
+        ("👨🏼\u{200d}🦲", &crate::gen::EMOJIS[552]),
+        ("👩🏼\u{200d}🦱", &crate::gen::EMOJIS[576]),
+        ("♎", &crate::gen::EMOJIS[2934]),
+        ("💸", &crate::gen::EMOJIS[2742]),
+        ("👨🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1977]),
+        ("🧑🏽\u{200d}🔬", &crate::gen::EMOJIS[985]),
+        ("🚣🏽\u{200d}♀", &crate::gen::EMOJIS[1753]),
+        ("👩\u{200d}⚖", &crate::gen::EMOJIS[886]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏿", &crate::gen::EMOJIS[1987]),
+        ("🧚🏻", &crate::gen::EMOJIS[1391]),
+        ("🙏🏻", &crate::gen::EMOJIS[401]),
+        ("🌛", &crate::gen::EMOJIS[2485]),
+        ("🧏🏻", &crate::gen::EMOJIS[749]),
+        ("🍼", &crate::gen::EMOJIS[2277]),
+        ("🙎🏼\u{200d}♂", &crate::gen::EMOJIS[666]),
+        ("🧚🏼\u{200d}♂", &crate::gen::EMOJIS[1398]),
+        ("🦍", &crate::gen::EMOJIS[2021]),
+        ("🤟🏻", &crate::gen::EMOJIS[269]),
+        ("🙎\u{200d}♀", &crate::gen::EMOJIS[670]),
+        ("👨🏿\u{200d}🦰", &crate::gen::EMOJIS[537]),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏼", &crate::gen::EMOJIS[1984]),
+        ("🍄", &crate::gen::EMOJIS[2170]),
+        ("🧨", &crate::gen::EMOJIS[2526]),
+        ("💆🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1476]),
+        ("🪡", &crate::gen::EMOJIS[2604]),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1977]),
+        ("👨🏾\u{200d}🏭", &crate::gen::EMOJIS[956]),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏼", &crate::gen::EMOJIS[1978]),
+        ("🚶🏼\u{200d}♀", &crate::gen::EMOJIS[1519]),
+        ("💇🏽\u{200d}♀", &crate::gen::EMOJIS[1502]),
+        ("🧑🏿\u{200d}⚕", &crate::gen::EMOJIS[825]),
+        ("🫘", &crate::gen::EMOJIS[2203]),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1976]),
+        ("🫛", &crate::gen::EMOJIS[2206]),
+        ("🫳🏾", &crate::gen::EMOJIS[212]),
+        ("🧔🏿", &crate::gen::EMOJIS[519]),
+        ("🏇🏻", &crate::gen::EMOJIS[1690]),
+        ("♨\u{fe0f}", &crate::gen::EMOJIS[2363]),
+        ("👩🏻\u{200d}🌾", &crate::gen::EMOJIS[905]),
+        ("🟰", &crate::gen::EMOJIS[2973]),
+        ("📟", &crate::gen::EMOJIS[2687]),
+        ("🧎🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1555]),
+        ("🧖🏽\u{200d}♂", &crate::gen::EMOJIS[1661]),
+        ("🕵\u{fe0f}", &crate::gen::EMOJIS[1126]),
+        ("🧏🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[755]),
+        ("✂", &crate::gen::EMOJIS[2785]),
+        ("🧏\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[754]),
+        ("🪸", &crate::gen::EMOJIS[2125]),
+        ("🚫", &crate::gen::EMOJIS[2884]),
+        ("🤷🏾\u{200d}♀", &crate::gen::EMOJIS[818]),
+        ("👩🏾\u{200d}🍼", &crate::gen::EMOJIS[1298]),
+        ("👨🏾\u{200d}🏫", &crate::gen::EMOJIS[866]),
+        ("🏚\u{fe0f}", &crate::gen::EMOJIS[2328]),
+        ("🙋🏼\u{200d}♂", &crate::gen::EMOJIS[738]),
+        ("🇹🇩", &crate::gen::EMOJIS[3320]),
+        ("👩🏽\u{200d}🦲", &crate::gen::EMOJIS[601]),
+        ("❓", &crate::gen::EMOJIS[2977]),
+        ("🎩", &crate::gen::EMOJIS[2645]),
+        ("🦾", &crate::gen::EMOJIS[430]),
+        ("🧏\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[760]),
+        ("💂\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1150]),
+        ("🕤", &crate::gen::EMOJIS[2470]),
+        ("🙎🏾\u{200d}♂", &crate::gen::EMOJIS[668]),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏽", &crate::gen::EMOJIS[1975]),
+        ("🧑🏾\u{200d}❤\u{200d}🧑🏻", &crate::gen::EMOJIS[1984]),
+        ("🤦🏽\u{200d}♂", &crate::gen::EMOJIS[793]),
+        ("ℹ\u{fe0f}", &crate::gen::EMOJIS[3029]),
+        ("🗜", &crate::gen::EMOJIS[2811]),
+        ("✝\u{fe0f}", &crate::gen::EMOJIS[2921]),
+        ("🪺", &crate::gen::EMOJIS[2169]),
+        ("🈹", &crate::gen::EMOJIS[3046]),
+        ("🍌", &crate::gen::EMOJIS[2176]),
+        ("🕍", &crate::gen::EMOJIS[2350]),
+        ("🧑🏿\u{200d}🏫", &crate::gen::EMOJIS[861]),
+        ("👨🏻\u{200d}🦽", &crate::gen::EMOJIS[1602]),
+        ("👱🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[621]),
+        ("🤷🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[813]),
+        ("👷🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1185]),
+        ("👩🏽\u{200d}🔬", &crate::gen::EMOJIS[997]),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏼", &crate::gen::EMOJIS[1978]),
+        ("👊", &crate::gen::EMOJIS[346]),
+        ("🙀", &crate::gen::EMOJIS[121]),
+        ("👩\u{200d}🦱", &crate::gen::EMOJIS[574]),
+        ("🔸", &crate::gen::EMOJIS[3083]),
+        ("💆🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1483]),
+        ("🗣\u{fe0f}", &crate::gen::EMOJIS[2014]),
+        ("🧏\u{200d}♂", &crate::gen::EMOJIS[754]),
+        ("🦯", &crate::gen::EMOJIS[2813]),
+        ("🇲🇺", &crate::gen::EMOJIS[3258]),
+        ("🧗🏿\u{200d}♂", &crate::gen::EMOJIS[1681]),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏾", &crate::gen::EMOJIS[1952]),
+        ("🚑", &crate::gen::EMOJIS[2386]),
+        ("🧜🏼\u{200d}♀", &crate::gen::EMOJIS[1440]),
+        ("💇🏾\u{200d}♀", &crate::gen::EMOJIS[1503]),
+        ("🫶🏼", &crate::gen::EMOJIS[378]),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏿", &crate::gen::EMOJIS[1984]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", &crate::gen::EMOJIS[1985]),
+        ("🤚🏾", &crate::gen::EMOJIS[176]),
+        ("©", &crate::gen::EMOJIS[3002]),
+        ("🔈", &crate::gen::EMOJIS[2655]),
+        ("🚣🏾", &crate::gen::EMOJIS[1742]),
+        ("🇮🇳", &crate::gen::EMOJIS[3209]),
+        ("🌾", &crate::gen::EMOJIS[2161]),
+        ("🧍\u{200d}♂", &crate::gen::EMOJIS[1529]),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏼", &crate::gen::EMOJIS[1964]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", &crate::gen::EMOJIS[1978]),
+        ("👧🏻", &crate::gen::EMOJIS[491]),
+        ("🏄🏽\u{200d}♂", &crate::gen::EMOJIS[1729]),
+        ("🧚🏽", &crate::gen::EMOJIS[1393]),
+        ("🧗🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1685]),
+        ("👩🏼\u{200d}🦰", &crate::gen::EMOJIS[564]),
+        ("👩🏻", &crate::gen::EMOJIS[557]),
+        ("🤏🏽", &crate::gen::EMOJIS[247]),
+        ("💗", &crate::gen::EMOJIS[131]),
+        ("👚", &crate::gen::EMOJIS[2626]),
+        ("👮\u{200d}♀", &crate::gen::EMOJIS[1120]),
+        ("🏂🏽", &crate::gen::EMOJIS[1699]),
+        ("👸🏻", &crate::gen::EMOJIS[1199]),
+        ("👩🏿\u{200d}🚒", &crate::gen::EMOJIS[1107]),
+        ("🗡\u{fe0f}", &crate::gen::EMOJIS[2800]),
+        ("🧘\u{200d}♂", &crate::gen::EMOJIS[1927]),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏽", &crate::gen::EMOJIS[1978]),
+        ("↖", &crate::gen::EMOJIS[2901]),
+        ("☦", &crate::gen::EMOJIS[2922]),
+        ("🏋\u{fe0f}\u{200d}♂", &crate::gen::EMOJIS[1798]),
+        ("🏂🏼", &crate::gen::EMOJIS[1698]),
+        ("🌲", &crate::gen::EMOJIS[2157]),
+        ("💑🏻", &crate::gen::EMOJIS[1980]),
+        ("🙍🏽", &crate::gen::EMOJIS[643]),
+        ("🫢", &crate::gen::EMOJIS[31]),
+        ("🫄🏽", &crate::gen::EMOJIS[1285]),
+        ("👨🏼\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1062]),
+        ("😮\u{200d}💨", &crate::gen::EMOJIS[47]),
+        ("🙇🏼", &crate::gen::EMOJIS[768]),
+        ("👩🏿\u{200d}❤\u{200d}👩🏼", &crate::gen::EMOJIS[1987]),
+        ("⁉\u{fe0f}", &crate::gen::EMOJIS[2976]),
+        ("🥘", &crate::gen::EMOJIS[2232]),
+        ("👨🏻\u{200d}🚀", &crate::gen::EMOJIS[1079]),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏼", &crate::gen::EMOJIS[1958]),
+        ("🙆🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[703]),
+        ("🌒", &crate::gen::EMOJIS[2476]),
+        ("🫵🏽", &crate::gen::EMOJIS[325]),
+        ("▫", &crate::gen::EMOJIS[3080]),
+        ("🏙", &crate::gen::EMOJIS[2357]),
+        ("🧑🏼\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1056]),
+        ("👨🏿\u{200d}🌾", &crate::gen::EMOJIS[903]),
+        ("🚈", &crate::gen::EMOJIS[2376]),
+        ("👩🏿\u{200d}🏭", &crate::gen::EMOJIS[963]),
+        ("🧑\u{200d}🦼", &crate::gen::EMOJIS[1577]),
+        ("🙋🏾", &crate::gen::EMOJIS[734]),
+        ("🫑", &crate::gen::EMOJIS[2196]),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏾", &crate::gen::EMOJIS[1951]),
+        ("🧴", &crate::gen::EMOJIS[2847]),
+        ("🍮", &crate::gen::EMOJIS[2275]),
+        ("🩷", &crate::gen::EMOJIS[141]),
+        ("🧜\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1438]),
+        ("🐴", &crate::gen::EMOJIS[2038]),
+        ("🦹🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1361]),
+        ("🙎🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[675]),
+        ("👷🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1181]),
+        ("🚣🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1755]),
+        ("🚳", &crate::gen::EMOJIS[2885]),
+        ("☄", &crate::gen::EMOJIS[2518]),
+        ("🙎🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[667]),
+        ("🤷🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[819]),
+        ("☀", &crate::gen::EMOJIS[2488]),
+        ("🇲🇱", &crate::gen::EMOJIS[3249]),
+        ("⛔", &crate::gen::EMOJIS[2883]),
+        ("🙅🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[690]),
+        ("👇🏼", &crate::gen::EMOJIS[312]),
+        ("🚶🏿\u{200d}♀", &crate::gen::EMOJIS[1522]),
+        ("🧑🏿\u{200d}🎓", &crate::gen::EMOJIS[843]),
+        ("🛀🏾", &crate::gen::EMOJIS[1943]),
+        ("🇰🇷", &crate::gen::EMOJIS[3226]),
+        ("🧑\u{200d}🦳", &crate::gen::EMOJIS[592]),
+        ("🏃🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1620]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏼", &crate::gen::EMOJIS[1987]),
+        ("👨🏾\u{200d}🔬", &crate::gen::EMOJIS[992]),
+        ("🗑", &crate::gen::EMOJIS[2788]),
+        ("👦🏽", &crate::gen::EMOJIS[487]),
+        ("🧙🏻\u{200d}♂", &crate::gen::EMOJIS[1379]),
+        ("👩🏼\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1068]),
+        ("🕋", &crate::gen::EMOJIS[2352]),
+        ("👩🏾\u{200d}🦲", &crate::gen::EMOJIS[602]),
+        ("😰", &crate::gen::EMOJIS[87]),
+        ("🤾🏼", &crate::gen::EMOJIS[1887]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", &crate::gen::EMOJIS[1978]),
+        ("🚵🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1843]),
+        ("💆🏿\u{200d}♀", &crate::gen::EMOJIS[1486]),
+        ("🈺", &crate::gen::EMOJIS[3055]),
+        ("👩\u{200d}👦", &crate::gen::EMOJIS[2009]),
+        ("😕", &crate::gen::EMOJIS[73]),
+        ("🚴🏽\u{200d}♀", &crate::gen::EMOJIS[1825]),
+        ("👨\u{200d}👧\u{200d}👧", &crate::gen::EMOJIS[2008]),
+        ("👩🏿\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[837]),
+        ("🧕🏾", &crate::gen::EMOJIS[1232]),
+        ("🦸🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1345]),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1976]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", &crate::gen::EMOJIS[1978]),
+        ("🙋🏼", &crate::gen::EMOJIS[732]),
+        ("👨🏼\u{200d}🦱", &crate::gen::EMOJIS[540]),
+        ("🇫🇷", &crate::gen::EMOJIS[3178]),
+        ("💂🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1158]),
+        ("📢", &crate::gen::EMOJIS[2658]),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏻", &crate::gen::EMOJIS[1984]),
+        ("😚", &crate::gen::EMOJIS[20]),
+        ("💁🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[725]),
+        ("🐵", &crate::gen::EMOJIS[2019]),
+        ("🟪", &crate::gen::EMOJIS[3071]),
+        ("👩\u{200d}🚀", &crate::gen::EMOJIS[1084]),
+        ("💁🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[720]),
+        ("🙍🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[650]),
+        ("👨\u{200d}❤\u{200d}👨", &crate::gen::EMOJIS[1986]),
+        ("👩🏻\u{200d}🦯", &crate::gen::EMOJIS[1572]),
+        ("🇮🇸", &crate::gen::EMOJIS[3213]),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1977]),
+        ("🤹🏻", &crate::gen::EMOJIS[1904]),
+        ("👳🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1211]),
+        ("✖", &crate::gen::EMOJIS[2969]),
+        ("💂🏾", &crate::gen::EMOJIS[1148]),
+        ("🧛🏻", &crate::gen::EMOJIS[1409]),
+        ("🏄🏿", &crate::gen::EMOJIS[1725]),
+        ("🧖🏿\u{200d}♂", &crate::gen::EMOJIS[1663]),
+        ("👂🏽", &crate::gen::EMOJIS[447]),
+        ("🚵🏿\u{200d}♀", &crate::gen::EMOJIS[1845]),
+        ("🧡", &crate::gen::EMOJIS[142]),
+        ("👨🏼\u{200d}💻", &crate::gen::EMOJIS[1008]),
+        ("🇲🇶", &crate::gen::EMOJIS[3254]),
+        ("👱🏽", &crate::gen::EMOJIS[505]),
+        ("➰", &crate::gen::EMOJIS[2996]),
+        ("⛹🏻\u{200d}♂", &crate::gen::EMOJIS[1781]),
+        ("🇧🇶", &crate::gen::EMOJIS[3129]),
+        ("↔\u{fe0f}", &crate::gen::EMOJIS[2903]),
+        ("👵", &crate::gen::EMOJIS[634]),
+        ("🥪", &crate::gen::EMOJIS[2224]),
+        ("💂🏾\u{200d}♀", &crate::gen::EMOJIS[1160]),
+        ("🤴🏼", &crate::gen::EMOJIS[1194]),
+        ("↗", &crate::gen::EMOJIS[2895]),
+        ("🧑🏽\u{200d}🍳", &crate::gen::EMOJIS[913]),
+        ("👰\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1264]),
+        ("🦹🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1365]),
+        ("👩\u{200d}🌾", &crate::gen::EMOJIS[904]),
+        ("🧘🏽\u{200d}♂", &crate::gen::EMOJIS[1930]),
+        ("🇵🇳", &crate::gen::EMOJIS[3285]),
+        ("🧑\u{200d}🦽", &crate::gen::EMOJIS[1595]),
+        ("🤳", &crate::gen::EMOJIS[418]),
+        ("📝", &crate::gen::EMOJIS[2765]),
+        ("🙎🏼\u{200d}♀", &crate::gen::EMOJIS[672]),
+        ("👨🏻\u{200d}💻", &crate::gen::EMOJIS[1007]),
+        ("🤵🏽\u{200d}♂", &crate::gen::EMOJIS[1243]),
+        ("👩🏾\u{200d}❤\u{200d}👨🏼", &crate::gen::EMOJIS[1985]),
+        ("🏒", &crate::gen::EMOJIS[2561]),
+        ("🤷🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[809]),
+        ("🧘", &crate::gen::EMOJIS[1921]),
+        ("🏋🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1801]),
+        ("⚡", &crate::gen::EMOJIS[2514]),
+        ("🧑\u{200d}🏫", &crate::gen::EMOJIS[856]),
+        ("🫸🏼", &crate::gen::EMOJIS[228]),
+        ("👨🏾\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[830]),
+        ("〰", &crate::gen::EMOJIS[2981]),
+        ("🪹", &crate::gen::EMOJIS[2168]),
+        ("🧑\u{200d}🤝\u{200d}🧑", &crate::gen::EMOJIS[1951]),
+        ("👩🏾\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[890]),
+        ("🚃", &crate::gen::EMOJIS[2371]),
+        ("🍩", &crate::gen::EMOJIS[2266]),
+        ("⛑", &crate::gen::EMOJIS[2649]),
+        ("🫦", &crate::gen::EMOJIS[471]),
+        ("👄", &crate::gen::EMOJIS[470]),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1976]),
+        ("👩🏽\u{200d}❤\u{200d}👨🏾", &crate::gen::EMOJIS[1985]),
+        ("🇿🇼", &crate::gen::EMOJIS[3356]),
+        ("👩🏼\u{200d}🦲", &crate::gen::EMOJIS[600]),
+        ("👰🏼\u{200d}♂", &crate::gen::EMOJIS[1260]),
+        ("👩\u{200d}🎨", &crate::gen::EMOJIS[1048]),
+        ("*\u{20e3}", &crate::gen::EMOJIS[3006]),
+        ("🇹🇨", &crate::gen::EMOJIS[3319]),
+        ("👩🏼\u{200d}⚖", &crate::gen::EMOJIS[888]),
+        ("😥", &crate::gen::EMOJIS[88]),
+        ("😇", &crate::gen::EMOJIS[13]),
+        ("🖼\u{fe0f}", &crate::gen::EMOJIS[2601]),
+        ("🤠", &crate::gen::EMOJIS[67]),
+        ("👩🏽\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[835]),
+        ("🥷🏽", &crate::gen::EMOJIS[1165]),
+        ("👨🏿\u{200d}🦳", &crate::gen::EMOJIS[549]),
+        ("🪽", &crate::gen::EMOJIS[2103]),
+        ("🏂🏿", &crate::gen::EMOJIS[1701]),
+        ("🦄", &crate::gen::EMOJIS[2042]),
+        ("🤱🏼", &crate::gen::EMOJIS[1290]),
+        ("🤽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1873]),
+        ("🔙", &crate::gen::EMOJIS[2910]),
+        ("🌶\u{fe0f}", &crate::gen::EMOJIS[2195]),
+        ("🤢", &crate::gen::EMOJIS[58]),
+        ("🧑🏼\u{200d}🚀", &crate::gen::EMOJIS[1074]),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1977]),
+        ("♉", &crate::gen::EMOJIS[2929]),
+        ("🙇\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[778]),
+        ("👐🏿", &crate::gen::EMOJIS[387]),
+        ("🇸🇬", &crate::gen::EMOJIS[3302]),
+        ("🏥", &crate::gen::EMOJIS[2334]),
+        ("🔊", &crate::gen::EMOJIS[2657]),
+        ("🧑🏼\u{200d}🏫", &crate::gen::EMOJIS[858]),
+        ("👨🏻\u{200d}❤\u{200d}👨🏻", &crate::gen::EMOJIS[1986]),
+        ("⛹🏼", &crate::gen::EMOJIS[1776]),
+        ("㊗", &crate::gen::EMOJIS[3053]),
+        ("⏮\u{fe0f}", &crate::gen::EMOJIS[2950]),
+        ("🧛🏿\u{200d}♂", &crate::gen::EMOJIS[1419]),
+        ("👳🏻\u{200d}♂", &crate::gen::EMOJIS[1211]),
+        ("🛟", &crate::gen::EMOJIS[2421]),
+        ("🇮🇲", &crate::gen::EMOJIS[3208]),
+        ("🐸", &crate::gen::EMOJIS[2106]),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", &crate::gen::EMOJIS[1970]),
+        ("👜", &crate::gen::EMOJIS[2629]),
+        ("🤦🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[799]),
+        ("👆🏻", &crate::gen::EMOJIS[299]),
+        ("🤵🏼\u{200d}♀", &crate::gen::EMOJIS[1248]),
+        ("👳🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1220]),
+        ("⛹🏿\u{200d}♂", &crate::gen::EMOJIS[1785]),
+        ("🇦🇴", &crate::gen::EMOJIS[3107]),
+        ("👨🏿\u{200d}❤\u{200d}👨🏾", &crate::gen::EMOJIS[1986]),
+        ("💑🏽", &crate::gen::EMOJIS[1982]),
+        ("👩🏼\u{200d}❤\u{200d}👨🏿", &crate::gen::EMOJIS[1985]),
+        ("🧑🏻\u{200d}💻", &crate::gen::EMOJIS[1001]),
+        ("🐳", &crate::gen::EMOJIS[2115]),
+        ("🐃", &crate::gen::EMOJIS[2048]),
+        ("🧘\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1927]),
+        ("👨🏿\u{200d}🔧", &crate::gen::EMOJIS[939]),
+        ("👮🏼", &crate::gen::EMOJIS[1110]),
+        ("🌯", &crate::gen::EMOJIS[2226]),
+        ("🇷🇺", &crate::gen::EMOJIS[3295]),
+        ("👮🏽", &crate::gen::EMOJIS[1111]),
+        ("👊🏾", &crate::gen::EMOJIS[350]),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏼", &crate::gen::EMOJIS[1964]),
+        ("🏎", &crate::gen::EMOJIS[2399]),
+        ("🦙", &crate::gen::EMOJIS[2059]),
+        ("🧑🏽\u{200d}🚒", &crate::gen::EMOJIS[1093]),
+        ("🔔", &crate::gen::EMOJIS[2661]),
+        ("👩🏼\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[888]),
+        ("🚵", &crate::gen::EMOJIS[1828]),
+        ("🧝🏻\u{200d}♂", &crate::gen::EMOJIS[1451]),
+        ("🇭🇳", &crate::gen::EMOJIS[3200]),
+        ("🧩", &crate::gen::EMOJIS[2587]),
+        ("🇹🇯", &crate::gen::EMOJIS[3324]),
+        ("🧍🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1540]),
+        ("🤛🏽", &crate::gen::EMOJIS[355]),
+        ("🤦🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[791]),
+        ("🔜", &crate::gen::EMOJIS[2913]),
+        ("🦹🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1364]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏻", &crate::gen::EMOJIS[1987]),
+        ("🤦🏻", &crate::gen::EMOJIS[785]),
+        ("👷🏻", &crate::gen::EMOJIS[1169]),
+        ("🗄", &crate::gen::EMOJIS[2787]),
+        ("❣\u{fe0f}", &crate::gen::EMOJIS[136]),
+        ("🚛", &crate::gen::EMOJIS[2397]),
+        ("🏳\u{fe0f}\u{200d}🌈", &crate::gen::EMOJIS[3096]),
+        ("😣", &crate::gen::EMOJIS[93]),
+        ("👨🏼\u{200d}⚖", &crate::gen::EMOJIS[882]),
+        ("🗻", &crate::gen::EMOJIS[2314]),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", &crate::gen::EMOJIS[1970]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1976]),
+        ("🧘🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1937]),
+        ("👩🏿\u{200d}🦲", &crate::gen::EMOJIS[603]),
+        ("📀", &crate::gen::EMOJIS[2701]),
+        ("🎚\u{fe0f}", &crate::gen::EMOJIS[2667]),
+        ("🇺🇿", &crate::gen::EMOJIS[3341]),
+        ("🇫🇴", &crate::gen::EMOJIS[3177]),
+        ("🧫", &crate::gen::EMOJIS[2822]),
+        ("🚵🏿", &crate::gen::EMOJIS[1833]),
+        ("👩🏾\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[836]),
+        ("🇳🇮", &crate::gen::EMOJIS[3269]),
+        ("🧜\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1432]),
+        ("🇩🇲", &crate::gen::EMOJIS[3161]),
+        ("🛏\u{fe0f}", &crate::gen::EMOJIS[2838]),
+        ("🥈", &crate::gen::EMOJIS[2547]),
+        ("🇹🇴", &crate::gen::EMOJIS[3329]),
+        ("®\u{fe0f}", &crate::gen::EMOJIS[3003]),
+        ("👩\u{200d}🦯", &crate::gen::EMOJIS[1571]),
+        ("🇬🇵", &crate::gen::EMOJIS[3190]),
+        ("🏷", &crate::gen::EMOJIS[2735]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", &crate::gen::EMOJIS[1985]),
+        ("🇺🇸", &crate::gen::EMOJIS[3339]),
+        ("👨🏽\u{200d}🔬", &crate::gen::EMOJIS[991]),
+        ("✳", &crate::gen::EMOJIS[2999]),
+        ("🕺🏿", &crate::gen::EMOJIS[1642]),
+        ("👳\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1216]),
+        ("🤷\u{200d}♀", &crate::gen::EMOJIS[814]),
+        ("🏊🏼", &crate::gen::EMOJIS[1758]),
+        ("🍉", &crate::gen::EMOJIS[2173]),
+        ("🇹🇬", &crate::gen::EMOJIS[3322]),
+        ("💆🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1486]),
+        ("🤦🏻\u{200d}♂", &crate::gen::EMOJIS[791]),
+        ("👰🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1269]),
+        ("🧘🏿", &crate::gen::EMOJIS[1926]),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏾", &crate::gen::EMOJIS[1964]),
+        ("🇬🇬", &crate::gen::EMOJIS[3184]),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏻", &crate::gen::EMOJIS[1986]),
+        ("🚪", &crate::gen::EMOJIS[2834]),
+        ("♓", &crate::gen::EMOJIS[2939]),
+        ("😟", &crate::gen::EMOJIS[75]),
+        ("🤦🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[800]),
+        ("👮🏿", &crate::gen::EMOJIS[1113]),
+        ("🏌🏽\u{200d}♂", &crate::gen::EMOJIS[1711]),
+        ("🦴", &crate::gen::EMOJIS[466]),
+        ("💡", &crate::gen::EMOJIS[2715]),
+        ("🦣", &crate::gen::EMOJIS[2062]),
+        ("🕐", &crate::gen::EMOJIS[2453]),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏼", &crate::gen::EMOJIS[1952]),
+        ("👆🏾", &crate::gen::EMOJIS[302]),
+        ("👘", &crate::gen::EMOJIS[2620]),
+        ("🙆🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[708]),
+        ("🕵🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1139]),
+        ("🧎🏿\u{200d}♀", &crate::gen::EMOJIS[1558]),
+        ("👩🏿\u{200d}❤\u{200d}👨🏼", &crate::gen::EMOJIS[1985]),
+        ("🤾🏻\u{200d}♂", &crate::gen::EMOJIS[1892]),
+        ("💁🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[729]),
+        ("🙋🏻\u{200d}♂", &crate::gen::EMOJIS[737]),
+        ("🌕", &crate::gen::EMOJIS[2479]),
+        ("🏌\u{fe0f}\u{200d}♀", &crate::gen::EMOJIS[1714]),
+        ("🧑\u{200d}🔬", &crate::gen::EMOJIS[982]),
+        ("👨🏿\u{200d}🔬", &crate::gen::EMOJIS[993]),
+        ("☎\u{fe0f}", &crate::gen::EMOJIS[2685]),
+        ("🪟", &crate::gen::EMOJIS[2837]),
+        ("💆🏾", &crate::gen::EMOJIS[1473]),
+        ("👩\u{200d}🍼", &crate::gen::EMOJIS[1294]),
+        ("🧜🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1434]),
+        ("🛞", &crate::gen::EMOJIS[2414]),
+        ("🏵", &crate::gen::EMOJIS[2147]),
+        ("🛴", &crate::gen::EMOJIS[2406]),
+        ("🧎🏾\u{200d}♂", &crate::gen::EMOJIS[1551]),
+        ("🐕", &crate::gen::EMOJIS[2024]),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1977]),
+        ("👨🏼\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[828]),
+        ("😭", &crate::gen::EMOJIS[90]),
+        ("📕", &crate::gen::EMOJIS[2720]),
+        ("🦼", &crate::gen::EMOJIS[2403]),
+        ("👨🏽\u{200d}❤\u{200d}👨🏻", &crate::gen::EMOJIS[1986]),
+        ("📅", &crate::gen::EMOJIS[2770]),
+        ("👳🏻\u{200d}♀", &crate::gen::EMOJIS[1217]),
+        ("👱🏿", &crate::gen::EMOJIS[507]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏽", &crate::gen::EMOJIS[1987]),
+        ("🌫\u{fe0f}", &crate::gen::EMOJIS[2506]),
+        ("🧥", &crate::gen::EMOJIS[2617]),
+        ("👨🏻\u{200d}🦼", &crate::gen::EMOJIS[1584]),
+        ("🎭", &crate::gen::EMOJIS[2600]),
+        ("⬅", &crate::gen::EMOJIS[2900]),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", &crate::gen::EMOJIS[1976]),
+        ("🧝🏼\u{200d}♀", &crate::gen::EMOJIS[1458]),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏽", &crate::gen::EMOJIS[1952]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", &crate::gen::EMOJIS[1978]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1976]),
+        ("📳", &crate::gen::EMOJIS[2964]),
+        ("📽\u{fe0f}", &crate::gen::EMOJIS[2705]),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏽", &crate::gen::EMOJIS[1978]),
+        ("👳🏼\u{200d}♂", &crate::gen::EMOJIS[1212]),
+        ("👨\u{200d}🌾", &crate::gen::EMOJIS[898]),
+        ("🦶🏼", &crate::gen::EMOJIS[440]),
+        ("🍕", &crate::gen::EMOJIS[2222]),
+        ("🫱🏿\u{200d}🫲🏾", &crate::gen::EMOJIS[394]),
+        ("🎐", &crate::gen::EMOJIS[2535]),
+        ("🕺", &crate::gen::EMOJIS[1637]),
+        ("🙇🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[780]),
+        ("🎹", &crate::gen::EMOJIS[2675]),
+        ("👩🏿\u{200d}🤝\u{200d}👩🏽", &crate::gen::EMOJIS[1952]),
+        ("🚷", &crate::gen::EMOJIS[2889]),
+        ("💂🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1157]),
+        ("🤷", &crate::gen::EMOJIS[802]),
+        ("🧑🏻\u{200d}🦱", &crate::gen::EMOJIS[581]),
+        ("🖕", &crate::gen::EMOJIS[304]),
+        ("🏂🏻", &crate::gen::EMOJIS[1697]),
+        ("🦝", &crate::gen::EMOJIS[2030]),
+        ("🔏", &crate::gen::EMOJIS[2791]),
+        ("👿", &crate::gen::EMOJIS[104]),
+        ("🇱🇷", &crate::gen::EMOJIS[3235]),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1976]),
+        ("🏖", &crate::gen::EMOJIS[2316]),
+        ("🏋🏻", &crate::gen::EMOJIS[1793]),
+        ("👨🏻\u{200d}💼", &crate::gen::EMOJIS[971]),
+        ("🤞🏼", &crate::gen::EMOJIS[258]),
+        ("🇳🇱", &crate::gen::EMOJIS[3270]),
+        ("🏴", &crate::gen::EMOJIS[3094]),
+        ("👮🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1123]),
+        ("🙎🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[669]),
+        ("🐠", &crate::gen::EMOJIS[2120]),
+        ("🆒", &crate::gen::EMOJIS[3027]),
+        ("🧍🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1538]),
+        ("🚣🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1747]),
+        ("🔌", &crate::gen::EMOJIS[2691]),
+        ("👉🏼", &crate::gen::EMOJIS[294]),
+        ("🧕🏿", &crate::gen::EMOJIS[1233]),
+        ("🥩", &crate::gen::EMOJIS[2218]),
+        ("🙇🏾\u{200d}♂", &crate::gen::EMOJIS[776]),
+        ("🫳🏿", &crate::gen::EMOJIS[213]),
+        ("🔮", &crate::gen::EMOJIS[2581]),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1977]),
+        ("🇹🇹", &crate::gen::EMOJIS[3331]),
+        ("🤶", &crate::gen::EMOJIS[1324]),
+        ("👨🏽\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[883]),
+        ("⛹🏻\u{200d}♀", &crate::gen::EMOJIS[1787]),
+        ("🙏🏿", &crate::gen::EMOJIS[405]),
+        ("👧🏿", &crate::gen::EMOJIS[495]),
+        ("🤸🏽\u{200d}♂", &crate::gen::EMOJIS[1855]),
+        ("🫰🏽", &crate::gen::EMOJIS[265]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏽", &crate::gen::EMOJIS[1987]),
+        ("🦭", &crate::gen::EMOJIS[2118]),
+        ("🤝🏽", &crate::gen::EMOJIS[397]),
+        ("🤹🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1916]),
+        ("🔕", &crate::gen::EMOJIS[2662]),
+        ("⛹\u{fe0f}\u{200d}♀", &crate::gen::EMOJIS[1786]),
+        ("🧔🏿\u{200d}♀", &crate::gen::EMOJIS[531]),
+        ("🧑🏿\u{200d}🚀", &crate::gen::EMOJIS[1077]),
+        ("🫳🏻", &crate::gen::EMOJIS[209]),
+        ("🥙", &crate::gen::EMOJIS[2228]),
+        ("🚶🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1518]),
+        ("🤷\u{200d}♂", &crate::gen::EMOJIS[808]),
+        ("🏞\u{fe0f}", &crate::gen::EMOJIS[2319]),
+        ("👩🏻\u{200d}🏭", &crate::gen::EMOJIS[959]),
+        ("▶\u{fe0f}", &crate::gen::EMOJIS[2944]),
+        ("🚴🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1827]),
+        ("⛹\u{fe0f}", &crate::gen::EMOJIS[1774]),
+        ("🚂", &crate::gen::EMOJIS[2370]),
+        ("💂🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1152]),
+        ("😛", &crate::gen::EMOJIS[24]),
+        ("1\u{20e3}", &crate::gen::EMOJIS[3008]),
+        ("🧑🏿\u{200d}🔬", &crate::gen::EMOJIS[987]),
+        ("🧋", &crate::gen::EMOJIS[2293]),
+        ("🛀🏻", &crate::gen::EMOJIS[1940]),
+        ("🧑🏾\u{200d}🔧", &crate::gen::EMOJIS[932]),
+        ("👌🏿", &crate::gen::EMOJIS[237]),
+        ("🤹🏾\u{200d}♂", &crate::gen::EMOJIS[1913]),
+        ("🤽🏻", &crate::gen::EMOJIS[1868]),
+        ("🇬🇪", &crate::gen::EMOJIS[3182]),
+        ("🇦🇷", &crate::gen::EMOJIS[3109]),
+        ("💂🏿\u{200d}♀", &crate::gen::EMOJIS[1161]),
+        ("🫲🏽", &crate::gen::EMOJIS[205]),
+        ("💧", &crate::gen::EMOJIS[2520]),
+        ("🎅🏽", &crate::gen::EMOJIS[1321]),
+        ("🏊🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1765]),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1977]),
+        ("💂🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1159]),
+        ("📚", &crate::gen::EMOJIS[2725]),
+        ("☹\u{fe0f}", &crate::gen::EMOJIS[77]),
+        ("👨🏻\u{200d}🦰", &crate::gen::EMOJIS[533]),
+        ("👨🏼\u{200d}🔬", &crate::gen::EMOJIS[990]),
+        ("🪳", &crate::gen::EMOJIS[2135]),
+        ("🇺🇾", &crate::gen::EMOJIS[3340]),
+        ("👩🏿\u{200d}⚕", &crate::gen::EMOJIS[837]),
+        ("🤵🏾", &crate::gen::EMOJIS[1238]),
+        ("👩\u{200d}🍳", &crate::gen::EMOJIS[922]),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1977]),
+        ("🧑🏾\u{200d}🚒", &crate::gen::EMOJIS[1094]),
+        ("🇬🇷", &crate::gen::EMOJIS[3192]),
+        ("🏌🏼\u{200d}♀", &crate::gen::EMOJIS[1716]),
+        ("🇨🇨", &crate::gen::EMOJIS[3138]),
+        ("👨🏾\u{200d}🦲", &crate::gen::EMOJIS[554]),
+        ("👨🏻", &crate::gen::EMOJIS[509]),
+        ("✋", &crate::gen::EMOJIS[184]),
+        ("👨🏻\u{200d}🌾", &crate::gen::EMOJIS[899]),
+        ("🦵🏿", &crate::gen::EMOJIS[437]),
+        ("🙋🏻", &crate::gen::EMOJIS[731]),
+        ("🏃🏽", &crate::gen::EMOJIS[1616]),
+        ("🐦\u{200d}⬛", &crate::gen::EMOJIS[2104]),
+        ("❤\u{fe0f}\u{200d}🔥", &crate::gen::EMOJIS[138]),
+        ("🎤", &crate::gen::EMOJIS[2669]),
+        ("🇦🇼", &crate::gen::EMOJIS[3113]),
+        ("👰🏽\u{200d}♀", &crate::gen::EMOJIS[1267]),
+        ("👮🏼\u{200d}♀", &crate::gen::EMOJIS[1122]),
+        ("🫱🏼\u{200d}🫲🏿", &crate::gen::EMOJIS[394]),
+        ("🧝🏾", &crate::gen::EMOJIS[1448]),
+        ("🖖🏽", &crate::gen::EMOJIS[193]),
+        ("🏴\u{e0067}\u{e0062}\u{e0077}\u{e006c}\u{e0073}\u{e007f}", &crate::gen::EMOJIS[3359]),
+        ("🌤", &crate::gen::EMOJIS[2499]),
+        ("🧏🏻\u{200d}♀", &crate::gen::EMOJIS[761]),
+        ("🧝🏿\u{200d}♀", &crate::gen::EMOJIS[1461]),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏿", &crate::gen::EMOJIS[1951]),
+        ("🏊🏽\u{200d}♂", &crate::gen::EMOJIS[1765]),
+        ("👨🏽\u{200d}✈", &crate::gen::EMOJIS[1063]),
+        ("🚙", &crate::gen::EMOJIS[2394]),
+        ("👨🏻\u{200d}🏫", &crate::gen::EMOJIS[863]),
+        ("👍🏿", &crate::gen::EMOJIS[333]),
+        ("🦛", &crate::gen::EMOJIS[2064]),
+        ("👩🏻\u{200d}⚖", &crate::gen::EMOJIS[887]),
+        ("🧛🏻\u{200d}♀", &crate::gen::EMOJIS[1421]),
+        ("🫐", &crate::gen::EMOJIS[2185]),
+        ("🇸🇸", &crate::gen::EMOJIS[3312]),
+        ("🤤", &crate::gen::EMOJIS[53]),
+        ("🧑\u{200d}🚒", &crate::gen::EMOJIS[1090]),
+        ("🏄🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1730]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", &crate::gen::EMOJIS[1978]),
+        ("🔼", &crate::gen::EMOJIS[2951]),
+        ("📎", &crate::gen::EMOJIS[2781]),
+        ("🪞", &crate::gen::EMOJIS[2836]),
+        ("🎌", &crate::gen::EMOJIS[3093]),
+        ("🤚🏻", &crate::gen::EMOJIS[173]),
+        ("🍰", &crate::gen::EMOJIS[2269]),
+        ("🤷\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[808]),
+        ("7\u{20e3}", &crate::gen::EMOJIS[3014]),
+        ("🇹🇻", &crate::gen::EMOJIS[3332]),
+        ("🙍🏼\u{200d}♂", &crate::gen::EMOJIS[648]),
+        ("🤦🏽", &crate::gen::EMOJIS[787]),
+        ("🫲🏾", &crate::gen::EMOJIS[206]),
+        ("👃🏽", &crate::gen::EMOJIS[459]),
+        ("🚣🏾\u{200d}♂", &crate::gen::EMOJIS[1748]),
+        ("🚡", &crate::gen::EMOJIS[2438]),
+        ("🅾", &crate::gen::EMOJIS[3034]),
+        ("🪙", &crate::gen::EMOJIS[2737]),
+        ("🧗", &crate::gen::EMOJIS[1670]),
+        ("🛀", &crate::gen::EMOJIS[1939]),
+        ("🧑🏽\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[877]),
+        ("🇬🇸", &crate::gen::EMOJIS[3193]),
+        ("🈶", &crate::gen::EMOJIS[3043]),
+        ("🟫", &crate::gen::EMOJIS[3072]),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1977]),
+        ("🌦\u{fe0f}", &crate::gen::EMOJIS[2501]),
+        ("🍋", &crate::gen::EMOJIS[2175]),
+        ("🧔🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[527]),
+        ("🛥\u{fe0f}", &crate::gen::EMOJIS[2427]),
+        ("⛏\u{fe0f}", &crate::gen::EMOJIS[2797]),
+        ("🗝\u{fe0f}", &crate::gen::EMOJIS[2794]),
+        ("👰🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1262]),
+        ("🧦", &crate::gen::EMOJIS[2618]),
+        ("💆🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1484]),
+        ("🫅", &crate::gen::EMOJIS[1186]),
+        ("🎆", &crate::gen::EMOJIS[2524]),
+        ("🤳🏿", &crate::gen::EMOJIS[423]),
+        ("🇵🇹", &crate::gen::EMOJIS[3288]),
+        ("⏪", &crate::gen::EMOJIS[2949]),
+        ("🧛🏽\u{200d}♂", &crate::gen::EMOJIS[1417]),
+        ("🇬🇧", &crate::gen::EMOJIS[3180]),
+        ("💅🏾", &crate::gen::EMOJIS[416]),
+        ("🍾", &crate::gen::EMOJIS[2283]),
+        ("💇🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1503]),
+        ("👩🏻\u{200d}❤\u{200d}👨🏿", &crate::gen::EMOJIS[1985]),
+        ("👩🏾\u{200d}🌾", &crate::gen::EMOJIS[908]),
+        ("💂🏻\u{200d}♀", &crate::gen::EMOJIS[1157]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", &crate::gen::EMOJIS[1978]),
+        ("🤴🏿", &crate::gen::EMOJIS[1197]),
+        ("🏌🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1711]),
+        ("🚣🏻\u{200d}♀", &crate::gen::EMOJIS[1751]),
+        ("🙂", &crate::gen::EMOJIS[8]),
+        ("🤛🏾", &crate::gen::EMOJIS[356]),
+        ("🧙🏿\u{200d}♂", &crate::gen::EMOJIS[1383]),
+        ("🏅", &crate::gen::EMOJIS[2545]),
+        ("😴", &crate::gen::EMOJIS[54]),
+        ("💄", &crate::gen::EMOJIS[2651]),
+        ("🏃🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1623]),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏻", &crate::gen::EMOJIS[1952]),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏽", &crate::gen::EMOJIS[1978]),
+        ("🤸🏼\u{200d}♂", &crate::gen::EMOJIS[1854]),
+        ("🚶🏽\u{200d}♂", &crate::gen::EMOJIS[1514]),
+        ("🏌🏿\u{200d}♀", &crate::gen::EMOJIS[1719]),
+        ("🙋\u{200d}♀", &crate::gen::EMOJIS[742]),
+        ("👃🏻", &crate::gen::EMOJIS[457]),
+        ("🙆🏼", &crate::gen::EMOJIS[696]),
+        ("🧖\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1664]),
+        ("🙋🏿\u{200d}♀", &crate::gen::EMOJIS[747]),
+        ("🇫🇯", &crate::gen::EMOJIS[3174]),
+        ("👩🏻\u{200d}🏫", &crate::gen::EMOJIS[869]),
+        ("🏧", &crate::gen::EMOJIS[2868]),
+        ("🧝🏾\u{200d}♂", &crate::gen::EMOJIS[1454]),
+        ("⚾", &crate::gen::EMOJIS[2550]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏻", &crate::gen::EMOJIS[1987]),
+        ("👨\u{200d}🎓", &crate::gen::EMOJIS[844]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1976]),
+        ("👰🏾", &crate::gen::EMOJIS[1256]),
+        ("👬🏼", &crate::gen::EMOJIS[1966]),
+        ("🫱🏿\u{200d}🫲🏽", &crate::gen::EMOJIS[394]),
+        ("🖖", &crate::gen::EMOJIS[190]),
+        ("💆🏾\u{200d}♀", &crate::gen::EMOJIS[1485]),
+        ("🧛🏼", &crate::gen::EMOJIS[1410]),
+        ("⛹🏾", &crate::gen::EMOJIS[1778]),
+        ("🤸🏿", &crate::gen::EMOJIS[1851]),
+        ("☠\u{fe0f}", &crate::gen::EMOJIS[106]),
+        ("🦻", &crate::gen::EMOJIS[450]),
+        ("💁🏾\u{200d}♀", &crate::gen::EMOJIS[728]),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", &crate::gen::EMOJIS[1986]),
+        ("🤦🏽\u{200d}♀", &crate::gen::EMOJIS[799]),
+        ("👋🏿", &crate::gen::EMOJIS[171]),
+        ("🙆🏿", &crate::gen::EMOJIS[699]),
+        ("✖\u{fe0f}", &crate::gen::EMOJIS[2969]),
+        ("🏌\u{200d}♂", &crate::gen::EMOJIS[1708]),
+        ("🤸🏻\u{200d}♀", &crate::gen::EMOJIS[1859]),
+        ("🙇🏽", &crate::gen::EMOJIS[769]),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏾", &crate::gen::EMOJIS[1984]),
+        ("🛥", &crate::gen::EMOJIS[2427]),
+        ("🙋🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[738]),
+        ("🇳🇴", &crate::gen::EMOJIS[3271]),
+        ("🏊🏽", &crate::gen::EMOJIS[1759]),
+        ("🐅", &crate::gen::EMOJIS[2036]),
+        ("🏊\u{200d}♀", &crate::gen::EMOJIS[1768]),
+        ("🧑🏾\u{200d}🎨", &crate::gen::EMOJIS[1040]),
+        ("🍚", &crate::gen::EMOJIS[2244]),
+        ("🇺🇦", &crate::gen::EMOJIS[3335]),
+        ("🧘\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1933]),
+        ("🫄", &crate::gen::EMOJIS[1282]),
+        ("😢", &crate::gen::EMOJIS[89]),
+        ("🇦🇩", &crate::gen::EMOJIS[3100]),
+        ("🕵🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1133]),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1976]),
+        ("🇯🇪", &crate::gen::EMOJIS[3215]),
+        ("🐆", &crate::gen::EMOJIS[2037]),
+        ("🙍", &crate::gen::EMOJIS[640]),
+        ("⬅\u{fe0f}", &crate::gen::EMOJIS[2900]),
+        ("🟨", &crate::gen::EMOJIS[3068]),
+        ("👂🏾", &crate::gen::EMOJIS[448]),
+        ("🏋🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1808]),
+        ("🙌🏼", &crate::gen::EMOJIS[372]),
+        ("👨\u{200d}👨\u{200d}👦\u{200d}👦", &crate::gen::EMOJIS[1997]),
+        ("🏋\u{fe0f}\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1804]),
+        ("💇\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1493]),
+        ("🛌🏽", &crate::gen::EMOJIS[1948]),
+        ("👵🏿", &crate::gen::EMOJIS[639]),
+        ("🇰🇮", &crate::gen::EMOJIS[3222]),
+        ("👩🏾\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1070]),
+        ("🥲", &crate::gen::EMOJIS[22]),
+        ("🦸🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1353]),
+        ("🛏", &crate::gen::EMOJIS[2838]),
+        ("🧏🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[762]),
+        ("🫲🏻", &crate::gen::EMOJIS[203]),
+        ("👩\u{200d}👩\u{200d}👧\u{200d}👦", &crate::gen::EMOJIS[2001]),
+        ("🏋🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1807]),
+        ("🪐", &crate::gen::EMOJIS[2491]),
+        ("🍦", &crate::gen::EMOJIS[2263]),
+        ("✋🏽", &crate::gen::EMOJIS[187]),
+        ("🌁", &crate::gen::EMOJIS[2355]),
+        ("🤦\u{200d}♂", &crate::gen::EMOJIS[790]),
+        ("♂", &crate::gen::EMOJIS[2967]),
+        ("🏷\u{fe0f}", &crate::gen::EMOJIS[2735]),
+        ("🦚", &crate::gen::EMOJIS[2101]),
+        ("👩🏿\u{200d}💼", &crate::gen::EMOJIS[981]),
+        ("⛹🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1789]),
+        ("🏚", &crate::gen::EMOJIS[2328]),
+        ("👎🏼", &crate::gen::EMOJIS[336]),
+        ("🤽🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1878]),
+        ("🧑🏿\u{200d}🦱", &crate::gen::EMOJIS[585]),
+        ("👩🏾\u{200d}❤\u{200d}👨🏿", &crate::gen::EMOJIS[1985]),
+        ("📣", &crate::gen::EMOJIS[2659]),
+        ("🤹\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1909]),
+        ("💇🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1498]),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1976]),
+        ("👩🏼\u{200d}❤\u{200d}👩🏾", &crate::gen::EMOJIS[1987]),
+        ("🧑🏽\u{200d}🦰", &crate::gen::EMOJIS[571]),
+        ("💦", &crate::gen::EMOJIS[157]),
+        ("👮🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1116]),
+        ("🧙\u{200d}♀", &crate::gen::EMOJIS[1384]),
+        ("🛀🏼", &crate::gen::EMOJIS[1941]),
+        ("🧑🏾", &crate::gen::EMOJIS[500]),
+        ("🌫", &crate::gen::EMOJIS[2506]),
+        ("🐻\u{200d}❄", &crate::gen::EMOJIS[2076]),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏿", &crate::gen::EMOJIS[1984]),
+        ("🌱", &crate::gen::EMOJIS[2155]),
+        ("🚍", &crate::gen::EMOJIS[2383]),
+        ("🎚", &crate::gen::EMOJIS[2667]),
+        ("🎯", &crate::gen::EMOJIS[2576]),
+        ("👩🏿\u{200d}⚖", &crate::gen::EMOJIS[891]),
+        ("🫸🏿", &crate::gen::EMOJIS[231]),
+        ("⌨\u{fe0f}", &crate::gen::EMOJIS[2695]),
+        ("🙆🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[710]),
+        ("🏋🏿\u{200d}♂", &crate::gen::EMOJIS[1803]),
+        ("🚣🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1751]),
+        ("🙆🏽\u{200d}♀", &crate::gen::EMOJIS[709]),
+        ("🧏🏾\u{200d}♀", &crate::gen::EMOJIS[764]),
+        ("🗯", &crate::gen::EMOJIS[163]),
+        ("🧝🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1461]),
+        ("🧞\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1464]),
+        ("🏋\u{fe0f}\u{200d}♀", &crate::gen::EMOJIS[1804]),
+        ("🇸🇽", &crate::gen::EMOJIS[3315]),
+        ("🧟\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1466]),
+        ("🧚🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1405]),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1976]),
+        ("👱🏾\u{200d}♂", &crate::gen::EMOJIS[620]),
+        ("🧝🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1459]),
+        ("↕\u{fe0f}", &crate::gen::EMOJIS[2902]),
+        ("🙆🏿\u{200d}♀", &crate::gen::EMOJIS[711]),
+        ("👸🏽", &crate::gen::EMOJIS[1201]),
+        ("🏔\u{fe0f}", &crate::gen::EMOJIS[2311]),
+        ("🛻", &crate::gen::EMOJIS[2395]),
+        ("🧖🏽\u{200d}♀", &crate::gen::EMOJIS[1667]),
+        ("🏊🏻", &crate::gen::EMOJIS[1757]),
+        ("↩", &crate::gen::EMOJIS[2904]),
+        ("▶", &crate::gen::EMOJIS[2944]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1976]),
+        ("🏇", &crate::gen::EMOJIS[1689]),
+        ("👩🏾\u{200d}❤\u{200d}👨🏻", &crate::gen::EMOJIS[1985]),
+        ("🚴🏿", &crate::gen::EMOJIS[1815]),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1977]),
+        ("🤦\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[790]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", &crate::gen::EMOJIS[1985]),
+        ("🕺🏻", &crate::gen::EMOJIS[1638]),
+        ("👩🏽\u{200d}🔧", &crate::gen::EMOJIS[943]),
+        ("🧟\u{200d}♀", &crate::gen::EMOJIS[1467]),
+        ("🤸🏻", &crate::gen::EMOJIS[1847]),
+        ("🤽🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1876]),
+        ("🤹🏾", &crate::gen::EMOJIS[1907]),
+        ("🧚🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1399]),
+        ("🔨", &crate::gen::EMOJIS[2795]),
+        ("♋", &crate::gen::EMOJIS[2931]),
+        ("🤌", &crate::gen::EMOJIS[238]),
+        ("💁\u{200d}♂", &crate::gen::EMOJIS[718]),
+        ("🚴\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1816]),
+        ("🏋🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1803]),
+        ("☝🏿", &crate::gen::EMOJIS[321]),
+        ("🥨", &crate::gen::EMOJIS[2211]),
+        ("👩\u{200d}🦳", &crate::gen::EMOJIS[586]),
+        ("👨🏾\u{200d}🦳", &crate::gen::EMOJIS[548]),
+        ("🧚🏽\u{200d}♀", &crate::gen::EMOJIS[1405]),
+        ("🏋\u{200d}♂", &crate::gen::EMOJIS[1798]),
+        ("🕢", &crate::gen::EMOJIS[2466]),
+        ("👨🏾", &crate::gen::EMOJIS[512]),
+        ("🧔\u{200d}♂", &crate::gen::EMOJIS[520]),
+        ("🧔🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[524]),
+        ("♑", &crate::gen::EMOJIS[2937]),
+        ("🙍🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[655]),
+        ("🍫", &crate::gen::EMOJIS[2272]),
+        ("🗳\u{fe0f}", &crate::gen::EMOJIS[2758]),
+        ("🍍", &crate::gen::EMOJIS[2177]),
+        ("🫅🏼", &crate::gen::EMOJIS[1188]),
+        ("🎞\u{fe0f}", &crate::gen::EMOJIS[2704]),
+        ("🧚🏼\u{200d}♀", &crate::gen::EMOJIS[1404]),
+        ("👷🏽\u{200d}♂", &crate::gen::EMOJIS[1177]),
+        ("💆🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1477]),
+        ("🧍🏾", &crate::gen::EMOJIS[1527]),
+        ("👩🏼\u{200d}❤\u{200d}👩🏼", &crate::gen::EMOJIS[1987]),
+        ("👖", &crate::gen::EMOJIS[2614]),
+        ("👨", &crate::gen::EMOJIS[508]),
+        ("🍵", &crate::gen::EMOJIS[2281]),
+        ("👩🏽\u{200d}❤\u{200d}👩🏼", &crate::gen::EMOJIS[1987]),
+        ("👈🏿", &crate::gen::EMOJIS[291]),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏼", &crate::gen::EMOJIS[1964]),
+        ("🍲", &crate::gen::EMOJIS[2233]),
+        ("⏯", &crate::gen::EMOJIS[2947]),
+        ("🧂", &crate::gen::EMOJIS[2239]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", &crate::gen::EMOJIS[1978]),
+        ("⚠\u{fe0f}", &crate::gen::EMOJIS[2881]),
+        ("🧔🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[523]),
+        ("💆🏽", &crate::gen::EMOJIS[1472]),
+        ("💇🏿\u{200d}♀", &crate::gen::EMOJIS[1504]),
+        ("🙅🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[693]),
+        ("♂\u{fe0f}", &crate::gen::EMOJIS[2967]),
+        ("👸🏾", &crate::gen::EMOJIS[1202]),
+        ("🕴🏼", &crate::gen::EMOJIS[1645]),
+        ("💃🏻", &crate::gen::EMOJIS[1632]),
+        ("☎", &crate::gen::EMOJIS[2685]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", &crate::gen::EMOJIS[1985]),
+        ("🗡", &crate::gen::EMOJIS[2800]),
+        ("🟥", &crate::gen::EMOJIS[3066]),
+        ("✌\u{fe0f}", &crate::gen::EMOJIS[250]),
+        ("🏨", &crate::gen::EMOJIS[2336]),
+        ("🏃🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1630]),
+        ("👩🏿\u{200d}🦽", &crate::gen::EMOJIS[1612]),
+        ("👨🏽\u{200d}❤\u{200d}👨🏾", &crate::gen::EMOJIS[1986]),
+        ("🧔🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[521]),
+        ("🐤", &crate::gen::EMOJIS[2089]),
+        ("🎞", &crate::gen::EMOJIS[2704]),
+        ("👩🏼\u{200d}✈", &crate::gen::EMOJIS[1068]),
+        ("👳", &crate::gen::EMOJIS[1204]),
+        ("🏋🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1802]),
+        ("🧚🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1403]),
+        ("🧑🏻\u{200d}✈", &crate::gen::EMOJIS[1055]),
+        ("🤹🏿\u{200d}♀", &crate::gen::EMOJIS[1920]),
+        ("⚰\u{fe0f}", &crate::gen::EMOJIS[2860]),
+        ("☮", &crate::gen::EMOJIS[2924]),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏿", &crate::gen::EMOJIS[1964]),
+        ("👨🏽\u{200d}🦰", &crate::gen::EMOJIS[535]),
+        ("🚵🏽\u{200d}♂", &crate::gen::EMOJIS[1837]),
+        ("🦜", &crate::gen::EMOJIS[2102]),
+        ("🍻", &crate::gen::EMOJIS[2288]),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1977]),
+        ("☕", &crate::gen::EMOJIS[2279]),
+        ("🙍🏻\u{200d}♂", &crate::gen::EMOJIS[647]),
+        ("🥵", &crate::gen::EMOJIS[61]),
+        ("💂🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1154]),
+        ("👩\u{200d}🦼", &crate::gen::EMOJIS[1589]),
+        ("👷🏾\u{200d}♀", &crate::gen::EMOJIS[1184]),
+        ("🎎", &crate::gen::EMOJIS[2533]),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏽", &crate::gen::EMOJIS[1958]),
+        ("👩🏾\u{200d}🦱", &crate::gen::EMOJIS[578]),
+        ("🏋🏾\u{200d}♀", &crate::gen::EMOJIS[1808]),
+        ("👩\u{200d}👩\u{200d}👧\u{200d}👧", &crate::gen::EMOJIS[2003]),
+        ("◀", &crate::gen::EMOJIS[2948]),
+        ("🚘", &crate::gen::EMOJIS[2393]),
+        ("🚰", &crate::gen::EMOJIS[2870]),
+        ("💆\u{200d}♀", &crate::gen::EMOJIS[1481]),
+        ("👨\u{200d}🦱", &crate::gen::EMOJIS[538]),
+        ("🧔\u{200d}♀", &crate::gen::EMOJIS[526]),
+        ("🐍", &crate::gen::EMOJIS[2110]),
+        ("🏌\u{fe0f}", &crate::gen::EMOJIS[1702]),
+        ("🥗", &crate::gen::EMOJIS[2236]),
+        ("💁", &crate::gen::EMOJIS[712]),
+        ("🖐", &crate::gen::EMOJIS[178]),
+        ("👩🏿\u{200d}🏫", &crate::gen::EMOJIS[873]),
+        ("🔤", &crate::gen::EMOJIS[3022]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1976]),
+        ("👱🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[611]),
+        ("⏱\u{fe0f}", &crate::gen::EMOJIS[2448]),
+        ("🤏🏿", &crate::gen::EMOJIS[249]),
+        ("👨🏿\u{200d}🤝\u{200d}👨🏻", &crate::gen::EMOJIS[1964]),
+        ("👨🏿\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1065]),
+        ("💆🏼\u{200d}♀", &crate::gen::EMOJIS[1483]),
+        ("🤵🏾\u{200d}♀", &crate::gen::EMOJIS[1250]),
+        ("🧑\u{200d}🦲", &crate::gen::EMOJIS[604]),
+        ("🧛🏽", &crate::gen::EMOJIS[1411]),
+        ("❤\u{fe0f}", &crate::gen::EMOJIS[140]),
+        ("🧗🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1687]),
+        ("👩🏻\u{200d}🚀", &crate::gen::EMOJIS[1085]),
+        ("🙌🏾", &crate::gen::EMOJIS[374]),
+        ("🥏", &crate::gen::EMOJIS[2557]),
+        ("😾", &crate::gen::EMOJIS[123]),
+        ("🫱🏾\u{200d}🫲🏼", &crate::gen::EMOJIS[394]),
+        ("💆\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1481]),
+        ("🧑🏿\u{200d}🎤", &crate::gen::EMOJIS[1023]),
+        ("🤵\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1240]),
+        ("♀\u{fe0f}", &crate::gen::EMOJIS[2966]),
+        ("🧔", &crate::gen::EMOJIS[514]),
+        ("🧎🏾", &crate::gen::EMOJIS[1545]),
+        ("👱🏼\u{200d}♂", &crate::gen::EMOJIS[618]),
+        ("🧍\u{200d}♀", &crate::gen::EMOJIS[1535]),
+        ("🚢", &crate::gen::EMOJIS[2428]),
+        ("🟠", &crate::gen::EMOJIS[3058]),
+        ("🧖🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1667]),
+        ("🏄🏿\u{200d}♀", &crate::gen::EMOJIS[1737]),
+        ("♻\u{fe0f}", &crate::gen::EMOJIS[2985]),
+        ("😱", &crate::gen::EMOJIS[91]),
+        ("🧙🏾\u{200d}♂", &crate::gen::EMOJIS[1382]),
+        ("⤵\u{fe0f}", &crate::gen::EMOJIS[2907]),
+        ("👨🏾\u{200d}❤\u{200d}👨🏾", &crate::gen::EMOJIS[1986]),
+        ("👨🏽\u{200d}🌾", &crate::gen::EMOJIS[901]),
+        ("👨🏽\u{200d}🎓", &crate::gen::EMOJIS[847]),
+        ("🤾🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1898]),
+        ("🇲🇨", &crate::gen::EMOJIS[3242]),
+        ("👪", &crate::gen::EMOJIS[1988]),
+        ("👋🏻", &crate::gen::EMOJIS[167]),
+        ("✋🏼", &crate::gen::EMOJIS[186]),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏿", &crate::gen::EMOJIS[1978]),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩", &crate::gen::EMOJIS[1978]),
+        ("🦷", &crate::gen::EMOJIS[465]),
+        ("🤦🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[801]),
+        ("🇩🇰", &crate::gen::EMOJIS[3160]),
+        ("🧙🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1388]),
+        ("🙋🏼\u{200d}♀", &crate::gen::EMOJIS[744]),
+        ("🏔", &crate::gen::EMOJIS[2311]),
+        ("📛", &crate::gen::EMOJIS[2988]),
+        ("👩\u{200d}🦽", &crate::gen::EMOJIS[1607]),
+        ("🇻🇬", &crate::gen::EMOJIS[3345]),
+        ("👩🏽\u{200d}⚕", &crate::gen::EMOJIS[835]),
+        ("🎱", &crate::gen::EMOJIS[2580]),
+        ("🏊\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1768]),
+        ("◾", &crate::gen::EMOJIS[3077]),
+        ("🏺", &crate::gen::EMOJIS[2303]),
+        ("⬆", &crate::gen::EMOJIS[2894]),
+        ("🆖", &crate::gen::EMOJIS[3033]),
+        ("🙅🏼\u{200d}♂", &crate::gen::EMOJIS[684]),
+        ("🧖🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1668]),
+        ("0\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3007]),
+        ("🗓\u{fe0f}", &crate::gen::EMOJIS[2773]),
+        ("🦸🏻", &crate::gen::EMOJIS[1337]),
+        ("🧑🏽\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[823]),
+        ("🧍🏽\u{200d}♂", &crate::gen::EMOJIS[1532]),
+        ("🇲🇲", &crate::gen::EMOJIS[3250]),
+        ("👩🏽\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[889]),
+        ("👰🏿\u{200d}♂", &crate::gen::EMOJIS[1263]),
+        ("🧟\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1467]),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1977]),
+        ("🍶", &crate::gen::EMOJIS[2282]),
+        ("🕺🏼", &crate::gen::EMOJIS[1639]),
+        ("🧝🏾\u{200d}♀", &crate::gen::EMOJIS[1460]),
+        ("🧑\u{200d}🦯", &crate::gen::EMOJIS[1559]),
+        ("❄", &crate::gen::EMOJIS[2515]),
+        ("😡", &crate::gen::EMOJIS[100]),
+        ("🧘🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1932]),
+        ("🧑🏽\u{200d}💼", &crate::gen::EMOJIS[967]),
+        ("🍸", &crate::gen::EMOJIS[2285]),
+        ("🇦🇨", &crate::gen::EMOJIS[3099]),
+        ("🤵🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1243]),
+        ("🏆", &crate::gen::EMOJIS[2544]),
+        ("🧱", &crate::gen::EMOJIS[2323]),
+        ("🦸🏼\u{200d}♂", &crate::gen::EMOJIS[1344]),
+        ("👨🏻\u{200d}⚖", &crate::gen::EMOJIS[881]),
+        ("🐰", &crate::gen::EMOJIS[2069]),
+        ("👩🏽\u{200d}💼", &crate::gen::EMOJIS[979]),
+        ("🦹🏾", &crate::gen::EMOJIS[1358]),
+        ("🤘🏼", &crate::gen::EMOJIS[276]),
+        ("🇲🇩", &crate::gen::EMOJIS[3243]),
+        ("👫🏾", &crate::gen::EMOJIS[1962]),
+        ("🚭", &crate::gen::EMOJIS[2886]),
+        ("🧑🏻\u{200d}🦰", &crate::gen::EMOJIS[569]),
+        ("🧔🏽\u{200d}♂", &crate::gen::EMOJIS[523]),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏽", &crate::gen::EMOJIS[1984]),
+        ("😻", &crate::gen::EMOJIS[118]),
+        ("👨🏿\u{200d}🚀", &crate::gen::EMOJIS[1083]),
+        ("🧝🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1457]),
+        ("🚵🏾", &crate::gen::EMOJIS[1832]),
+        ("🫶🏿", &crate::gen::EMOJIS[381]),
+        ("💝", &crate::gen::EMOJIS[129]),
+        ("👩🏻\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[887]),
+        ("🏃🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1621]),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1977]),
+        ("🧏🏻\u{200d}♂", &crate::gen::EMOJIS[755]),
+        ("🌳", &crate::gen::EMOJIS[2158]),
+        ("🕵🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1136]),
+        ("🤞", &crate::gen::EMOJIS[256]),
+        ("💆🏿", &crate::gen::EMOJIS[1474]),
+        ("🦺", &crate::gen::EMOJIS[2611]),
+        ("🥥", &crate::gen::EMOJIS[2189]),
+        ("👩🏻\u{200d}🎨", &crate::gen::EMOJIS[1049]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏼", &crate::gen::EMOJIS[1978]),
+        ("🤦\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[796]),
+        ("🧍🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1532]),
+        ("🥜", &crate::gen::EMOJIS[2202]),
+        ("📑", &crate::gen::EMOJIS[2733]),
+        ("☢", &crate::gen::EMOJIS[2892]),
+        ("🕒", &crate::gen::EMOJIS[2457]),
+        ("🔰", &crate::gen::EMOJIS[2989]),
+        ("🎂", &crate::gen::EMOJIS[2268]),
+        ("☝🏾", &crate::gen::EMOJIS[320]),
+        ("🚎", &crate::gen::EMOJIS[2384]),
+        ("📆", &crate::gen::EMOJIS[2771]),
+        ("🫥", &crate::gen::EMOJIS[41]),
+        ("💅", &crate::gen::EMOJIS[412]),
+        ("⚧\u{fe0f}", &crate::gen::EMOJIS[2968]),
+        ("🎥", &crate::gen::EMOJIS[2703]),
+        ("☝🏼", &crate::gen::EMOJIS[318]),
+        ("🥾", &crate::gen::EMOJIS[2636]),
+        ("🧛🏾", &crate::gen::EMOJIS[1412]),
+        ("🌥", &crate::gen::EMOJIS[2500]),
+        ("💂🏽", &crate::gen::EMOJIS[1147]),
+        ("🖕🏾", &crate::gen::EMOJIS[308]),
+        ("🫱🏻\u{200d}🫲🏿", &crate::gen::EMOJIS[394]),
+        ("↗\u{fe0f}", &crate::gen::EMOJIS[2895]),
+        ("😦", &crate::gen::EMOJIS[84]),
+        ("😜", &crate::gen::EMOJIS[25]),
+        ("🧛🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1422]),
+        ("🫄🏾", &crate::gen::EMOJIS[1286]),
+        ("👍🏻", &crate::gen::EMOJIS[329]),
+        ("👮🏾\u{200d}♀", &crate::gen::EMOJIS[1124]),
+        ("🚴🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1823]),
+        ("🧙🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1380]),
+        ("🤼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1866]),
+        ("👏🏿", &crate::gen::EMOJIS[369]),
+        ("🧍🏿", &crate::gen::EMOJIS[1528]),
+        ("🧗🏿\u{200d}♀", &crate::gen::EMOJIS[1687]),
+        ("🧎🏽\u{200d}♀", &crate::gen::EMOJIS[1556]),
+        ("🦹🏻\u{200d}♀", &crate::gen::EMOJIS[1367]),
+        ("🧑🏿\u{200d}⚖", &crate::gen::EMOJIS[879]),
+        ("👌🏼", &crate::gen::EMOJIS[234]),
+        ("🙉", &crate::gen::EMOJIS[125]),
+        ("🤷🏽", &crate::gen::EMOJIS[805]),
+        ("🧙🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1387]),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", &crate::gen::EMOJIS[1970]),
+        ("👩🏾\u{200d}❤\u{200d}👩🏾", &crate::gen::EMOJIS[1987]),
+        ("👨\u{200d}👩\u{200d}👧", &crate::gen::EMOJIS[1990]),
+        ("🤚🏿", &crate::gen::EMOJIS[177]),
+        ("🧚🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1397]),
+        ("🧠", &crate::gen::EMOJIS[462]),
+        ("🧔🏼\u{200d}♀", &crate::gen::EMOJIS[528]),
+        ("🧑🏽\u{200d}🏫", &crate::gen::EMOJIS[859]),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", &crate::gen::EMOJIS[1970]),
+        ("🦸🏽\u{200d}♀", &crate::gen::EMOJIS[1351]),
+        ("👩🏻\u{200d}❤\u{200d}👩🏾", &crate::gen::EMOJIS[1987]),
+        ("🤴🏻", &crate::gen::EMOJIS[1193]),
+        ("▪", &crate::gen::EMOJIS[3079]),
+        ("🧏🏿\u{200d}♂", &crate::gen::EMOJIS[759]),
+        ("👷🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1177]),
+        ("💭", &crate::gen::EMOJIS[164]),
+        ("↩\u{fe0f}", &crate::gen::EMOJIS[2904]),
+        ("🙎🏽\u{200d}♀", &crate::gen::EMOJIS[673]),
+        ("🛡", &crate::gen::EMOJIS[2805]),
+        ("🫶🏻", &crate::gen::EMOJIS[377]),
+        ("🙅🏾", &crate::gen::EMOJIS[680]),
+        ("🙅🏾\u{200d}♀", &crate::gen::EMOJIS[692]),
+        ("👨🏾\u{200d}💻", &crate::gen::EMOJIS[1010]),
+        ("🥀", &crate::gen::EMOJIS[2149]),
+        ("🚵🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1835]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", &crate::gen::EMOJIS[1985]),
+        ("🤚🏼", &crate::gen::EMOJIS[174]),
+        ("🎠", &crate::gen::EMOJIS[2364]),
+        ("🧝\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1450]),
+        ("🤛🏼", &crate::gen::EMOJIS[354]),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏾", &crate::gen::EMOJIS[1952]),
+        ("👩🏽\u{200d}❤\u{200d}👨🏽", &crate::gen::EMOJIS[1985]),
+        ("👩\u{200d}🦰", &crate::gen::EMOJIS[562]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏾", &crate::gen::EMOJIS[1978]),
+        ("👩🏻\u{200d}🎤", &crate::gen::EMOJIS[1031]),
+        ("💂🏿\u{200d}♂", &crate::gen::EMOJIS[1155]),
+        ("🚣🏿\u{200d}♂", &crate::gen::EMOJIS[1749]),
+        ("🧑🏻\u{200d}💼", &crate::gen::EMOJIS[965]),
+        ("✍\u{fe0f}", &crate::gen::EMOJIS[406]),
+        ("👩🏿\u{200d}🤝\u{200d}👨🏼", &crate::gen::EMOJIS[1958]),
+        ("🚮", &crate::gen::EMOJIS[2869]),
+        ("🤸🏼\u{200d}♀", &crate::gen::EMOJIS[1860]),
+        ("💺", &crate::gen::EMOJIS[2434]),
+        ("🏤", &crate::gen::EMOJIS[2333]),
+        ("👽", &crate::gen::EMOJIS[112]),
+        ("💇🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1502]),
+        ("🏄🏾\u{200d}♀", &crate::gen::EMOJIS[1736]),
+        ("🏄\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1726]),
+        ("🏌🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1716]),
+        ("👩🏽\u{200d}🚒", &crate::gen::EMOJIS[1105]),
+        ("🪰", &crate::gen::EMOJIS[2140]),
+        ("👩🏽\u{200d}🦱", &crate::gen::EMOJIS[577]),
+        ("🙎🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[666]),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏼", &crate::gen::EMOJIS[1975]),
+        ("🦸🏿\u{200d}♀", &crate::gen::EMOJIS[1353]),
+        ("🇱🇺", &crate::gen::EMOJIS[3238]),
+        ("🏖\u{fe0f}", &crate::gen::EMOJIS[2316]),
+        ("👨\u{200d}👧", &crate::gen::EMOJIS[2006]),
+        ("👩🏼\u{200d}🎤", &crate::gen::EMOJIS[1032]),
+        ("🧙🏽", &crate::gen::EMOJIS[1375]),
+        ("👩🏻\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[833]),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏾", &crate::gen::EMOJIS[1986]),
+        ("🦹\u{200d}♂", &crate::gen::EMOJIS[1360]),
+        ("🚗", &crate::gen::EMOJIS[2392]),
+        ("🫷🏿", &crate::gen::EMOJIS[225]),
+        ("🏘\u{fe0f}", &crate::gen::EMOJIS[2327]),
+        ("🤵🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1242]),
+        ("🏋🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1799]),
+        ("🧑🏼\u{200d}❤\u{200d}🧑🏽", &crate::gen::EMOJIS[1984]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1976]),
+        ("🧝🏽\u{200d}♀", &crate::gen::EMOJIS[1459]),
+        ("🔲", &crate::gen::EMOJIS[3090]),
+        ("👫🏼", &crate::gen::EMOJIS[1960]),
+        ("🏗\u{fe0f}", &crate::gen::EMOJIS[2322]),
+        ("👩\u{200d}👩\u{200d}👦", &crate::gen::EMOJIS[1999]),
+        ("👮🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1118]),
+        ("👩🏻\u{200d}🍼", &crate::gen::EMOJIS[1295]),
+        ("🧑🏻\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[821]),
+        ("👨🏼\u{200d}❤\u{200d}👨🏿", &crate::gen::EMOJIS[1986]),
+        ("🧑🏼\u{200d}🌾", &crate::gen::EMOJIS[894]),
+        ("🧑🏾\u{200d}🎄", &crate::gen::EMOJIS[1334]),
+        ("👨\u{200d}👧\u{200d}👦", &crate::gen::EMOJIS[2007]),
+        ("👨🏻\u{200d}🎨", &crate::gen::EMOJIS[1043]),
+        ("👧🏼", &crate::gen::EMOJIS[492]),
+        ("🖕🏻", &crate::gen::EMOJIS[305]),
+        ("🧍\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1535]),
+        ("👨🏾\u{200d}🎨", &crate::gen::EMOJIS[1046]),
+        ("🍂", &crate::gen::EMOJIS[2166]),
+        ("🧜🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1441]),
+        ("🧗\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1676]),
+        ("👨🏻\u{200d}❤\u{200d}👨🏽", &crate::gen::EMOJIS[1986]),
+        ("🫡", &crate::gen::EMOJIS[35]),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1977]),
+        ("🧏🏾\u{200d}♂", &crate::gen::EMOJIS[758]),
+        ("🌼", &crate::gen::EMOJIS[2152]),
+        ("🏇🏾", &crate::gen::EMOJIS[1693]),
+        ("🧘🏿\u{200d}♀", &crate::gen::EMOJIS[1938]),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", &crate::gen::EMOJIS[1970]),
+        ("👨🏻\u{200d}🚒", &crate::gen::EMOJIS[1097]),
+        ("🧜🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1436]),
+        ("🦹🏾\u{200d}♂", &crate::gen::EMOJIS[1364]),
+        ("⚠", &crate::gen::EMOJIS[2881]),
+        ("👩🏿\u{200d}🌾", &crate::gen::EMOJIS[909]),
+        ("🧒🏾", &crate::gen::EMOJIS[482]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏼", &crate::gen::EMOJIS[1987]),
+        ("🇸🇾", &crate::gen::EMOJIS[3316]),
+        ("🙃", &crate::gen::EMOJIS[9]),
+        ("🏴\u{e0067}\u{e0062}\u{e0073}\u{e0063}\u{e0074}\u{e007f}", &crate::gen::EMOJIS[3358]),
+        ("🧑🏻\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[875]),
+        ("🚶🏻\u{200d}♀", &crate::gen::EMOJIS[1518]),
+        ("🏌\u{fe0f}\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1714]),
+        ("🤲🏾", &crate::gen::EMOJIS[392]),
+        ("🐋", &crate::gen::EMOJIS[2116]),
+        ("⛹\u{200d}♂", &crate::gen::EMOJIS[1780]),
+        ("⚜\u{fe0f}", &crate::gen::EMOJIS[2986]),
+        ("👤", &crate::gen::EMOJIS[2015]),
+        ("📐", &crate::gen::EMOJIS[2784]),
+        ("👩\u{200d}⚕", &crate::gen::EMOJIS[832]),
+        ("🌀", &crate::gen::EMOJIS[2508]),
+        ("💏🏾", &crate::gen::EMOJIS[1974]),
+        ("🧖🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1661]),
+        ("🇹🇫", &crate::gen::EMOJIS[3321]),
+        ("🤷🏿\u{200d}♀", &crate::gen::EMOJIS[819]),
+        ("👨🏼\u{200d}🦳", &crate::gen::EMOJIS[546]),
+        ("🤸🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1854]),
+        ("🦻🏼", &crate::gen::EMOJIS[452]),
+        ("🚵🏻", &crate::gen::EMOJIS[1829]),
+        ("🙍🏻", &crate::gen::EMOJIS[641]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏿", &crate::gen::EMOJIS[1987]),
+        ("🇨🇿", &crate::gen::EMOJIS[3156]),
+        ("💁\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[718]),
+        ("🙅\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[688]),
+        ("💇🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1496]),
+        ("🤽", &crate::gen::EMOJIS[1867]),
+        ("👩\u{200d}👩\u{200d}👦\u{200d}👦", &crate::gen::EMOJIS[2002]),
+        ("🏓", &crate::gen::EMOJIS[2563]),
+        ("🏣", &crate::gen::EMOJIS[2332]),
+        ("♍", &crate::gen::EMOJIS[2933]),
+        ("👷🏼\u{200d}♂", &crate::gen::EMOJIS[1176]),
+        ("🏴\u{200d}☠", &crate::gen::EMOJIS[3098]),
+        ("🤽🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1875]),
+        ("🫸", &crate::gen::EMOJIS[226]),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1976]),
+        ("👩\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[832]),
+        ("⚰", &crate::gen::EMOJIS[2860]),
+        ("🦡", &crate::gen::EMOJIS[2083]),
+        ("🧗🏻\u{200d}♂", &crate::gen::EMOJIS[1677]),
+        ("◼\u{fe0f}", &crate::gen::EMOJIS[3075]),
+        ("👩🏻\u{200d}🍳", &crate::gen::EMOJIS[923]),
+        ("🧑🏼\u{200d}⚕", &crate::gen::EMOJIS[822]),
+        ("🚶🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1514]),
+        ("👲🏽", &crate::gen::EMOJIS[1225]),
+        ("🤟🏽", &crate::gen::EMOJIS[271]),
+        ("📇", &crate::gen::EMOJIS[2774]),
+        ("⛱\u{fe0f}", &crate::gen::EMOJIS[2513]),
+        ("🦹🏽\u{200d}♀", &crate::gen::EMOJIS[1369]),
+        ("😝", &crate::gen::EMOJIS[27]),
+        ("🤙🏾", &crate::gen::EMOJIS[284]),
+        ("😉", &crate::gen::EMOJIS[11]),
+        ("🧒🏿", &crate::gen::EMOJIS[483]),
+        ("👩🏻\u{200d}🔧", &crate::gen::EMOJIS[941]),
+        ("🫕", &crate::gen::EMOJIS[2234]),
+        ("💲", &crate::gen::EMOJIS[2983]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1976]),
+        ("🤦🏿", &crate::gen::EMOJIS[789]),
+        ("🇸🇳", &crate::gen::EMOJIS[3309]),
+        ("👋", &crate::gen::EMOJIS[166]),
+        ("😸", &crate::gen::EMOJIS[116]),
+        ("🐊", &crate::gen::EMOJIS[2107]),
+        ("🥓", &crate::gen::EMOJIS[2219]),
+        ("🏄\u{200d}♂", &crate::gen::EMOJIS[1726]),
+        ("🩼", &crate::gen::EMOJIS[2831]),
+        ("🇯🇵", &crate::gen::EMOJIS[3218]),
+        ("🐌", &crate::gen::EMOJIS[2127]),
+        ("🐲", &crate::gen::EMOJIS[2111]),
+        ("🦸🏾\u{200d}♀", &crate::gen::EMOJIS[1352]),
+        ("👊🏼", &crate::gen::EMOJIS[348]),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏾", &crate::gen::EMOJIS[1978]),
+        ("👩🏿\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1071]),
+        ("🫵", &crate::gen::EMOJIS[322]),
+        ("🪛", &crate::gen::EMOJIS[2808]),
+        ("🖨\u{fe0f}", &crate::gen::EMOJIS[2694]),
+        ("🦤", &crate::gen::EMOJIS[2098]),
+        ("👂🏻", &crate::gen::EMOJIS[445]),
+        ("👩\u{200d}👦\u{200d}👦", &crate::gen::EMOJIS[2010]),
+        ("🧘🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1934]),
+        ("🦠", &crate::gen::EMOJIS[2142]),
+        ("👩🏼\u{200d}🍳", &crate::gen::EMOJIS[924]),
+        ("💊", &crate::gen::EMOJIS[2829]),
+        ("💆🏼\u{200d}♂", &crate::gen::EMOJIS[1477]),
+        ("😷", &crate::gen::EMOJIS[55]),
+        ("🙎🏻", &crate::gen::EMOJIS[659]),
+        ("👀", &crate::gen::EMOJIS[467]),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", &crate::gen::EMOJIS[1970]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏼", &crate::gen::EMOJIS[1987]),
+        ("🦦", &crate::gen::EMOJIS[2080]),
+        ("🪝", &crate::gen::EMOJIS[2816]),
+        ("🫰🏿", &crate::gen::EMOJIS[267]),
+        ("💢", &crate::gen::EMOJIS[154]),
+        ("🛷", &crate::gen::EMOJIS[2574]),
+        ("✌🏼", &crate::gen::EMOJIS[252]),
+        ("🏌🏾\u{200d}♂", &crate::gen::EMOJIS[1712]),
+        ("🕵\u{fe0f}\u{200d}♂", &crate::gen::EMOJIS[1132]),
+        ("🤏🏾", &crate::gen::EMOJIS[248]),
+        ("🇻🇮", &crate::gen::EMOJIS[3346]),
+        ("💋", &crate::gen::EMOJIS[152]),
+        ("🤾🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1900]),
+        ("♏", &crate::gen::EMOJIS[2935]),
+        ("🚣🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1748]),
+        ("🍱", &crate::gen::EMOJIS[2241]),
+        ("☢\u{fe0f}", &crate::gen::EMOJIS[2892]),
+        ("🧜🏿", &crate::gen::EMOJIS[1431]),
+        ("💆🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1480]),
+        ("☃", &crate::gen::EMOJIS[2516]),
+        ("👨🏿", &crate::gen::EMOJIS[513]),
+        ("🦔", &crate::gen::EMOJIS[2073]),
+        ("⚕", &crate::gen::EMOJIS[2984]),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏻", &crate::gen::EMOJIS[1978]),
+        ("🏃🏽\u{200d}♂", &crate::gen::EMOJIS[1622]),
+        ("⬆\u{fe0f}", &crate::gen::EMOJIS[2894]),
+        ("🫱🏾\u{200d}🫲🏻", &crate::gen::EMOJIS[394]),
+        ("👮", &crate::gen::EMOJIS[1108]),
+        ("🛤", &crate::gen::EMOJIS[2411]),
+        ("👩🏾\u{200d}🔬", &crate::gen::EMOJIS[998]),
+        ("👨\u{200d}🦳", &crate::gen::EMOJIS[544]),
+        ("🛜", &crate::gen::EMOJIS[2963]),
+        ("🏴\u{200d}☠\u{fe0f}", &crate::gen::EMOJIS[3098]),
+        ("👨🏻\u{200d}🦯", &crate::gen::EMOJIS[1566]),
+        ("⛓", &crate::gen::EMOJIS[2815]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏻", &crate::gen::EMOJIS[1987]),
+        ("🍙", &crate::gen::EMOJIS[2243]),
+        ("🙎\u{200d}♂", &crate::gen::EMOJIS[664]),
+        ("💟", &crate::gen::EMOJIS[135]),
+        ("🇷🇸", &crate::gen::EMOJIS[3294]),
+        ("🧑🏽\u{200d}🍼", &crate::gen::EMOJIS[1309]),
+        ("🚶🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1512]),
+        ("👨🏾\u{200d}❤\u{200d}👨🏼", &crate::gen::EMOJIS[1986]),
+        ("🤵🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1251]),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏼", &crate::gen::EMOJIS[1951]),
+        ("🚌", &crate::gen::EMOJIS[2382]),
+        ("📠", &crate::gen::EMOJIS[2688]),
+        ("🟩", &crate::gen::EMOJIS[3069]),
+        ("🥯", &crate::gen::EMOJIS[2212]),
+        ("🚶🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1519]),
+        ("🤷🏿\u{200d}♂", &crate::gen::EMOJIS[813]),
+        ("🇬🇳", &crate::gen::EMOJIS[3189]),
+        ("👨🏽\u{200d}💼", &crate::gen::EMOJIS[973]),
+        ("🙎🏼", &crate::gen::EMOJIS[660]),
+        ("🧝\u{200d}♂", &crate::gen::EMOJIS[1450]),
+        ("❎", &crate::gen::EMOJIS[2995]),
+        ("👨\u{200d}🦯", &crate::gen::EMOJIS[1565]),
+        ("🧙🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1383]),
+        ("🐇", &crate::gen::EMOJIS[2070]),
+        ("😔", &crate::gen::EMOJIS[51]),
+        ("🧑🏿\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[879]),
+        ("🧍🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1530]),
+        ("🇲🇵", &crate::gen::EMOJIS[3253]),
+        ("🫣", &crate::gen::EMOJIS[32]),
+        ("⏸", &crate::gen::EMOJIS[2955]),
+        ("🕵\u{200d}♀", &crate::gen::EMOJIS[1138]),
+        ("👱", &crate::gen::EMOJIS[502]),
+        ("💆🏿\u{200d}♂", &crate::gen::EMOJIS[1480]),
+        ("🇹🇷", &crate::gen::EMOJIS[3330]),
+        ("🦻🏿", &crate::gen::EMOJIS[455]),
+        ("🪬", &crate::gen::EMOJIS[2864]),
+        ("🏌🏽", &crate::gen::EMOJIS[1705]),
+        ("💁🏾\u{200d}♂", &crate::gen::EMOJIS[722]),
+        ("🧛🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1416]),
+        ("🔳", &crate::gen::EMOJIS[3089]),
+        ("🤝🏾", &crate::gen::EMOJIS[398]),
+        ("🧎🏻", &crate::gen::EMOJIS[1542]),
+        ("4\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3011]),
+        ("👨\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[826]),
+        ("👩🏽\u{200d}🌾", &crate::gen::EMOJIS[907]),
+        ("♀", &crate::gen::EMOJIS[2966]),
+        ("🎮", &crate::gen::EMOJIS[2583]),
+        ("🏋\u{200d}♀", &crate::gen::EMOJIS[1804]),
+        ("❇\u{fe0f}", &crate::gen::EMOJIS[3001]),
+        ("💇🏼\u{200d}♀", &crate::gen::EMOJIS[1501]),
+        ("🕛", &crate::gen::EMOJIS[2451]),
+        ("✍🏾", &crate::gen::EMOJIS[410]),
+        ("🇲🇷", &crate::gen::EMOJIS[3255]),
+        ("🫵🏿", &crate::gen::EMOJIS[327]),
+        ("🏃🏾\u{200d}♂", &crate::gen::EMOJIS[1623]),
+        ("🐹", &crate::gen::EMOJIS[2068]),
+        ("😐", &crate::gen::EMOJIS[38]),
+        ("🧎\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1553]),
+        ("👩\u{200d}🚒", &crate::gen::EMOJIS[1102]),
+        ("💇", &crate::gen::EMOJIS[1487]),
+        ("🇱🇹", &crate::gen::EMOJIS[3237]),
+        ("🥕", &crate::gen::EMOJIS[2193]),
+        ("🚵🏽", &crate::gen::EMOJIS[1831]),
+        ("💂🏻\u{200d}♂", &crate::gen::EMOJIS[1151]),
+        ("👩\u{200d}🔧", &crate::gen::EMOJIS[940]),
+        ("🤽🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1874]),
+        ("🧖🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1660]),
+        ("🧑🏾\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1058]),
+        ("⚓", &crate::gen::EMOJIS[2420]),
+        ("💆🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1479]),
+        ("👰🏾\u{200d}♀", &crate::gen::EMOJIS[1268]),
+        ("👨🏻\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[881]),
+        ("👨\u{200d}🚒", &crate::gen::EMOJIS[1096]),
+        ("🫶🏽", &crate::gen::EMOJIS[379]),
+        ("👨🏼\u{200d}🎤", &crate::gen::EMOJIS[1026]),
+        ("🐝", &crate::gen::EMOJIS[2131]),
+        ("🙅🏼\u{200d}♀", &crate::gen::EMOJIS[690]),
+        ("🤾🏽\u{200d}♀", &crate::gen::EMOJIS[1900]),
+        ("👩🏽\u{200d}🦯", &crate::gen::EMOJIS[1574]),
+        ("👨🏻\u{200d}🎤", &crate::gen::EMOJIS[1025]),
+        ("🕵\u{200d}♂", &crate::gen::EMOJIS[1132]),
+        ("🤽\u{200d}♀", &crate::gen::EMOJIS[1879]),
+        ("🦹🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1371]),
+        ("🧎🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1549]),
+        ("✴\u{fe0f}", &crate::gen::EMOJIS[3000]),
+        ("🕸\u{fe0f}", &crate::gen::EMOJIS[2137]),
+        ("☄\u{fe0f}", &crate::gen::EMOJIS[2518]),
+        ("🦹🏻", &crate::gen::EMOJIS[1355]),
+        ("🫰🏻", &crate::gen::EMOJIS[263]),
+        ("🇲🇴", &crate::gen::EMOJIS[3252]),
+        ("📤", &crate::gen::EMOJIS[2750]),
+        ("🍏", &crate::gen::EMOJIS[2180]),
+        ("🙆🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[702]),
+        ("🏄🏼\u{200d}♂", &crate::gen::EMOJIS[1728]),
+        ("🎪", &crate::gen::EMOJIS[2369]),
+        ("👨🏻\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1061]),
+        ("🦹🏼\u{200d}♀", &crate::gen::EMOJIS[1368]),
+        ("⏫", &crate::gen::EMOJIS[2952]),
+        ("🧑🏽\u{200d}✈", &crate::gen::EMOJIS[1057]),
+        ("👨🏽\u{200d}❤\u{200d}👨🏽", &crate::gen::EMOJIS[1986]),
+        ("🏊🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1771]),
+        ("🕰\u{fe0f}", &crate::gen::EMOJIS[2450]),
+        ("👨🏼\u{200d}🍳", &crate::gen::EMOJIS[918]),
+        ("🩰", &crate::gen::EMOJIS[2640]),
+        ("🦸\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1342]),
+        ("♦", &crate::gen::EMOJIS[2594]),
+        ("🍟", &crate::gen::EMOJIS[2221]),
+        ("🐻\u{200d}❄\u{fe0f}", &crate::gen::EMOJIS[2076]),
+        ("🈷\u{fe0f}", &crate::gen::EMOJIS[3042]),
+        ("🧑\u{200d}🎄", &crate::gen::EMOJIS[1330]),
+        ("👨🏿\u{200d}⚕", &crate::gen::EMOJIS[831]),
+        ("🇧🇯", &crate::gen::EMOJIS[3124]),
+        ("🥂", &crate::gen::EMOJIS[2289]),
+        ("🔘", &crate::gen::EMOJIS[3088]),
+        ("👋🏼", &crate::gen::EMOJIS[168]),
+        ("🤓", &crate::gen::EMOJIS[71]),
+        ("🧑🏽\u{200d}🦱", &crate::gen::EMOJIS[583]),
+        ("👰🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1260]),
+        ("🤽🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1883]),
+        ("🏝", &crate::gen::EMOJIS[2318]),
+        ("🎢", &crate::gen::EMOJIS[2367]),
+        ("🤾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1897]),
+        ("🧑🏾\u{200d}🎤", &crate::gen::EMOJIS[1022]),
+        ("🍈", &crate::gen::EMOJIS[2172]),
+        ("🌖", &crate::gen::EMOJIS[2480]),
+        ("🐑", &crate::gen::EMOJIS[2055]),
+        ("🚶\u{200d}♀", &crate::gen::EMOJIS[1517]),
+        ("✍🏻", &crate::gen::EMOJIS[407]),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏻", &crate::gen::EMOJIS[1951]),
+        ("🤸🏾", &crate::gen::EMOJIS[1850]),
+        ("🚯", &crate::gen::EMOJIS[2887]),
+        ("👩", &crate::gen::EMOJIS[556]),
+        ("🚴🏼", &crate::gen::EMOJIS[1812]),
+        ("🦹\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1360]),
+        ("🫙", &crate::gen::EMOJIS[2302]),
+        ("✏\u{fe0f}", &crate::gen::EMOJIS[2759]),
+        ("✴", &crate::gen::EMOJIS[3000]),
+        ("🦶🏽", &crate::gen::EMOJIS[441]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1976]),
+        ("🧑🏿\u{200d}🦳", &crate::gen::EMOJIS[597]),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏻", &crate::gen::EMOJIS[1975]),
+        ("↔", &crate::gen::EMOJIS[2903]),
+        ("👮🏽\u{200d}♀", &crate::gen::EMOJIS[1123]),
+        ("🛒", &crate::gen::EMOJIS[2858]),
+        ("🚶🏾\u{200d}♀", &crate::gen::EMOJIS[1521]),
+        ("◻\u{fe0f}", &crate::gen::EMOJIS[3076]),
+        ("💏", &crate::gen::EMOJIS[1970]),
+        ("🚵\u{200d}♀", &crate::gen::EMOJIS[1840]),
+        ("🤾🏻\u{200d}♀", &crate::gen::EMOJIS[1898]),
+        ("🙆🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[709]),
+        ("🌬\u{fe0f}", &crate::gen::EMOJIS[2507]),
+        ("✉", &crate::gen::EMOJIS[2746]),
+        ("🌨\u{fe0f}", &crate::gen::EMOJIS[2503]),
+        ("🦹\u{200d}♀", &crate::gen::EMOJIS[1366]),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏾", &crate::gen::EMOJIS[1975]),
+        ("🏄🏼\u{200d}♀", &crate::gen::EMOJIS[1734]),
+        ("🚴🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1824]),
+        ("🇨🇦", &crate::gen::EMOJIS[3137]),
+        ("🖖🏻", &crate::gen::EMOJIS[191]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1976]),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏽", &crate::gen::EMOJIS[1951]),
+        ("🚴🏽\u{200d}♂", &crate::gen::EMOJIS[1819]),
+        ("🙍\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[652]),
+        ("🧑\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[820]),
+        ("🏄🏻\u{200d}♂", &crate::gen::EMOJIS[1727]),
+        ("🫱", &crate::gen::EMOJIS[196]),
+        ("🩵", &crate::gen::EMOJIS[146]),
+        ("💓", &crate::gen::EMOJIS[132]),
+        ("🇲🇳", &crate::gen::EMOJIS[3251]),
+        ("🧑🏽\u{200d}🦽", &crate::gen::EMOJIS[1598]),
+        ("🤵🏻\u{200d}♀", &crate::gen::EMOJIS[1247]),
+        ("👴🏾", &crate::gen::EMOJIS[632]),
+        ("🇮🇪", &crate::gen::EMOJIS[3206]),
+        ("🙋\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[736]),
+        ("🧊", &crate::gen::EMOJIS[2296]),
+        ("🇸🇰", &crate::gen::EMOJIS[3306]),
+        ("🕌", &crate::gen::EMOJIS[2348]),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏿", &crate::gen::EMOJIS[1951]),
+        ("👷🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1178]),
+        ("👩🏽", &crate::gen::EMOJIS[559]),
+        ("🧎🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1551]),
+        ("👇🏿", &crate::gen::EMOJIS[315]),
+        ("👨🏼\u{200d}🦽", &crate::gen::EMOJIS[1603]),
+        ("👨🏻\u{200d}❤\u{200d}👨🏿", &crate::gen::EMOJIS[1986]),
+        ("💃🏾", &crate::gen::EMOJIS[1635]),
+        ("🤦🏻\u{200d}♀", &crate::gen::EMOJIS[797]),
+        ("⛹\u{200d}♀", &crate::gen::EMOJIS[1786]),
+        ("🇬🇮", &crate::gen::EMOJIS[3186]),
+        ("🏃🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1627]),
+        ("👃", &crate::gen::EMOJIS[456]),
+        ("👩🏿\u{200d}❤\u{200d}👩🏾", &crate::gen::EMOJIS[1987]),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", &crate::gen::EMOJIS[1970]),
+        ("🫄🏻", &crate::gen::EMOJIS[1283]),
+        ("🌝", &crate::gen::EMOJIS[2489]),
+        ("🇬🇲", &crate::gen::EMOJIS[3188]),
+        ("👨🏿\u{200d}❤\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1977]),
+        ("🕵\u{fe0f}\u{200d}♀", &crate::gen::EMOJIS[1138]),
+        ("🚒", &crate::gen::EMOJIS[2387]),
+        ("🤦\u{200d}♀", &crate::gen::EMOJIS[796]),
+        ("👨🏼\u{200d}🦼", &crate::gen::EMOJIS[1585]),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏻", &crate::gen::EMOJIS[1979]),
+        ("👩\u{200d}✈", &crate::gen::EMOJIS[1066]),
+        ("☘", &crate::gen::EMOJIS[2163]),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏽", &crate::gen::EMOJIS[1951]),
+        ("💖", &crate::gen::EMOJIS[130]),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏿", &crate::gen::EMOJIS[1978]),
+        ("🛠\u{fe0f}", &crate::gen::EMOJIS[2799]),
+        ("🌘", &crate::gen::EMOJIS[2482]),
+        ("👨🏾\u{200d}⚖", &crate::gen::EMOJIS[884]),
+        ("🧗🏻\u{200d}♀", &crate::gen::EMOJIS[1683]),
+        ("🧛🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1421]),
+        ("🤾🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1894]),
+        ("🕵🏿", &crate::gen::EMOJIS[1131]),
+        ("🕵🏼\u{200d}♀", &crate::gen::EMOJIS[1140]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👩🏾", &crate::gen::EMOJIS[1987]),
+        ("🧎", &crate::gen::EMOJIS[1541]),
+        ("Ⓜ\u{fe0f}", &crate::gen::EMOJIS[3031]),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏻", &crate::gen::EMOJIS[1984]),
+        ("🤾🏾\u{200d}♀", &crate::gen::EMOJIS[1901]),
+        ("🕵🏽\u{200d}♀", &crate::gen::EMOJIS[1141]),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏾", &crate::gen::EMOJIS[1978]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👩🏾", &crate::gen::EMOJIS[1987]),
+        ("🤎", &crate::gen::EMOJIS[148]),
+        ("🇵🇪", &crate::gen::EMOJIS[3278]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", &crate::gen::EMOJIS[1985]),
+        ("🙎🏿", &crate::gen::EMOJIS[663]),
+        ("🍪", &crate::gen::EMOJIS[2267]),
+        ("🏃🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1624]),
+        ("🤲🏼", &crate::gen::EMOJIS[390]),
+        ("🏛\u{fe0f}", &crate::gen::EMOJIS[2321]),
+        ("🏙\u{fe0f}", &crate::gen::EMOJIS[2357]),
+        ("🫃🏽", &crate::gen::EMOJIS[1279]),
+        ("⬜", &crate::gen::EMOJIS[3074]),
+        ("🐾", &crate::gen::EMOJIS[2084]),
+        ("👇🏽", &crate::gen::EMOJIS[313]),
+        ("🧔🏻\u{200d}♂", &crate::gen::EMOJIS[521]),
+        ("👎🏻", &crate::gen::EMOJIS[335]),
+        ("🌡\u{fe0f}", &crate::gen::EMOJIS[2487]),
+        ("🕘", &crate::gen::EMOJIS[2469]),
+        ("👱\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[616]),
+        ("🧺", &crate::gen::EMOJIS[2850]),
+        ("👨🏿\u{200d}💻", &crate::gen::EMOJIS[1011]),
+        ("🛠", &crate::gen::EMOJIS[2799]),
+        ("🤹\u{200d}♂", &crate::gen::EMOJIS[1909]),
+        ("🧑🏽\u{200d}🦲", &crate::gen::EMOJIS[607]),
+        ("❕", &crate::gen::EMOJIS[2979]),
+        ("🇲🇽", &crate::gen::EMOJIS[3261]),
+        ("🦹🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1368]),
+        ("👷🏽\u{200d}♀", &crate::gen::EMOJIS[1183]),
+        ("🛋\u{fe0f}", &crate::gen::EMOJIS[2839]),
+        ("🪁", &crate::gen::EMOJIS[2578]),
+        ("👩\u{200d}🎓", &crate::gen::EMOJIS[850]),
+        ("🏮", &crate::gen::EMOJIS[2717]),
+        ("🧖🏼\u{200d}♂", &crate::gen::EMOJIS[1660]),
+        ("⛎", &crate::gen::EMOJIS[2940]),
+        ("⏹\u{fe0f}", &crate::gen::EMOJIS[2956]),
+        ("🏃🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1626]),
+        ("🐙", &crate::gen::EMOJIS[2123]),
+        ("🤱🏾", &crate::gen::EMOJIS[1292]),
+        ("🤙🏻", &crate::gen::EMOJIS[281]),
+        ("🙍🏽\u{200d}♀", &crate::gen::EMOJIS[655]),
+        ("👸", &crate::gen::EMOJIS[1198]),
+        ("🧑🏾\u{200d}🦼", &crate::gen::EMOJIS[1581]),
+        ("🦸🏼", &crate::gen::EMOJIS[1338]),
+        ("👩🏻\u{200d}❤\u{200d}👩🏼", &crate::gen::EMOJIS[1987]),
+        ("🤾🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1899]),
+        ("📼", &crate::gen::EMOJIS[2711]),
+        ("🚵🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1836]),
+        ("🍺", &crate::gen::EMOJIS[2287]),
+        ("😘", &crate::gen::EMOJIS[17]),
+        ("👳🏼", &crate::gen::EMOJIS[1206]),
+        ("🧚🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1404]),
+        ("👮🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1122]),
+        ("🙆🏾\u{200d}♂", &crate::gen::EMOJIS[704]),
+        ("🇬🇭", &crate::gen::EMOJIS[3185]),
+        ("👨🏼\u{200d}🏭", &crate::gen::EMOJIS[954]),
+        ("🚱", &crate::gen::EMOJIS[2888]),
+        ("🇧🇷", &crate::gen::EMOJIS[3130]),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}👨🏻", &crate::gen::EMOJIS[1986]),
+        ("👉🏽", &crate::gen::EMOJIS[295]),
+        ("👨🏿\u{200d}⚖", &crate::gen::EMOJIS[885]),
+        ("🏜\u{fe0f}", &crate::gen::EMOJIS[2317]),
+        ("👩🏾\u{200d}🦰", &crate::gen::EMOJIS[566]),
+        ("👁", &crate::gen::EMOJIS[468]),
+        ("👂", &crate::gen::EMOJIS[444]),
+        ("🧑🏾\u{200d}🔬", &crate::gen::EMOJIS[986]),
+        ("🤜🏿", &crate::gen::EMOJIS[363]),
+        ("👷🏿\u{200d}♀", &crate::gen::EMOJIS[1185]),
+        ("👴🏽", &crate::gen::EMOJIS[631]),
+        ("🤵🏿\u{200d}♂", &crate::gen::EMOJIS[1245]),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏿", &crate::gen::EMOJIS[1964]),
+        ("🕦", &crate::gen::EMOJIS[2474]),
+        ("🐏", &crate::gen::EMOJIS[2054]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1976]),
+        ("🫏", &crate::gen::EMOJIS[2040]),
+        ("🫱🏿", &crate::gen::EMOJIS[201]),
+        ("9\u{20e3}", &crate::gen::EMOJIS[3016]),
+        ("🏃🏿\u{200d}♂", &crate::gen::EMOJIS[1624]),
+        ("👴", &crate::gen::EMOJIS[628]),
+        ("😃", &crate::gen::EMOJIS[1]),
+        ("🤾🏽\u{200d}♂", &crate::gen::EMOJIS[1894]),
+        ("🚴🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1821]),
+        ("🫗", &crate::gen::EMOJIS[2291]),
+        ("🤰🏾", &crate::gen::EMOJIS[1274]),
+        ("🦧", &crate::gen::EMOJIS[2022]),
+        ("🧓🏿", &crate::gen::EMOJIS[627]),
+        ("👫🏽", &crate::gen::EMOJIS[1961]),
+        ("☪", &crate::gen::EMOJIS[2923]),
+        ("❌", &crate::gen::EMOJIS[2994]),
+        ("🙇🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[773]),
+        ("🥟", &crate::gen::EMOJIS[2255]),
+        ("💂🏼\u{200d}♀", &crate::gen::EMOJIS[1158]),
+        ("🇧🇩", &crate::gen::EMOJIS[3118]),
+        ("🌨", &crate::gen::EMOJIS[2503]),
+        ("🏊🏾\u{200d}♀", &crate::gen::EMOJIS[1772]),
+        ("🚴🏽", &crate::gen::EMOJIS[1813]),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏿", &crate::gen::EMOJIS[1964]),
+        ("🤧", &crate::gen::EMOJIS[60]),
+        ("👬🏿", &crate::gen::EMOJIS[1969]),
+        ("👏🏼", &crate::gen::EMOJIS[366]),
+        ("🧝🏼", &crate::gen::EMOJIS[1446]),
+        ("🇪🇪", &crate::gen::EMOJIS[3166]),
+        ("🇬🇾", &crate::gen::EMOJIS[3197]),
+        ("🧑🏻\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1055]),
+        ("🧓🏽", &crate::gen::EMOJIS[625]),
+        ("🪢", &crate::gen::EMOJIS[2606]),
+        ("💂🏻", &crate::gen::EMOJIS[1145]),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏾", &crate::gen::EMOJIS[1951]),
+        ("🧖\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1658]),
+        ("👨🏼\u{200d}🦯", &crate::gen::EMOJIS[1567]),
+        ("〰\u{fe0f}", &crate::gen::EMOJIS[2981]),
+        ("👂🏼", &crate::gen::EMOJIS[446]),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏽", &crate::gen::EMOJIS[1975]),
+        ("🧗🏼", &crate::gen::EMOJIS[1672]),
+        ("🦩", &crate::gen::EMOJIS[2100]),
+        ("🖌\u{fe0f}", &crate::gen::EMOJIS[2763]),
+        ("🫄🏼", &crate::gen::EMOJIS[1284]),
+        ("🙅🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[689]),
+        ("↙\u{fe0f}", &crate::gen::EMOJIS[2899]),
+        ("🫔", &crate::gen::EMOJIS[2227]),
+        ("🖋\u{fe0f}", &crate::gen::EMOJIS[2761]),
+        ("👰🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1266]),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏿", &crate::gen::EMOJIS[1978]),
+        ("◼", &crate::gen::EMOJIS[3075]),
+        ("🧘🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1938]),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏿", &crate::gen::EMOJIS[1986]),
+        ("🧜🏿\u{200d}♀", &crate::gen::EMOJIS[1443]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", &crate::gen::EMOJIS[1985]),
+        ("🫅🏻", &crate::gen::EMOJIS[1187]),
+        ("🤽🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1877]),
+        ("🙅🏼", &crate::gen::EMOJIS[678]),
+        ("🫱🏼\u{200d}🫲🏾", &crate::gen::EMOJIS[394]),
+        ("🤙🏿", &crate::gen::EMOJIS[285]),
+        ("🐚", &crate::gen::EMOJIS[2124]),
+        ("🤣", &crate::gen::EMOJIS[6]),
+        ("🤹🏼\u{200d}♀", &crate::gen::EMOJIS[1917]),
+        ("🐪", &crate::gen::EMOJIS[2057]),
+        ("🙏🏾", &crate::gen::EMOJIS[404]),
+        ("🏩", &crate::gen::EMOJIS[2337]),
+        ("🧑🏾\u{200d}✈", &crate::gen::EMOJIS[1058]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", &crate::gen::EMOJIS[1985]),
+        ("◀\u{fe0f}", &crate::gen::EMOJIS[2948]),
+        ("🏌🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1719]),
+        ("🇸🇹", &crate::gen::EMOJIS[3313]),
+        ("🧙🏾", &crate::gen::EMOJIS[1376]),
+        ("🧸", &crate::gen::EMOJIS[2588]),
+        ("🗃", &crate::gen::EMOJIS[2786]),
+        ("😼", &crate::gen::EMOJIS[119]),
+        ("🇪🇸", &crate::gen::EMOJIS[3170]),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👩🏻", &crate::gen::EMOJIS[1978]),
+        ("👨🏻\u{200d}✈", &crate::gen::EMOJIS[1061]),
+        ("🏜", &crate::gen::EMOJIS[2317]),
+        ("🦸🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1350]),
+        ("👩🏻\u{200d}❤\u{200d}👨🏻", &crate::gen::EMOJIS[1985]),
+        ("1\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3008]),
+        ("📍", &crate::gen::EMOJIS[2780]),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏼", &crate::gen::EMOJIS[1984]),
+        ("👨🏼\u{200d}✈", &crate::gen::EMOJIS[1062]),
+        ("🧝🏿", &crate::gen::EMOJIS[1449]),
+        ("👉🏻", &crate::gen::EMOJIS[293]),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏻", &crate::gen::EMOJIS[1978]),
+        ("👩🏻\u{200d}❤\u{200d}👨🏽", &crate::gen::EMOJIS[1985]),
+        ("✍🏼", &crate::gen::EMOJIS[408]),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏻", &crate::gen::EMOJIS[1964]),
+        ("🧙\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1378]),
+        ("🧝🏽", &crate::gen::EMOJIS[1447]),
+        ("🇧🇮", &crate::gen::EMOJIS[3123]),
+        ("💁🏿\u{200d}♂", &crate::gen::EMOJIS[723]),
+        ("🦏", &crate::gen::EMOJIS[2063]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1976]),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏼", &crate::gen::EMOJIS[1952]),
+        ("🧕🏽", &crate::gen::EMOJIS[1231]),
+        ("👩🏼\u{200d}❤\u{200d}👩🏿", &crate::gen::EMOJIS[1987]),
+        ("🕴🏾", &crate::gen::EMOJIS[1647]),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏽", &crate::gen::EMOJIS[1951]),
+        ("🕯\u{fe0f}", &crate::gen::EMOJIS[2714]),
+        ("🤽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1879]),
+        ("👱🏽\u{200d}♂", &crate::gen::EMOJIS[619]),
+        ("💹", &crate::gen::EMOJIS[2745]),
+        ("🏊\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1762]),
+        ("🙁", &crate::gen::EMOJIS[76]),
+        ("👩🏿\u{200d}🍼", &crate::gen::EMOJIS[1299]),
+        ("🧝🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1454]),
+        ("🎻", &crate::gen::EMOJIS[2677]),
+        ("🧎🏼", &crate::gen::EMOJIS[1543]),
+        ("🦹🏿\u{200d}♀", &crate::gen::EMOJIS[1371]),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏽", &crate::gen::EMOJIS[1964]),
+        ("🕊\u{fe0f}", &crate::gen::EMOJIS[2093]),
+        ("🙇\u{200d}♀", &crate::gen::EMOJIS[778]),
+        ("⛹🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1784]),
+        ("💇🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1494]),
+        ("👆🏿", &crate::gen::EMOJIS[303]),
+        ("💂🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1155]),
+        ("🧖🏾\u{200d}♂", &crate::gen::EMOJIS[1662]),
+        ("🇰🇿", &crate::gen::EMOJIS[3229]),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏼", &crate::gen::EMOJIS[1958]),
+        ("👩🏻\u{200d}❤\u{200d}👩🏽", &crate::gen::EMOJIS[1987]),
+        ("🚴🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1818]),
+        ("🦬", &crate::gen::EMOJIS[2045]),
+        ("👬🏽", &crate::gen::EMOJIS[1967]),
+        ("🧖🏻\u{200d}♂", &crate::gen::EMOJIS[1659]),
+        ("🛃", &crate::gen::EMOJIS[2878]),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1977]),
+        ("🦸🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1343]),
+        ("🗾", &crate::gen::EMOJIS[2309]),
+        ("🧎🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1548]),
+        ("🧽", &crate::gen::EMOJIS[2856]),
+        ("☣", &crate::gen::EMOJIS[2893]),
+        ("🥼", &crate::gen::EMOJIS[2610]),
+        ("🏃🏼", &crate::gen::EMOJIS[1615]),
+        ("🤜🏼", &crate::gen::EMOJIS[360]),
+        ("🏇🏿", &crate::gen::EMOJIS[1694]),
+        ("🙇🏿", &crate::gen::EMOJIS[771]),
+        ("💏🏻", &crate::gen::EMOJIS[1971]),
+        ("🔩", &crate::gen::EMOJIS[2809]),
+        ("😹", &crate::gen::EMOJIS[117]),
+        ("🚕", &crate::gen::EMOJIS[2390]),
+        ("🏂🏾", &crate::gen::EMOJIS[1700]),
+        ("🚓", &crate::gen::EMOJIS[2388]),
+        ("🏄\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1732]),
+        ("🇻🇳", &crate::gen::EMOJIS[3347]),
+        ("🌧", &crate::gen::EMOJIS[2502]),
+        ("🇹🇰", &crate::gen::EMOJIS[3325]),
+        ("🧙🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1385]),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", &crate::gen::EMOJIS[1970]),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", &crate::gen::EMOJIS[1986]),
+        ("👩\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[886]),
+        ("👩🏽\u{200d}❤\u{200d}👩🏾", &crate::gen::EMOJIS[1987]),
+        ("🙌", &crate::gen::EMOJIS[370]),
+        ("✌", &crate::gen::EMOJIS[250]),
+        ("🧗🏽\u{200d}♂", &crate::gen::EMOJIS[1679]),
+        ("🧈", &crate::gen::EMOJIS[2238]),
+        ("🙎🏽", &crate::gen::EMOJIS[661]),
+        ("⬛", &crate::gen::EMOJIS[3073]),
+        ("🦞", &crate::gen::EMOJIS[2259]),
+        ("©\u{fe0f}", &crate::gen::EMOJIS[3002]),
+        ("👨\u{200d}👩\u{200d}👦\u{200d}👦", &crate::gen::EMOJIS[1992]),
+        ("🤽🏽", &crate::gen::EMOJIS[1870]),
+        ("🧑\u{200d}💼", &crate::gen::EMOJIS[964]),
+        ("🪵", &crate::gen::EMOJIS[2325]),
+        ("🧔🏼\u{200d}♂", &crate::gen::EMOJIS[522]),
+        ("🧝🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1458]),
+        ("🎲", &crate::gen::EMOJIS[2586]),
+        ("🚴🏻", &crate::gen::EMOJIS[1811]),
+        ("➖", &crate::gen::EMOJIS[2971]),
+        ("🥤", &crate::gen::EMOJIS[2292]),
+        ("🦸🏻\u{200d}♂", &crate::gen::EMOJIS[1343]),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1976]),
+        ("🦅", &crate::gen::EMOJIS[2094]),
+        ("🈚", &crate::gen::EMOJIS[3047]),
+        ("🏀", &crate::gen::EMOJIS[2552]),
+        ("🧼", &crate::gen::EMOJIS[2853]),
+        ("🤵🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1250]),
+        ("🤸🏽\u{200d}♀", &crate::gen::EMOJIS[1861]),
+        ("💃", &crate::gen::EMOJIS[1631]),
+        ("🤵", &crate::gen::EMOJIS[1234]),
+        ("💂🏼\u{200d}♂", &crate::gen::EMOJIS[1152]),
+        ("👋🏽", &crate::gen::EMOJIS[169]),
+        ("🏳\u{fe0f}\u{200d}⚧", &crate::gen::EMOJIS[3097]),
+        ("♦\u{fe0f}", &crate::gen::EMOJIS[2594]),
+        ("🏄🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1735]),
+        ("🌋", &crate::gen::EMOJIS[2313]),
+        ("🌃", &crate::gen::EMOJIS[2356]),
+        ("🤾🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1902]),
+        ("👩🏿\u{200d}🚀", &crate::gen::EMOJIS[1089]),
+        ("👨\u{200d}👨\u{200d}👦", &crate::gen::EMOJIS[1994]),
+        ("👩🏻\u{200d}🚒", &crate::gen::EMOJIS[1103]),
+        ("🥫", &crate::gen::EMOJIS[2240]),
+        ("🧚🏽\u{200d}♂", &crate::gen::EMOJIS[1399]),
+        ("🦶🏿", &crate::gen::EMOJIS[443]),
+        ("⏺\u{fe0f}", &crate::gen::EMOJIS[2957]),
+        ("⏩", &crate::gen::EMOJIS[2945]),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1977]),
+        ("👯\u{200d}♂", &crate::gen::EMOJIS[1650]),
+        ("🧑🏾\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[878]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1976]),
+        ("⤴", &crate::gen::EMOJIS[2906]),
+        ("💁🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[721]),
+        ("🧑🏻", &crate::gen::EMOJIS[497]),
+        ("🦵🏽", &crate::gen::EMOJIS[435]),
+        ("👨\u{200d}🎨", &crate::gen::EMOJIS[1042]),
+        ("🫸🏾", &crate::gen::EMOJIS[230]),
+        ("👈", &crate::gen::EMOJIS[286]),
+        ("🫱🏻\u{200d}🫲🏼", &crate::gen::EMOJIS[394]),
+        ("🗒\u{fe0f}", &crate::gen::EMOJIS[2772]),
+        ("🙋🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[739]),
+        ("⛸\u{fe0f}", &crate::gen::EMOJIS[2569]),
+        ("🏌🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1715]),
+        ("🌮", &crate::gen::EMOJIS[2225]),
+        ("🧏🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[764]),
+        ("🏌", &crate::gen::EMOJIS[1702]),
+        ("👈🏼", &crate::gen::EMOJIS[288]),
+        ("👩🏻\u{200d}🦼", &crate::gen::EMOJIS[1590]),
+        ("🈵", &crate::gen::EMOJIS[3056]),
+        ("👈🏽", &crate::gen::EMOJIS[289]),
+        ("👩🏻\u{200d}💻", &crate::gen::EMOJIS[1013]),
+        ("⛹🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1791]),
+        ("🖍", &crate::gen::EMOJIS[2764]),
+        ("👫🏿", &crate::gen::EMOJIS[1963]),
+        ("🧑\u{200d}🎓", &crate::gen::EMOJIS[838]),
+        ("🙆\u{200d}♂", &crate::gen::EMOJIS[700]),
+        ("🏌\u{fe0f}\u{200d}♂", &crate::gen::EMOJIS[1708]),
+        ("🧑🏿\u{200d}❤\u{200d}🧑🏾", &crate::gen::EMOJIS[1984]),
+        ("🙏🏼", &crate::gen::EMOJIS[402]),
+        ("👩🏽\u{200d}🤝\u{200d}👩🏼", &crate::gen::EMOJIS[1952]),
+        ("👼", &crate::gen::EMOJIS[1312]),
+        ("🙎\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[670]),
+        ("🧑🏼\u{200d}🍳", &crate::gen::EMOJIS[912]),
+        ("👌🏻", &crate::gen::EMOJIS[233]),
+        ("🙍🏾\u{200d}♀", &crate::gen::EMOJIS[656]),
+        ("🫵🏾", &crate::gen::EMOJIS[326]),
+        ("🤟🏾", &crate::gen::EMOJIS[272]),
+        ("🆘", &crate::gen::EMOJIS[3037]),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1977]),
+        ("👨\u{200d}🦽", &crate::gen::EMOJIS[1601]),
+        ("🫱🏽", &crate::gen::EMOJIS[199]),
+        ("🦸🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1346]),
+        ("🐿", &crate::gen::EMOJIS[2071]),
+        ("🇼🇸", &crate::gen::EMOJIS[3350]),
+        ("👰\u{200d}♀", &crate::gen::EMOJIS[1264]),
+        ("🚶🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1515]),
+        ("👨🏿\u{200d}🎨", &crate::gen::EMOJIS[1047]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1976]),
+        ("💇🏻", &crate::gen::EMOJIS[1488]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", &crate::gen::EMOJIS[1978]),
+        ("🦁", &crate::gen::EMOJIS[2034]),
+        ("👩🏻\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1067]),
+        ("🎺", &crate::gen::EMOJIS[2676]),
+        ("🤟🏼", &crate::gen::EMOJIS[270]),
+        ("🧖\u{200d}♂", &crate::gen::EMOJIS[1658]),
+        ("🤵🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1241]),
+        ("🏳", &crate::gen::EMOJIS[3095]),
+        ("🚴🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1817]),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", &crate::gen::EMOJIS[1986]),
+        ("🧚🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1400]),
+        ("🙍🏾\u{200d}♂", &crate::gen::EMOJIS[650]),
+        ("🚣🏻", &crate::gen::EMOJIS[1739]),
+        ("👮🏽\u{200d}♂", &crate::gen::EMOJIS[1117]),
+        ("↪\u{fe0f}", &crate::gen::EMOJIS[2905]),
+        ("🤵🏻\u{200d}♂", &crate::gen::EMOJIS[1241]),
+        ("☸", &crate::gen::EMOJIS[2919]),
+        ("👨🏿\u{200d}❤\u{200d}👨🏻", &crate::gen::EMOJIS[1986]),
+        ("🧑🏿\u{200d}🎄", &crate::gen::EMOJIS[1335]),
+        ("🕵🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1135]),
+        ("🧍🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1536]),
+        ("🇲🇦", &crate::gen::EMOJIS[3241]),
+        ("🏃🏼\u{200d}♂", &crate::gen::EMOJIS[1621]),
+        ("💃🏽", &crate::gen::EMOJIS[1634]),
+        ("😠", &crate::gen::EMOJIS[101]),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1976]),
+        ("🌹", &crate::gen::EMOJIS[2148]),
+        ("🕶\u{fe0f}", &crate::gen::EMOJIS[2608]),
+        ("👲", &crate::gen::EMOJIS[1222]),
+        ("🧑🏼\u{200d}🎤", &crate::gen::EMOJIS[1020]),
+        ("🙍🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[656]),
+        ("🏋🏼\u{200d}♀", &crate::gen::EMOJIS[1806]),
+        ("🧑🏼\u{200d}💻", &crate::gen::EMOJIS[1002]),
+        ("👱🏿\u{200d}♂", &crate::gen::EMOJIS[621]),
+        ("🧑🏼\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[876]),
+        ("🧑🏾\u{200d}⚖", &crate::gen::EMOJIS[878]),
+        ("🐒", &crate::gen::EMOJIS[2020]),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏽", &crate::gen::EMOJIS[1951]),
+        ("👰🏻", &crate::gen::EMOJIS[1253]),
+        ("🧜🏻\u{200d}♀", &crate::gen::EMOJIS[1439]),
+        ("🖱\u{fe0f}", &crate::gen::EMOJIS[2696]),
+        ("🚣\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1744]),
+        ("🧛🏿\u{200d}♀", &crate::gen::EMOJIS[1425]),
+        ("🙅🏻", &crate::gen::EMOJIS[677]),
+        ("🙆🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[705]),
+        ("🅱\u{fe0f}", &crate::gen::EMOJIS[3025]),
+        ("↙", &crate::gen::EMOJIS[2899]),
+        ("⏱", &crate::gen::EMOJIS[2448]),
+        ("🎳", &crate::gen::EMOJIS[2558]),
+        ("🏫", &crate::gen::EMOJIS[2339]),
+        ("🏄🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1736]),
+        ("👷🏻\u{200d}♂", &crate::gen::EMOJIS[1175]),
+        ("🧍🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1531]),
+        ("🕷\u{fe0f}", &crate::gen::EMOJIS[2136]),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏽", &crate::gen::EMOJIS[1979]),
+        ("™\u{fe0f}", &crate::gen::EMOJIS[3004]),
+        ("📻", &crate::gen::EMOJIS[2671]),
+        ("🚴🏼\u{200d}♀", &crate::gen::EMOJIS[1824]),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏽", &crate::gen::EMOJIS[1952]),
+        ("🧎🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1556]),
+        ("♒", &crate::gen::EMOJIS[2938]),
+        ("🇸🇱", &crate::gen::EMOJIS[3307]),
+        ("🍖", &crate::gen::EMOJIS[2216]),
+        ("👨🏻\u{200d}⚕", &crate::gen::EMOJIS[827]),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏻", &crate::gen::EMOJIS[1984]),
+        ("🫓", &crate::gen::EMOJIS[2210]),
+        ("👨🏾\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1064]),
+        ("🦸🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1344]),
+        ("🇲🇹", &crate::gen::EMOJIS[3257]),
+        ("🫳🏼", &crate::gen::EMOJIS[210]),
+        ("🚶🏽\u{200d}♀", &crate::gen::EMOJIS[1520]),
+        ("👍🏽", &crate::gen::EMOJIS[331]),
+        ("🕸", &crate::gen::EMOJIS[2137]),
+        ("🤗", &crate::gen::EMOJIS[29]),
+        ("🧗🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1683]),
+        ("👩\u{200d}❤\u{200d}👩", &crate::gen::EMOJIS[1987]),
+        ("🧘🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1931]),
+        ("🧑🏻\u{200d}🦲", &crate::gen::EMOJIS[605]),
+        ("👩🏾\u{200d}🦳", &crate::gen::EMOJIS[590]),
+        ("🧑🏻\u{200d}🌾", &crate::gen::EMOJIS[893]),
+        ("🧘🏿\u{200d}♂", &crate::gen::EMOJIS[1932]),
+        ("🚴🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1820]),
+        ("🧔🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[530]),
+        ("👳🏽\u{200d}♀", &crate::gen::EMOJIS[1219]),
+        ("🙇🏽\u{200d}♂", &crate::gen::EMOJIS[775]),
+        ("🏌🏿", &crate::gen::EMOJIS[1707]),
+        ("🕴", &crate::gen::EMOJIS[1643]),
+        ("🧒", &crate::gen::EMOJIS[478]),
+        ("👨\u{200d}🏫", &crate::gen::EMOJIS[862]),
+        ("🤷🏻\u{200d}♀", &crate::gen::EMOJIS[815]),
+        ("💑", &crate::gen::EMOJIS[1979]),
+        ("🇫🇮", &crate::gen::EMOJIS[3173]),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1977]),
+        ("🤾", &crate::gen::EMOJIS[1885]),
+        ("👨🏿\u{200d}✈", &crate::gen::EMOJIS[1065]),
+        ("💁🏾", &crate::gen::EMOJIS[716]),
+        ("🚨", &crate::gen::EMOJIS[2415]),
+        ("🫱🏽\u{200d}🫲🏾", &crate::gen::EMOJIS[394]),
+        ("🤳🏾", &crate::gen::EMOJIS[422]),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏽", &crate::gen::EMOJIS[1951]),
+        ("🧍🏽", &crate::gen::EMOJIS[1526]),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1977]),
+        ("🦸🏼\u{200d}♀", &crate::gen::EMOJIS[1350]),
+        ("🐀", &crate::gen::EMOJIS[2067]),
+        ("😨", &crate::gen::EMOJIS[86]),
+        ("👸🏼", &crate::gen::EMOJIS[1200]),
+        ("🎍", &crate::gen::EMOJIS[2532]),
+        ("🤟🏿", &crate::gen::EMOJIS[273]),
+        ("🤲🏻", &crate::gen::EMOJIS[389]),
+        ("👩🏾", &crate::gen::EMOJIS[560]),
+        ("⛹🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1788]),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1976]),
+        ("💇🏻\u{200d}♂", &crate::gen::EMOJIS[1494]),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}👩", &crate::gen::EMOJIS[1987]),
+        ("👨🏽\u{200d}💻", &crate::gen::EMOJIS[1009]),
+        ("🧑🏽\u{200d}❤\u{200d}🧑🏾", &crate::gen::EMOJIS[1984]),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏾", &crate::gen::EMOJIS[1979]),
+        ("🤸🏽", &crate::gen::EMOJIS[1849]),
+        ("🙇🏼\u{200d}♀", &crate::gen::EMOJIS[780]),
+        ("🙆🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[707]),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏾", &crate::gen::EMOJIS[1986]),
+        ("🐓", &crate::gen::EMOJIS[2087]),
+        ("🌭", &crate::gen::EMOJIS[2223]),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", &crate::gen::EMOJIS[1970]),
+        ("🚺", &crate::gen::EMOJIS[2873]),
+        ("🧍", &crate::gen::EMOJIS[1523]),
+        ("🏊🏻\u{200d}♀", &crate::gen::EMOJIS[1769]),
+        ("👕", &crate::gen::EMOJIS[2613]),
+        ("🔶", &crate::gen::EMOJIS[3081]),
+        ("👛", &crate::gen::EMOJIS[2628]),
+        ("🧜🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1440]),
+        ("👩🏽\u{200d}❤\u{200d}👩🏽", &crate::gen::EMOJIS[1987]),
+        ("🏐", &crate::gen::EMOJIS[2553]),
+        ("🚾", &crate::gen::EMOJIS[2876]),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏾", &crate::gen::EMOJIS[1958]),
+        ("👰🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1259]),
+        ("🔝", &crate::gen::EMOJIS[2914]),
+        ("👨🏽\u{200d}⚖", &crate::gen::EMOJIS[883]),
+        ("🍘", &crate::gen::EMOJIS[2242]),
+        ("🚁", &crate::gen::EMOJIS[2435]),
+        ("🏄🏾\u{200d}♂", &crate::gen::EMOJIS[1730]),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}👨🏻", &crate::gen::EMOJIS[1986]),
+        ("🇰🇳", &crate::gen::EMOJIS[3224]),
+        ("🪅", &crate::gen::EMOJIS[2589]),
+        ("🛎\u{fe0f}", &crate::gen::EMOJIS[2442]),
+        ("👨🏾\u{200d}🦰", &crate::gen::EMOJIS[536]),
+        ("🚵🏾\u{200d}♂", &crate::gen::EMOJIS[1838]),
+        ("😤", &crate::gen::EMOJIS[99]),
+        ("😪", &crate::gen::EMOJIS[52]),
+        ("🧑\u{200d}🏭", &crate::gen::EMOJIS[946]),
+        ("⏳", &crate::gen::EMOJIS[2445]),
+        ("🇨🇴", &crate::gen::EMOJIS[3148]),
+        ("🕴🏽", &crate::gen::EMOJIS[1646]),
+        ("☯\u{fe0f}", &crate::gen::EMOJIS[2920]),
+        ("👩🏼\u{200d}🏫", &crate::gen::EMOJIS[870]),
+        ("🇼🇫", &crate::gen::EMOJIS[3349]),
+        ("🇾🇹", &crate::gen::EMOJIS[3353]),
+        ("⛹🏻", &crate::gen::EMOJIS[1775]),
+        ("🇦🇹", &crate::gen::EMOJIS[3111]),
+        ("⛴\u{fe0f}", &crate::gen::EMOJIS[2426]),
+        ("🇲🇾", &crate::gen::EMOJIS[3262]),
+        ("💼", &crate::gen::EMOJIS[2766]),
+        ("🦈", &crate::gen::EMOJIS[2122]),
+        ("👳🏻", &crate::gen::EMOJIS[1205]),
+        ("🙅🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[685]),
+        ("🚴🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1819]),
+        ("💇🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1495]),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏻", &crate::gen::EMOJIS[1986]),
+        ("🦘", &crate::gen::EMOJIS[2082]),
+        ("📥", &crate::gen::EMOJIS[2751]),
+        ("㊙\u{fe0f}", &crate::gen::EMOJIS[3054]),
+        ("🕳", &crate::gen::EMOJIS[159]),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1977]),
+        ("🤷🏻", &crate::gen::EMOJIS[803]),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1977]),
+        ("🤜🏽", &crate::gen::EMOJIS[361]),
+        ("👨🏾\u{200d}❤\u{200d}👨🏽", &crate::gen::EMOJIS[1986]),
+        ("🖌", &crate::gen::EMOJIS[2763]),
+        ("💇\u{200d}♀", &crate::gen::EMOJIS[1499]),
+        ("🧕🏻", &crate::gen::EMOJIS[1229]),
+        ("🧖🏼\u{200d}♀", &crate::gen::EMOJIS[1666]),
+        ("📔", &crate::gen::EMOJIS[2719]),
+        ("🇭🇲", &crate::gen::EMOJIS[3199]),
+        ("🌇", &crate::gen::EMOJIS[2361]),
+        ("🏄🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1729]),
+        ("🧑\u{200d}🚀", &crate::gen::EMOJIS[1072]),
+        ("🥷🏾", &crate::gen::EMOJIS[1166]),
+        ("💌", &crate::gen::EMOJIS[127]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏽", &crate::gen::EMOJIS[1985]),
+        ("⛹\u{fe0f}\u{200d}♂", &crate::gen::EMOJIS[1780]),
+        ("🧚🏿\u{200d}♀", &crate::gen::EMOJIS[1407]),
+        ("🤴🏾", &crate::gen::EMOJIS[1196]),
+        ("⛹🏼\u{200d}♀", &crate::gen::EMOJIS[1788]),
+        ("👮🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1125]),
+        ("👩\u{200d}❤\u{200d}👨", &crate::gen::EMOJIS[1985]),
+        ("🕴\u{fe0f}", &crate::gen::EMOJIS[1643]),
+        ("👩\u{200d}👩\u{200d}👧", &crate::gen::EMOJIS[2000]),
+        ("🪯", &crate::gen::EMOJIS[2927]),
+        ("🤽🏾\u{200d}♀", &crate::gen::EMOJIS[1883]),
+        ("🧑🏼\u{200d}🦯", &crate::gen::EMOJIS[1561]),
+        ("🧑\u{200d}🎤", &crate::gen::EMOJIS[1018]),
+        ("⛏", &crate::gen::EMOJIS[2797]),
+        ("🧚🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1407]),
+        ("🏃🏻", &crate::gen::EMOJIS[1614]),
+        ("🏢", &crate::gen::EMOJIS[2331]),
+        ("🈯", &crate::gen::EMOJIS[3044]),
+        ("🎅🏼", &crate::gen::EMOJIS[1320]),
+        ("👩🏻\u{200d}💼", &crate::gen::EMOJIS[977]),
+        ("🏟\u{fe0f}", &crate::gen::EMOJIS[2320]),
+        ("👩🏽\u{200d}🍼", &crate::gen::EMOJIS[1297]),
+        ("👩🏾\u{200d}❤\u{200d}👩🏼", &crate::gen::EMOJIS[1987]),
+        ("😶\u{200d}🌫\u{fe0f}", &crate::gen::EMOJIS[42]),
+        ("💩", &crate::gen::EMOJIS[107]),
+        ("🥷🏼", &crate::gen::EMOJIS[1164]),
+        ("🈂\u{fe0f}", &crate::gen::EMOJIS[3041]),
+        ("⛲", &crate::gen::EMOJIS[2353]),
+        ("💇🏿\u{200d}♂", &crate::gen::EMOJIS[1498]),
+        ("👨🏽\u{200d}🎨", &crate::gen::EMOJIS[1045]),
+        ("🦹🏽", &crate::gen::EMOJIS[1357]),
+        ("💂🏽\u{200d}♂", &crate::gen::EMOJIS[1153]),
+        ("🔯", &crate::gen::EMOJIS[2926]),
+        ("👮🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1119]),
+        ("🛡\u{fe0f}", &crate::gen::EMOJIS[2805]),
+        ("🇰🇬", &crate::gen::EMOJIS[3220]),
+        ("🧝🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1460]),
+        ("👺", &crate::gen::EMOJIS[110]),
+        ("🛎", &crate::gen::EMOJIS[2442]),
+        ("🤹🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1919]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1976]),
+        ("✒", &crate::gen::EMOJIS[2760]),
+        ("👨\u{200d}⚕", &crate::gen::EMOJIS[826]),
+        ("👨🏽\u{200d}🍼", &crate::gen::EMOJIS[1303]),
+        ("👷🏾\u{200d}♂", &crate::gen::EMOJIS[1178]),
+        ("🧚🏾\u{200d}♀", &crate::gen::EMOJIS[1406]),
+        ("👨🏼\u{200d}🎓", &crate::gen::EMOJIS[846]),
+        ("🧾", &crate::gen::EMOJIS[2744]),
+        ("😗", &crate::gen::EMOJIS[18]),
+        ("🫅🏽", &crate::gen::EMOJIS[1189]),
+        ("🦿", &crate::gen::EMOJIS[431]),
+        ("👈🏾", &crate::gen::EMOJIS[290]),
+        ("👬", &crate::gen::EMOJIS[1964]),
+        ("↘\u{fe0f}", &crate::gen::EMOJIS[2897]),
+        ("🏌\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1714]),
+        ("🪀", &crate::gen::EMOJIS[2577]),
+        ("🧘🏽", &crate::gen::EMOJIS[1924]),
+        ("🛌🏾", &crate::gen::EMOJIS[1949]),
+        ("🤽🏼", &crate::gen::EMOJIS[1869]),
+        ("💆🏻\u{200d}♂", &crate::gen::EMOJIS[1476]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏽", &crate::gen::EMOJIS[1978]),
+        ("🍃", &crate::gen::EMOJIS[2167]),
+        ("🦻🏽", &crate::gen::EMOJIS[453]),
+        ("😫", &crate::gen::EMOJIS[97]),
+        ("🏃🏻\u{200d}♂", &crate::gen::EMOJIS[1620]),
+        ("🚵🏼\u{200d}♀", &crate::gen::EMOJIS[1842]),
+        ("💅🏻", &crate::gen::EMOJIS[413]),
+        ("👨🏿\u{200d}🏭", &crate::gen::EMOJIS[957]),
+        ("👨\u{200d}🍳", &crate::gen::EMOJIS[916]),
+        ("🤾🏼\u{200d}♂", &crate::gen::EMOJIS[1893]),
+        ("🦹🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1363]),
+        ("👨🏻\u{200d}🍼", &crate::gen::EMOJIS[1301]),
+        ("🇪🇷", &crate::gen::EMOJIS[3169]),
+        ("🦹🏽\u{200d}♂", &crate::gen::EMOJIS[1363]),
+        ("🏋🏼\u{200d}♂", &crate::gen::EMOJIS[1800]),
+        ("⏲\u{fe0f}", &crate::gen::EMOJIS[2449]),
+        ("👓", &crate::gen::EMOJIS[2607]),
+        ("🙇🏿\u{200d}♂", &crate::gen::EMOJIS[777]),
+        ("💏🏿", &crate::gen::EMOJIS[1975]),
+        ("🚶🏼", &crate::gen::EMOJIS[1507]),
+        ("🪿", &crate::gen::EMOJIS[2105]),
+        ("😊", &crate::gen::EMOJIS[12]),
+        ("🧍🏻\u{200d}♂", &crate::gen::EMOJIS[1530]),
+        ("🥮", &crate::gen::EMOJIS[2253]),
+        ("🛰", &crate::gen::EMOJIS[2439]),
+        ("💂🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1153]),
+        ("☔", &crate::gen::EMOJIS[2512]),
+        ("🖥", &crate::gen::EMOJIS[2693]),
+        ("🇿🇦", &crate::gen::EMOJIS[3354]),
+        ("😲", &crate::gen::EMOJIS[80]),
+        ("📶", &crate::gen::EMOJIS[2962]),
+        ("🚴🏻\u{200d}♂", &crate::gen::EMOJIS[1817]),
+        ("🩻", &crate::gen::EMOJIS[2833]),
+        ("👼🏼", &crate::gen::EMOJIS[1314]),
+        ("✌🏾", &crate::gen::EMOJIS[254]),
+        ("🇪🇦", &crate::gen::EMOJIS[3164]),
+        ("🧑🏼\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[822]),
+        ("🚵\u{200d}♂", &crate::gen::EMOJIS[1834]),
+        ("🧔🏿\u{200d}♂", &crate::gen::EMOJIS[525]),
+        ("🤾🏾", &crate::gen::EMOJIS[1889]),
+        ("‼\u{fe0f}", &crate::gen::EMOJIS[2975]),
+        ("🍿", &crate::gen::EMOJIS[2237]),
+        ("🧵", &crate::gen::EMOJIS[2603]),
+        ("🙆\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[700]),
+        ("🧶", &crate::gen::EMOJIS[2605]),
+        ("🛤\u{fe0f}", &crate::gen::EMOJIS[2411]),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", &crate::gen::EMOJIS[1970]),
+        ("🫤", &crate::gen::EMOJIS[74]),
+        ("🏊🏼\u{200d}♀", &crate::gen::EMOJIS[1770]),
+        ("🧎🏽", &crate::gen::EMOJIS[1544]),
+        ("👩\u{200d}🏫", &crate::gen::EMOJIS[868]),
+        ("🧒🏻", &crate::gen::EMOJIS[479]),
+        ("🚦", &crate::gen::EMOJIS[2417]),
+        ("🇲🇰", &crate::gen::EMOJIS[3248]),
+        ("🚄", &crate::gen::EMOJIS[2372]),
+        ("👩\u{200d}💼", &crate::gen::EMOJIS[976]),
+        ("🧑🏿\u{200d}🦽", &crate::gen::EMOJIS[1600]),
+        ("🗄\u{fe0f}", &crate::gen::EMOJIS[2787]),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏾", &crate::gen::EMOJIS[1970]),
+        ("🙋", &crate::gen::EMOJIS[730]),
+        ("👨🏻\u{200d}🎓", &crate::gen::EMOJIS[845]),
+        ("👨🏽\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[829]),
+        ("7\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3014]),
+        ("🙍🏽\u{200d}♂", &crate::gen::EMOJIS[649]),
+        ("🥒", &crate::gen::EMOJIS[2197]),
+        ("👨🏾\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[884]),
+        ("👷\u{200d}♂", &crate::gen::EMOJIS[1174]),
+        ("🧎🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1554]),
+        ("🤽🏻\u{200d}♂", &crate::gen::EMOJIS[1874]),
+        ("👱🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[618]),
+        ("👲🏼", &crate::gen::EMOJIS[1224]),
+        ("🇲🇼", &crate::gen::EMOJIS[3260]),
+        ("🧜🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1435]),
+        ("🧘🏼\u{200d}♀", &crate::gen::EMOJIS[1935]),
+        ("🧏🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[758]),
+        ("🇧🇱", &crate::gen::EMOJIS[3125]),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏼", &crate::gen::EMOJIS[1979]),
+        ("💁🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[719]),
+        ("🧑🏾\u{200d}💼", &crate::gen::EMOJIS[968]),
+        ("🤞🏻", &crate::gen::EMOJIS[257]),
+        ("🌂", &crate::gen::EMOJIS[2510]),
+        ("🌓", &crate::gen::EMOJIS[2477]),
+        ("🍇", &crate::gen::EMOJIS[2171]),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏾", &crate::gen::EMOJIS[1984]),
+        ("🧑🏻\u{200d}⚕", &crate::gen::EMOJIS[821]),
+        ("🦶🏻", &crate::gen::EMOJIS[439]),
+        ("🧄", &crate::gen::EMOJIS[2200]),
+        ("🪶", &crate::gen::EMOJIS[2099]),
+        ("🛋", &crate::gen::EMOJIS[2839]),
+        ("✈\u{fe0f}", &crate::gen::EMOJIS[2429]),
+        ("🛌🏻", &crate::gen::EMOJIS[1946]),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏽", &crate::gen::EMOJIS[1958]),
+        ("🏯", &crate::gen::EMOJIS[2342]),
+        ("🙇", &crate::gen::EMOJIS[766]),
+        ("🙍\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[646]),
+        ("🧝🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1452]),
+        ("🇸🇦", &crate::gen::EMOJIS[3297]),
+        ("🚵🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1838]),
+        ("🤌🏽", &crate::gen::EMOJIS[241]),
+        ("👱🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[619]),
+        ("🧑🏿\u{200d}🎨", &crate::gen::EMOJIS[1041]),
+        ("👩🏾\u{200d}❤\u{200d}👨🏾", &crate::gen::EMOJIS[1985]),
+        ("🤜🏻", &crate::gen::EMOJIS[359]),
+        ("👷🏿", &crate::gen::EMOJIS[1173]),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏾", &crate::gen::EMOJIS[1964]),
+        ("🤸\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1858]),
+        ("🏋\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1798]),
+        ("👨\u{200d}👩\u{200d}👧\u{200d}👧", &crate::gen::EMOJIS[1993]),
+        ("👩🏻\u{200d}🤝\u{200d}👨🏽", &crate::gen::EMOJIS[1958]),
+        ("🧑\u{200d}🔧", &crate::gen::EMOJIS[928]),
+        ("👩🏾\u{200d}❤\u{200d}👨🏽", &crate::gen::EMOJIS[1985]),
+        ("👨\u{200d}👩\u{200d}👦", &crate::gen::EMOJIS[1989]),
+        ("🪒", &crate::gen::EMOJIS[2846]),
+        ("🦃", &crate::gen::EMOJIS[2085]),
+        ("🏌🏾", &crate::gen::EMOJIS[1706]),
+        ("⚙\u{fe0f}", &crate::gen::EMOJIS[2810]),
+        ("🧑🏾\u{200d}🏭", &crate::gen::EMOJIS[950]),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1976]),
+        ("🙍🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[649]),
+        ("🍎", &crate::gen::EMOJIS[2179]),
+        ("🏃\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1625]),
+        ("🐱", &crate::gen::EMOJIS[2031]),
+        ("🇴🇲", &crate::gen::EMOJIS[3276]),
+        ("🉐", &crate::gen::EMOJIS[3045]),
+        ("🧑🏿\u{200d}❤\u{200d}💋\u{200d}🧑🏻", &crate::gen::EMOJIS[1975]),
+        ("🤽🏻\u{200d}♀", &crate::gen::EMOJIS[1880]),
+        ("🏃🏻\u{200d}♀", &crate::gen::EMOJIS[1626]),
+        ("🧑🏼\u{200d}⚖", &crate::gen::EMOJIS[876]),
+        ("🗨", &crate::gen::EMOJIS[162]),
+        ("🐄", &crate::gen::EMOJIS[2049]),
+        ("👨🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1977]),
+        ("‼", &crate::gen::EMOJIS[2975]),
+        ("🏌🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1712]),
+        ("👨🏼\u{200d}❤\u{200d}👨🏾", &crate::gen::EMOJIS[1986]),
+        ("🚀", &crate::gen::EMOJIS[2440]),
+        ("👩🏼\u{200d}🚒", &crate::gen::EMOJIS[1104]),
+        ("👆🏼", &crate::gen::EMOJIS[300]),
+        ("🌍", &crate::gen::EMOJIS[2304]),
+        ("⭕", &crate::gen::EMOJIS[2990]),
+        ("🫲", &crate::gen::EMOJIS[202]),
+        ("💬", &crate::gen::EMOJIS[160]),
+        ("👩🏼\u{200d}🤝\u{200d}👩🏻", &crate::gen::EMOJIS[1952]),
+        ("🥹", &crate::gen::EMOJIS[83]),
+        ("👳\u{200d}♂", &crate::gen::EMOJIS[1210]),
+        ("🌽", &crate::gen::EMOJIS[2194]),
+        ("🦥", &crate::gen::EMOJIS[2079]),
+        ("☑", &crate::gen::EMOJIS[2992]),
+        ("👨🏾\u{200d}🦯", &crate::gen::EMOJIS[1569]),
+        ("👩🏿\u{200d}💻", &crate::gen::EMOJIS[1017]),
+        ("✌🏻", &crate::gen::EMOJIS[251]),
+        ("🗝", &crate::gen::EMOJIS[2794]),
+        ("👷🏼\u{200d}♀", &crate::gen::EMOJIS[1182]),
+        ("🎅🏻", &crate::gen::EMOJIS[1319]),
+        ("⛈\u{fe0f}", &crate::gen::EMOJIS[2498]),
+        ("🇧🇳", &crate::gen::EMOJIS[3127]),
+        ("🫰", &crate::gen::EMOJIS[262]),
+        ("🚠", &crate::gen::EMOJIS[2437]),
+        ("👉🏿", &crate::gen::EMOJIS[297]),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏾", &crate::gen::EMOJIS[1951]),
+        ("🐮", &crate::gen::EMOJIS[2046]),
+        ("📸", &crate::gen::EMOJIS[2709]),
+        ("👨🏿\u{200d}🎤", &crate::gen::EMOJIS[1029]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1976]),
+        ("🐽", &crate::gen::EMOJIS[2053]),
+        ("🙎🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[672]),
+        ("👩🏽\u{200d}✈", &crate::gen::EMOJIS[1069]),
+        ("💑🏾", &crate::gen::EMOJIS[1983]),
+        ("🙋🏾\u{200d}♀", &crate::gen::EMOJIS[746]),
+        ("👮🏿\u{200d}♀", &crate::gen::EMOJIS[1125]),
+        ("🔉", &crate::gen::EMOJIS[2656]),
+        ("👨🏻\u{200d}❤\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1977]),
+        ("🧗\u{200d}♀", &crate::gen::EMOJIS[1682]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1976]),
+        ("👨🏻\u{200d}🍳", &crate::gen::EMOJIS[917]),
+        ("🇵🇷", &crate::gen::EMOJIS[3286]),
+        ("🏠", &crate::gen::EMOJIS[2329]),
+        ("💁🏿\u{200d}♀", &crate::gen::EMOJIS[729]),
+        ("🤵\u{200d}♂", &crate::gen::EMOJIS[1240]),
+        ("👨🏾\u{200d}🚀", &crate::gen::EMOJIS[1082]),
+        ("🎛\u{fe0f}", &crate::gen::EMOJIS[2668]),
+        ("🧳", &crate::gen::EMOJIS[2443]),
+        ("🪲", &crate::gen::EMOJIS[2132]),
+        ("😙", &crate::gen::EMOJIS[21]),
+        ("🧝🏽\u{200d}♂", &crate::gen::EMOJIS[1453]),
+        ("👞", &crate::gen::EMOJIS[2634]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏻", &crate::gen::EMOJIS[1976]),
+        ("✊🏼", &crate::gen::EMOJIS[342]),
+        ("💎", &crate::gen::EMOJIS[2653]),
+        ("✋🏿", &crate::gen::EMOJIS[189]),
+        ("🕵🏿\u{200d}♂", &crate::gen::EMOJIS[1137]),
+        ("👯\u{200d}♀", &crate::gen::EMOJIS[1651]),
+        ("👨🏽\u{200d}🔧", &crate::gen::EMOJIS[937]),
+        ("🔬", &crate::gen::EMOJIS[2824]),
+        ("👨🏽\u{200d}🦱", &crate::gen::EMOJIS[541]),
+        ("🤔", &crate::gen::EMOJIS[34]),
+        ("🧔🏽", &crate::gen::EMOJIS[517]),
+        ("👨🏽\u{200d}❤\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1977]),
+        ("👳🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1221]),
+        ("🖼", &crate::gen::EMOJIS[2601]),
+        ("👁\u{fe0f}", &crate::gen::EMOJIS[468]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏿", &crate::gen::EMOJIS[1987]),
+        ("🏄🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1727]),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏽", &crate::gen::EMOJIS[1979]),
+        ("🕎", &crate::gen::EMOJIS[2925]),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏻", &crate::gen::EMOJIS[1978]),
+        ("🤸\u{200d}♀", &crate::gen::EMOJIS[1858]),
+        ("🧑🏾\u{200d}🦱", &crate::gen::EMOJIS[584]),
+        ("🟧", &crate::gen::EMOJIS[3067]),
+        ("👩🏼\u{200d}❤\u{200d}👨🏼", &crate::gen::EMOJIS[1985]),
+        ("🍣", &crate::gen::EMOJIS[2250]),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏻", &crate::gen::EMOJIS[1986]),
+        ("🟡", &crate::gen::EMOJIS[3059]),
+        ("🧙🏼\u{200d}♀", &crate::gen::EMOJIS[1386]),
+        ("👉", &crate::gen::EMOJIS[292]),
+        ("🤵🏽\u{200d}♀", &crate::gen::EMOJIS[1249]),
+        ("🥭", &crate::gen::EMOJIS[2178]),
+        ("🤖", &crate::gen::EMOJIS[114]),
+        ("🔽", &crate::gen::EMOJIS[2953]),
+        ("🧑🏻\u{200d}🎨", &crate::gen::EMOJIS[1037]),
+        ("🫎", &crate::gen::EMOJIS[2039]),
+        ("👐🏾", &crate::gen::EMOJIS[386]),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏻", &crate::gen::EMOJIS[1952]),
+        ("👆", &crate::gen::EMOJIS[298]),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1977]),
+        ("🌧\u{fe0f}", &crate::gen::EMOJIS[2502]),
+        ("💁🏽\u{200d}♀", &crate::gen::EMOJIS[727]),
+        ("🇷🇼", &crate::gen::EMOJIS[3296]),
+        ("📹", &crate::gen::EMOJIS[2710]),
+        ("🏋\u{fe0f}", &crate::gen::EMOJIS[1792]),
+        ("👩🏿\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[891]),
+        ("🏃🏼\u{200d}♀", &crate::gen::EMOJIS[1627]),
+        ("🙋🏽", &crate::gen::EMOJIS[733]),
+        ("🗞", &crate::gen::EMOJIS[2732]),
+        ("👩\u{200d}👧\u{200d}👦", &crate::gen::EMOJIS[2012]),
+        ("😎", &crate::gen::EMOJIS[70]),
+        ("🤶🏽", &crate::gen::EMOJIS[1327]),
+        ("🍽\u{fe0f}", &crate::gen::EMOJIS[2298]),
+        ("🦵🏾", &crate::gen::EMOJIS[436]),
+        ("🫲🏼", &crate::gen::EMOJIS[204]),
+        ("🦆", &crate::gen::EMOJIS[2095]),
+        ("4\u{20e3}", &crate::gen::EMOJIS[3011]),
+        ("☝", &crate::gen::EMOJIS[316]),
+        ("🧑🏾\u{200d}🚀", &crate::gen::EMOJIS[1076]),
+        ("🤽🏿\u{200d}♂", &crate::gen::EMOJIS[1878]),
+        ("🚶🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1520]),
+        ("🦑", &crate::gen::EMOJIS[2261]),
+        ("☝🏽", &crate::gen::EMOJIS[319]),
+        ("📙", &crate::gen::EMOJIS[2724]),
+        ("🚴🏼\u{200d}♂", &crate::gen::EMOJIS[1818]),
+        ("👩🏿\u{200d}❤\u{200d}👨🏻", &crate::gen::EMOJIS[1985]),
+        ("💂🏼", &crate::gen::EMOJIS[1146]),
+        ("🙅🏽", &crate::gen::EMOJIS[679]),
+        ("😶\u{200d}🌫", &crate::gen::EMOJIS[42]),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏻", &crate::gen::EMOJIS[1958]),
+        ("🫃🏼", &crate::gen::EMOJIS[1278]),
+        ("👩🏿\u{200d}🦳", &crate::gen::EMOJIS[591]),
+        ("8\u{fe0f}\u{20e3}", &crate::gen::EMOJIS[3015]),
+        ("💃🏼", &crate::gen::EMOJIS[1633]),
+        ("🤹", &crate::gen::EMOJIS[1903]),
+        ("🤏🏻", &crate::gen::EMOJIS[245]),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏾", &crate::gen::EMOJIS[1979]),
+        ("🤼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1865]),
+        ("⤴\u{fe0f}", &crate::gen::EMOJIS[2906]),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏿", &crate::gen::EMOJIS[1970]),
+        ("🔂", &crate::gen::EMOJIS[2943]),
+        ("🫚", &crate::gen::EMOJIS[2205]),
+        ("🤽🏽\u{200d}♂", &crate::gen::EMOJIS[1876]),
+        ("👨\u{200d}🔬", &crate::gen::EMOJIS[988]),
+        ("🧔🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[531]),
+        ("🧙🏽\u{200d}♂", &crate::gen::EMOJIS[1381]),
+        ("🤷🏼\u{200d}♂", &crate::gen::EMOJIS[810]),
+        ("🙇\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[772]),
+        ("✌🏽", &crate::gen::EMOJIS[253]),
+        ("🫱🏿\u{200d}🫲🏻", &crate::gen::EMOJIS[394]),
+        ("🚣🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1749]),
+        ("🧙🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1379]),
+        ("🧘🏻", &crate::gen::EMOJIS[1922]),
+        ("🚶🏾", &crate::gen::EMOJIS[1509]),
+        ("🇹🇿", &crate::gen::EMOJIS[3334]),
+        ("🤿", &crate::gen::EMOJIS[2571]),
+        ("👷🏿\u{200d}♂", &crate::gen::EMOJIS[1179]),
+        ("🍨", &crate::gen::EMOJIS[2265]),
+        ("🇱🇨", &crate::gen::EMOJIS[3232]),
+        ("🚔", &crate::gen::EMOJIS[2389]),
+        ("🦸🏽\u{200d}♂", &crate::gen::EMOJIS[1345]),
+        ("🆑", &crate::gen::EMOJIS[3026]),
+        ("💁🏻\u{200d}♂", &crate::gen::EMOJIS[719]),
+        ("🙏🏽", &crate::gen::EMOJIS[403]),
+        ("🗣", &crate::gen::EMOJIS[2014]),
+        ("🇧🇪", &crate::gen::EMOJIS[3119]),
+        ("☮\u{fe0f}", &crate::gen::EMOJIS[2924]),
+        ("🧙", &crate::gen::EMOJIS[1372]),
+        ("👨\u{200d}👨\u{200d}👧", &crate::gen::EMOJIS[1995]),
+        ("💇🏼", &crate::gen::EMOJIS[1489]),
+        ("😒", &crate::gen::EMOJIS[44]),
+        ("👨🏻\u{200d}🔬", &crate::gen::EMOJIS[989]),
+        ("💆", &crate::gen::EMOJIS[1469]),
+        ("🔓", &crate::gen::EMOJIS[2790]),
+        ("🅿\u{fe0f}", &crate::gen::EMOJIS[3036]),
+        ("👳🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1218]),
+        ("🧏🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[759]),
+        ("⛷", &crate::gen::EMOJIS[1695]),
+        ("🇺🇲", &crate::gen::EMOJIS[3337]),
+        ("🎼", &crate::gen::EMOJIS[2663]),
+        ("🧘🏾\u{200d}♀", &crate::gen::EMOJIS[1937]),
+        ("🫱🏼", &crate::gen::EMOJIS[198]),
+        ("🫴", &crate::gen::EMOJIS[214]),
+        ("🍠", &crate::gen::EMOJIS[2248]),
+        ("😄", &crate::gen::EMOJIS[2]),
+        ("🧑🏾\u{200d}🤝\u{200d}🧑🏾", &crate::gen::EMOJIS[1951]),
+        ("📦", &crate::gen::EMOJIS[2752]),
+        ("🧙🏻\u{200d}♀", &crate::gen::EMOJIS[1385]),
+        ("📮", &crate::gen::EMOJIS[2757]),
+        ("🧏", &crate::gen::EMOJIS[748]),
+        ("🇹🇼", &crate::gen::EMOJIS[3333]),
+        ("🔹", &crate::gen::EMOJIS[3084]),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏻", &crate::gen::EMOJIS[1958]),
+        ("🥛", &crate::gen::EMOJIS[2278]),
+        ("❣", &crate::gen::EMOJIS[136]),
+        ("🧜🏼\u{200d}♂", &crate::gen::EMOJIS[1434]),
+        ("👩🏼\u{200d}🚀", &crate::gen::EMOJIS[1086]),
+        ("👩🏽\u{200d}🏭", &crate::gen::EMOJIS[961]),
+        ("🍓", &crate::gen::EMOJIS[2184]),
+        ("👷🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1184]),
+        ("👦", &crate::gen::EMOJIS[484]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👩🏽", &crate::gen::EMOJIS[1987]),
+        ("🙇🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[783]),
+        ("🤹🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1920]),
+        ("🚣🏿\u{200d}♀", &crate::gen::EMOJIS[1755]),
+        ("🐢", &crate::gen::EMOJIS[2108]),
+        ("🇸🇿", &crate::gen::EMOJIS[3317]),
+        ("⛹🏿\u{200d}♀", &crate::gen::EMOJIS[1791]),
+        ("✨", &crate::gen::EMOJIS[2527]),
+        ("🛗", &crate::gen::EMOJIS[2835]),
+        ("👨🏽\u{200d}🎤", &crate::gen::EMOJIS[1027]),
+        ("👩\u{200d}❤\u{fe0f}\u{200d}👨", &crate::gen::EMOJIS[1985]),
+        ("🧍\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1529]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}👩🏾", &crate::gen::EMOJIS[1987]),
+        ("🇩🇴", &crate::gen::EMOJIS[3162]),
+        ("😵", &crate::gen::EMOJIS[64]),
+        ("🇳🇿", &crate::gen::EMOJIS[3275]),
+        ("🎸", &crate::gen::EMOJIS[2674]),
+        ("👼🏿", &crate::gen::EMOJIS[1317]),
+        ("👨🏻\u{200d}❤\u{200d}👨🏾", &crate::gen::EMOJIS[1986]),
+        ("💜", &crate::gen::EMOJIS[147]),
+        ("🥞", &crate::gen::EMOJIS[2213]),
+        ("👩🏼\u{200d}🔬", &crate::gen::EMOJIS[996]),
+        ("⌨", &crate::gen::EMOJIS[2695]),
+        ("🎷", &crate::gen::EMOJIS[2672]),
+        ("👔", &crate::gen::EMOJIS[2612]),
+        ("🐘", &crate::gen::EMOJIS[2061]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1976]),
+        ("🙋\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[742]),
+        ("👐🏽", &crate::gen::EMOJIS[385]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏼", &crate::gen::EMOJIS[1985]),
+        ("🧗🏾", &crate::gen::EMOJIS[1674]),
+        ("🇹🇭", &crate::gen::EMOJIS[3323]),
+        ("👩🏼\u{200d}🍼", &crate::gen::EMOJIS[1296]),
+        ("👴🏿", &crate::gen::EMOJIS[633]),
+        ("〽\u{fe0f}", &crate::gen::EMOJIS[2998]),
+        ("🐔", &crate::gen::EMOJIS[2086]),
+        ("👍", &crate::gen::EMOJIS[328]),
+        ("🏵\u{fe0f}", &crate::gen::EMOJIS[2147]),
+        ("🧍🏼\u{200d}♂", &crate::gen::EMOJIS[1531]),
+        ("👏🏻", &crate::gen::EMOJIS[365]),
+        ("⛹🏽", &crate::gen::EMOJIS[1777]),
+        ("🐷", &crate::gen::EMOJIS[2050]),
+        ("🙍🏼", &crate::gen::EMOJIS[642]),
+        ("🧚\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1396]),
+        ("⬇", &crate::gen::EMOJIS[2898]),
+        ("👨🏼\u{200d}🦰", &crate::gen::EMOJIS[534]),
+        ("🙍🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[647]),
+        ("👨🏼\u{200d}🎨", &crate::gen::EMOJIS[1044]),
+        ("👩🏾\u{200d}🔧", &crate::gen::EMOJIS[944]),
+        ("🔪", &crate::gen::EMOJIS[2301]),
+        ("🥺", &crate::gen::EMOJIS[82]),
+        ("⛵", &crate::gen::EMOJIS[2422]),
+        ("💪🏿", &crate::gen::EMOJIS[429]),
+        ("👭🏿", &crate::gen::EMOJIS[1957]),
+        ("💍", &crate::gen::EMOJIS[2652]),
+        ("🙇\u{200d}♂", &crate::gen::EMOJIS[772]),
+        ("🧑🏾\u{200d}🦲", &crate::gen::EMOJIS[608]),
+        ("📁", &crate::gen::EMOJIS[2767]),
+        ("🤝🏻", &crate::gen::EMOJIS[395]),
+        ("🏪", &crate::gen::EMOJIS[2338]),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏿", &crate::gen::EMOJIS[1979]),
+        ("💪🏼", &crate::gen::EMOJIS[426]),
+        ("🕗", &crate::gen::EMOJIS[2467]),
+        ("🚴🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1826]),
+        ("🇨🇩", &crate::gen::EMOJIS[3139]),
+        ("💇🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1501]),
+        ("🤷🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[811]),
+        ("🚼", &crate::gen::EMOJIS[2875]),
+        ("🅰", &crate::gen::EMOJIS[3023]),
+        ("🙇🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[782]),
+        ("🏕", &crate::gen::EMOJIS[2315]),
+        ("🫨", &crate::gen::EMOJIS[49]),
+        ("🙍\u{200d}♂", &crate::gen::EMOJIS[646]),
+        ("👩🏾\u{200d}🤝\u{200d}👨🏼", &crate::gen::EMOJIS[1958]),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1976]),
+        ("👨🏼\u{200d}🍼", &crate::gen::EMOJIS[1302]),
+        ("🏌\u{200d}♀", &crate::gen::EMOJIS[1714]),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏼", &crate::gen::EMOJIS[1970]),
+        ("🇧🇦", &crate::gen::EMOJIS[3116]),
+        ("♿", &crate::gen::EMOJIS[2871]),
+        ("🧑🏽\u{200d}🦼", &crate::gen::EMOJIS[1580]),
+        ("🙋🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[747]),
+        ("🇸🇮", &crate::gen::EMOJIS[3304]),
+        ("🧜🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1442]),
+        ("🖇\u{fe0f}", &crate::gen::EMOJIS[2782]),
+        ("🧖\u{200d}♀", &crate::gen::EMOJIS[1664]),
+        ("♣", &crate::gen::EMOJIS[2595]),
+        ("⏬", &crate::gen::EMOJIS[2954]),
+        ("🤜", &crate::gen::EMOJIS[358]),
+        ("🤸🏻\u{200d}♂", &crate::gen::EMOJIS[1853]),
+        ("⏮", &crate::gen::EMOJIS[2950]),
+        ("🤹🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1913]),
+        ("✒\u{fe0f}", &crate::gen::EMOJIS[2760]),
+        ("👨🏼\u{200d}🤝\u{200d}👨🏻", &crate::gen::EMOJIS[1964]),
+        ("👨🏾\u{200d}🚒", &crate::gen::EMOJIS[1100]),
+        ("☠", &crate::gen::EMOJIS[106]),
+        ("👳🏾\u{200d}♀", &crate::gen::EMOJIS[1220]),
+        ("🕺🏾", &crate::gen::EMOJIS[1641]),
+        ("✊🏽", &crate::gen::EMOJIS[343]),
+        ("🧑🏿", &crate::gen::EMOJIS[501]),
+        ("🧚🏼", &crate::gen::EMOJIS[1392]),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏿", &crate::gen::EMOJIS[1975]),
+        ("📊", &crate::gen::EMOJIS[2777]),
+        ("🤛", &crate::gen::EMOJIS[352]),
+        ("👨\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1060]),
+        ("🤸🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1855]),
+        ("🙍🏿", &crate::gen::EMOJIS[645]),
+        ("👨🏿\u{200d}🦼", &crate::gen::EMOJIS[1588]),
+        ("🤦🏿\u{200d}♀", &crate::gen::EMOJIS[801]),
+        ("🐿\u{fe0f}", &crate::gen::EMOJIS[2071]),
+        ("🤸🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1856]),
+        ("👩🏿\u{200d}❤\u{200d}👩🏻", &crate::gen::EMOJIS[1987]),
+        ("😺", &crate::gen::EMOJIS[115]),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1976]),
+        ("🕵🏻\u{200d}♀", &crate::gen::EMOJIS[1139]),
+        ("🤷🏾", &crate::gen::EMOJIS[806]),
+        ("🛐", &crate::gen::EMOJIS[2915]),
+        ("⬇\u{fe0f}", &crate::gen::EMOJIS[2898]),
+        ("🧓🏻", &crate::gen::EMOJIS[623]),
+        ("🚣🏻\u{200d}♂", &crate::gen::EMOJIS[1745]),
+        ("👇🏾", &crate::gen::EMOJIS[314]),
+        ("📨", &crate::gen::EMOJIS[2748]),
+        ("🪑", &crate::gen::EMOJIS[2840]),
+        ("📗", &crate::gen::EMOJIS[2722]),
+        ("🤽\u{200d}♂", &crate::gen::EMOJIS[1873]),
+        ("🤾🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1895]),
+        ("👩🏾\u{200d}⚕", &crate::gen::EMOJIS[836]),
+        ("👲🏾", &crate::gen::EMOJIS[1226]),
+        ("👎", &crate::gen::EMOJIS[334]),
+        ("🙋🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[740]),
+        ("🕶", &crate::gen::EMOJIS[2608]),
+        ("🥠", &crate::gen::EMOJIS[2256]),
+        ("🛬", &crate::gen::EMOJIS[2432]),
+        ("🚴🏾", &crate::gen::EMOJIS[1814]),
+        ("👮🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1115]),
+        ("👱🏽\u{200d}♀", &crate::gen::EMOJIS[613]),
+        ("🙋\u{200d}♂", &crate::gen::EMOJIS[736]),
+        ("👁\u{200d}🗨", &crate::gen::EMOJIS[161]),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏽", &crate::gen::EMOJIS[1958]),
+        ("☪\u{fe0f}", &crate::gen::EMOJIS[2923]),
+        ("👫", &crate::gen::EMOJIS[1958]),
+        ("🩸", &crate::gen::EMOJIS[2828]),
+        ("🌟", &crate::gen::EMOJIS[2493]),
+        ("🙋🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[745]),
+        ("🇵🇭", &crate::gen::EMOJIS[3281]),
+        ("🧍🏼", &crate::gen::EMOJIS[1525]),
+        ("🧮", &crate::gen::EMOJIS[2702]),
+        ("📈", &crate::gen::EMOJIS[2775]),
+        ("👷🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1176]),
+        ("🇳🇺", &crate::gen::EMOJIS[3274]),
+        ("👨🏿\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[831]),
+        ("👩\u{200d}❤\u{200d}💋\u{200d}👩", &crate::gen::EMOJIS[1978]),
+        ("😍", &crate::gen::EMOJIS[15]),
+        ("👨🏻\u{200d}🦲", &crate::gen::EMOJIS[551]),
+        ("🧏🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[756]),
+        ("♈", &crate::gen::EMOJIS[2928]),
+        ("🧑🏼\u{200d}🎓", &crate::gen::EMOJIS[840]),
+        ("☝\u{fe0f}", &crate::gen::EMOJIS[316]),
+        ("🤘🏻", &crate::gen::EMOJIS[275]),
+        ("🏏", &crate::gen::EMOJIS[2559]),
+        ("🇲🇻", &crate::gen::EMOJIS[3259]),
+        ("🎿", &crate::gen::EMOJIS[2573]),
+        ("☁\u{fe0f}", &crate::gen::EMOJIS[2496]),
+        ("⚗", &crate::gen::EMOJIS[2820]),
+        ("👩🏽\u{200d}🎤", &crate::gen::EMOJIS[1033]),
+        ("🐩", &crate::gen::EMOJIS[2027]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", &crate::gen::EMOJIS[1978]),
+        ("👩🏼\u{200d}🦳", &crate::gen::EMOJIS[588]),
+        ("🤙🏽", &crate::gen::EMOJIS[283]),
+        ("🖱", &crate::gen::EMOJIS[2696]),
+        ("🛳", &crate::gen::EMOJIS[2425]),
+        ("🇧🇧", &crate::gen::EMOJIS[3117]),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👩🏾", &crate::gen::EMOJIS[1978]),
+        ("🧖🏿\u{200d}♀", &crate::gen::EMOJIS[1669]),
+        ("🙇🏼\u{200d}♂", &crate::gen::EMOJIS[774]),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏾", &crate::gen::EMOJIS[1964]),
+        ("😋", &crate::gen::EMOJIS[23]),
+        ("👠", &crate::gen::EMOJIS[2638]),
+        ("👩🏼\u{200d}⚕", &crate::gen::EMOJIS[834]),
+        ("🕵🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1141]),
+        ("🏰", &crate::gen::EMOJIS[2343]),
+        ("🧗🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1684]),
+        ("🧚🏿\u{200d}♂", &crate::gen::EMOJIS[1401]),
+        ("🕙", &crate::gen::EMOJIS[2471]),
+        ("🏃🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1622]),
+        ("🧑🏻\u{200d}🍼", &crate::gen::EMOJIS[1307]),
+        ("🤰🏼", &crate::gen::EMOJIS[1272]),
+        ("⚜", &crate::gen::EMOJIS[2986]),
+        ("🇨🇷", &crate::gen::EMOJIS[3150]),
+        ("👨\u{200d}🍼", &crate::gen::EMOJIS[1300]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏾", &crate::gen::EMOJIS[1985]),
+        ("🧏🏽\u{200d}♀", &crate::gen::EMOJIS[763]),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}👨🏼", &crate::gen::EMOJIS[1986]),
+        ("🗼", &crate::gen::EMOJIS[2345]),
+        ("🧝🏻", &crate::gen::EMOJIS[1445]),
+        ("🤝🏿", &crate::gen::EMOJIS[399]),
+        ("🤞🏽", &crate::gen::EMOJIS[259]),
+        ("👶🏿", &crate::gen::EMOJIS[477]),
+        ("🏊🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1773]),
+        ("🧓🏾", &crate::gen::EMOJIS[626]),
+        ("🔥", &crate::gen::EMOJIS[2519]),
+        ("🏃\u{200d}♂", &crate::gen::EMOJIS[1619]),
+        ("🎙\u{fe0f}", &crate::gen::EMOJIS[2666]),
+        ("🦸🏽", &crate::gen::EMOJIS[1339]),
+        ("🍡", &crate::gen::EMOJIS[2254]),
+        ("🙋🏻\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[737]),
+        ("🪚", &crate::gen::EMOJIS[2806]),
+        ("🆚", &crate::gen::EMOJIS[3039]),
+        ("🥍", &crate::gen::EMOJIS[2562]),
+        ("🇲🇪", &crate::gen::EMOJIS[3244]),
+        ("🗞\u{fe0f}", &crate::gen::EMOJIS[2732]),
+        ("🤵🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1247]),
+        ("⚒", &crate::gen::EMOJIS[2798]),
+        ("🎑", &crate::gen::EMOJIS[2536]),
+        ("🦊", &crate::gen::EMOJIS[2029]),
+        ("🧗🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1679]),
+        ("🧑🏿\u{200d}🦰", &crate::gen::EMOJIS[573]),
+        ("🇨🇲", &crate::gen::EMOJIS[3146]),
+        ("🏳\u{fe0f}\u{200d}⚧\u{fe0f}", &crate::gen::EMOJIS[3097]),
+        ("👰🏽", &crate::gen::EMOJIS[1255]),
+        ("🧑🏻\u{200d}🤝\u{200d}🧑🏿", &crate::gen::EMOJIS[1951]),
+        ("🍥", &crate::gen::EMOJIS[2252]),
+        ("👍🏾", &crate::gen::EMOJIS[332]),
+        ("👨🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1977]),
+        ("👩🏼\u{200d}🌾", &crate::gen::EMOJIS[906]),
+        ("🧑🏾\u{200d}❤\u{200d}💋\u{200d}🧑🏼", &crate::gen::EMOJIS[1975]),
+        ("🙅🏻\u{200d}♂", &crate::gen::EMOJIS[683]),
+        ("👌", &crate::gen::EMOJIS[232]),
+        ("🤸🏾\u{200d}♀", &crate::gen::EMOJIS[1862]),
+        ("👩🏽\u{200d}❤\u{200d}👩🏻", &crate::gen::EMOJIS[1987]),
+        ("🦉", &crate::gen::EMOJIS[2097]),
+        ("👩🏿\u{200d}❤\u{200d}👨🏾", &crate::gen::EMOJIS[1985]),
+        ("🛩", &crate::gen::EMOJIS[2430]),
+        ("👩🏼\u{200d}🔧", &crate::gen::EMOJIS[942]),
+        ("🥬", &crate::gen::EMOJIS[2198]),
+        ("🐫", &crate::gen::EMOJIS[2058]),
+        ("👨🏾\u{200d}🤝\u{200d}👨🏼", &crate::gen::EMOJIS[1964]),
+        ("👨\u{200d}⚖", &crate::gen::EMOJIS[880]),
+        ("🇨🇬", &crate::gen::EMOJIS[3141]),
+        ("👱🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[614]),
+        ("🚵\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1834]),
+        ("🛫", &crate::gen::EMOJIS[2431]),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏻", &crate::gen::EMOJIS[1951]),
+        ("👨🏼\u{200d}🏫", &crate::gen::EMOJIS[864]),
+        ("🪜", &crate::gen::EMOJIS[2819]),
+        ("👭🏼", &crate::gen::EMOJIS[1954]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏽", &crate::gen::EMOJIS[1985]),
+        ("🧙🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1389]),
+        ("🦐", &crate::gen::EMOJIS[2260]),
+        ("⚫", &crate::gen::EMOJIS[3064]),
+        ("🏦", &crate::gen::EMOJIS[2335]),
+        ("🇧🇹", &crate::gen::EMOJIS[3132]),
+        ("🙆🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[704]),
+        ("👷🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1183]),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏿", &crate::gen::EMOJIS[1979]),
+        ("👰\u{200d}♂", &crate::gen::EMOJIS[1258]),
+        ("👩🏽\u{200d}🦰", &crate::gen::EMOJIS[565]),
+        ("🇨🇺", &crate::gen::EMOJIS[3151]),
+        ("🧑🏼\u{200d}❤\u{fe0f}\u{200d}🧑🏻", &crate::gen::EMOJIS[1979]),
+        ("🔦", &crate::gen::EMOJIS[2716]),
+        ("🐧", &crate::gen::EMOJIS[2092]),
+        ("🧑🏼\u{200d}🔧", &crate::gen::EMOJIS[930]),
+        ("👨\u{200d}👨\u{200d}👧\u{200d}👦", &crate::gen::EMOJIS[1996]),
+        ("👨\u{200d}💻", &crate::gen::EMOJIS[1006]),
+        ("💅🏼", &crate::gen::EMOJIS[414]),
+        ("🧗🏿", &crate::gen::EMOJIS[1675]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏾", &crate::gen::EMOJIS[1985]),
+        ("🪷", &crate::gen::EMOJIS[2146]),
+        ("🤌🏿", &crate::gen::EMOJIS[243]),
+        ("🧛🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1424]),
+        ("🧤", &crate::gen::EMOJIS[2616]),
+        ("🀄", &crate::gen::EMOJIS[2598]),
+        ("🕹", &crate::gen::EMOJIS[2584]),
+        ("🚵🏻\u{200d}♂", &crate::gen::EMOJIS[1835]),
+        ("🗓", &crate::gen::EMOJIS[2773]),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1976]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏿", &crate::gen::EMOJIS[1978]),
+        ("🤦🏾\u{200d}♂", &crate::gen::EMOJIS[794]),
+        ("🕵🏿\u{200d}♀", &crate::gen::EMOJIS[1143]),
+        ("🧗🏾\u{200d}♀", &crate::gen::EMOJIS[1686]),
+        ("🤚", &crate::gen::EMOJIS[172]),
+        ("🎴", &crate::gen::EMOJIS[2599]),
+        ("🧏🏿\u{200d}♀", &crate::gen::EMOJIS[765]),
+        ("🧑🏻\u{200d}⚖", &crate::gen::EMOJIS[875]),
+        ("🏂", &crate::gen::EMOJIS[1696]),
+        ("👩🏼\u{200d}💻", &crate::gen::EMOJIS[1014]),
+        ("🙇🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[779]),
+        ("🥷", &crate::gen::EMOJIS[1162]),
+        ("👳🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1212]),
+        ("🧑🏿\u{200d}🤝\u{200d}🧑🏼", &crate::gen::EMOJIS[1951]),
+        ("🍢", &crate::gen::EMOJIS[2249]),
+        ("🇿🇲", &crate::gen::EMOJIS[3355]),
+        ("🍑", &crate::gen::EMOJIS[2182]),
+        ("🤽🏾", &crate::gen::EMOJIS[1871]),
+        ("💔", &crate::gen::EMOJIS[137]),
+        ("🕔", &crate::gen::EMOJIS[2461]),
+        ("🥻", &crate::gen::EMOJIS[2621]),
+        ("🇨🇱", &crate::gen::EMOJIS[3145]),
+        ("🏋\u{fe0f}\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1798]),
+        ("🥊", &crate::gen::EMOJIS[2565]),
+        ("👨\u{200d}🦰", &crate::gen::EMOJIS[532]),
+        ("👢", &crate::gen::EMOJIS[2641]),
+        ("🧌", &crate::gen::EMOJIS[1468]),
+        ("🫱🏾", &crate::gen::EMOJIS[200]),
+        ("📺", &crate::gen::EMOJIS[2707]),
+        ("🤞🏿", &crate::gen::EMOJIS[261]),
+        ("🪗", &crate::gen::EMOJIS[2673]),
+        ("🚤", &crate::gen::EMOJIS[2424]),
+        ("🚵🏼", &crate::gen::EMOJIS[1830]),
+        ("🧖🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1669]),
+        ("🧿", &crate::gen::EMOJIS[2863]),
+        ("💂🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1160]),
+        ("🖐\u{fe0f}", &crate::gen::EMOJIS[178]),
+        ("👱🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[615]),
+        ("📏", &crate::gen::EMOJIS[2783]),
+        ("🕴🏻", &crate::gen::EMOJIS[1644]),
+        ("🍷", &crate::gen::EMOJIS[2284]),
+        ("🤺", &crate::gen::EMOJIS[1688]),
+        ("🧑🏼\u{200d}🦰", &crate::gen::EMOJIS[570]),
+        ("🛼", &crate::gen::EMOJIS[2408]),
+        ("👷🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1182]),
+        ("🧑🏽\u{200d}⚖", &crate::gen::EMOJIS[877]),
+        ("⚙", &crate::gen::EMOJIS[2810]),
+        ("📽", &crate::gen::EMOJIS[2705]),
+        ("🈸", &crate::gen::EMOJIS[3050]),
+        ("💁🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[722]),
+        ("🗺", &crate::gen::EMOJIS[2308]),
+        ("🧎🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1558]),
+        ("👮🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1117]),
+        ("🧑\u{200d}🍳", &crate::gen::EMOJIS[910]),
+        ("🙍\u{200d}♀", &crate::gen::EMOJIS[652]),
+        ("👫🏻", &crate::gen::EMOJIS[1959]),
+        ("🦵🏻", &crate::gen::EMOJIS[433]),
+        ("💇🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1500]),
+        ("🏃🏾", &crate::gen::EMOJIS[1617]),
+        ("👨\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨", &crate::gen::EMOJIS[1977]),
+        ("🙋🏾\u{200d}♂", &crate::gen::EMOJIS[740]),
+        ("🚵🏻\u{200d}♀", &crate::gen::EMOJIS[1841]),
+        ("🚶🏿", &crate::gen::EMOJIS[1510]),
+        ("👼🏻", &crate::gen::EMOJIS[1313]),
+        ("👩🏿\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1976]),
+        ("👳🏿", &crate::gen::EMOJIS[1209]),
+        ("🎾", &crate::gen::EMOJIS[2556]),
+        ("🧑🏻\u{200d}🎓", &crate::gen::EMOJIS[839]),
+        ("🧖🏾", &crate::gen::EMOJIS[1656]),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏻", &crate::gen::EMOJIS[1951]),
+        ("👨🏾\u{200d}❤\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1977]),
+        ("💥", &crate::gen::EMOJIS[155]),
+        ("🧑🏻\u{200d}🏭", &crate::gen::EMOJIS[947]),
+        ("🚹", &crate::gen::EMOJIS[2872]),
+        ("🌜", &crate::gen::EMOJIS[2486]),
+        ("🎬", &crate::gen::EMOJIS[2706]),
+        ("🧑🏻\u{200d}🦯", &crate::gen::EMOJIS[1560]),
+        ("😑", &crate::gen::EMOJIS[39]),
+        ("📱", &crate::gen::EMOJIS[2683]),
+        ("🪥", &crate::gen::EMOJIS[2855]),
+        ("💂\u{200d}♀", &crate::gen::EMOJIS[1156]),
+        ("🙅🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[687]),
+        ("🛹", &crate::gen::EMOJIS[2407]),
+        ("🙇🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[781]),
+        ("🧑🏻\u{200d}🔬", &crate::gen::EMOJIS[983]),
+        ("🧗🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1678]),
+        ("🧑🏿\u{200d}🌾", &crate::gen::EMOJIS[897]),
+        ("💃🏿", &crate::gen::EMOJIS[1636]),
+        ("↪", &crate::gen::EMOJIS[2905]),
+        ("👋🏾", &crate::gen::EMOJIS[170]),
+        ("🙎🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[673]),
+        ("🧀", &crate::gen::EMOJIS[2215]),
+        ("🚴🏿\u{200d}♂", &crate::gen::EMOJIS[1821]),
+        ("💇🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1497]),
+        ("🤷🏽\u{200d}♂", &crate::gen::EMOJIS[811]),
+        ("🖕🏽", &crate::gen::EMOJIS[307]),
+        ("🦸\u{200d}♂", &crate::gen::EMOJIS[1342]),
+        ("🚵🏿\u{200d}♂", &crate::gen::EMOJIS[1839]),
+        ("👩🏻\u{200d}❤\u{200d}💋\u{200d}👨🏿", &crate::gen::EMOJIS[1976]),
+        ("🛸", &crate::gen::EMOJIS[2441]),
+        ("🟤", &crate::gen::EMOJIS[3063]),
+        ("🌻", &crate::gen::EMOJIS[2151]),
+        ("🪕", &crate::gen::EMOJIS[2678]),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏾", &crate::gen::EMOJIS[1964]),
+        ("🕵\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1138]),
+        ("🧍🏾\u{200d}♂", &crate::gen::EMOJIS[1533]),
+        ("🏳\u{200d}⚧", &crate::gen::EMOJIS[3097]),
+        ("🤦🏼\u{200d}♀", &crate::gen::EMOJIS[798]),
+        ("🚶🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1522]),
+        ("🧹", &crate::gen::EMOJIS[2849]),
+        ("🧎🏿", &crate::gen::EMOJIS[1546]),
+        ("5\u{20e3}", &crate::gen::EMOJIS[3012]),
+        ("💆🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1485]),
+        ("0\u{20e3}", &crate::gen::EMOJIS[3007]),
+        ("🙇🏾\u{200d}♀", &crate::gen::EMOJIS[782]),
+        ("🖐🏼", &crate::gen::EMOJIS[180]),
+        ("🤮", &crate::gen::EMOJIS[59]),
+        ("🚶\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1511]),
+        ("🕑", &crate::gen::EMOJIS[2455]),
+        ("👩🏿\u{200d}❤\u{200d}💋\u{200d}👩🏽", &crate::gen::EMOJIS[1978]),
+        ("⚒\u{fe0f}", &crate::gen::EMOJIS[2798]),
+        ("🔷", &crate::gen::EMOJIS[3082]),
+        ("⚔\u{fe0f}", &crate::gen::EMOJIS[2801]),
+        ("🧙🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1386]),
+        ("🙄", &crate::gen::EMOJIS[45]),
+        ("🇻🇪", &crate::gen::EMOJIS[3344]),
+        ("👩🏻\u{200d}⚕", &crate::gen::EMOJIS[833]),
+        ("🤕", &crate::gen::EMOJIS[57]),
+        ("⛹🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1790]),
+        ("🧏🏿", &crate::gen::EMOJIS[753]),
+        ("🏊🏾\u{200d}♂", &crate::gen::EMOJIS[1766]),
+        ("🧗\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1682]),
+        ("👩🏾\u{200d}⚖", &crate::gen::EMOJIS[890]),
+        ("🗳", &crate::gen::EMOJIS[2758]),
+        ("🇬🇱", &crate::gen::EMOJIS[3187]),
+        ("🤼\u{200d}♀", &crate::gen::EMOJIS[1866]),
+        ("🤸🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1862]),
+        ("🖐🏾", &crate::gen::EMOJIS[182]),
+        ("🇸🇲", &crate::gen::EMOJIS[3308]),
+        ("6\u{20e3}", &crate::gen::EMOJIS[3013]),
+        ("🇧🇫", &crate::gen::EMOJIS[3120]),
+        ("👵🏻", &crate::gen::EMOJIS[635]),
+        ("✊🏿", &crate::gen::EMOJIS[345]),
+        ("🦸", &crate::gen::EMOJIS[1336]),
+        ("🙇🏻\u{200d}♀", &crate::gen::EMOJIS[779]),
+        ("👵🏼", &crate::gen::EMOJIS[636]),
+        ("🧞", &crate::gen::EMOJIS[1462]),
+        ("👈🏻", &crate::gen::EMOJIS[287]),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}👨🏼", &crate::gen::EMOJIS[1986]),
+        ("👩🏻\u{200d}✈", &crate::gen::EMOJIS[1067]),
+        ("🧔🏾\u{200d}♀", &crate::gen::EMOJIS[530]),
+        ("🔐", &crate::gen::EMOJIS[2792]),
+        ("🤹🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1918]),
+        ("🧑🏻\u{200d}🦽", &crate::gen::EMOJIS[1596]),
+        ("🛅", &crate::gen::EMOJIS[2880]),
+        ("🌦", &crate::gen::EMOJIS[2501]),
+        ("🤸\u{200d}♂", &crate::gen::EMOJIS[1852]),
+        ("🧎🏻\u{200d}♂", &crate::gen::EMOJIS[1548]),
+        ("🤹🏿\u{200d}♂", &crate::gen::EMOJIS[1914]),
+        ("🕚", &crate::gen::EMOJIS[2473]),
+        ("🤌🏼", &crate::gen::EMOJIS[240]),
+        ("➡\u{fe0f}", &crate::gen::EMOJIS[2896]),
+        ("⛹🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1782]),
+        ("🧛🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1418]),
+        ("🇧🇴", &crate::gen::EMOJIS[3128]),
+        ("👑", &crate::gen::EMOJIS[2643]),
+        ("👨🏾\u{200d}❤\u{200d}👨🏿", &crate::gen::EMOJIS[1986]),
+        ("👌🏽", &crate::gen::EMOJIS[235]),
+        ("📧", &crate::gen::EMOJIS[2747]),
+        ("🎣", &crate::gen::EMOJIS[2570]),
+        ("🧞\u{200d}♂", &crate::gen::EMOJIS[1463]),
+        ("🧓", &crate::gen::EMOJIS[622]),
+        ("🧑🏽", &crate::gen::EMOJIS[499]),
+        ("🙅🏽\u{200d}♂", &crate::gen::EMOJIS[685]),
+        ("👬🏾", &crate::gen::EMOJIS[1968]),
+        ("🧑🏽\u{200d}💻", &crate::gen::EMOJIS[1003]),
+        ("📯", &crate::gen::EMOJIS[2660]),
+        ("🏉", &crate::gen::EMOJIS[2555]),
+        ("🧑🏼\u{200d}🤝\u{200d}🧑🏼", &crate::gen::EMOJIS[1951]),
+        ("🪇", &crate::gen::EMOJIS[2681]),
+        ("🫳🏽", &crate::gen::EMOJIS[211]),
+        ("🐻", &crate::gen::EMOJIS[2075]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩🏻", &crate::gen::EMOJIS[1978]),
+        ("✋🏾", &crate::gen::EMOJIS[188]),
+        ("🙎🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[674]),
+        ("🍆", &crate::gen::EMOJIS[2191]),
+        ("😵\u{200d}💫", &crate::gen::EMOJIS[65]),
+        ("🤾🏿\u{200d}♂", &crate::gen::EMOJIS[1896]),
+        ("🇪🇺", &crate::gen::EMOJIS[3172]),
+        ("🙋🏿\u{200d}♂", &crate::gen::EMOJIS[741]),
+        ("🍒", &crate::gen::EMOJIS[2183]),
+        ("💏🏼", &crate::gen::EMOJIS[1972]),
+        ("⌛", &crate::gen::EMOJIS[2444]),
+        ("👨🏻\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[827]),
+        ("💂🏾\u{200d}♂", &crate::gen::EMOJIS[1154]),
+        ("💳", &crate::gen::EMOJIS[2743]),
+        ("🦹🏼", &crate::gen::EMOJIS[1356]),
+        ("🥔", &crate::gen::EMOJIS[2192]),
+        ("👩🏿", &crate::gen::EMOJIS[561]),
+        ("🧑🏽\u{200d}⚕", &crate::gen::EMOJIS[823]),
+        ("🇦🇿", &crate::gen::EMOJIS[3115]),
+        ("👊🏽", &crate::gen::EMOJIS[349]),
+        ("👨\u{200d}🎤", &crate::gen::EMOJIS[1024]),
+        ("👩🏼\u{200d}❤\u{200d}👩🏽", &crate::gen::EMOJIS[1987]),
+        ("🚵🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1841]),
+        ("👨\u{200d}👦\u{200d}👦", &crate::gen::EMOJIS[2005]),
+        ("🚶🏻", &crate::gen::EMOJIS[1506]),
+        ("🏭", &crate::gen::EMOJIS[2341]),
+        ("💁🏿", &crate::gen::EMOJIS[717]),
+        ("👩🏾\u{200d}❤\u{200d}💋\u{200d}👩🏼", &crate::gen::EMOJIS[1978]),
+        ("🧑🏼\u{200d}🦽", &crate::gen::EMOJIS[1597]),
+        ("🦒", &crate::gen::EMOJIS[2060]),
+        ("👩🏾\u{200d}✈", &crate::gen::EMOJIS[1070]),
+        ("🍝", &crate::gen::EMOJIS[2247]),
+        ("🎁", &crate::gen::EMOJIS[2539]),
+        ("🧖🏾\u{200d}♀", &crate::gen::EMOJIS[1668]),
+        ("🇫🇰", &crate::gen::EMOJIS[3175]),
+        ("🙅", &crate::gen::EMOJIS[676]),
+        ("🧑🏼\u{200d}🦼", &crate::gen::EMOJIS[1579]),
+        ("👩🏼\u{200d}❤\u{200d}👨🏽", &crate::gen::EMOJIS[1985]),
+        ("👨🏻\u{200d}🏭", &crate::gen::EMOJIS[953]),
+        ("👃🏼", &crate::gen::EMOJIS[458]),
+        ("🧑🏻\u{200d}❤\u{200d}💋\u{200d}🧑🏿", &crate::gen::EMOJIS[1975]),
+        ("🎋", &crate::gen::EMOJIS[2531]),
+        ("👮🏾\u{200d}♂", &crate::gen::EMOJIS[1118]),
+        ("🏄🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1731]),
+        ("👩🏼\u{200d}💼", &crate::gen::EMOJIS[978]),
+        ("🫱🏻\u{200d}🫲🏾", &crate::gen::EMOJIS[394]),
+        ("💂🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1161]),
+        ("👰🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1261]),
+        ("🕵🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1134]),
+        ("🧑🏽\u{200d}🤝\u{200d}🧑🏿", &crate::gen::EMOJIS[1951]),
+        ("💯", &crate::gen::EMOJIS[153]),
+        ("🕵🏼", &crate::gen::EMOJIS[1128]),
+        ("🤾🏿\u{200d}♀", &crate::gen::EMOJIS[1902]),
+        ("🧬", &crate::gen::EMOJIS[2823]),
+        ("🧑🏽\u{200d}❤\u{200d}💋\u{200d}🧑🏻", &crate::gen::EMOJIS[1975]),
+        ("🙎🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[668]),
+        ("☣\u{fe0f}", &crate::gen::EMOJIS[2893]),
+        ("🇷🇴", &crate::gen::EMOJIS[3293]),
+        ("☁", &crate::gen::EMOJIS[2496]),
+        ("🈲", &crate::gen::EMOJIS[3048]),
+        ("🤽🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1881]),
+        ("🤲🏿", &crate::gen::EMOJIS[393]),
+        ("🦹", &crate::gen::EMOJIS[1354]),
+        ("🤹🏽\u{200d}♂", &crate::gen::EMOJIS[1912]),
+        ("🦌", &crate::gen::EMOJIS[2044]),
+        ("🧑🏻\u{200d}❤\u{200d}🧑🏽", &crate::gen::EMOJIS[1984]),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏼", &crate::gen::EMOJIS[1979]),
+        ("🤽🏿\u{200d}♀", &crate::gen::EMOJIS[1884]),
+        ("🇨🇮", &crate::gen::EMOJIS[3143]),
+        ("👣", &crate::gen::EMOJIS[2018]),
+        ("👩🏼\u{200d}🤝\u{200d}👨🏿", &crate::gen::EMOJIS[1958]),
+        ("🗽", &crate::gen::EMOJIS[2346]),
+        ("🇳🇨", &crate::gen::EMOJIS[3265]),
+        ("🐣", &crate::gen::EMOJIS[2088]),
+        ("🧜🏽", &crate::gen::EMOJIS[1429]),
+        ("🦹🏿\u{200d}♂", &crate::gen::EMOJIS[1365]),
+        ("👩🏽\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1069]),
+        ("🦨", &crate::gen::EMOJIS[2081]),
+        ("👩🏿\u{200d}🎨", &crate::gen::EMOJIS[1053]),
+        ("🚴🏾\u{200d}♀", &crate::gen::EMOJIS[1826]),
+        ("👯\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1650]),
+        ("🥱", &crate::gen::EMOJIS[98]),
+        ("💪🏽", &crate::gen::EMOJIS[427]),
+        ("🤸\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1852]),
+        ("⛰\u{fe0f}", &crate::gen::EMOJIS[2312]),
+        ("☦\u{fe0f}", &crate::gen::EMOJIS[2922]),
+        ("🫷🏽", &crate::gen::EMOJIS[223]),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}👨🏼", &crate::gen::EMOJIS[1986]),
+        ("🧗🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1686]),
+        ("🚶🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1521]),
+        ("🌴", &crate::gen::EMOJIS[2159]),
+        ("👨\u{200d}🦼", &crate::gen::EMOJIS[1583]),
+        ("🚧", &crate::gen::EMOJIS[2419]),
+        ("🧛🏾\u{200d}♂", &crate::gen::EMOJIS[1418]),
+        ("🦵", &crate::gen::EMOJIS[432]),
+        ("☃\u{fe0f}", &crate::gen::EMOJIS[2516]),
+        ("🏹", &crate::gen::EMOJIS[2804]),
+        ("💇🏾", &crate::gen::EMOJIS[1491]),
+        ("🫠", &crate::gen::EMOJIS[10]),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏽", &crate::gen::EMOJIS[1970]),
+        ("🕵🏾\u{200d}♀", &crate::gen::EMOJIS[1142]),
+        ("🤷🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[816]),
+        ("🎙", &crate::gen::EMOJIS[2666]),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏿", &crate::gen::EMOJIS[1964]),
+        ("🇹🇦", &crate::gen::EMOJIS[3318]),
+        ("🦸🏻\u{200d}♀", &crate::gen::EMOJIS[1349]),
+        ("🚣🏽\u{200d}♂", &crate::gen::EMOJIS[1747]),
+        ("🤦🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[795]),
+        ("👨🏽\u{200d}🦼", &crate::gen::EMOJIS[1586]),
+        ("📴", &crate::gen::EMOJIS[2965]),
+        ("🫴🏼", &crate::gen::EMOJIS[216]),
+        ("🧑🏿\u{200d}🚒", &crate::gen::EMOJIS[1095]),
+        ("👧", &crate::gen::EMOJIS[490]),
+        ("👮🏻\u{200d}♀", &crate::gen::EMOJIS[1121]),
+        ("👨🏼", &crate::gen::EMOJIS[510]),
+        ("👳🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1215]),
+        ("📬", &crate::gen::EMOJIS[2755]),
+        ("🧛🏽\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1423]),
+        ("😈", &crate::gen::EMOJIS[103]),
+        ("🤽🏻\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1880]),
+        ("🧎🏽\u{200d}♂", &crate::gen::EMOJIS[1550]),
+        ("👱🏻\u{200d}♂", &crate::gen::EMOJIS[617]),
+        ("🏋🏻\u{200d}♂", &crate::gen::EMOJIS[1799]),
+        ("⏺", &crate::gen::EMOJIS[2957]),
+        ("💁🏽", &crate::gen::EMOJIS[715]),
+        ("🕕", &crate::gen::EMOJIS[2463]),
+        ("🙊", &crate::gen::EMOJIS[126]),
+        ("🧝", &crate::gen::EMOJIS[1444]),
+        ("🧙🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1382]),
+        ("🤷🏿", &crate::gen::EMOJIS[807]),
+        ("👨🏿\u{200d}🚒", &crate::gen::EMOJIS[1101]),
+        ("👦🏾", &crate::gen::EMOJIS[488]),
+        ("🕵🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1137]),
+        ("👩🏿\u{200d}🔧", &crate::gen::EMOJIS[945]),
+        ("🏗", &crate::gen::EMOJIS[2322]),
+        ("🇭🇰", &crate::gen::EMOJIS[3198]),
+        ("👨\u{200d}🔧", &crate::gen::EMOJIS[934]),
+        ("🕣", &crate::gen::EMOJIS[2468]),
+        ("👂🏿", &crate::gen::EMOJIS[449]),
+        ("🇨🇽", &crate::gen::EMOJIS[3154]),
+        ("🇦🇬", &crate::gen::EMOJIS[3103]),
+        ("👴🏻", &crate::gen::EMOJIS[629]),
+        ("🐺", &crate::gen::EMOJIS[2028]),
+        ("📭", &crate::gen::EMOJIS[2756]),
+        ("👹", &crate::gen::EMOJIS[109]),
+        ("🤽🏿\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1884]),
+        ("💁🏽\u{200d}♂", &crate::gen::EMOJIS[721]),
+        ("👨🏽\u{200d}🚒", &crate::gen::EMOJIS[1099]),
+        ("👁\u{fe0f}\u{200d}🗨", &crate::gen::EMOJIS[161]),
+        ("🙍🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[654]),
+        ("🚣🏾\u{200d}♀", &crate::gen::EMOJIS[1754]),
+        ("🤲🏽", &crate::gen::EMOJIS[391]),
+        ("🇲🇿", &crate::gen::EMOJIS[3263]),
+        ("🧚🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1401]),
+        ("🦸\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1348]),
+        ("🧑🏽\u{200d}🦳", &crate::gen::EMOJIS[595]),
+        ("👨🏽\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏾", &crate::gen::EMOJIS[1977]),
+        ("👰🏻\u{200d}♀", &crate::gen::EMOJIS[1265]),
+        ("🧔🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[528]),
+        ("👩🏾\u{200d}❤\u{fe0f}\u{200d}👨🏿", &crate::gen::EMOJIS[1985]),
+        ("🏄🏻", &crate::gen::EMOJIS[1721]),
+        ("👰🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1263]),
+        ("🐞", &crate::gen::EMOJIS[2133]),
+        ("👨🏽\u{200d}🤝\u{200d}👨🏻", &crate::gen::EMOJIS[1964]),
+        ("🚥", &crate::gen::EMOJIS[2416]),
+        ("⛪", &crate::gen::EMOJIS[2347]),
+        ("🕴🏿", &crate::gen::EMOJIS[1648]),
+        ("🧅", &crate::gen::EMOJIS[2201]),
+        ("⚔", &crate::gen::EMOJIS[2801]),
+        ("🚣🏼\u{200d}♂", &crate::gen::EMOJIS[1746]),
+        ("🤹🏼\u{200d}♂", &crate::gen::EMOJIS[1911]),
+        ("🤸🏿\u{200d}♀", &crate::gen::EMOJIS[1863]),
+        ("🌩", &crate::gen::EMOJIS[2504]),
+        ("🚞", &crate::gen::EMOJIS[2380]),
+        ("🛍\u{fe0f}", &crate::gen::EMOJIS[2631]),
+        ("😏", &crate::gen::EMOJIS[43]),
+        ("🕵\u{fe0f}\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1132]),
+        ("👩🏾\u{200d}❤\u{200d}👩🏻", &crate::gen::EMOJIS[1987]),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}🧑🏼", &crate::gen::EMOJIS[1979]),
+        ("🖤", &crate::gen::EMOJIS[149]),
+        ("🇦🇶", &crate::gen::EMOJIS[3108]),
+        ("👨🏼\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏽", &crate::gen::EMOJIS[1977]),
+        ("🧑🏿\u{200d}✈\u{fe0f}", &crate::gen::EMOJIS[1059]),
+        ("💛", &crate::gen::EMOJIS[143]),
+        ("🧑🏿\u{200d}❤\u{fe0f}\u{200d}🧑🏻", &crate::gen::EMOJIS[1979]),
+        ("😶", &crate::gen::EMOJIS[40]),
+        ("🥶", &crate::gen::EMOJIS[62]),
+        ("👩🏽\u{200d}❤\u{fe0f}\u{200d}👨🏽", &crate::gen::EMOJIS[1985]),
+        ("👨🏼\u{200d}🚒", &crate::gen::EMOJIS[1098]),
+        ("🤷🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[818]),
+        ("👩🏽\u{200d}❤\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1976]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👨🏽", &crate::gen::EMOJIS[1985]),
+        ("👥", &crate::gen::EMOJIS[2016]),
+        ("🙈", &crate::gen::EMOJIS[124]),
+        ("🇦🇽", &crate::gen::EMOJIS[3114]),
+        ("💆🏾\u{200d}♂", &crate::gen::EMOJIS[1479]),
+        ("🤷🏼\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[810]),
+        ("🧑🏽\u{200d}❤\u{fe0f}\u{200d}🧑🏻", &crate::gen::EMOJIS[1979]),
+        ("👩🏿\u{200d}✈", &crate::gen::EMOJIS[1071]),
+        ("🧘🏼\u{200d}♂", &crate::gen::EMOJIS[1929]),
+        ("🙎🏾\u{200d}♀", &crate::gen::EMOJIS[674]),
+        ("🕰", &crate::gen::EMOJIS[2450]),
+        ("🙇🏿\u{200d}♀", &crate::gen::EMOJIS[783]),
+        ("🤦🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[793]),
+        ("👩🏽\u{200d}🦳", &crate::gen::EMOJIS[589]),
+        ("🃏", &crate::gen::EMOJIS[2597]),
+        ("🧖🏼", &crate::gen::EMOJIS[1654]),
+        ("🧑🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}🧑🏻", &crate::gen::EMOJIS[1970]),
+        ("👨🏿\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[885]),
+        ("🇺🇳", &crate::gen::EMOJIS[3338]),
+        ("🏋", &crate::gen::EMOJIS[1792]),
+        ("📌", &crate::gen::EMOJIS[2779]),
+        ("👮🏿\u{200d}♂", &crate::gen::EMOJIS[1119]),
+        ("🚉", &crate::gen::EMOJIS[2377]),
+        ("🫶", &crate::gen::EMOJIS[376]),
+        ("🧗🏽\u{200d}♀", &crate::gen::EMOJIS[1685]),
+        ("👮🏾\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1124]),
+        ("💀", &crate::gen::EMOJIS[105]),
+        ("🐜", &crate::gen::EMOJIS[2130]),
+        ("🔇", &crate::gen::EMOJIS[2654]),
+        ("🌉", &crate::gen::EMOJIS[2362]),
+        ("🇭🇹", &crate::gen::EMOJIS[3202]),
+        ("🪧", &crate::gen::EMOJIS[2866]),
+        ("🤦🏼\u{200d}♂", &crate::gen::EMOJIS[792]),
+        ("🇨🇳", &crate::gen::EMOJIS[3147]),
+        ("🫷🏻", &crate::gen::EMOJIS[221]),
+        ("🙎🏽\u{200d}♂", &crate::gen::EMOJIS[667]),
+        ("🍜", &crate::gen::EMOJIS[2246]),
+        ("👩🏾\u{200d}💻", &crate::gen::EMOJIS[1016]),
+        ("⛑\u{fe0f}", &crate::gen::EMOJIS[2649]),
+        ("💂🏽\u{200d}♀", &crate::gen::EMOJIS[1159]),
+        ("🫱🏼\u{200d}🫲🏻", &crate::gen::EMOJIS[394]),
+        ("🤵🏿\u{200d}♀", &crate::gen::EMOJIS[1251]),
+        ("🧚🏾", &crate::gen::EMOJIS[1394]),
+        ("🦟", &crate::gen::EMOJIS[2139]),
+        ("🤵🏾\u{200d}♂", &crate::gen::EMOJIS[1244]),
+        ("🦎", &crate::gen::EMOJIS[2109]),
+        ("🧚🏿", &crate::gen::EMOJIS[1395]),
+        ("🛵", &crate::gen::EMOJIS[2401]),
+        ("👨🏿\u{200d}🏫", &crate::gen::EMOJIS[867]),
+        ("👩🏾\u{200d}🦼", &crate::gen::EMOJIS[1593]),
+        ("👩🏻\u{200d}🤝\u{200d}👩🏿", &crate::gen::EMOJIS[1952]),
+        ("🙎🏻\u{200d}♀", &crate::gen::EMOJIS[671]),
+        ("👰🏿\u{200d}♀", &crate::gen::EMOJIS[1269]),
+        ("🙇🏾\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[776]),
+        ("🏋🏼\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1806]),
+        ("🧚🏻\u{200d}♂", &crate::gen::EMOJIS[1397]),
+        ("👩🏼\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[834]),
+        ("🥄", &crate::gen::EMOJIS[2300]),
+        ("🇮🇷", &crate::gen::EMOJIS[3212]),
+        ("🖕🏼", &crate::gen::EMOJIS[306]),
+        ("🎃", &crate::gen::EMOJIS[2522]),
+        ("👩\u{200d}👧", &crate::gen::EMOJIS[2011]),
+        ("⛄", &crate::gen::EMOJIS[2517]),
+        ("📉", &crate::gen::EMOJIS[2776]),
+        ("🪱", &crate::gen::EMOJIS[2141]),
+        ("🆎", &crate::gen::EMOJIS[3024]),
+        ("👩🏾\u{200d}🤝\u{200d}👩🏼", &crate::gen::EMOJIS[1952]),
+        ("🚶\u{200d}♂", &crate::gen::EMOJIS[1511]),
+        ("🪫", &crate::gen::EMOJIS[2690]),
+        ("🥷🏻", &crate::gen::EMOJIS[1163]),
+        ("🇵🇾", &crate::gen::EMOJIS[3290]),
+        ("🍽", &crate::gen::EMOJIS[2298]),
+        ("🚆", &crate::gen::EMOJIS[2374]),
+        ("👲🏻", &crate::gen::EMOJIS[1223]),
+        ("👨🏾\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨🏼", &crate::gen::EMOJIS[1977]),
+        ("🦸🏿", &crate::gen::EMOJIS[1341]),
+        ("🧭", &crate::gen::EMOJIS[2310]),
+        ("🇱🇸", &crate::gen::EMOJIS[3236]),
+        ("🧑🏻\u{200d}❤\u{fe0f}\u{200d}🧑🏽", &crate::gen::EMOJIS[1979]),
+        ("👮🏻\u{200d}♂", &crate::gen::EMOJIS[1115]),
+        ("🤾🏻", &crate::gen::EMOJIS[1886]),
+        ("🧖🏿\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1663]),
+        ("🧜", &crate::gen::EMOJIS[1426]),
+        ("🧚🏻\u{200d}♀", &crate::gen::EMOJIS[1403]),
+        ("🫱🏽\u{200d}🫲🏿", &crate::gen::EMOJIS[394]),
+        ("⛹\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1786]),
+        ("🇹🇳", &crate::gen::EMOJIS[3328]),
+        ("👩🏼\u{200d}❤\u{fe0f}\u{200d}👩🏻", &crate::gen::EMOJIS[1987]),
+        ("🏋🏿", &crate::gen::EMOJIS[1797]),
+        ("👩🏽\u{200d}🤝\u{200d}👨🏻", &crate::gen::EMOJIS[1958]),
+        ("🏁", &crate::gen::EMOJIS[3091]),
+        ("🫃🏿", &crate::gen::EMOJIS[1281]),
+        ("🧑🏻\u{200d}🎤", &crate::gen::EMOJIS[1019]),
+        ("🇵🇱", &crate::gen::EMOJIS[3283]),
+        ("✍", &crate::gen::EMOJIS[406]),
+        ("🤛🏻", &crate::gen::EMOJIS[353]),
+        ("👩🏽\u{200d}💻", &crate::gen::EMOJIS[1015]),
+        ("⏭", &crate::gen::EMOJIS[2946]),
+        ("👩🏼\u{200d}❤\u{200d}💋\u{200d}👩🏿", &crate::gen::EMOJIS[1978]),
+        ("👩🏻\u{200d}🔬", &crate::gen::EMOJIS[995]),
+        ("🫵🏻", &crate::gen::EMOJIS[323]),
+        ("💫", &crate::gen::EMOJIS[156]),
+        ("👰🏼\u{200d}♀", &crate::gen::EMOJIS[1266]),
+        ("👨🏾\u{200d}🦱", &crate::gen::EMOJIS[542]),
+        ("🧜🏿\u{200d}♂", &crate::gen::EMOJIS[1437]),
+        ("👷🏾", &crate::gen::EMOJIS[1172]),
+        ("🤏🏼", &crate::gen::EMOJIS[246]),
+        ("🍔", &crate::gen::EMOJIS[2220]),
+        ("👩\u{200d}💻", &crate::gen::EMOJIS[1012]),
+        ("🏃🏿\u{200d}♀", &crate::gen::EMOJIS[1630]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏿", &crate::gen::EMOJIS[1985]),
+        ("🧑🏿\u{200d}⚕\u{fe0f}", &crate::gen::EMOJIS[825]),
+        ("👇", &crate::gen::EMOJIS[310]),
+        ("👃🏿", &crate::gen::EMOJIS[461]),
+        ("🧝\u{200d}♀\u{fe0f}", &crate::gen::EMOJIS[1456]),
+        ("☑\u{fe0f}", &crate::gen::EMOJIS[2992]),
+        ("🧔\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[520]),
+        ("🧙🏽\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1381]),
+        ("🚣🏼\u{200d}♀", &crate::gen::EMOJIS[1752]),
+        ("🇧🇸", &crate::gen::EMOJIS[3131]),
+        ("🔺", &crate::gen::EMOJIS[3085]),
+        ("🚬", &crate::gen::EMOJIS[2859]),
+        ("👩🏾\u{200d}🎓", &crate::gen::EMOJIS[854]),
+        ("🥝", &crate::gen::EMOJIS[2186]),
+        ("🏌🏼", &crate::gen::EMOJIS[1704]),
+        ("👨🏻\u{200d}❤\u{200d}👨🏼", &crate::gen::EMOJIS[1986]),
+        ("🫀", &crate::gen::EMOJIS[463]),
+        ("👩🏻\u{200d}❤\u{200d}👩🏻", &crate::gen::EMOJIS[1987]),
+        ("🧑\u{200d}⚖\u{fe0f}", &crate::gen::EMOJIS[874]),
+        ("🗒", &crate::gen::EMOJIS[2772]),
+        ("👨🏻\u{200d}🤝\u{200d}👨🏽", &crate::gen::EMOJIS[1964]),
+        ("👩🏻\u{200d}❤\u{fe0f}\u{200d}👨🏾", &crate::gen::EMOJIS[1985]),
+        ("ℹ", &crate::gen::EMOJIS[3029]),
+        ("🍴", &crate::gen::EMOJIS[2299]),
+        ("👐🏻", &crate::gen::EMOJIS[383]),
+        ("🦹🏼\u{200d}♂", &crate::gen::EMOJIS[1362]),
+        ("🤞🏾", &crate::gen::EMOJIS[260]),
+        ("🇬🇹", &crate::gen::EMOJIS[3194]),
+        ("🖨", &crate::gen::EMOJIS[2694]),
+        ("🏊🏽\u{200d}♀", &crate::gen::EMOJIS[1771]),
+        ("🎦", &crate::gen::EMOJIS[2959]),
+        ("🖊\u{fe0f}", &crate::gen::EMOJIS[2762]),
+        ("💇\u{200d}♂", &crate::gen::EMOJIS[1493]),
+        ("🫱🏿\u{200d}🫲🏼", &crate::gen::EMOJIS[394]),
+        ("👰\u{200d}♂\u{fe0f}", &crate::gen::EMOJIS[1258]),
+    ],
+};
@@ -0,0 +1,203 @@
+//! Conversion between gendered and gender-neutral emojis.
+
+use crate::{Emoji, MAX_BYTES};
+
+/// The gender of a person in an emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Neutral,
+    Male,
+    Female,
+}
+
+pub(crate) const ZWJ: char = '\u{200d}';
+const FEMALE: &str = "\u{200d}\u{2640}\u{fe0f}";
+const MALE: &str = "\u{200d}\u{2642}\u{fe0f}";
+pub(crate) const PERSON: char = '🧑';
+pub(crate) const MAN: char = '👨';
+pub(crate) const WOMAN: char = '👩';
+
+/// Gender-neutral emojis and their gendered forms that are not simply the
+/// neutral emoji with a gender sign or with the person replaced.
+const FORMS: &[(&str, &[&str])] = &[
+    ("🧑", &["👨", "👩"]),
+    ("🧒", &["👦", "👧"]),
+    ("🧓", &["👴", "👵"]),
+    ("🫅", &["🤴", "👸"]),
+    ("🧑\u{200d}🎄", &["🎅", "🤶"]),
+    ("🫄", &["🫃", "🤰"]),
+    ("🧑\u{200d}🤝\u{200d}🧑", &["👬", "👫", "👭"]),
+    (
+        "💏",
+        &[
+            "👨\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨",
+            "👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👨",
+            "👩\u{200d}❤\u{fe0f}\u{200d}💋\u{200d}👩",
+        ],
+    ),
+    (
+        "💑",
+        &[
+            "👨\u{200d}❤\u{fe0f}\u{200d}👨",
+            "👩\u{200d}❤\u{fe0f}\u{200d}👨",
+            "👩\u{200d}❤\u{fe0f}\u{200d}👩",
+        ],
+    ),
+];
+
+/// A small stack allocated string used to build emoji candidates.
+pub(crate) struct Buf {
+    bytes: [u8; MAX_BYTES],
+    len: usize,
+}
+
+impl Buf {
+    pub(crate) fn new() -> Self {
+        Self {
+            bytes: [0; MAX_BYTES],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn push_str(&mut self, s: &str) -> &mut Self {
+        let end = (self.len + s.len()).min(MAX_BYTES);
+        self.bytes[self.len..end].copy_from_slice(&s.as_bytes()[..end - self.len]);
+        self.len = end;
+        self
+    }
+
+    pub(crate) fn push(&mut self, c: char) -> &mut Self {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    fn get(&self) -> Option<&'static Emoji> {
+        crate::get(core::str::from_utf8(&self.bytes[..self.len]).ok()?)
+    }
+
+    /// Returns the key in the emoji table that is equal to this string.
+    pub(crate) fn get_key(&self) -> Option<&'static str> {
+        let s = core::str::from_utf8(&self.bytes[..self.len]).ok()?;
+        crate::gen::unicode::MAP.get_key(s).copied()
+    }
+}
+
+/// Splits an emoji into its first character, its skin tone modifier if it
+/// directly follows the first character, and the rest.
+fn split_tone(s: &str) -> (char, Option<char>, &str) {
+    let mut chars = s.chars();
+    let first = chars.next().unwrap_or('\0');
+    let rest = chars.as_str();
+    match rest.chars().next() {
+        Some(c @ '\u{1f3fb}'..='\u{1f3ff}') => (first, Some(c), &rest[c.len_utf8()..]),
+        _ => (first, None, rest),
+    }
+}
+
+/// Builds an emoji from its first character, skin tone, and the rest.
+fn join(first: char, tone: Option<char>, rest: &str) -> Option<&'static Emoji> {
+    let mut buf = Buf::new();
+    buf.push(first);
+    if let Some(tone) = tone {
+        buf.push(tone);
+    }
+    buf.push_str(rest).get()
+}
+
+/// Builds the given emoji with the skin tone inserted after its first
+/// character.
+fn with_tone(s: &str, tone: Option<char>) -> Option<&'static Emoji> {
+    let (first, _, rest) = split_tone(s);
+    join(first, tone, rest)
+}
+
+/// Looks up the entry in [`FORMS`] for the given emoji without its skin tone.
+fn find_forms(
+    first: char,
+    rest: &str,
+    f: impl Fn(&(&str, &[&str]), &str) -> bool,
+) -> Option<&'static (&'static str, &'static [&'static str])> {
+    let mut key = Buf::new();
+    key.push(first).push_str(rest);
+    let key = core::str::from_utf8(&key.bytes[..key.len]).ok()?;
+    FORMS.iter().find(|entry| f(entry, key))
+}
+
+fn neutral(s: &str) -> Option<&'static Emoji> {
+    if let Some(base) = s.strip_suffix(FEMALE).or_else(|| s.strip_suffix(MALE)) {
+        return crate::get(base);
+    }
+    let (first, tone, rest) = split_tone(s);
+    if let Some((neutral, _)) = find_forms(first, rest, |(_, forms), key| forms.contains(&key)) {
+        return with_tone(neutral, tone);
+    }
+    if matches!(first, MAN | WOMAN) && rest.starts_with(ZWJ) {
+        return join(PERSON, tone, rest);
+    }
+    None
+}
+
+impl Emoji {
+    /// Returns the gender-neutral form of this emoji.
+    ///
+    /// Any skin tone is preserved. If this emoji is not gendered, or there is
+    /// no gender-neutral form, then this emoji is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let officer = emojis::get("👮🏽‍♀️").unwrap();
+    /// assert_eq!(officer.neutral(), "👮🏽");
+    ///
+    /// let cook = emojis::get("👨‍🍳").unwrap();
+    /// assert_eq!(cook.neutral(), "🧑‍🍳");
+    ///
+    /// let cool = emojis::get("😎").unwrap();
+    /// assert_eq!(cool.neutral(), "😎");
+    /// ```
+    pub fn neutral(&self) -> &'static Emoji {
+        neutral(self.emoji).unwrap_or(&crate::gen::EMOJIS[self.index()])
+    }
+
+    /// Returns an iterator over the gender-neutral and gendered forms of this
+    /// emoji.
+    ///
+    /// The gender-neutral form is always first, followed by the male and the
+    /// female forms. Any skin tone is preserved. If this emoji is not gendered
+    /// then only this emoji is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Emoji;
+    ///
+    /// let officer = emojis::get("👮‍♀️").unwrap();
+    /// let forms: Vec<_> = officer.gendered_variants().map(Emoji::as_str).collect();
+    /// assert_eq!(forms, ["👮", "👮‍♂️", "👮‍♀️"]);
+    /// ```
+    pub fn gendered_variants(&self) -> impl Iterator<Item = &'static Emoji> {
+        let neutral = self.neutral();
+        let (first, tone, rest) = split_tone(neutral.emoji);
+
+        let mut forms = [None; 4];
+        let mut n = 0;
+        let mut add = |emoji| {
+            forms[n] = emoji;
+            n += 1;
+        };
+        add(Some(neutral));
+        if let Some((_, others)) = find_forms(first, rest, |(neutral, _), key| *neutral == key) {
+            for other in others.iter() {
+                add(with_tone(other, tone));
+            }
+        } else if first == PERSON && rest.starts_with(ZWJ) {
+            for c in [MAN, WOMAN] {
+                add(join(c, tone, rest));
+            }
+        } else {
+            for sign in [MALE, FEMALE] {
+                add(Buf::new().push_str(neutral.emoji).push_str(sign).get());
+            }
+        }
+        forms.into_iter().flatten()
+    }
+}
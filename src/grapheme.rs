@@ -0,0 +1,67 @@
+//! Resolving emojis from an iterator of grapheme clusters.
+
+use crate::Emoji;
+
+/// An extension trait for iterators over grapheme clusters.
+///
+/// This is implemented for every iterator over string slices, such as the
+/// iterators returned by [unicode-segmentation].
+///
+/// # Examples
+///
+/// ```
+/// use emojis::EmojiGraphemes;
+/// use unicode_segmentation::UnicodeSegmentation;
+///
+/// let found: Vec<_> = "hi 👋🏽!"
+///     .graphemes(true)
+///     .emojis()
+///     .map(|e| e.map(|e| e.as_str()))
+///     .collect();
+/// assert_eq!(found, [None, None, None, Some("👋🏽"), None]);
+/// ```
+///
+/// [unicode-segmentation]: https://docs.rs/unicode-segmentation
+pub trait EmojiGraphemes<'a>: Iterator<Item = &'a str> + Sized {
+    /// Returns an iterator that resolves each grapheme cluster to an emoji.
+    ///
+    /// Clusters are looked up using [`get()`][crate::get] and yield `None`
+    /// if they are not an emoji.
+    fn emojis(self) -> Emojis<Self> {
+        Emojis { iter: self }
+    }
+}
+
+impl<'a, I> EmojiGraphemes<'a> for I where I: Iterator<Item = &'a str> {}
+
+/// An iterator that resolves grapheme clusters to emojis.
+///
+/// Returned by [`EmojiGraphemes::emojis()`].
+#[derive(Debug, Clone)]
+pub struct Emojis<I> {
+    iter: I,
+}
+
+impl<'a, I> Iterator for Emojis<I>
+where
+    I: Iterator<Item = &'a str>,
+{
+    type Item = Option<&'static Emoji>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(crate::get)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I> DoubleEndedIterator for Emojis<I>
+where
+    I: DoubleEndedIterator<Item = &'a str>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(crate::get)
+    }
+}
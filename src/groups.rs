@@ -0,0 +1,166 @@
+//! A compact set of groups.
+
+use core::fmt;
+
+use crate::{Emoji, Group};
+
+/// A set of groups.
+///
+/// The set is stored as a bitmap with one bit for every [`Group`], so checking
+/// whether an emoji is in one of many groups is a single bitwise operation
+/// instead of a comparison against each group. This makes it well suited to
+/// hot filtering loops.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{Group, Groups};
+///
+/// const OUTDOORS: Groups = Groups::new()
+///     .with(Group::AnimalsAndNature)
+///     .with(Group::TravelAndPlaces);
+///
+/// let count = emojis::iter().filter(|e| OUTDOORS.contains(e)).count();
+/// assert_eq!(
+///     count,
+///     Group::AnimalsAndNature.emojis().count() + Group::TravelAndPlaces.emojis().count()
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Groups {
+    bits: u16,
+}
+
+impl Groups {
+    /// Construct a new empty set.
+    pub const fn new() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Construct a set of all groups.
+    pub const fn all() -> Self {
+        let mut groups = Self::new();
+        let mut i = 0;
+        while i < Group::ALL.len() {
+            groups = groups.with(Group::ALL[i]);
+            i += 1;
+        }
+        groups
+    }
+
+    /// Returns this set with the given group added.
+    ///
+    /// This is useful for constructing sets at compile time.
+    pub const fn with(self, group: Group) -> Self {
+        Self {
+            bits: self.bits | bit(group),
+        }
+    }
+
+    /// Returns the bitmap used to store the set.
+    ///
+    /// Bit `n` is set if the group at position `n` in [`Group::ALL`] is in the
+    /// set.
+    pub const fn bits(&self) -> u16 {
+        self.bits
+    }
+
+    /// Adds a group to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, group: Group) -> bool {
+        let new = !self.contains_group(group);
+        self.bits |= bit(group);
+        new
+    }
+
+    /// Removes a group from the set, returning whether it was present.
+    pub fn remove(&mut self, group: Group) -> bool {
+        let present = self.contains_group(group);
+        self.bits &= !bit(group);
+        present
+    }
+
+    /// Returns whether the emoji's group is in the set.
+    pub const fn contains(&self, emoji: &Emoji) -> bool {
+        self.bits & emoji.group_bit().bits != 0
+    }
+
+    /// Returns whether the group is in the set.
+    pub const fn contains_group(&self, group: Group) -> bool {
+        self.bits & bit(group) != 0
+    }
+
+    /// Returns the number of groups in the set.
+    pub const fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns whether the set is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns an iterator over the groups in the set.
+    pub fn iter(&self) -> impl Iterator<Item = Group> + '_ {
+        Group::iter().filter(move |&group| self.contains_group(group))
+    }
+
+    /// Returns the union of this set and another.
+    pub const fn union(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Returns the intersection of this set and another.
+    pub const fn intersection(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Returns the groups in this set that are not in the other.
+    pub const fn difference(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+}
+
+/// Returns the bit for the given group.
+const fn bit(group: Group) -> u16 {
+    1 << group as u16
+}
+
+impl Default for Groups {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Groups {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<Group> for Groups {
+    fn from(group: Group) -> Self {
+        Self::new().with(group)
+    }
+}
+
+impl Extend<Group> for Groups {
+    fn extend<I: IntoIterator<Item = Group>>(&mut self, iter: I) {
+        for group in iter {
+            self.insert(group);
+        }
+    }
+}
+
+impl FromIterator<Group> for Groups {
+    fn from_iter<I: IntoIterator<Item = Group>>(iter: I) -> Self {
+        let mut groups = Self::new();
+        groups.extend(iter);
+        groups
+    }
+}
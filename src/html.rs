@@ -0,0 +1,107 @@
+//! Replacing emojis in HTML.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use crate::text::Matches;
+use crate::Emoji;
+
+/// Elements whose content is raw text and never contains emojis to replace.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
+
+/// Replaces emojis in the text nodes of the HTML using the given function.
+///
+/// Emojis inside tags, attribute values, comments, and raw text elements such
+/// as `<script>` and `<style>` are left unchanged. This is useful for
+/// pre-rendering emojis as images on the server, e.g. using Twemoji.
+///
+/// The HTML is not validated, this function only recognizes enough of the
+/// syntax to tell markup and text apart.
+///
+/// # Examples
+///
+/// ```
+/// let html = r#"<p title="🚀">Launch 🚀</p>"#;
+/// let out = emojis::html::emojify(html, |emoji| {
+///     format!(r#"<img alt="{}" src="{}.svg">"#, emoji, emoji.name())
+/// });
+/// assert_eq!(out, r#"<p title="🚀">Launch <img alt="🚀" src="rocket.svg"></p>"#);
+/// ```
+pub fn emojify<F, S>(html: &str, mut f: F) -> Cow<'_, str>
+where
+    F: FnMut(&'static Emoji) -> S,
+    S: AsRef<str>,
+{
+    let mut out = String::new();
+    let mut last = 0;
+    let mut text_start = 0;
+    let mut replace = |out: &mut String, last: &mut usize, start: usize, end: usize| {
+        for (range, emoji) in Matches::new(&html[start..end]) {
+            out.push_str(&html[*last..start + range.start]);
+            out.push_str(f(emoji).as_ref());
+            *last = start + range.end;
+        }
+    };
+    while let Some(i) = html[text_start..].find('<').map(|i| text_start + i) {
+        replace(&mut out, &mut last, text_start, i);
+        text_start = skip_markup(html, i);
+    }
+    replace(&mut out, &mut last, text_start, html.len());
+    if last == 0 {
+        return Cow::Borrowed(html);
+    }
+    out.push_str(&html[last..]);
+    Cow::Owned(out)
+}
+
+/// Returns the position after the markup starting at the given `<`.
+///
+/// For raw text elements this includes the content and the end tag.
+fn skip_markup(html: &str, start: usize) -> usize {
+    let rest = &html[start..];
+    if !rest[1..].starts_with(|c: char| c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?')) {
+        // Not markup, e.g. a less than sign in text.
+        return start + 1;
+    }
+    if rest.starts_with("<!--") {
+        return rest.find("-->").map_or(html.len(), |i| start + i + 3);
+    }
+    let end = tag_end(html, start);
+    let name = rest[1..]
+        .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .next()
+        .unwrap();
+    match RAW_TEXT_ELEMENTS
+        .iter()
+        .find(|raw| raw.eq_ignore_ascii_case(name))
+    {
+        Some(raw) => find_end_tag(html, end, raw).map_or(html.len(), |i| tag_end(html, i)),
+        None => end,
+    }
+}
+
+/// Returns the position after the `>` that closes the tag starting at the
+/// given position, skipping over quoted attribute values.
+fn tag_end(html: &str, start: usize) -> usize {
+    let mut quote = None;
+    for (i, b) in html.bytes().enumerate().skip(start + 1) {
+        match (quote, b) {
+            (None, b'"' | b'\'') => quote = Some(b),
+            (Some(q), _) if q == b => quote = None,
+            (None, b'>') => return i + 1,
+            _ => {}
+        }
+    }
+    html.len()
+}
+
+/// Returns the position of the end tag for the given element.
+fn find_end_tag(html: &str, start: usize, name: &str) -> Option<usize> {
+    let bytes = html.as_bytes();
+    (start..bytes.len()).find(|&i| {
+        bytes[i..].starts_with(b"</")
+            && bytes
+                .get(i + 2..i + 2 + name.len())
+                .map_or(false, |n| n.eq_ignore_ascii_case(name.as_bytes()))
+    })
+}
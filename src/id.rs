@@ -0,0 +1,151 @@
+//! Compact emoji identifiers.
+
+use core::num::NonZeroU16;
+
+use crate::Emoji;
+
+/// A compact identifier for an emoji.
+///
+/// Every emoji, including skin tone variations, has a unique id. Ids are only
+/// stable for a given emoji dataset since they depend on the order of the
+/// Unicode CLDR data, so persist them along with [`dataset_fingerprint()`]
+/// and upgrade old ids with [`EmojiId::migrate_from()`].
+///
+/// # Stability
+///
+/// Ids change whenever the emoji dataset changes, which only happens when the
+/// crate is updated to a new Unicode or CLDR release, and never in a patch
+/// release. When persisting ids also persist [`dataset_fingerprint()`] and
+/// compare it at startup to detect an incompatible upgrade, ids from a
+/// previous dataset can be upgraded with [`EmojiId::migrate_from()`].
+///
+/// # Layout
+///
+/// This type is guaranteed to have the same layout as [`NonZeroU16`], so both
+/// `EmojiId` and `Option<EmojiId>` are two bytes.
+///
+/// # Examples
+///
+/// ```
+/// use std::mem::size_of;
+/// use emojis::EmojiId;
+///
+/// let rocket = emojis::get("🚀").unwrap();
+/// assert_eq!(rocket.id().emoji(), rocket);
+/// assert_eq!(size_of::<Option<EmojiId>>(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct EmojiId(NonZeroU16);
+
+const ONE: NonZeroU16 = match NonZeroU16::new(1) {
+    Some(one) => one,
+    None => unreachable!(),
+};
+
+/// The fingerprint of the emoji dataset, see [`dataset_fingerprint()`].
+const FINGERPRINT: u64 = fingerprint();
+
+/// Returns a fingerprint of the emoji dataset compiled into this crate.
+///
+/// The fingerprint is a hash of every emoji in order, so it changes exactly
+/// when an [`EmojiId`], [`Emoji::order_key()`], or the
+/// [`EmojiSet::to_bytes()`][crate::EmojiSet::to_bytes] layout would resolve to
+/// a different emoji. Two builds with the same fingerprint agree on all of
+/// them.
+///
+/// # Examples
+///
+/// ```
+/// # let stored = emojis::dataset_fingerprint();
+/// // `stored` was persisted alongside some `EmojiId`s
+/// if stored != emojis::dataset_fingerprint() {
+///     panic!("emoji ids were persisted with an incompatible dataset");
+/// }
+/// ```
+pub const fn dataset_fingerprint() -> u64 {
+    FINGERPRINT
+}
+
+/// Computes the 64-bit FNV-1a hash of every emoji separated by `0xff`, which
+/// never occurs in UTF-8.
+const fn fingerprint() -> u64 {
+    const PRIME: u64 = 0x100_0000_01b3;
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+    while i < crate::gen::EMOJIS.len() {
+        let bytes = crate::gen::EMOJIS[i].as_str().as_bytes();
+        let mut j = 0;
+        while j < bytes.len() {
+            hash = (hash ^ bytes[j] as u64).wrapping_mul(PRIME);
+            j += 1;
+        }
+        hash = (hash ^ 0xff).wrapping_mul(PRIME);
+        i += 1;
+    }
+    hash
+}
+
+impl EmojiId {
+    /// Construct an id from its raw value.
+    ///
+    /// Returns `None` if the value is not the id of an emoji.
+    pub fn new(value: u16) -> Option<Self> {
+        let id = NonZeroU16::new(value)?;
+        (usize::from(value) <= crate::gen::EMOJIS.len()).then(|| Self(id))
+    }
+
+    /// Construct an id from the raw value of an id persisted with the dataset
+    /// with the given fingerprint.
+    ///
+    /// If the fingerprint is the current [`dataset_fingerprint()`] this is the
+    /// same as [`EmojiId::new()`], otherwise the id is migrated using the
+    /// tables in [`migrations`][crate::migrations]. Returns `None` if the
+    /// dataset is unknown, the value is not an id in that dataset, or the
+    /// emoji no longer exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::EmojiId;
+    ///
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// let (fingerprint, value) = (emojis::dataset_fingerprint(), rocket.id().get().get());
+    /// assert_eq!(EmojiId::migrate_from(fingerprint, value), Some(rocket.id()));
+    /// assert_eq!(EmojiId::migrate_from(0, value), None);
+    /// ```
+    pub fn migrate_from(fingerprint: u64, value: u16) -> Option<Self> {
+        if fingerprint == FINGERPRINT {
+            Self::new(value)
+        } else {
+            crate::migrations::find(fingerprint)?.get(value)
+        }
+    }
+
+    /// Returns the raw value of this id.
+    pub const fn get(self) -> NonZeroU16 {
+        self.0
+    }
+
+    /// Returns the emoji for this id.
+    pub fn emoji(self) -> &'static Emoji {
+        &crate::gen::EMOJIS[usize::from(self.0.get()) - 1]
+    }
+}
+
+impl Emoji {
+    /// Returns the compact identifier for this emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// let id = rocket.id();
+    /// assert_eq!(emojis::EmojiId::new(id.get().get()), Some(id));
+    /// ```
+    pub fn id(&self) -> EmojiId {
+        // There are fewer than `u16::MAX` emojis so the fallback is never used.
+        let value = (self.index() as u16).wrapping_add(1);
+        EmojiId(NonZeroU16::new(value).unwrap_or(ONE))
+    }
+}
@@ -0,0 +1,222 @@
+//! Streaming text transformations using [`std::io::Write`].
+//!
+//! The writers in this module transform data as it is written to them and
+//! correctly handle matches that straddle separate write calls. Only a small
+//! amount of data that could be the start of a match is ever buffered.
+//!
+//! Since a trailing partial match can only be resolved once all the data has
+//! been written you should call `finish()` when done. If the writer is dropped
+//! instead any buffered data is written on a best-effort basis.
+
+use std::io;
+use std::io::Write;
+use std::vec::Vec;
+
+use crate::text::is_emoji_start_byte;
+use crate::tokenizer::is_shortcode_byte;
+use crate::{MAX_BYTES, MAX_SHORTCODE_LEN};
+
+/// A writer that replaces GitHub shortcodes, e.g. `:rocket:`, with emojis.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use emojis::io::ShortcodeExpander;
+///
+/// let mut w = ShortcodeExpander::new(Vec::new());
+/// w.write_all(b"launch :roc")?;
+/// w.write_all(b"ket: now")?;
+/// assert_eq!(w.finish()?, "launch 🚀 now".as_bytes());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct ShortcodeExpander<W: Write> {
+    inner: Option<W>,
+    /// A possible shortcode, starting with a colon.
+    pending: Vec<u8>,
+}
+
+/// A writer that replaces emojis with their GitHub shortcodes, e.g. `:rocket:`.
+///
+/// Emojis that do not have a shortcode are written unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use emojis::io::Demojifier;
+///
+/// let mut w = Demojifier::new(Vec::new());
+/// w.write_all(&"launch 🚀 now".as_bytes()[..9])?;
+/// w.write_all(&"launch 🚀 now".as_bytes()[9..])?;
+/// assert_eq!(w.finish()?, b"launch :rocket: now");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Demojifier<W: Write> {
+    inner: Option<W>,
+    /// Data that could still be the start of an emoji.
+    pending: Vec<u8>,
+}
+
+impl<W: Write> ShortcodeExpander<W> {
+    /// Construct a new writer that writes to the given writer.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: Some(inner),
+            pending: Vec::with_capacity(MAX_SHORTCODE_LEN + 1),
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// Writes any buffered data and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        let mut inner = self.inner.take().unwrap();
+        inner.write_all(&self.pending)?;
+        Ok(inner)
+    }
+
+    fn transform(&mut self, buf: &[u8]) -> io::Result<()> {
+        let inner = self.inner.as_mut().unwrap();
+        let mut start = 0;
+        for (i, &b) in buf.iter().enumerate() {
+            if self.pending.is_empty() {
+                if b == b':' {
+                    inner.write_all(&buf[start..i])?;
+                    self.pending.push(b);
+                }
+                continue;
+            }
+            start = i + 1;
+            if b == b':' {
+                match crate::get_by_shortcode(core::str::from_utf8(&self.pending[1..]).unwrap()) {
+                    Some(emoji) => {
+                        inner.write_all(emoji.as_bytes())?;
+                        self.pending.clear();
+                    }
+                    None => {
+                        // The closing colon could be the start of the next
+                        // shortcode.
+                        inner.write_all(&self.pending)?;
+                        self.pending.truncate(1);
+                    }
+                }
+            } else if is_shortcode_byte(b) && self.pending.len() <= MAX_SHORTCODE_LEN {
+                self.pending.push(b);
+            } else {
+                inner.write_all(&self.pending)?;
+                self.pending.clear();
+                start = i;
+            }
+        }
+        if self.pending.is_empty() {
+            inner.write_all(&buf[start..])?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for ShortcodeExpander<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.transform(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.as_mut().unwrap().flush()
+    }
+}
+
+impl<W: Write> Drop for ShortcodeExpander<W> {
+    fn drop(&mut self) {
+        if let Some(inner) = &mut self.inner {
+            let _ = inner.write_all(&self.pending);
+        }
+    }
+}
+
+impl<W: Write> Demojifier<W> {
+    /// Construct a new writer that writes to the given writer.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner: Some(inner),
+            pending: Vec::with_capacity(2 * MAX_BYTES),
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// Writes any buffered data and returns the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.transform(true)?;
+        Ok(self.inner.take().unwrap())
+    }
+
+    /// Transforms the pending data.
+    ///
+    /// Unless this is the final call, data is only transformed while there is
+    /// enough lookahead to determine the longest matching emoji.
+    fn transform(&mut self, last: bool) -> io::Result<()> {
+        let inner = self.inner.as_mut().unwrap();
+        let mut i = 0;
+        let mut start = 0;
+        while i < self.pending.len() && (last || self.pending.len() - i >= MAX_BYTES) {
+            if !is_emoji_start_byte(self.pending[i]) {
+                i += 1;
+                continue;
+            }
+            let rest = &self.pending[i..];
+            let found = (1..=rest.len().min(MAX_BYTES)).rev().find_map(|n| {
+                let s = core::str::from_utf8(&rest[..n]).ok()?;
+                crate::get(s).map(|emoji| (n, emoji))
+            });
+            match found {
+                Some((n, emoji)) => {
+                    inner.write_all(&self.pending[start..i])?;
+                    match emoji.shortcode() {
+                        Some(shortcode) => {
+                            inner.write_all(b":")?;
+                            inner.write_all(shortcode.as_bytes())?;
+                            inner.write_all(b":")?;
+                        }
+                        None => inner.write_all(&rest[..n])?,
+                    }
+                    i += n;
+                    start = i;
+                }
+                None => i += 1,
+            }
+        }
+        inner.write_all(&self.pending[start..i])?;
+        self.pending.drain(..i);
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for Demojifier<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+        self.transform(false)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.as_mut().unwrap().flush()
+    }
+}
+
+impl<W: Write> Drop for Demojifier<W> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            let _ = self.transform(true);
+        }
+    }
+}
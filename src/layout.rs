@@ -0,0 +1,76 @@
+//! The layout of an emoji keyboard.
+
+use crate::{Emoji, Group, Subgroup};
+
+/// A group in the [`layout()`] of an emoji keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayoutGroup {
+    pub(crate) group: Group,
+    pub(crate) subgroups: &'static [LayoutSubgroup],
+}
+
+/// A subgroup in the [`layout()`] of an emoji keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayoutSubgroup {
+    pub(crate) subgroup: Subgroup,
+    pub(crate) emojis: &'static [&'static Emoji],
+}
+
+impl LayoutGroup {
+    /// Returns the group.
+    pub const fn group(&self) -> Group {
+        self.group
+    }
+
+    /// Returns the subgroups in this group in Unicode CLDR order.
+    pub const fn subgroups(&self) -> &'static [LayoutSubgroup] {
+        self.subgroups
+    }
+
+    /// Returns an iterator over all the emojis in this group in Unicode CLDR
+    /// order.
+    pub fn emojis(&self) -> impl Iterator<Item = &'static Emoji> {
+        self.subgroups
+            .iter()
+            .flat_map(|subgroup| subgroup.emojis.iter().copied())
+    }
+}
+
+impl LayoutSubgroup {
+    /// Returns the subgroup.
+    pub const fn subgroup(&self) -> Subgroup {
+        self.subgroup
+    }
+
+    /// Returns the emojis in this subgroup in Unicode CLDR order.
+    ///
+    /// Skin tone variations are collapsed into the default skin tone emoji.
+    pub const fn emojis(&self) -> &'static [&'static Emoji] {
+        self.emojis
+    }
+}
+
+/// Returns the layout of an emoji keyboard.
+///
+/// The layout is the groups, their subgroups, and the emojis in each subgroup,
+/// all in Unicode CLDR order, which is the order system emoji keyboards are
+/// based on. Skin tone variations are collapsed into the default skin tone
+/// emoji, since keyboards usually offer them in a popup instead, see
+/// [`Emoji::skin_tones()`]. The whole layout is a static table so this takes
+/// *Ο(1)* time.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{Group, Subgroup};
+///
+/// let layout = emojis::layout();
+/// assert_eq!(layout[0].group(), Group::SmileysAndEmotion);
+///
+/// let subgroup = &layout[0].subgroups()[0];
+/// assert_eq!(subgroup.subgroup(), Subgroup::FaceSmiling);
+/// assert_eq!(subgroup.emojis()[0], "😀");
+/// ```
+pub fn layout() -> &'static [LayoutGroup] {
+    crate::gen::LAYOUT
+}
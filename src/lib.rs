@@ -6,6 +6,7 @@
 //! - Lookup up emoji by [GitHub shortcode][gemoji]
 //! - Iterate over emojis in recommended order
 //! - Iterate over emojis in an emoji group, e.g. "Smileys & Emotion" or "Flags"
+//! - Iterate over emojis in an emoji subgroup, e.g. "face-smiling" or "animal-bird"
 //! - Iterate over the skin tones for an emoji
 //! - Uses Unicode v15.0 emoji specification
 //!
@@ -30,12 +31,79 @@
 //!
 //! These operations take *Ο(1)* time.
 //!
+//! # Optional features
+//!
+//! - **shortcodes** *(enabled by default)*: Includes the [gemoji] shortcode
+//!   table used by [`get_by_shortcode()`] and [`Emoji::shortcode()`].
+//!   Disabling it removes the table from the binary for users that only
+//!   resolve shortcodes with their own [`ShortcodeTable`].
+//! - **alloc**: Enables functions that return owned strings, such as
+//!   [`strip_groups()`], and the [`reactions`] module which counts emoji
+//!   reactions.
+//! - **std**: Enables the [`io`] module which provides writers that transform
+//!   emojis and shortcodes in streamed text, and the [`export`] module which
+//!   writes the emoji data as CSV or TSV, and the [`preferences`] module
+//!   which stores a process-wide preferred skin tone and gender, and
+//!   [`term::Capability::detect()`] which detects what the terminal can
+//!   display, and [`Dataset`] which loads an emoji dataset at runtime.
+//!   Implies **alloc**.
+//! - **platform-support**: Enables [`Emoji::supported_on()`] which reports
+//!   whether an emoji is supported by a version of iOS, Android, or Windows.
+//! - **sprite-sheet**: Enables [`Emoji::sprite_position()`] which returns the
+//!   position of an emoji in an [emoji-datasource] sprite sheet.
+//! - **aliases**: Enables [`Emoji::aliases()`] which returns common and
+//!   scientific names for animals and plants, e.g. "canine" for 🐕.
+//! - **sentiment**: Enables [`Emoji::sentiment()`] which returns a coarse
+//!   positive, negative, or neutral polarity for smileys and emotions.
+//! - **locales**: Enables [`search_in()`] which searches for emojis using
+//!   localized keywords, e.g. "rakete" for 🚀 in German.
+//! - **phf-map**: Enables [`UNICODE_MAP`] and [`SHORTCODE_MAP`] which expose
+//!   the lookup tables as [`phf`] maps for composing with other `phf` tables.
+//! - **gemoji**: Enables the [`gemoji`] module which provides GitHub's custom
+//!   shortcodes that are not Unicode emojis, e.g. `:octocat:`.
+//! - **simd**: Scans text for emojis by skipping ASCII a word at a time,
+//!   which is much faster for mostly ASCII text such as logs.
+//! - **fuzzing**: Enables the [`fuzz`] module which provides properties of
+//!   this crate for use in downstream fuzz and property tests.
+//! - **source-lines**: Enables [`Emoji::source_line()`] which returns the
+//!   line in the Unicode emoji-test.txt file an emoji was generated from.
+//! - **compressed-data**: Compresses the table used by **source-lines**,
+//!   and nothing else, shrinking it from about 400 KB to about 55 KB. The
+//!   table is decompressed on the heap the first time it is used, trading a
+//!   one-off decompression for a smaller binary. The core tables are always
+//!   stored as is, so without **source-lines** this feature has no effect. On
+//!   targets without atomic pointers the table is stored uncompressed.
+//!   Implies **alloc**.
+//! - **colors**: Enables [`Emoji::color()`] and [`with_color()`] which
+//!   filter emojis by their dominant color.
+//! - **descriptions**: Enables [`Emoji::description()`] which returns a short
+//!   prose description of an emoji. This adds a large table to the binary.
+//! - **serde**: Implements `Serialize` and `Deserialize` for [`EmojiSet`]
+//!   using the layout of [`EmojiSet::to_bytes()`], and for [`RecentList`] as
+//!   a sequence of emoji strings.
+//! - **proptest**: Enables the [`strategies`] module which provides
+//!   [`proptest`] strategies that generate emojis and text containing emojis.
+//!   Implies **std**.
+//! - **rusqlite**: Enables [`export::to_sqlite()`] which creates and populates
+//!   the tables written by [`export::to_sql()`] in a [`rusqlite`] database.
+//!   Implies **std**.
+//!
+//! # Panics
+//!
+//! No function in this crate panics on any input, except where documented in
+//! a "Panics" section, and each of those has a checked alternative that
+//! returns an error instead, e.g. [`Emoji::try_encode_utf16()`]. This is
+//! enforced by tests that run every function over adversarial input.
+//!
 //! # MSRV
 //!
 //! Currently the minimum supported Rust version is 1.60 due to the dependency
 //! on [`phf`]. The policy of this crate is to only increase the MSRV in a
 //! breaking release.
 //!
+//! The **proptest** and **rusqlite** features depend on those crates and
+//! follow their MSRVs instead.
+//!
 //! # Examples
 //!
 //! The returned [`Emoji`] struct has various information about the emoji.
@@ -86,21 +154,161 @@
 //! ```
 //!
 //! [examples/replace.rs]: https://github.com/rossmacarthur/emojis/blob/trunk/examples/replace.rs
+//! [emoji-datasource]: https://github.com/iamcal/emoji-data
 //! [gemoji]: https://github.com/github/gemoji
 
 #![no_std]
 
-#[cfg(test)]
+#[cfg(any(test, feature = "alloc"))]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+#[cfg(feature = "aliases")]
+mod alias;
+mod assert;
+pub mod codepoint_table;
+mod color;
+mod concept;
+mod couple;
+pub mod data;
+#[cfg(feature = "std")]
+mod dataset;
+mod dump;
+#[cfg(feature = "std")]
+pub mod export;
+mod ext;
+#[cfg(feature = "alloc")]
+mod filter;
+mod flag;
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
+#[cfg(feature = "gemoji")]
+pub mod gemoji;
 mod gen;
+mod gender;
+mod grapheme;
+mod groups;
+#[cfg(feature = "alloc")]
+pub mod html;
+mod id;
+#[cfg(feature = "std")]
+pub mod io;
+mod layout;
+#[cfg(feature = "locales")]
+mod locale;
+#[cfg(all(
+    feature = "source-lines",
+    feature = "compressed-data",
+    target_has_atomic = "ptr"
+))]
+mod lz;
+pub mod meta;
+pub mod migrations;
+mod name;
+mod options;
+mod owned;
+#[cfg(feature = "platform-support")]
+mod platform;
+#[cfg(feature = "std")]
+pub mod preferences;
+pub mod prelude;
+pub mod presets;
+mod profile;
+#[cfg(feature = "alloc")]
+pub mod reactions;
+mod recent;
+mod replaced;
+mod role;
+mod search;
+mod semantic;
+#[cfg(feature = "sentiment")]
+mod sentiment;
+mod series;
+mod set;
+mod shortcodes;
+mod sort;
+#[cfg(feature = "sprite-sheet")]
+mod sprite;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+pub mod term;
+mod text;
+mod tokenizer;
+mod validate;
+mod variation;
 
 use core::cmp;
 use core::convert;
 use core::fmt;
 use core::hash;
 
-pub use crate::gen::Group;
+#[doc(hidden)]
+pub use crate::assert::__contains;
+#[cfg(feature = "colors")]
+pub use crate::color::with_color;
+pub use crate::color::Color;
+pub use crate::couple::{couple_with_heart, handshake};
+#[cfg(feature = "std")]
+pub use crate::dataset::{Dataset, DecodeDatasetError};
+pub use crate::dump::{dump, Record};
+pub use crate::ext::{EmojiStrExt, TextEmojis};
+#[cfg(feature = "alloc")]
+pub use crate::filter::{Filter, FilterPolicy};
+pub use crate::flag::{flag_to_letters, letters_to_flag, regional_indicator, spell, Spell};
+/// A map of every GitHub shortcode to its emoji.
+///
+/// This is the table used by [`get_by_shortcode()`].
+#[cfg(all(feature = "phf-map", feature = "shortcodes"))]
+pub use crate::gen::shortcode::EMOJI_MAP as SHORTCODE_MAP;
+/// A map of every emoji and emoji variation to its emoji.
+///
+/// This is the table used by [`get()`]. It includes the unqualified and
+/// minimally-qualified variations, so keys are not always equal to the
+/// emoji they map to.
+#[cfg(feature = "phf-map")]
+pub use crate::gen::unicode::EMOJI_MAP as UNICODE_MAP;
+pub use crate::gen::{Group, Subgroup};
+pub use crate::gender::Gender;
+pub use crate::grapheme::{EmojiGraphemes, Emojis};
+pub use crate::groups::Groups;
+pub use crate::id::{dataset_fingerprint, EmojiId};
+pub use crate::layout::{layout, LayoutGroup, LayoutSubgroup};
+#[cfg(feature = "locales")]
+pub use crate::locale::{search_in, Locale};
+pub use crate::options::IterOptions;
+pub use crate::owned::EmojiBuf;
+#[cfg(feature = "platform-support")]
+pub use crate::platform::{Platform, PlatformVersion};
+#[cfg(feature = "alloc")]
+pub use crate::profile::convert_shortcodes;
+pub use crate::profile::Profile;
+pub use crate::recent::RecentList;
+pub use crate::replaced::Replaced;
+pub use crate::role::{iter_roles, Role};
+pub use crate::search::{find_by_name, with_keyword};
+#[cfg(feature = "alloc")]
+pub use crate::search::{search_with, Scorer};
+pub use crate::semantic::{transport, weather, TransportMode, WeatherCondition};
+#[cfg(feature = "sentiment")]
+pub use crate::sentiment::Sentiment;
+pub use crate::series::{
+    circle, clock, digits, heart, medal, moon_phase, square, zodiac, Digits, MoonPhase, ZodiacSign,
+};
+pub use crate::set::{DecodeSetError, EmojiSet};
+pub use crate::shortcodes::{GitHubShortcodes, ShortcodeTable};
+pub use crate::sort::{is_sorted, is_sorted_by_name, sort, sort_by_name};
+#[cfg(feature = "sprite-sheet")]
+pub use crate::sprite::{SpritePosition, SpriteSheet};
+pub use crate::text::{
+    could_start_emoji, detect_skin_tone, first, grapheme_count, histogram, is_emoji_only, last,
+    pattern, sms_units, truncate, weighted_len, wrap_indices, EmojiPattern, SmsUnits, WeightConfig,
+    WrapIndices,
+};
+#[cfg(feature = "alloc")]
+pub use crate::text::{replace_shortcodes_markdown, strip_groups, strip_set};
+pub use crate::tokenizer::{ShortcodeToken, ShortcodeTokenizer, TokenKind};
+pub use crate::validate::{validate_reaction, validate_reaction_in, ValidationError};
 
 /// Represents an emoji.
 ///
@@ -113,7 +321,8 @@ pub struct Emoji {
     unicode_version: UnicodeVersion,
     group: Group,
     skin_tone: Option<(u16, SkinTone)>,
-    aliases: Option<&'static [&'static str]>,
+    /// The index of this emoji in the table of all emojis.
+    index: u16,
 }
 
 /// A Unicode version.
@@ -134,6 +343,57 @@ pub enum SkinTone {
     Dark,
 }
 
+impl SkinTone {
+    /// Returns the skin tone for the given Fitzpatrick modifier character.
+    pub(crate) fn from_modifier(c: char) -> Option<Self> {
+        match c {
+            '\u{1f3fb}' => Some(Self::Light),
+            '\u{1f3fc}' => Some(Self::MediumLight),
+            '\u{1f3fd}' => Some(Self::Medium),
+            '\u{1f3fe}' => Some(Self::MediumDark),
+            '\u{1f3ff}' => Some(Self::Dark),
+            _ => None,
+        }
+    }
+
+    /// Returns the Fitzpatrick modifier character for this skin tone.
+    pub(crate) fn modifier(self) -> Option<char> {
+        match self {
+            Self::Default => None,
+            Self::Light => Some('\u{1f3fb}'),
+            Self::MediumLight => Some('\u{1f3fc}'),
+            Self::Medium => Some('\u{1f3fd}'),
+            Self::MediumDark => Some('\u{1f3fe}'),
+            Self::Dark => Some('\u{1f3ff}'),
+        }
+    }
+
+    /// Returns an iterator that maps each emoji to its version with this skin
+    /// tone.
+    ///
+    /// Emojis that don't support this skin tone are yielded unchanged. This is
+    /// useful for rendering a whole emoji picker in a user's preferred skin
+    /// tone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::SkinTone;
+    ///
+    /// let emojis = ["👍", "🚀", "👋🏿"].iter().filter_map(|s| emojis::get(s));
+    /// let toned: Vec<_> = SkinTone::Medium.apply_to_all(emojis).map(|e| e.as_str()).collect();
+    /// assert_eq!(toned, ["👍🏽", "🚀", "👋🏽"]);
+    /// ```
+    pub fn apply_to_all<I>(self, emojis: I) -> impl Iterator<Item = &'static Emoji>
+    where
+        I: IntoIterator<Item = &'static Emoji>,
+    {
+        emojis
+            .into_iter()
+            .map(move |emoji| emoji.with_skin_tone(self).unwrap_or(emoji))
+    }
+}
+
 impl UnicodeVersion {
     /// Construct a new version.
     pub const fn new(major: u32, minor: u32) -> Self {
@@ -147,6 +407,37 @@ impl UnicodeVersion {
     pub const fn minor(self) -> u32 {
         self.minor
     }
+
+    /// Returns the year this emoji version was released.
+    ///
+    /// Returns `None` if the version is not known to this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::UnicodeVersion;
+    ///
+    /// assert_eq!(UnicodeVersion::new(13, 1).release_year(), Some(2020));
+    /// assert_eq!(UnicodeVersion::new(99, 0).release_year(), None);
+    /// ```
+    pub const fn release_year(self) -> Option<u32> {
+        let year = match (self.major, self.minor) {
+            (0, 6) => 2010,
+            (0, 7) => 2014,
+            (1, 0) | (2, 0) => 2015,
+            (3, 0) | (4, 0) => 2016,
+            (5, 0) => 2017,
+            (11, 0) => 2018,
+            (12, 0) | (12, 1) => 2019,
+            (13, 0) | (13, 1) => 2020,
+            (14, 0) => 2021,
+            (15, 0) => 2022,
+            (15, 1) => 2023,
+            (16, 0) => 2024,
+            _ => return None,
+        };
+        Some(year)
+    }
 }
 
 impl Emoji {
@@ -174,6 +465,171 @@ impl Emoji {
         self.emoji.as_bytes()
     }
 
+    /// Returns the number of bytes required to encode this emoji as UTF-8.
+    ///
+    /// This is never greater than [`MAX_EMOJI_BYTES`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.utf8_len(), 4);
+    /// ```
+    pub const fn utf8_len(&self) -> usize {
+        self.emoji.len()
+    }
+
+    /// Returns the number of UTF-16 code units required to encode this emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.utf16_len(), 2);
+    /// ```
+    pub fn utf16_len(&self) -> usize {
+        self.emoji.chars().map(char::len_utf16).sum()
+    }
+
+    /// Encodes this emoji as UTF-16 into the provided buffer, returning the
+    /// number of code units written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough. A buffer of length
+    /// [`utf16_len()`][Emoji::utf16_len] is always large enough, otherwise use
+    /// [`try_encode_utf16()`][Emoji::try_encode_utf16].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// let mut buf = [0; 2];
+    /// let n = rocket.encode_utf16(&mut buf);
+    /// assert_eq!(&buf[..n], &[0xd83d, 0xde80]);
+    /// ```
+    pub fn encode_utf16(&self, dst: &mut [u16]) -> usize {
+        let mut n = 0;
+        for c in self.emoji.chars() {
+            n += c.encode_utf16(&mut dst[n..]).len();
+        }
+        n
+    }
+
+    /// Encodes this emoji as UTF-16 into the given buffer, returning the
+    /// number of code units written or an error if the buffer is not large
+    /// enough.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.try_encode_utf16(&mut [0; 2]), Ok(2));
+    /// assert_eq!(rocket.try_encode_utf16(&mut [0; 1]).unwrap_err().required(), 2);
+    /// ```
+    pub fn try_encode_utf16(&self, dst: &mut [u16]) -> Result<usize, BufferTooSmall> {
+        let required = self.utf16_len();
+        if dst.len() < required {
+            return Err(BufferTooSmall { required });
+        }
+        Ok(self.encode_utf16(dst))
+    }
+
+    /// Writes this emoji to the given writer.
+    ///
+    /// This is useful with fixed capacity writers, since unlike formatting
+    /// with [`Display`][fmt::Display] the error from the writer is returned
+    /// directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// let mut s = String::new();
+    /// rocket.write_to(&mut s).unwrap();
+    /// assert_eq!(s, "🚀");
+    /// ```
+    pub fn write_to<W>(&self, w: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        w.write_str(self.emoji)
+    }
+
+    /// Copies this emoji as UTF-8 into the provided buffer, returning the
+    /// number of bytes written.
+    ///
+    /// Returns an error and leaves the buffer unchanged if it is not large
+    /// enough.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// let mut buf = [0; 4];
+    /// let n = rocket.copy_to_slice(&mut buf).unwrap();
+    /// assert_eq!(&buf[..n], "🚀".as_bytes());
+    ///
+    /// let err = rocket.copy_to_slice(&mut buf[..2]).unwrap_err();
+    /// assert_eq!(err.required(), 4);
+    /// ```
+    pub fn copy_to_slice(&self, dst: &mut [u8]) -> Result<usize, BufferTooSmall> {
+        let bytes = self.emoji.as_bytes();
+        match dst.get_mut(..bytes.len()) {
+            Some(dst) => {
+                dst.copy_from_slice(bytes);
+                Ok(bytes.len())
+            }
+            None => Err(BufferTooSmall {
+                required: bytes.len(),
+            }),
+        }
+    }
+
+    /// Returns the number of Unicode scalar values in this emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.scalar_count(), 1);
+    ///
+    /// let pirate = emojis::get("🏴‍☠️").unwrap();
+    /// assert_eq!(pirate.scalar_count(), 4);
+    /// ```
+    pub fn scalar_count(&self) -> usize {
+        self.emoji.chars().count()
+    }
+
+    /// Returns the largest Unicode scalar value in this emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.max_scalar(), '\u{1f680}');
+    /// ```
+    pub fn max_scalar(&self) -> char {
+        self.emoji.chars().max().unwrap_or('\0')
+    }
+
+    /// Returns whether every scalar value in this emoji is in the Basic
+    /// Multilingual Plane.
+    ///
+    /// Such emojis are encoded without surrogate pairs in UTF-16 and with at
+    /// most three bytes per scalar value in UTF-8, e.g. they can be stored in
+    /// MySQL `utf8mb3` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(emojis::get("☕").unwrap().is_bmp());
+    /// assert!(!emojis::get("🚀").unwrap().is_bmp());
+    /// ```
+    pub fn is_bmp(&self) -> bool {
+        self.max_scalar() <= '\u{ffff}'
+    }
+
     /// Returns the CLDR short name for this emoji.
     ///
     /// # Examples
@@ -186,6 +642,82 @@ impl Emoji {
         self.name
     }
 
+    /// Returns the CLDR text-to-speech annotation for this emoji.
+    ///
+    /// This is the officially recommended spoken form of the emoji and should
+    /// be preferred by screen readers and alt-text generators. In English it
+    /// is currently the same as the [`name()`][Emoji::name] for every emoji,
+    /// since the names in emoji-test.txt are taken from CLDR.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let cool = emojis::get("😎").unwrap();
+    /// assert_eq!(cool.tts(), "smiling face with sunglasses");
+    /// ```
+    pub fn tts(&self) -> &str {
+        let i = self.index();
+        match crate::gen::TTS.binary_search_by_key(&i, |&(j, _)| j) {
+            Ok(k) => crate::gen::TTS[k].1,
+            Err(_) => self.name,
+        }
+    }
+
+    /// Returns the line in the Unicode [emoji-test.txt] file that this emoji
+    /// was generated from.
+    ///
+    /// This is intended for tracing data bugs back to the upstream file, the
+    /// line includes the code points, the qualification status, and the
+    /// comment with the Unicode version and name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert!(rocket.source_line().starts_with("1F680 "));
+    /// assert!(rocket.source_line().ends_with("; fully-qualified     # 🚀 E0.6 rocket"));
+    /// ```
+    ///
+    /// [emoji-test.txt]: https://unicode.org/Public/emoji/15.0/emoji-test.txt
+    #[cfg(feature = "source-lines")]
+    pub fn source_line(&self) -> &'static str {
+        #[cfg(not(all(feature = "compressed-data", target_has_atomic = "ptr")))]
+        {
+            crate::gen::SOURCE_LINES[self.index()]
+        }
+        #[cfg(all(feature = "compressed-data", target_has_atomic = "ptr"))]
+        {
+            crate::lz::source_line(self.index())
+        }
+    }
+
+    /// Returns a short prose description of this emoji, e.g. for help
+    /// tooltips or accessibility text that is richer than the [name].
+    ///
+    /// Every skin tone variation has the same description as the default skin
+    /// tone emoji. Descriptions are maintained by this crate and do not yet
+    /// cover every emoji, so this returns `None` if there is no description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let thumbs_up = emojis::get("👍🏽").unwrap();
+    /// assert!(thumbs_up.description().unwrap().contains("thumbs up"));
+    /// ```
+    ///
+    /// [name]: Emoji::name
+    #[cfg(feature = "descriptions")]
+    pub fn description(&self) -> Option<&'static str> {
+        let i = match self.skin_tone {
+            Some((i, _)) => usize::from(i),
+            None => self.index(),
+        };
+        let k = crate::gen::DESCRIPTIONS
+            .binary_search_by_key(&i, |&(j, _)| j)
+            .ok()?;
+        Some(crate::gen::DESCRIPTIONS[k].1)
+    }
+
     /// Returns the Unicode version this emoji first appeared in.
     ///
     /// # Examples
@@ -200,6 +732,27 @@ impl Emoji {
         self.unicode_version
     }
 
+    /// Returns the year this emoji was introduced.
+    ///
+    /// This is the release year of its [Unicode
+    /// version][Emoji::unicode_version()]. Emojis from before the versions
+    /// were numbered, i.e. version 0.6 and 0.7, are dated by the Unicode
+    /// release that first encoded them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let villain = emojis::get("🦹").unwrap();
+    /// assert_eq!(villain.introduced_year(), 2018);
+    /// ```
+    pub const fn introduced_year(&self) -> u32 {
+        // Every version in the dataset is known so the fallback is never used.
+        match self.unicode_version.release_year() {
+            Some(year) => year,
+            None => 0,
+        }
+    }
+
     /// Returns this emoji's group.
     ///
     /// # Examples
@@ -214,6 +767,20 @@ impl Emoji {
         self.group
     }
 
+    /// Returns this emoji's group as a set containing only that group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{Group, Groups};
+    ///
+    /// let flag = emojis::get("🇿🇦").unwrap();
+    /// assert_eq!(flag.group_bit(), Groups::from(Group::Flags));
+    /// ```
+    pub const fn group_bit(&self) -> Groups {
+        Groups::new().with(self.group)
+    }
+
     /// Returns the skin tone of this emoji.
     ///
     /// # Examples
@@ -238,6 +805,53 @@ impl Emoji {
         self.skin_tone.map(|(_, v)| v)
     }
 
+    /// Returns whether this emoji is likely to render on a platform that
+    /// supports emojis up to the given Unicode version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::UnicodeVersion;
+    ///
+    /// let melting = emojis::get("🫠").unwrap();
+    /// assert!(!melting.likely_renderable(UnicodeVersion::new(13, 1)));
+    /// assert!(melting.likely_renderable(UnicodeVersion::new(14, 0)));
+    /// ```
+    pub fn likely_renderable(&self, max_version: UnicodeVersion) -> bool {
+        self.unicode_version <= max_version
+    }
+
+    /// Returns the position of this emoji in the Unicode CLDR emoji ordering.
+    ///
+    /// This matches the order of the [emoji-test.txt] file published by
+    /// Unicode, where skin tone variations directly follow their default skin
+    /// tone emoji. Sorting by this key sorts emojis identically to the rest of
+    /// this crate, e.g. [`iter()`]. Like [`EmojiId`] the key is only stable for
+    /// a given version of this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let grinning = emojis::get("😀").unwrap();
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(grinning.order_key(), 0);
+    /// assert!(grinning.order_key() < rocket.order_key());
+    /// ```
+    ///
+    /// [emoji-test.txt]: https://unicode.org/Public/emoji/15.0/emoji-test.txt
+    pub fn order_key(&self) -> u32 {
+        self.index() as u32
+    }
+
+    /// Returns the position of this emoji in the table of all emojis.
+    pub(crate) const fn index(&self) -> usize {
+        self.index as usize
+    }
+
+    pub(crate) fn is_default_skin_tone(&self) -> bool {
+        matches!(self.skin_tone(), Some(SkinTone::Default) | None)
+    }
+
     /// Returns an iterator over the emoji and all the related skin tone emojis.
     ///
     /// # Examples
@@ -258,7 +872,7 @@ impl Emoji {
     /// ```
     pub fn skin_tones(&self) -> Option<impl Iterator<Item = &'static Self>> {
         let (i, _) = self.skin_tone?;
-        Some(crate::gen::EMOJIS[i as usize..].iter().take(6))
+        Some(crate::gen::EMOJIS.get(i as usize..)?.iter().take(6))
     }
 
     /// Returns a version of this emoji that has the given skin tone.
@@ -285,14 +899,15 @@ impl Emoji {
     /// ```
     pub fn with_skin_tone(&self, skin_tone: SkinTone) -> Option<&'static Self> {
         self.skin_tones()?
-            .find(|emoji| emoji.skin_tone().unwrap() == skin_tone)
+            .find(|emoji| emoji.skin_tone() == Some(skin_tone))
     }
 
     /// Returns this emoji's first GitHub shortcode.
     ///
     /// Most emojis only have one shortcode but for a few there are multiple.
     /// Use the [`shortcodes()`][Emoji::shortcodes] method to return all the
-    /// shortcodes.
+    /// shortcodes. Without the **shortcodes** feature this always returns
+    /// `None`.
     ///
     /// See [gemoji] for more information.
     ///
@@ -305,12 +920,13 @@ impl Emoji {
     ///
     /// [gemoji]: https://github.com/github/gemoji
     pub fn shortcode(&self) -> Option<&str> {
-        self.aliases.and_then(|aliases| aliases.first().copied())
+        self.shortcode_slice().first().copied()
     }
 
     /// Returns an iterator over this emoji's GitHub shortcodes.
     ///
-    /// See [gemoji] for more information.
+    /// See [gemoji] for more information. Without the **shortcodes** feature
+    /// this is always empty.
     ///
     /// # Examples
     ///
@@ -324,7 +940,20 @@ impl Emoji {
     ///
     /// [gemoji]: https://github.com/github/gemoji
     pub fn shortcodes(&self) -> impl Iterator<Item = &str> {
-        self.aliases.into_iter().flatten().copied()
+        self.shortcode_slice().iter().copied()
+    }
+
+    /// Returns this emoji's GitHub shortcodes, which are empty without the
+    /// **shortcodes** feature.
+    pub(crate) fn shortcode_slice(&self) -> &'static [&'static str] {
+        #[cfg(feature = "shortcodes")]
+        {
+            crate::gen::SHORTCODES[self.index()]
+        }
+        #[cfg(not(feature = "shortcodes"))]
+        {
+            &[]
+        }
     }
 }
 
@@ -373,6 +1002,33 @@ impl fmt::Display for Emoji {
 }
 
 impl Group {
+    /// All groups in Unicode CLDR order.
+    ///
+    /// # Stability
+    ///
+    /// [`Group`] is non-exhaustive because new versions of Unicode can add
+    /// groups. The position of a group in this slice is equal to `group as
+    /// u8` and can be converted back using [`TryFrom<u8>`]. Existing groups
+    /// never change position, new groups are only ever added at the end, so
+    /// these integers are safe to persist across upgrades of this crate.
+    pub const ALL: &'static [Group] = &[
+        Self::SmileysAndEmotion,
+        Self::PeopleAndBody,
+        Self::AnimalsAndNature,
+        Self::FoodAndDrink,
+        Self::TravelAndPlaces,
+        Self::Activities,
+        Self::Objects,
+        Self::Symbols,
+        Self::Flags,
+    ];
+
+    /// The last group known to this version of the crate.
+    ///
+    /// Any integer greater than `LATEST_KNOWN as u8` was persisted by a newer
+    /// version of this crate.
+    pub const LATEST_KNOWN: Group = Self::ALL[Self::ALL.len() - 1];
+
     /// Returns an iterator over all groups.
     ///
     /// # Examples
@@ -383,19 +1039,7 @@ impl Group {
     /// assert_eq!(iter.next().unwrap(), emojis::Group::PeopleAndBody);
     /// ```
     pub fn iter() -> impl Iterator<Item = Group> {
-        [
-            Self::SmileysAndEmotion,
-            Self::PeopleAndBody,
-            Self::AnimalsAndNature,
-            Self::FoodAndDrink,
-            Self::TravelAndPlaces,
-            Self::Activities,
-            Self::Objects,
-            Self::Symbols,
-            Self::Flags,
-        ]
-        .iter()
-        .copied()
+        Self::ALL.iter().copied()
     }
 
     /// Returns an iterator over all emojis in this group.
@@ -407,13 +1051,271 @@ impl Group {
     /// assert_eq!(iter.next().unwrap(), "🏁");
     /// ```
     pub fn emojis(&self) -> impl Iterator<Item = &'static Emoji> {
+        self.as_slice()
+            .iter()
+            .filter(|emoji| emoji.is_default_skin_tone())
+    }
+
+    /// Returns an iterator over all subgroups in this group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{Group, Subgroup};
+    ///
+    /// let mut iter = Group::Flags.subgroups();
+    /// assert_eq!(iter.next().unwrap(), Subgroup::Flag);
+    /// assert_eq!(iter.next().unwrap(), Subgroup::CountryFlag);
+    /// ```
+    pub fn subgroups(&self) -> impl Iterator<Item = Subgroup> {
         let group = *self;
-        iter()
-            .skip_while(move |emoji| emoji.group != group)
-            .take_while(move |emoji| emoji.group == group)
+        Subgroup::iter().filter(move |subgroup| subgroup.group() == group)
+    }
+
+    /// Returns the group of the given emoji.
+    ///
+    /// This is the same as [`Emoji::group()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Group;
+    ///
+    /// let flag = emojis::get("🇿🇦").unwrap();
+    /// assert_eq!(Group::of(flag), Group::Flags);
+    /// ```
+    pub const fn of(emoji: &Emoji) -> Self {
+        emoji.group
+    }
+
+    /// Returns the first emoji in this group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(emojis::Group::Flags.first(), "🏁");
+    /// ```
+    pub fn first(&self) -> &'static Emoji {
+        // Every generated group range is non-empty and starts with an emoji
+        // with the default skin tone, so this never panics.
+        &crate::gen::EMOJIS[self.range().start]
+    }
+
+    /// Returns the last emoji in this group.
+    ///
+    /// This never returns an emoji with a non-default skin tone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(emojis::Group::Flags.last(), "🏴󠁧󠁢󠁷󠁬󠁳󠁿");
+    /// ```
+    pub fn last(&self) -> &'static Emoji {
+        // Every generated group range is non-empty, so this never panics.
+        let emoji = &crate::gen::EMOJIS[self.range().end - 1];
+        // Skin tone variations directly follow the emoji with the default
+        // skin tone, which is the first of its skin tones.
+        emoji
+            .skin_tones()
+            .and_then(|mut tones| tones.next())
+            .unwrap_or(emoji)
+    }
+
+    /// Returns all the emojis in this group as a slice.
+    ///
+    /// Unlike [`emojis()`][Group::emojis] this includes all skin tone
+    /// variations. The slice is a contiguous part of the table of all emojis
+    /// so this takes *Ο(1)* time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let flags = emojis::Group::Flags.as_slice();
+    /// assert_eq!(flags[0], "🏁");
+    /// assert_eq!(flags.len(), 269);
+    /// ```
+    pub fn as_slice(&self) -> &'static [Emoji] {
+        &crate::gen::EMOJIS[self.range()]
+    }
+
+    /// Returns an iterator over the emojis in this group selected by the
+    /// given options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{Group, IterOptions};
+    ///
+    /// let options = IterOptions::new().skin_tones(true);
+    /// let mut iter = Group::PeopleAndBody.emojis_with(options);
+    /// assert_eq!(iter.next().unwrap(), "👋");
+    /// assert_eq!(iter.next().unwrap(), "👋🏻");
+    /// ```
+    pub fn emojis_with(&self, options: IterOptions) -> impl Iterator<Item = &'static Emoji> {
+        self.as_slice()
+            .iter()
+            .filter(move |emoji| options.matches(emoji))
+    }
+
+    /// Returns an iterator over consecutive pages of the emojis in this
+    /// group.
+    ///
+    /// The pages contain the same emojis as [`emojis()`][Group::emojis], see
+    /// [`pages()`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Group;
+    ///
+    /// let mut pages = Group::Flags.pages(50);
+    /// assert_eq!(pages.len(), 6);
+    /// assert_eq!(pages.next().unwrap()[0], "🏁");
+    /// assert_eq!(pages.last().unwrap().len(), 19);
+    /// ```
+    pub fn pages(
+        &self,
+        page_size: usize,
+    ) -> impl DoubleEndedIterator<Item = &'static [&'static Emoji]> + ExactSizeIterator {
+        let mut subgroups = self.subgroups();
+        let slice = match (subgroups.next(), subgroups.last()) {
+            (Some(first), last) => {
+                let end = last.unwrap_or(first).range().end;
+                &crate::gen::DEFAULT_EMOJIS[first.range().start..end]
+            }
+            (None, _) => &[],
+        };
+        paginate(slice, page_size)
     }
 }
 
+impl Subgroup {
+    /// All subgroups in Unicode CLDR order.
+    ///
+    /// # Stability
+    ///
+    /// The same stability policy as [`Group::ALL`] applies.
+    pub const ALL: &'static [Subgroup] = crate::gen::SUBGROUPS;
+
+    /// The last subgroup known to this version of the crate.
+    pub const LATEST_KNOWN: Subgroup = Self::ALL[Self::ALL.len() - 1];
+
+    /// Returns an iterator over all subgroups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut iter = emojis::Subgroup::iter();
+    /// assert_eq!(iter.next().unwrap(), emojis::Subgroup::FaceSmiling);
+    /// assert_eq!(iter.next().unwrap(), emojis::Subgroup::FaceAffection);
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Subgroup> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Returns the group this subgroup belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{Group, Subgroup};
+    ///
+    /// assert_eq!(Subgroup::AnimalBird.group(), Group::AnimalsAndNature);
+    /// ```
+    pub const fn group(&self) -> Group {
+        self.parent()
+    }
+
+    /// Returns all the emojis in this subgroup.
+    ///
+    /// - Ordered by Unicode CLDR data.
+    /// - Excludes skin tones.
+    ///
+    /// The slice is a contiguous part of the table of all emojis so this takes
+    /// *Ο(1)* time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let hearts = emojis::Subgroup::Heart.emojis();
+    /// assert_eq!(hearts[0], "💌");
+    /// assert!(hearts.contains(&emojis::get("❤️").unwrap()));
+    /// ```
+    pub fn emojis(&self) -> &'static [&'static Emoji] {
+        &crate::gen::DEFAULT_EMOJIS[self.range()]
+    }
+}
+
+impl convert::TryFrom<u8> for Group {
+    type Error = UnknownGroupError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::ALL
+            .get(value as usize)
+            .copied()
+            .ok_or(UnknownGroupError { value })
+    }
+}
+
+impl convert::TryFrom<u8> for Subgroup {
+    type Error = UnknownGroupError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::ALL
+            .get(value as usize)
+            .copied()
+            .ok_or(UnknownGroupError { value })
+    }
+}
+
+/// The error returned when a buffer is too small to hold an emoji.
+///
+/// Returned by [`Emoji::copy_to_slice()`] and [`Emoji::try_encode_utf16()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    required: usize,
+}
+
+impl BufferTooSmall {
+    /// Returns the length of the buffer required to hold the emoji, i.e. the
+    /// number of bytes or UTF-16 code units.
+    pub fn required(&self) -> usize {
+        self.required
+    }
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "buffer too small, length {} required", self.required)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+/// The error returned when converting an integer to a [`Group`] or
+/// [`Subgroup`] that is not known to this version of the crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownGroupError {
+    value: u8,
+}
+
+impl UnknownGroupError {
+    /// Returns the integer that failed to convert.
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl fmt::Display for UnknownGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown group or subgroup `{}`", self.value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownGroupError {}
+
 /// Returns an iterator over all emojis.
 ///
 /// - Ordered by Unicode CLDR data.
@@ -426,9 +1328,269 @@ impl Group {
 /// assert_eq!(iter.next().unwrap(), "😀");
 /// ```
 pub fn iter() -> impl Iterator<Item = &'static Emoji> {
+    all().iter().copied()
+}
+
+/// Returns an iterator over all emojis that support skin tones.
+///
+/// - Ordered by Unicode CLDR data.
+/// - Yields the default skin tone version of each emoji, use
+///   [`Emoji::skin_tones()`] to get the rest.
+///
+/// # Examples
+///
+/// ```
+/// let mut iter = emojis::iter_skin_tone_bases();
+/// assert_eq!(iter.next().unwrap(), "👋");
+/// assert!(iter.all(|e| e.skin_tones().is_some()));
+/// ```
+pub fn iter_skin_tone_bases() -> impl Iterator<Item = &'static Emoji> {
+    iter().filter(|emoji| matches!(emoji.skin_tone, Some((_, SkinTone::Default))))
+}
+
+/// Returns an iterator over all emojis grouped by their length in bytes.
+///
+/// - Ordered by length from shortest to longest, each length is yielded once
+///   with an iterator over the emojis of that length in Unicode CLDR order.
+/// - Includes skin tones.
+///
+/// This is useful for building length-bucketed lookup structures, e.g. for a
+/// matching engine that tries the longest candidates first. Only the
+/// fully-qualified form of each emoji is included, the lengths of the other
+/// [variations][Emoji::variations] can differ.
+///
+/// # Examples
+///
+/// ```
+/// let (len, mut emojis) = emojis::iter_by_len().next().unwrap();
+/// assert_eq!(len, 3);
+/// assert_eq!(emojis.next().unwrap(), "✋");
+/// let (len, _) = emojis::iter_by_len().last().unwrap();
+/// assert!(len <= emojis::MAX_EMOJI_BYTES);
+/// ```
+pub fn iter_by_len() -> impl Iterator<Item = (usize, impl Iterator<Item = &'static Emoji>)> {
+    (1..=MAX_BYTES)
+        .filter(|&len| crate::gen::EMOJIS.iter().any(|e| e.utf8_len() == len))
+        .map(|len| {
+            let emojis = crate::gen::EMOJIS.iter();
+            (len, emojis.filter(move |e| e.utf8_len() == len))
+        })
+}
+
+/// Returns a slice of all emojis.
+///
+/// - Ordered by Unicode CLDR data.
+/// - Excludes skin tones.
+///
+/// This contains the same emojis as [`iter()`] but allows random access, for
+/// example to paginate through the emojis.
+///
+/// # Examples
+///
+/// ```
+/// let all = emojis::all();
+/// assert_eq!(all[0], "😀");
+/// let page = &all[100..110];
+/// assert_eq!(page.len(), 10);
+/// ```
+pub fn all() -> &'static [&'static Emoji] {
+    crate::gen::DEFAULT_EMOJIS
+}
+
+/// Returns an iterator over consecutive pages of [`all()`] emojis.
+///
+/// Each page is a slice of `page_size` emojis, except the last page which can
+/// be shorter. The pages borrow the static table so this does not allocate,
+/// and jumping to a page with [`Iterator::nth()`] takes *Ο(1)* time. If
+/// `page_size` is zero then no pages are yielded.
+///
+/// # Examples
+///
+/// ```
+/// let mut pages = emojis::pages(40);
+/// let page = pages.nth(2).unwrap();
+/// assert_eq!(page.len(), 40);
+/// assert_eq!(page[0], emojis::nth(80).unwrap());
+/// ```
+pub fn pages(
+    page_size: usize,
+) -> impl DoubleEndedIterator<Item = &'static [&'static Emoji]> + ExactSizeIterator {
+    paginate(all(), page_size)
+}
+
+fn paginate(
+    emojis: &'static [&'static Emoji],
+    page_size: usize,
+) -> impl DoubleEndedIterator<Item = &'static [&'static Emoji]> + ExactSizeIterator {
+    let emojis = if page_size == 0 { &[] } else { emojis };
+    emojis.chunks(page_size.max(1))
+}
+
+/// Returns the emoji at the given position in the order used by [`all()`].
+///
+/// This takes *Ο(1)* time.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::nth(0).unwrap(), "😀");
+/// assert!(emojis::nth(100_000).is_none());
+/// ```
+pub fn nth(index: usize) -> Option<&'static Emoji> {
+    all().get(index).copied()
+}
+
+/// Returns an iterator over the emojis assembled from a stream of characters.
+///
+/// Characters that are not part of an emoji are skipped. Where sequences
+/// overlap the longest matching emoji is yielded. This is useful when the text
+/// is not available as a contiguous `&str`.
+///
+/// # Examples
+///
+/// ```
+/// let chars = "launch 🚀 to ✌🏽".chars();
+/// let found: Vec<_> = emojis::from_chars(chars).map(|e| e.as_str()).collect();
+/// assert_eq!(found, ["🚀", "✌🏽"]);
+/// ```
+pub fn from_chars<I>(iter: I) -> impl Iterator<Item = &'static Emoji>
+where
+    I: IntoIterator<Item = char>,
+{
+    FromChars {
+        iter: iter.into_iter(),
+        buf: ['\0'; MAX_CHARS],
+        len: 0,
+    }
+}
+
+/// The maximum number of characters in any emoji or emoji variation.
+const MAX_CHARS: usize = 10;
+
+/// The maximum number of UTF-8 bytes in any fully-qualified emoji, i.e. in
+/// any [`Emoji::as_str()`].
+///
+/// Unqualified and over-qualified [variations][Emoji::variations] are not
+/// included and can be longer.
+///
+/// # Examples
+///
+/// ```
+/// let max = emojis::iter().map(|e| e.as_str().len()).max().unwrap();
+/// assert!(max <= emojis::MAX_EMOJI_BYTES);
+/// ```
+pub const MAX_EMOJI_BYTES: usize = 28;
+
+/// The maximum number of UTF-8 bytes in any emoji or emoji variation.
+const MAX_BYTES: usize = 35;
+
+/// The maximum length of any GitHub shortcode.
+const MAX_SHORTCODE_LEN: usize = 40;
+
+struct FromChars<I> {
+    iter: I,
+    buf: [char; MAX_CHARS],
+    len: usize,
+}
+
+impl<I> FromChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    fn consume(&mut self, n: usize) {
+        self.buf.copy_within(n..self.len, 0);
+        self.len -= n;
+    }
+}
+
+impl<I> Iterator for FromChars<I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = &'static Emoji;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while self.len < MAX_CHARS {
+                match self.iter.next() {
+                    Some(c) => {
+                        self.buf[self.len] = c;
+                        self.len += 1;
+                    }
+                    None => break,
+                }
+            }
+            if self.len == 0 {
+                return None;
+            }
+
+            // Encode the buffered characters and record where each one ends
+            // so that each prefix can be looked up, longest first.
+            let mut bytes = [0; MAX_CHARS * 4];
+            let mut ends = [0; MAX_CHARS];
+            let mut n = 0;
+            for (c, end) in self.buf[..self.len].iter().zip(&mut ends) {
+                n += c.encode_utf8(&mut bytes[n..]).len();
+                *end = n;
+            }
+            let found = (0..self.len).rev().find_map(|i| {
+                let s = core::str::from_utf8(&bytes[..ends[i]]).ok()?;
+                get(s).map(|emoji| (i + 1, emoji))
+            });
+
+            match found {
+                Some((n, emoji)) => {
+                    self.consume(n);
+                    return Some(emoji);
+                }
+                None => self.consume(1),
+            }
+        }
+    }
+}
+
+/// Returns the set of emojis supported by the given Unicode version.
+///
+/// The set includes all skin tone variations. This is useful to build a "safe"
+/// subset of emojis for older clients.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::UnicodeVersion;
+///
+/// let safe = emojis::supported_at(UnicodeVersion::new(13, 1));
+/// assert!(safe.contains(emojis::get("🚀").unwrap()));
+/// assert!(!safe.contains(emojis::get("🫠").unwrap()));
+/// ```
+pub fn supported_at(version: UnicodeVersion) -> EmojiSet {
     crate::gen::EMOJIS
         .iter()
-        .filter(|emoji| matches!(emoji.skin_tone(), Some(SkinTone::Default) | None))
+        .filter(|emoji| emoji.likely_renderable(version))
+        .collect()
+}
+
+/// Returns an iterator over the emojis introduced after the first Unicode
+/// version up to and including the second.
+///
+/// Like [`iter()`] this only includes the default skin tone versions.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::UnicodeVersion;
+///
+/// let new: Vec<_> = emojis::added_between(UnicodeVersion::new(14, 0), UnicodeVersion::new(15, 0))
+///     .map(|e| e.as_str())
+///     .collect();
+/// assert_eq!(new[0], "🫨");
+/// assert!(!new.contains(&"🫠"));
+/// ```
+pub fn added_between(
+    v1: UnicodeVersion,
+    v2: UnicodeVersion,
+) -> impl Iterator<Item = &'static Emoji> {
+    iter().filter(move |emoji| v1 < emoji.unicode_version && emoji.unicode_version <= v2)
 }
 
 /// Lookup an emoji by Unicode value.
@@ -447,18 +1609,139 @@ pub fn get(s: &str) -> Option<&'static Emoji> {
         .map(|&i| &crate::gen::EMOJIS[i])
 }
 
+/// Lookup an emoji by a single character.
+///
+/// This is equivalent to calling [`get()`] with the character as a string
+/// but uses a table sorted by code point, which avoids encoding and hashing
+/// the character. Like [`get()`] unqualified characters map to the
+/// fully-qualified emoji, e.g. U+263A maps to ☺️.
+///
+/// This takes *Ο(log n)* time in the number of emojis.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::get_char('🚀').unwrap(), "🚀");
+/// assert_eq!(emojis::get_char('\u{263a}').unwrap(), "☺️");
+/// assert_eq!(emojis::get_char('a'), None);
+/// ```
+pub fn get_char(c: char) -> Option<&'static Emoji> {
+    let chars = crate::gen::CHARS;
+    let i = chars.binary_search_by_key(&c, |&(c, _)| c).ok()?;
+    Some(&crate::gen::EMOJIS[chars[i].1 as usize])
+}
+
+/// Lookup an emoji by Unicode value, ignoring variation selectors.
+///
+/// Text that has been through other software sometimes loses or gains
+/// variation selectors, e.g. U+FE0F is dropped or replaced with the text
+/// presentation selector U+FE0E. This function first tries an exact lookup
+/// and then retries with all variation selectors removed, which always
+/// resolves to the same emoji as the fully-qualified form.
+///
+/// This takes *Ο(1)* time.
+///
+/// Nothing is reordered or decomposed. Emoji sequences are unchanged by NFC
+/// and NFD normalization so normalized text can be looked up directly. NFKC
+/// and NFKD normalization however replace a few emojis with plain text, e.g.
+/// ™️ becomes "TM", which can not be recovered.
+///
+/// # Examples
+///
+/// ```
+/// let heart = emojis::get_normalized("\u{2764}\u{fe0e}").unwrap();
+/// assert_eq!(heart, "❤️");
+/// assert_eq!(emojis::get_normalized("🚀"), emojis::get("🚀"));
+/// ```
+pub fn get_normalized(s: &str) -> Option<&'static Emoji> {
+    get(s).or_else(|| {
+        let mut buf = [0; MAX_BYTES];
+        let mut len = 0;
+        for c in s.chars().filter(|c| !matches!(c, '\u{fe0e}' | '\u{fe0f}')) {
+            let end = len + c.len_utf8();
+            c.encode_utf8(buf.get_mut(len..end)?);
+            len = end;
+        }
+        get(core::str::from_utf8(&buf[..len]).ok()?)
+    })
+}
+
+/// Lookup an emoji by UTF-16 encoded Unicode value.
+///
+/// This is useful when interoperating with environments that use UTF-16
+/// strings, such as JavaScript, Java, or Windows. Invalid UTF-16 never
+/// matches an emoji.
+///
+/// # Examples
+///
+/// ```
+/// let rocket = emojis::get_utf16(&[0xd83d, 0xde80]).unwrap();
+/// assert_eq!(rocket, "🚀");
+/// ```
+pub fn get_utf16(s: &[u16]) -> Option<&'static Emoji> {
+    let mut buf = [0; MAX_BYTES];
+    let mut n = 0;
+    for c in char::decode_utf16(s.iter().copied()) {
+        let c = c.ok()?;
+        if n + c.len_utf8() > buf.len() {
+            return None;
+        }
+        n += c.encode_utf8(&mut buf[n..]).len();
+    }
+    get(core::str::from_utf8(&buf[..n]).ok()?)
+}
+
 /// Lookup an emoji by GitHub shortcode.
 ///
-/// This take *Ο(1)* time.
+/// Slack style skin tone suffixes are also supported, e.g.
+/// `thumbsup::skin-tone-3` where the tones 2 to 6 are light to dark.
+///
+/// This take *Ο(1)* time. Without the **shortcodes** feature there are no
+/// shortcodes so this always returns `None`, use a [`ShortcodeTable`] to
+/// lookup shortcodes from another source.
 ///
 /// # Examples
 ///
 /// ```
 /// let rocket = emojis::get_by_shortcode("rocket").unwrap();
 /// assert_eq!(rocket, "🚀");
+///
+/// let wave = emojis::get_by_shortcode("wave::skin-tone-5").unwrap();
+/// assert_eq!(wave, "👋🏾");
 /// ```
 pub fn get_by_shortcode(s: &str) -> Option<&'static Emoji> {
-    crate::gen::shortcode::MAP
-        .get(s)
-        .map(|&i| &crate::gen::EMOJIS[i])
+    get_by_untoned_shortcode(s).or_else(|| {
+        let (base, tone) = split_slack_skin_tone(s)?;
+        get_by_untoned_shortcode(base)?.with_skin_tone(tone)
+    })
+}
+
+/// Splits a shortcode with a Slack skin tone suffix, e.g. `wave::skin-tone-3`,
+/// into the base shortcode and the skin tone.
+pub(crate) fn split_slack_skin_tone(s: &str) -> Option<(&str, SkinTone)> {
+    let (base, tone) = s.split_once("::skin-tone-")?;
+    let tone = match tone {
+        "2" => SkinTone::Light,
+        "3" => SkinTone::MediumLight,
+        "4" => SkinTone::Medium,
+        "5" => SkinTone::MediumDark,
+        "6" => SkinTone::Dark,
+        _ => return None,
+    };
+    Some((base, tone))
+}
+
+/// Lookup an emoji by GitHub shortcode without a skin tone suffix.
+fn get_by_untoned_shortcode(s: &str) -> Option<&'static Emoji> {
+    #[cfg(feature = "shortcodes")]
+    {
+        crate::gen::shortcode::MAP
+            .get(s)
+            .map(|&i| &crate::gen::EMOJIS[i])
+    }
+    #[cfg(not(feature = "shortcodes"))]
+    {
+        let _ = s;
+        None
+    }
 }
@@ -0,0 +1,53 @@
+//! Searching for emojis using localized CLDR annotations.
+
+use crate::search::prefix_matches;
+use crate::Emoji;
+
+/// A locale with Unicode CLDR annotations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    En,
+    De,
+    Es,
+    Fr,
+    Ja,
+    Pt,
+    Zh,
+}
+
+impl Locale {
+    /// Returns the keyword index for this locale.
+    fn keywords(self) -> &'static [(&'static str, &'static [&'static Emoji])] {
+        match self {
+            Self::En => crate::gen::KEYWORDS,
+            Self::De => crate::gen::KEYWORDS_DE,
+            Self::Es => crate::gen::KEYWORDS_ES,
+            Self::Fr => crate::gen::KEYWORDS_FR,
+            Self::Ja => crate::gen::KEYWORDS_JA,
+            Self::Pt => crate::gen::KEYWORDS_PT,
+            Self::Zh => crate::gen::KEYWORDS_ZH,
+        }
+    }
+}
+
+/// Returns an iterator over the emojis with a keyword in the given locale
+/// that starts with the query.
+///
+/// Keywords are the localized CLDR text-to-speech names and keywords, e.g.
+/// "rakete" for 🚀 in German. Matching ignores ASCII case and surrounding
+/// whitespace. Emojis are yielded once each in Unicode CLDR order and only the
+/// default skin tone versions are included.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Locale;
+///
+/// assert!(emojis::search_in(Locale::En, "rocke").any(|e| e == "🚀"));
+/// assert!(emojis::search_in(Locale::De, "Rakete").any(|e| e == "🚀"));
+/// assert!(emojis::search_in(Locale::Ja, "ロケ").any(|e| e == "🚀"));
+/// ```
+pub fn search_in(locale: Locale, query: &str) -> impl Iterator<Item = &'static Emoji> {
+    let set = prefix_matches(locale.keywords(), query.trim());
+    crate::iter().filter(move |emoji| set.contains(emoji))
+}
@@ -0,0 +1,120 @@
+//! Tables stored compressed with the `compressed-data` feature.
+//!
+//! The tables are compressed by the generator using a simple LZ77 format, see
+//! `generate/src/lz.rs`, and decompressed on the heap the first time they are
+//! used. The decompressed table is never freed.
+//!
+//! First use is synchronized with [`AtomicPtr::compare_exchange()`], so this
+//! is only compiled for targets with atomic pointers and other targets use
+//! the uncompressed tables.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// The minimum length of a match, which is encoded as zero.
+const MIN_MATCH: usize = 4;
+
+/// Decompresses data written by the generator.
+///
+/// The data is generated so it is always valid, but decoding stops instead of
+/// panicking if it is not.
+fn decompress(data: &[u8], len: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(len);
+    let mut i = 0;
+    while let Some(&c) = data.get(i) {
+        if c < 0x80 {
+            let n = usize::from(c) + 1;
+            match data.get(i + 1..i + 1 + n) {
+                Some(literals) => out.extend_from_slice(literals),
+                None => break,
+            }
+            i += 1 + n;
+        } else {
+            let n = usize::from(c & 0x7f) + MIN_MATCH;
+            let distance = match data.get(i + 1..i + 3) {
+                Some(&[a, b]) => usize::from(u16::from_le_bytes([a, b])),
+                _ => break,
+            };
+            if distance == 0 || distance > out.len() {
+                break;
+            }
+            // The match can overlap the bytes it produces so it is copied one
+            // byte at a time.
+            let start = out.len() - distance;
+            for j in start..start + n {
+                out.push(out[j]);
+            }
+            i += 3;
+        }
+    }
+    out
+}
+
+/// Lines of text decompressed from a table.
+struct Lines {
+    text: String,
+    /// The start of each line in `text`.
+    starts: Vec<usize>,
+}
+
+impl Lines {
+    fn new(data: &[u8], len: usize) -> Self {
+        // The data is generated from valid UTF-8 so the fallback is never used.
+        let text = String::from_utf8(decompress(data, len)).unwrap_or_default();
+        let starts = core::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { text, starts }
+    }
+
+    fn get(&self, index: usize) -> Option<&str> {
+        let start = *self.starts.get(index)?;
+        let end = self
+            .starts
+            .get(index + 1)
+            .map_or(self.text.len(), |&i| i - 1);
+        self.text.get(start..end)
+    }
+}
+
+/// Returns the lines decompressed and stored in `table` on first use.
+///
+/// If several threads race on first use then each decompresses the data, the
+/// first one to finish wins and the others free their copy.
+fn lazy_lines(table: &'static AtomicPtr<Lines>, data: &[u8], len: usize) -> &'static Lines {
+    let mut lines = table.load(Ordering::Acquire);
+    if lines.is_null() {
+        let new = Box::into_raw(Box::new(Lines::new(data, len)));
+        lines =
+            match table.compare_exchange(ptr::null_mut(), new, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => new,
+                Err(existing) => {
+                    // SAFETY: `new` was created by `Box::into_raw()` above and was
+                    // not stored in the table.
+                    drop(unsafe { Box::from_raw(new) });
+                    existing
+                }
+            };
+    }
+    // SAFETY: the pointer is non-null, it was created by `Box::into_raw()`, and
+    // once stored in the table it is never changed or freed.
+    unsafe { &*lines }
+}
+
+/// Returns the line from [emoji-test.txt] for the emoji at the given index.
+///
+/// [emoji-test.txt]: https://unicode.org/Public/emoji/15.0/emoji-test.txt
+pub(crate) fn source_line(index: usize) -> &'static str {
+    static LINES: AtomicPtr<Lines> = AtomicPtr::new(ptr::null_mut());
+    let lines = lazy_lines(
+        &LINES,
+        crate::gen::SOURCE_LINES_LZ,
+        crate::gen::SOURCE_LINES_LEN,
+    );
+    // Every emoji has a line so the fallback is never used.
+    lines.get(index).unwrap_or("")
+}
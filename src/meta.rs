@@ -0,0 +1,224 @@
+//! Information about the compiled dataset.
+//!
+//! # Examples
+//!
+//! ```
+//! for table in emojis::meta::table_sizes() {
+//!     println!("{:<20} {:>8} bytes", table.name(), table.bytes());
+//! }
+//! ```
+
+use core::mem::size_of_val;
+
+use crate::gen;
+use crate::Emoji;
+
+/// The memory used by one of the generated static tables.
+///
+/// Returned by [`table_sizes()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TableSize {
+    name: &'static str,
+    feature: Option<&'static str>,
+    bytes: usize,
+}
+
+impl TableSize {
+    /// Returns the name of the table, e.g. `"emojis"`.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the optional feature that includes the table, if any.
+    ///
+    /// Tables without a feature are always compiled in.
+    pub const fn feature(&self) -> Option<&'static str> {
+        self.feature
+    }
+
+    /// Returns the number of bytes used by the table, including the strings
+    /// and nested slices it refers to.
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+/// Returns the size of each generated static table in the compiled feature
+/// set.
+///
+/// Only tables enabled by the current features are yielded, so comparing the
+/// output of builds with different features shows what each feature costs.
+/// Sizes are estimates of the read-only data, the linker can merge identical
+/// strings, e.g. GitHub shortcodes are used by both the `"shortcodes"` and
+/// `"shortcode_map"` tables, and code size is not included. Tables stored
+/// compressed with the **compressed-data** feature report their compressed
+/// size.
+///
+/// # Examples
+///
+/// ```
+/// let emojis = emojis::meta::table_sizes()
+///     .find(|table| table.name() == "emojis")
+///     .unwrap();
+/// assert_eq!(emojis.feature(), None);
+/// assert!(emojis.bytes() > 100_000);
+/// ```
+pub fn table_sizes() -> impl Iterator<Item = TableSize> {
+    let table = |name, feature, bytes| {
+        Some(TableSize {
+            name,
+            feature,
+            bytes,
+        })
+    };
+    [
+        table("emojis", None, emojis(gen::EMOJIS)),
+        table("default_emojis", None, size_of_val(gen::DEFAULT_EMOJIS)),
+        table("unicode_map", None, map(&gen::unicode::MAP)),
+        #[cfg(feature = "shortcodes")]
+        table("shortcodes", Some("shortcodes"), shortcodes()),
+        #[cfg(feature = "shortcodes")]
+        table(
+            "shortcode_map",
+            Some("shortcodes"),
+            map(&gen::shortcode::MAP),
+        ),
+        #[cfg(feature = "phf-map")]
+        table(
+            "unicode_emoji_map",
+            Some("phf-map"),
+            map(&gen::unicode::EMOJI_MAP),
+        ),
+        #[cfg(all(feature = "phf-map", feature = "shortcodes"))]
+        table(
+            "shortcode_emoji_map",
+            Some("phf-map"),
+            map(&gen::shortcode::EMOJI_MAP),
+        ),
+        table("keywords", None, keywords(gen::KEYWORDS)),
+        #[cfg(feature = "locales")]
+        table(
+            "locale_keywords",
+            Some("locales"),
+            [
+                gen::KEYWORDS_DE,
+                gen::KEYWORDS_ES,
+                gen::KEYWORDS_FR,
+                gen::KEYWORDS_JA,
+                gen::KEYWORDS_PT,
+                gen::KEYWORDS_ZH,
+            ]
+            .into_iter()
+            .map(keywords)
+            .sum(),
+        ),
+        table("tts", None, indexed_strs(gen::TTS)),
+        #[cfg(feature = "sprite-sheet")]
+        table("sprites", Some("sprite-sheet"), size_of_val(gen::SPRITES)),
+        #[cfg(all(
+            feature = "source-lines",
+            not(all(feature = "compressed-data", target_has_atomic = "ptr"))
+        ))]
+        table(
+            "source_lines",
+            Some("source-lines"),
+            size_of_val(gen::SOURCE_LINES) + strs(gen::SOURCE_LINES.iter().copied()),
+        ),
+        #[cfg(all(
+            feature = "source-lines",
+            feature = "compressed-data",
+            target_has_atomic = "ptr"
+        ))]
+        table(
+            "source_lines",
+            Some("source-lines"),
+            size_of_val(gen::SOURCE_LINES_LZ),
+        ),
+        #[cfg(feature = "descriptions")]
+        table(
+            "descriptions",
+            Some("descriptions"),
+            indexed_strs(gen::DESCRIPTIONS),
+        ),
+        #[cfg(feature = "colors")]
+        table("colors", Some("colors"), size_of_val(gen::COLORS)),
+        table("chars", None, size_of_val(gen::CHARS)),
+        table("first_chars", None, size_of_val(gen::FIRST_CHARS)),
+        table("all_chars", None, size_of_val(gen::ALL_CHARS)),
+        table("subgroups", None, size_of_val(gen::SUBGROUPS)),
+        table("layout", None, layout()),
+        table("migrations", None, migrations()),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Returns the total number of bytes used by the generated static tables in
+/// the compiled feature set.
+///
+/// This is the sum of the sizes returned by [`table_sizes()`].
+pub fn total_bytes() -> usize {
+    table_sizes().map(|table| table.bytes()).sum()
+}
+
+fn strs<'a>(strs: impl IntoIterator<Item = &'a str>) -> usize {
+    strs.into_iter().map(str::len).sum()
+}
+
+fn emojis(emojis: &[Emoji]) -> usize {
+    size_of_val(emojis)
+        + emojis
+            .iter()
+            .map(|e| e.emoji.len() + e.name.len())
+            .sum::<usize>()
+}
+
+#[cfg(feature = "shortcodes")]
+fn shortcodes() -> usize {
+    size_of_val(gen::SHORTCODES)
+        + gen::SHORTCODES
+            .iter()
+            .map(|shortcodes| size_of_val(*shortcodes) + strs(shortcodes.iter().copied()))
+            .sum::<usize>()
+}
+
+fn map<V>(map: &phf::Map<&'static str, V>) -> usize {
+    size_of_val(map)
+        + size_of_val(map.disps)
+        + size_of_val(map.entries)
+        + strs(map.entries.iter().map(|(k, _)| *k))
+}
+
+fn keywords(keywords: &[(&str, &[&Emoji])]) -> usize {
+    size_of_val(keywords)
+        + keywords
+            .iter()
+            .map(|(k, emojis)| k.len() + size_of_val(*emojis))
+            .sum::<usize>()
+}
+
+fn indexed_strs(table: &[(usize, &str)]) -> usize {
+    size_of_val(table) + strs(table.iter().map(|(_, s)| *s))
+}
+
+fn layout() -> usize {
+    size_of_val(gen::LAYOUT)
+        + gen::LAYOUT
+            .iter()
+            .map(|g| {
+                size_of_val(g.subgroups)
+                    + g.subgroups
+                        .iter()
+                        .map(|s| size_of_val(s.emojis))
+                        .sum::<usize>()
+            })
+            .sum::<usize>()
+}
+
+fn migrations() -> usize {
+    size_of_val(gen::migrations::MIGRATIONS)
+        + gen::migrations::MIGRATIONS
+            .iter()
+            .map(|(_, ids)| size_of_val(*ids))
+            .sum::<usize>()
+}
@@ -0,0 +1,46 @@
+//! Migrating persisted emoji ids from previous datasets.
+//!
+//! Every release that changes the emoji dataset records a table mapping the
+//! ids of each previous dataset to the current ones, so ids stored alongside a
+//! [`dataset_fingerprint()`][crate::dataset_fingerprint] can be upgraded
+//! without re-keying them by hand. Usually [`EmojiId::migrate_from()`] is all
+//! that is needed.
+
+use crate::EmojiId;
+
+/// A mapping from the ids of a previous dataset to the current dataset.
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    fingerprint: u64,
+    ids: &'static [u16],
+}
+
+impl Migration {
+    /// Returns the fingerprint of the previous dataset.
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// Returns the current id for the raw value of an id in the previous
+    /// dataset.
+    ///
+    /// Returns `None` if the value was not an id in the previous dataset or
+    /// if the emoji was removed.
+    pub fn get(&self, value: u16) -> Option<EmojiId> {
+        let index = usize::from(value).checked_sub(1)?;
+        EmojiId::new(*self.ids.get(index)?)
+    }
+}
+
+/// Returns the migration from the dataset with the given fingerprint, if it
+/// is a known previous dataset.
+pub fn find(fingerprint: u64) -> Option<Migration> {
+    iter().find(|m| m.fingerprint == fingerprint)
+}
+
+/// Returns an iterator over the migrations from all known previous datasets.
+pub fn iter() -> impl Iterator<Item = Migration> {
+    crate::gen::migrations::MIGRATIONS
+        .iter()
+        .map(|&(fingerprint, ids)| Migration { fingerprint, ids })
+}
@@ -0,0 +1,67 @@
+//! Formatting emoji names.
+
+use core::fmt;
+use core::fmt::Write as _;
+
+use crate::Emoji;
+
+impl Emoji {
+    /// Returns an iterator over the words in this emoji's name.
+    ///
+    /// Words are separated by whitespace and trailing colons and commas are
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let kiss = emojis::get("💏🏻").unwrap();
+    /// let words: Vec<_> = kiss.name_words().collect();
+    /// assert_eq!(words, ["kiss", "light", "skin", "tone"]);
+    /// ```
+    pub fn name_words(&self) -> impl Iterator<Item = &'static str> {
+        self.name
+            .split_whitespace()
+            .map(|word| word.trim_end_matches([':', ',']))
+            .filter(|word| !word.is_empty())
+    }
+
+    /// Returns a value that formats this emoji's name in title case.
+    ///
+    /// The first letter of each word, including each part of a hyphenated
+    /// word, is capitalized and everything else is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let grinning = emojis::get("😀").unwrap();
+    /// assert_eq!(grinning.title_name().to_string(), "Grinning Face");
+    ///
+    /// let hand = emojis::get("👋🏼").unwrap();
+    /// assert_eq!(hand.title_name().to_string(), "Waving Hand: Medium-Light Skin Tone");
+    /// ```
+    pub fn title_name(&self) -> impl fmt::Display {
+        TitleCase(self.name)
+    }
+}
+
+struct TitleCase(&'static str);
+
+impl fmt::Display for TitleCase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut start = true;
+        for c in self.0.chars() {
+            if start && c.is_alphabetic() {
+                for u in c.to_uppercase() {
+                    f.write_char(u)?;
+                }
+                start = false;
+            } else {
+                f.write_char(c)?;
+                if c.is_whitespace() || c == '-' {
+                    start = true;
+                }
+            }
+        }
+        Ok(())
+    }
+}
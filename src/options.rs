@@ -0,0 +1,82 @@
+//! Options for iterating over emojis.
+
+use crate::{Emoji, UnicodeVersion};
+
+/// Options that control which emojis are yielded when iterating.
+///
+/// Used by [`Group::emojis_with()`][crate::Group::emojis_with]. The default
+/// options yield the same emojis as [`Group::emojis()`][crate::Group::emojis],
+/// i.e. gendered variants are included but skin tone variations are not.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{Group, IterOptions, UnicodeVersion};
+///
+/// let options = IterOptions::new()
+///     .skin_tones(true)
+///     .gendered_variants(false)
+///     .max_unicode_version(UnicodeVersion::new(13, 0));
+/// let people: Vec<_> = Group::PeopleAndBody.emojis_with(options).collect();
+/// assert!(people.contains(&emojis::get("🧑🏽‍🍳").unwrap()));
+/// assert!(!people.contains(&emojis::get("👨‍🍳").unwrap()));
+/// assert!(!people.contains(&emojis::get("🫅").unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IterOptions {
+    skin_tones: bool,
+    gendered: bool,
+    max_version: Option<UnicodeVersion>,
+}
+
+impl IterOptions {
+    /// Construct the default options.
+    pub const fn new() -> Self {
+        Self {
+            skin_tones: false,
+            gendered: true,
+            max_version: None,
+        }
+    }
+
+    /// Sets whether to include skin tone variations, which directly follow
+    /// their default skin tone emoji.
+    ///
+    /// Defaults to `false`.
+    pub fn skin_tones(mut self, yes: bool) -> Self {
+        self.skin_tones = yes;
+        self
+    }
+
+    /// Sets whether to include gendered variants of emojis that have a
+    /// gender-neutral form, see [`Emoji::neutral()`].
+    ///
+    /// Defaults to `true`.
+    pub fn gendered_variants(mut self, yes: bool) -> Self {
+        self.gendered = yes;
+        self
+    }
+
+    /// Sets the maximum Unicode version of the emojis to include.
+    ///
+    /// Defaults to no maximum.
+    pub fn max_unicode_version(mut self, version: UnicodeVersion) -> Self {
+        self.max_version = Some(version);
+        self
+    }
+
+    /// Returns whether the emoji should be yielded with these options.
+    pub(crate) fn matches(&self, emoji: &Emoji) -> bool {
+        (self.skin_tones || emoji.is_default_skin_tone())
+            && (self.gendered || emoji.neutral() == emoji)
+            && self
+                .max_version
+                .map_or(true, |v| emoji.unicode_version() <= v)
+    }
+}
+
+impl Default for IterOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,105 @@
+//! An emoji stored by value.
+
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{Emoji, EmojiId, MAX_EMOJI_BYTES};
+
+/// An emoji stored by value in a fixed capacity inline buffer.
+///
+/// This is useful at FFI boundaries and on embedded systems where holding a
+/// reference into the static table of emojis is inconvenient. It is `Copy`
+/// and dereferences to the [`Emoji`] it was created from.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::EmojiBuf;
+///
+/// let buf = EmojiBuf::new(emojis::get("🚀").unwrap());
+/// let copy = buf;
+/// assert_eq!(copy.as_str(), "🚀");
+/// assert_eq!(copy.name(), "rocket");
+/// ```
+#[derive(Clone, Copy)]
+pub struct EmojiBuf {
+    bytes: [u8; MAX_EMOJI_BYTES],
+    len: u8,
+    id: EmojiId,
+}
+
+impl EmojiBuf {
+    /// Construct a new buffer holding the given emoji.
+    pub fn new(emoji: &Emoji) -> Self {
+        let mut bytes = [0; MAX_EMOJI_BYTES];
+        // Every emoji fits in `MAX_EMOJI_BYTES` so the fallback is never used.
+        let len = emoji.copy_to_slice(&mut bytes).unwrap_or(0);
+        Self {
+            bytes,
+            len: len as u8,
+            id: emoji.id(),
+        }
+    }
+
+    /// Returns the emoji as a string.
+    pub fn as_str(&self) -> &str {
+        // The bytes are copied from an emoji so the fallback is never used.
+        core::str::from_utf8(self.as_bytes()).unwrap_or("")
+    }
+
+    /// Returns the emoji as UTF-8 bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.len)]
+    }
+
+    /// Returns the emoji in the static table of all emojis.
+    pub fn emoji(&self) -> &'static Emoji {
+        self.id.emoji()
+    }
+}
+
+impl Deref for EmojiBuf {
+    type Target = Emoji;
+
+    fn deref(&self) -> &Self::Target {
+        self.emoji()
+    }
+}
+
+impl From<&Emoji> for EmojiBuf {
+    fn from(emoji: &Emoji) -> Self {
+        Self::new(emoji)
+    }
+}
+
+impl PartialEq for EmojiBuf {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for EmojiBuf {}
+
+impl core::hash::Hash for EmojiBuf {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl AsRef<str> for EmojiBuf {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for EmojiBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EmojiBuf").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for EmojiBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
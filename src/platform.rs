@@ -0,0 +1,108 @@
+//! Minimum platform versions that support each emoji.
+
+use crate::{Emoji, UnicodeVersion};
+
+/// An operating system that renders emojis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    Ios,
+    Android,
+    Windows,
+}
+
+/// A version of a [`Platform`].
+///
+/// For iOS and Android this is the marketing version, e.g. iOS 16.4 or
+/// Android 13.0. For Windows the major version is the OS build number, e.g.
+/// `22000` for the first release of Windows 11, and the minor version is
+/// always zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PlatformVersion {
+    major: u32,
+    minor: u32,
+}
+
+impl PlatformVersion {
+    /// Construct a new version.
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    pub const fn major(self) -> u32 {
+        self.major
+    }
+
+    pub const fn minor(self) -> u32 {
+        self.minor
+    }
+}
+
+/// The first platform versions to support each emoji version, indexed by
+/// [`Platform`].
+#[rustfmt::skip]
+const TABLE: &[(UnicodeVersion, [PlatformVersion; 3])] = &[
+    (UnicodeVersion::new(0, 6), [v(5, 0), v(4, 4), v(9600, 0)]),
+    (UnicodeVersion::new(0, 7), [v(5, 0), v(4, 4), v(9600, 0)]),
+    (UnicodeVersion::new(1, 0), [v(8, 3), v(6, 0), v(10240, 0)]),
+    (UnicodeVersion::new(2, 0), [v(9, 1), v(7, 0), v(14393, 0)]),
+    (UnicodeVersion::new(3, 0), [v(10, 2), v(7, 0), v(14393, 0)]),
+    (UnicodeVersion::new(4, 0), [v(10, 2), v(7, 1), v(15063, 0)]),
+    (UnicodeVersion::new(5, 0), [v(11, 1), v(8, 1), v(17134, 0)]),
+    (UnicodeVersion::new(11, 0), [v(12, 1), v(9, 0), v(18362, 0)]),
+    (UnicodeVersion::new(12, 0), [v(13, 2), v(10, 0), v(18362, 0)]),
+    (UnicodeVersion::new(12, 1), [v(13, 2), v(11, 0), v(19041, 0)]),
+    (UnicodeVersion::new(13, 0), [v(14, 2), v(11, 0), v(22000, 0)]),
+    (UnicodeVersion::new(13, 1), [v(14, 5), v(12, 0), v(22000, 0)]),
+    (UnicodeVersion::new(14, 0), [v(15, 4), v(12, 1), v(22621, 0)]),
+    (UnicodeVersion::new(15, 0), [v(16, 4), v(14, 0), v(22631, 0)]),
+];
+
+const fn v(major: u32, minor: u32) -> PlatformVersion {
+    PlatformVersion::new(major, minor)
+}
+
+impl Emoji {
+    /// Returns the first version of the given platform that supports this
+    /// emoji.
+    ///
+    /// This is derived from the emoji's Unicode version and the first platform
+    /// release to support that version, so individual vendors may have
+    /// supported some emojis earlier. Returns `None` if no known release of
+    /// the platform supports the emoji's Unicode version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{Platform, PlatformVersion};
+    ///
+    /// let melting = emojis::get("🫠").unwrap();
+    /// assert_eq!(
+    ///     melting.min_platform_version(Platform::Ios),
+    ///     Some(PlatformVersion::new(15, 4))
+    /// );
+    /// ```
+    pub fn min_platform_version(&self, platform: Platform) -> Option<PlatformVersion> {
+        TABLE
+            .iter()
+            .find(|(v, _)| *v >= self.unicode_version)
+            .map(|(_, versions)| versions[platform as usize])
+    }
+
+    /// Returns whether this emoji is supported on the given platform version.
+    ///
+    /// Emojis newer than every known platform release are not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{Platform, PlatformVersion};
+    ///
+    /// let melting = emojis::get("🫠").unwrap();
+    /// assert!(melting.supported_on(Platform::Android, PlatformVersion::new(13, 0)));
+    /// assert!(!melting.supported_on(Platform::Android, PlatformVersion::new(11, 0)));
+    /// ```
+    pub fn supported_on(&self, platform: Platform, version: PlatformVersion) -> bool {
+        self.min_platform_version(platform)
+            .map_or(false, |min| min <= version)
+    }
+}
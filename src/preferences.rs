@@ -0,0 +1,107 @@
+//! Process-wide skin tone and gender preferences.
+//!
+//! An application can set its preferred skin tone and gender once, and then
+//! use [`Emoji::preferred()`] to resolve emojis wherever they are rendered
+//! instead of threading the preferences through every call site.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::{preferences, Gender, SkinTone};
+//!
+//! preferences::set_skin_tone(Some(SkinTone::Medium));
+//! preferences::set_gender(Some(Gender::Female));
+//!
+//! let officer = emojis::get("👮").unwrap();
+//! assert_eq!(officer.preferred(), "👮🏽‍♀️");
+//!
+//! preferences::reset();
+//! assert_eq!(officer.preferred(), "👮");
+//! ```
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::{Emoji, Gender, SkinTone};
+
+/// The preferred skin tone, `0` means no preference.
+static SKIN_TONE: AtomicU8 = AtomicU8::new(0);
+
+/// The preferred gender, `0` means no preference.
+static GENDER: AtomicU8 = AtomicU8::new(0);
+
+const SKIN_TONES: [SkinTone; 6] = [
+    SkinTone::Default,
+    SkinTone::Light,
+    SkinTone::MediumLight,
+    SkinTone::Medium,
+    SkinTone::MediumDark,
+    SkinTone::Dark,
+];
+
+const GENDERS: [Gender; 3] = [Gender::Neutral, Gender::Male, Gender::Female];
+
+fn encode<T: PartialEq>(values: &[T], value: Option<T>) -> u8 {
+    value.map_or(0, |v| {
+        values
+            .iter()
+            .position(|x| *x == v)
+            .map_or(0, |i| i as u8 + 1)
+    })
+}
+
+fn decode<T: Copy>(values: &[T], value: u8) -> Option<T> {
+    values.get(usize::from(value).checked_sub(1)?).copied()
+}
+
+/// Sets the preferred skin tone, or clears it if `None`.
+pub fn set_skin_tone(skin_tone: Option<SkinTone>) {
+    SKIN_TONE.store(encode(&SKIN_TONES, skin_tone), Ordering::Relaxed);
+}
+
+/// Returns the preferred skin tone, if one is set.
+pub fn skin_tone() -> Option<SkinTone> {
+    decode(&SKIN_TONES, SKIN_TONE.load(Ordering::Relaxed))
+}
+
+/// Sets the preferred gender, or clears it if `None`.
+pub fn set_gender(gender: Option<Gender>) {
+    GENDER.store(encode(&GENDERS, gender), Ordering::Relaxed);
+}
+
+/// Returns the preferred gender, if one is set.
+pub fn gender() -> Option<Gender> {
+    decode(&GENDERS, GENDER.load(Ordering::Relaxed))
+}
+
+/// Clears the preferred skin tone and gender.
+pub fn reset() {
+    set_skin_tone(None);
+    set_gender(None);
+}
+
+impl Emoji {
+    /// Returns the variant of this emoji that matches the process-wide
+    /// [`preferences`][crate::preferences].
+    ///
+    /// The preferred gender is applied first and then the preferred skin tone.
+    /// A preference that does not apply to this emoji is ignored, e.g. a skin
+    /// tone for 🚀 or a gender for 👨‍👩‍👧. Gender preferences are also
+    /// ignored for emojis with more than one person, such as couples. If no
+    /// preferences are set then this emoji is returned.
+    pub fn preferred(&self) -> &'static Emoji {
+        let mut emoji = &crate::gen::EMOJIS[self.index()];
+        if let Some(gender) = gender() {
+            if emoji.gendered_variants().count() == GENDERS.len() {
+                let i = encode(&GENDERS, Some(gender)) - 1;
+                emoji = emoji
+                    .gendered_variants()
+                    .nth(usize::from(i))
+                    .unwrap_or(emoji);
+            }
+        }
+        if let Some(skin_tone) = skin_tone() {
+            emoji = emoji.with_skin_tone(skin_tone).unwrap_or(emoji);
+        }
+        emoji
+    }
+}
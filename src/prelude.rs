@@ -0,0 +1,24 @@
+//! The most commonly used types and traits.
+//!
+//! This module is intended to be glob imported.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::prelude::*;
+//!
+//! let rocket: &Emoji = emojis::get("🚀").unwrap();
+//! assert_eq!(rocket.group(), Group::TravelAndPlaces);
+//! assert!(rocket.with_skin_tone(SkinTone::Medium).is_none());
+//! assert!(rocket.likely_renderable(UnicodeVersion::new(15, 0)));
+//!
+//! let found: Vec<_> = ["🚀", "a"].into_iter().emojis().collect();
+//! assert_eq!(found, [Some(rocket), None]);
+//! ```
+
+pub use crate::{
+    Emoji, EmojiGraphemes, EmojiId, EmojiSet, Group, SkinTone, Subgroup, UnicodeVersion,
+};
+
+#[cfg(feature = "alloc")]
+pub use crate::Scorer;
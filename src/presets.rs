@@ -0,0 +1,177 @@
+//! Conventional emojis for statuses, log levels, and checkboxes.
+//!
+//! Each preset is available in several [`Style`]s, so that a tool can pick
+//! one style and use it consistently.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::presets::{self, Status, Style};
+//!
+//! assert_eq!(presets::status(Status::Success), "✅");
+//! assert_eq!(presets::checkbox(true), "☑️");
+//! assert_eq!(Style::Circles.status(Status::Error), "🔴");
+//! ```
+
+use crate::{Color, Emoji};
+
+/// The outcome of an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    Success,
+    Info,
+    Warning,
+    Error,
+}
+
+/// The severity of a log message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Level {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+/// A style of preset emojis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Style {
+    /// Symbols, e.g. ✅ and ❌. This is the default.
+    Symbols,
+    /// Colored circles, e.g. 🟢 and 🔴.
+    Circles,
+    /// Colored squares, e.g. 🟩 and 🟥.
+    Squares,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::Symbols
+    }
+}
+
+impl Status {
+    fn color(self) -> Color {
+        match self {
+            Self::Success => Color::Green,
+            Self::Info => Color::Blue,
+            Self::Warning => Color::Yellow,
+            Self::Error => Color::Red,
+        }
+    }
+}
+
+impl Level {
+    fn color(self) -> Color {
+        match self {
+            Self::Trace => Color::White,
+            Self::Debug => Color::Purple,
+            Self::Info => Color::Blue,
+            Self::Warn => Color::Yellow,
+            Self::Error => Color::Red,
+        }
+    }
+}
+
+/// Returns the emoji for the single character, which must be in the dataset.
+fn get(c: char) -> &'static Emoji {
+    crate::series::get_known(c)
+}
+
+impl Style {
+    /// Returns the emoji in this style with the given color.
+    fn colored(self, color: Color) -> Option<&'static Emoji> {
+        match self {
+            Self::Symbols => None,
+            Self::Circles => crate::circle(color),
+            Self::Squares => crate::square(color),
+        }
+    }
+
+    /// Returns the emoji in this style for the given status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::presets::{Status, Style};
+    ///
+    /// assert_eq!(Style::Symbols.status(Status::Warning), "⚠️");
+    /// assert_eq!(Style::Squares.status(Status::Success), "🟩");
+    /// ```
+    pub fn status(self, status: Status) -> &'static Emoji {
+        self.colored(status.color()).unwrap_or_else(|| {
+            get(match status {
+                Status::Success => '✅',
+                Status::Info => '\u{2139}',
+                Status::Warning => '\u{26a0}',
+                Status::Error => '❌',
+            })
+        })
+    }
+
+    /// Returns the emoji in this style for the given log level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::presets::{Level, Style};
+    ///
+    /// assert_eq!(Style::Symbols.level(Level::Debug), "🐛");
+    /// assert_eq!(Style::Circles.level(Level::Warn), "🟡");
+    /// ```
+    pub fn level(self, level: Level) -> &'static Emoji {
+        self.colored(level.color()).unwrap_or_else(|| {
+            get(match level {
+                Level::Trace => '🔍',
+                Level::Debug => '🐛',
+                Level::Info => '\u{2139}',
+                Level::Warn => '\u{26a0}',
+                Level::Error => '❌',
+            })
+        })
+    }
+
+    /// Returns the emoji in this style for a checked or an unchecked
+    /// checkbox.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::presets::Style;
+    ///
+    /// assert_eq!(Style::Symbols.checkbox(false), "⬜");
+    /// assert_eq!(Style::Circles.checkbox(true), "🟢");
+    /// ```
+    pub fn checkbox(self, checked: bool) -> &'static Emoji {
+        let color = if checked { Color::Green } else { Color::White };
+        match self.colored(color) {
+            Some(emoji) => emoji,
+            None if checked => get('\u{2611}'),
+            None => get('⬜'),
+        }
+    }
+}
+
+/// Returns the emoji for the given status in the default style.
+///
+/// See [`Style::status()`].
+pub fn status(status: Status) -> &'static Emoji {
+    Style::default().status(status)
+}
+
+/// Returns the emoji for the given log level in the default style.
+///
+/// See [`Style::level()`].
+pub fn level(level: Level) -> &'static Emoji {
+    Style::default().level(level)
+}
+
+/// Returns the emoji for a checked or an unchecked checkbox in the default
+/// style.
+///
+/// See [`Style::checkbox()`].
+pub fn checkbox(checked: bool) -> &'static Emoji {
+    Style::default().checkbox(checked)
+}
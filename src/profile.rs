@@ -0,0 +1,158 @@
+//! Platform profiles for parsing and formatting shortcodes.
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+
+use crate::{Emoji, ShortcodeTokenizer, SkinTone};
+
+/// A chat or code hosting platform with its own shortcode conventions.
+///
+/// A profile bundles the shortcode syntax a platform uses, how it encodes
+/// skin tones, and whether it expects shortcodes or fully-qualified emojis,
+/// so that bridge software can be configured with a single value.
+///
+/// | Profile   | Skin tone syntax            | Formats as             |
+/// |-----------|-----------------------------|------------------------|
+/// | `GitHub`  | none                        | shortcode              |
+/// | `Slack`   | `:thumbsup::skin-tone-3:`   | shortcode              |
+/// | `Discord` | `:thumbsup_tone2:`          | shortcode              |
+/// | `Matrix`  | none                        | fully-qualified emoji  |
+///
+/// Emojis without a shortcode are always formatted as the fully-qualified
+/// emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Profile {
+    GitHub,
+    Slack,
+    Discord,
+    Matrix,
+}
+
+impl Profile {
+    /// Returns an iterator over the shortcodes in the text using the syntax
+    /// of this platform.
+    ///
+    /// Code spans are skipped for all platforms, link destinations are also
+    /// skipped for GitHub, and Discord custom emojis and skin tone shortcodes
+    /// are recognized for Discord.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Profile;
+    ///
+    /// let found: Vec<_> = Profile::Discord
+    ///     .parse("nice :thumbsup_tone2: `:tada:`")
+    ///     .filter_map(|t| t.emoji())
+    ///     .collect();
+    /// assert_eq!(found, ["👍🏼"]);
+    /// ```
+    pub fn parse(self, text: &str) -> ShortcodeTokenizer<'_> {
+        ShortcodeTokenizer::new(text)
+            .skip_code_spans(true)
+            .skip_link_urls(self == Self::GitHub)
+            .discord_custom_emojis(self == Self::Discord)
+    }
+
+    /// Returns a value that formats the emoji the way this platform expects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Profile;
+    ///
+    /// let thumbs = emojis::get("👍🏽").unwrap();
+    /// assert_eq!(Profile::GitHub.format(thumbs).to_string(), "👍🏽");
+    /// assert_eq!(Profile::Slack.format(thumbs).to_string(), ":+1::skin-tone-4:");
+    /// assert_eq!(Profile::Discord.format(thumbs).to_string(), ":+1_tone3:");
+    /// assert_eq!(Profile::Matrix.format(thumbs).to_string(), "👍🏽");
+    /// ```
+    pub fn format(self, emoji: &Emoji) -> impl fmt::Display + '_ {
+        Formatted {
+            profile: self,
+            emoji,
+        }
+    }
+}
+
+/// Rewrites the shortcodes in the text from one platform's syntax to
+/// another's.
+///
+/// Shortcodes are found using [`Profile::parse`] and written using
+/// [`Profile::format`]. Unknown shortcodes and Discord custom emojis are left
+/// unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Profile;
+///
+/// let text = emojis::convert_shortcodes(
+///     "ok :+1::skin-tone-2: :pepe:",
+///     Profile::Slack,
+///     Profile::Discord,
+/// );
+/// assert_eq!(text, "ok :+1_tone1: :pepe:");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn convert_shortcodes(text: &str, from: Profile, to: Profile) -> Cow<'_, str> {
+    use core::fmt::Write;
+
+    let mut tokens = from
+        .parse(text)
+        .filter_map(|token| Some((token.range(), token.emoji()?)))
+        .peekable();
+    if tokens.peek().is_none() {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::new();
+    let mut last = 0;
+    for (range, emoji) in tokens {
+        out.push_str(&text[last..range.start]);
+        write!(out, "{}", to.format(emoji)).unwrap();
+        last = range.end;
+    }
+    out.push_str(&text[last..]);
+    Cow::Owned(out)
+}
+
+/// Returns the number of the skin tone, starting at one for light.
+fn tone_number(tone: SkinTone) -> Option<u8> {
+    match tone {
+        SkinTone::Default => None,
+        SkinTone::Light => Some(1),
+        SkinTone::MediumLight => Some(2),
+        SkinTone::Medium => Some(3),
+        SkinTone::MediumDark => Some(4),
+        SkinTone::Dark => Some(5),
+    }
+}
+
+struct Formatted<'a> {
+    profile: Profile,
+    emoji: &'a Emoji,
+}
+
+impl fmt::Display for Formatted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let emoji = self.emoji;
+        let tone = emoji.skin_tone().and_then(tone_number);
+        let base = emoji
+            .skin_tones()
+            .and_then(|mut tones| tones.next())
+            .unwrap_or(emoji);
+        match (self.profile, tone, base.shortcode()) {
+            (Profile::GitHub, None, Some(shortcode)) => write!(f, ":{shortcode}:"),
+            (Profile::Slack, None, Some(shortcode)) => write!(f, ":{shortcode}:"),
+            (Profile::Slack, Some(n), Some(shortcode)) => {
+                write!(f, ":{shortcode}::skin-tone-{}:", n + 1)
+            }
+            (Profile::Discord, None, Some(shortcode)) => write!(f, ":{shortcode}:"),
+            (Profile::Discord, Some(n), Some(shortcode)) => write!(f, ":{shortcode}_tone{n}:"),
+            _ => f.write_str(emoji.as_str()),
+        }
+    }
+}
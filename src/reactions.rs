@@ -0,0 +1,162 @@
+//! Counting emoji reactions.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::reactions::Tally;
+//!
+//! let mut tally = Tally::new().tone_insensitive(true);
+//! for reaction in ["👍", "🎉", "👍🏽", "not an emoji", "\u{2764}"] {
+//!     tally.add(reaction);
+//! }
+//! let top: Vec<_> = tally
+//!     .top(2)
+//!     .into_iter()
+//!     .map(|(emoji, n)| (emoji.as_str(), n))
+//!     .collect();
+//! assert_eq!(top, [("👍", 2), ("❤️", 1)]);
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use crate::Emoji;
+
+/// The number of times each emoji was used as a reaction.
+///
+/// Reactions are normalized before they are counted, so the fully-qualified,
+/// minimally-qualified, and unqualified forms of an emoji are counted
+/// together, e.g. `"\u{2764}"` and `"❤️"`. If the tally is
+/// [tone-insensitive][Tally::tone_insensitive] then skin tone variations are
+/// also counted together as the default skin tone emoji.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tally {
+    counts: BTreeMap<u16, usize>,
+    tone_insensitive: bool,
+}
+
+impl Tally {
+    /// Construct a new empty tally.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether skin tone variations are counted as the default skin
+    /// tone emoji, e.g. 👍🏽 is counted as 👍.
+    ///
+    /// This only affects reactions added afterwards. Defaults to `false`.
+    pub fn tone_insensitive(mut self, yes: bool) -> Self {
+        self.tone_insensitive = yes;
+        self
+    }
+
+    /// Returns the key for the given emoji.
+    fn key(&self, emoji: &Emoji) -> u16 {
+        match emoji.skin_tone {
+            Some((i, _)) if self.tone_insensitive => i,
+            _ => emoji.index() as u16,
+        }
+    }
+
+    /// Adds one reaction and returns the emoji it was counted as.
+    ///
+    /// Returns `None` and does nothing if the string is not an emoji.
+    pub fn add(&mut self, reaction: &str) -> Option<&'static Emoji> {
+        let emoji = crate::get(reaction)?;
+        Some(self.add_emoji(emoji))
+    }
+
+    /// Adds one reaction with the given emoji and returns the emoji it was
+    /// counted as.
+    pub fn add_emoji(&mut self, emoji: &Emoji) -> &'static Emoji {
+        let key = self.key(emoji);
+        *self.counts.entry(key).or_insert(0) += 1;
+        &crate::gen::EMOJIS[usize::from(key)]
+    }
+
+    /// Removes one reaction, e.g. when a user takes back their reaction.
+    ///
+    /// Returns whether the reaction was counted.
+    pub fn remove(&mut self, reaction: &str) -> bool {
+        let key = match crate::get(reaction) {
+            Some(emoji) => self.key(emoji),
+            None => return false,
+        };
+        match self.counts.get_mut(&key) {
+            Some(n) if *n > 1 => *n -= 1,
+            Some(_) => {
+                self.counts.remove(&key);
+            }
+            None => return false,
+        }
+        true
+    }
+
+    /// Returns the number of times the given reaction was counted.
+    pub fn count(&self, reaction: &str) -> usize {
+        crate::get(reaction)
+            .and_then(|emoji| self.counts.get(&self.key(emoji)))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the total number of reactions.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns the number of distinct emojis.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns whether there are no reactions.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Removes all reactions.
+    pub fn clear(&mut self) {
+        self.counts.clear();
+    }
+
+    /// Returns an iterator over the emojis and the number of times each was
+    /// counted.
+    ///
+    /// Emojis are yielded in Unicode CLDR order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static Emoji, usize)> + '_ {
+        self.counts
+            .iter()
+            .map(|(&key, &n)| (&crate::gen::EMOJIS[usize::from(key)], n))
+    }
+
+    /// Returns the `n` most used emojis and the number of times each was
+    /// counted.
+    ///
+    /// Emojis are ordered by count from most to least used, emojis with the
+    /// same count are in Unicode CLDR order.
+    pub fn top(&self, n: usize) -> Vec<(&'static Emoji, usize)> {
+        let mut top: Vec<_> = self.iter().collect();
+        // The sort is stable so ties remain in CLDR order.
+        top.sort_by_key(|&(_, count)| Reverse(count));
+        top.truncate(n);
+        top
+    }
+}
+
+impl<'a> Extend<&'a str> for Tally {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        for reaction in iter {
+            self.add(reaction);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Tally {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut tally = Self::new();
+        tally.extend(iter);
+        tally
+    }
+}
@@ -0,0 +1,221 @@
+//! A fixed-capacity list of recently used emojis.
+
+use core::fmt;
+
+use crate::{Emoji, EmojiId};
+
+/// A list of the most recently used emojis, e.g. for the "frequently used" row
+/// of an emoji picker.
+///
+/// The list holds at most `N` emojis ordered from most to least recently
+/// used. Using an emoji that is already in the list moves it to the front
+/// instead of adding it twice, and using a new emoji when the list is full
+/// evicts the least recently used one. The list is stored inline as `N`
+/// [`EmojiId`]s so it never allocates.
+///
+/// Skin tone variations are separate emojis, e.g. 👍 and 👍🏽 can both be in the
+/// list.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::RecentList;
+///
+/// let mut recent = RecentList::<3>::new();
+/// for emoji in ["🚀", "🎉", "🚀", "👍", "😀"] {
+///     recent.push(emojis::get(emoji).unwrap());
+/// }
+/// let emojis: Vec<_> = recent.iter().map(|e| e.as_str()).collect();
+/// assert_eq!(emojis, ["😀", "👍", "🚀"]);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RecentList<const N: usize> {
+    ids: [Option<EmojiId>; N],
+    len: usize,
+}
+
+impl<const N: usize> RecentList<N> {
+    /// Construct a new empty list.
+    pub const fn new() -> Self {
+        Self {
+            ids: [None; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of emojis in the list.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of emojis in the list.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the list is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the position of the given id in the list.
+    fn position(&self, id: EmojiId) -> Option<usize> {
+        self.ids[..self.len].iter().position(|&x| x == Some(id))
+    }
+
+    /// Marks the emoji as the most recently used.
+    ///
+    /// Returns the emoji that was evicted to make room for it, if any.
+    pub fn push(&mut self, emoji: &Emoji) -> Option<&'static Emoji> {
+        self.push_id(emoji.id()).map(EmojiId::emoji)
+    }
+
+    /// Marks the emoji with the given id as the most recently used.
+    ///
+    /// Returns the id that was evicted to make room for it, if any.
+    pub fn push_id(&mut self, id: EmojiId) -> Option<EmojiId> {
+        if N == 0 {
+            return Some(id);
+        }
+        let (end, evicted) = match self.position(id) {
+            Some(i) => (i, None),
+            None if self.len < N => {
+                self.len += 1;
+                (self.len - 1, None)
+            }
+            None => (N - 1, self.ids[N - 1]),
+        };
+        self.ids[..=end].rotate_right(1);
+        self.ids[0] = Some(id);
+        evicted
+    }
+
+    /// Removes the emoji from the list.
+    ///
+    /// Returns whether the emoji was in the list.
+    pub fn remove(&mut self, emoji: &Emoji) -> bool {
+        match self.position(emoji.id()) {
+            Some(i) => {
+                self.ids[i..self.len].rotate_left(1);
+                self.len -= 1;
+                self.ids[self.len] = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns whether the emoji is in the list.
+    pub fn contains(&self, emoji: &Emoji) -> bool {
+        self.position(emoji.id()).is_some()
+    }
+
+    /// Removes all emojis from the list.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns an iterator over the emojis from most to least recently used.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &'static Emoji> + ExactSizeIterator + '_ {
+        self.ids().map(EmojiId::emoji)
+    }
+
+    /// Returns an iterator over the ids of the emojis from most to least
+    /// recently used.
+    pub fn ids(&self) -> impl DoubleEndedIterator<Item = EmojiId> + ExactSizeIterator + '_ {
+        // Every slot before `len` is occupied so the fallback is never used.
+        self.ids[..self.len]
+            .iter()
+            .map(|id| id.unwrap_or_else(|| crate::gen::EMOJIS[0].id()))
+    }
+}
+
+impl<const N: usize> Default for RecentList<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Debug for RecentList<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(Emoji::as_str))
+            .finish()
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a Emoji> for RecentList<N> {
+    fn extend<I: IntoIterator<Item = &'a Emoji>>(&mut self, iter: I) {
+        for emoji in iter {
+            self.push(emoji);
+        }
+    }
+}
+
+/// Serialized as a sequence of emoji strings, from most to least recently
+/// used, so that it can be read by any version of this crate.
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for RecentList<N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(Emoji::as_str))
+    }
+}
+
+/// Unknown emojis, e.g. ones written by a newer version of this crate, are
+/// skipped, and only the first `N` emojis are kept.
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for RecentList<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// An emoji string that is `None` if the emoji is unknown.
+        struct Item(Option<&'static Emoji>);
+
+        impl<'de> serde::Deserialize<'de> for Item {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl<'de> serde::de::Visitor<'de> for Visitor {
+                    type Value = Item;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        f.write_str("an emoji")
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                        Ok(Item(crate::get(v)))
+                    }
+                }
+
+                deserializer.deserialize_str(Visitor)
+            }
+        }
+
+        struct Visitor<const N: usize>;
+
+        impl<'de, const N: usize> serde::de::Visitor<'de> for Visitor<N> {
+            type Value = RecentList<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence of emojis")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut list = RecentList::new();
+                while let Some(Item(emoji)) = seq.next_element()? {
+                    match emoji {
+                        Some(emoji) if list.len < N && !list.contains(emoji) => {
+                            list.ids[list.len] = Some(emoji.id());
+                            list.len += 1;
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(Visitor)
+    }
+}
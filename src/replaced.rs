@@ -0,0 +1,79 @@
+//! Allocation free text transformations using [`core::fmt::Display`].
+
+use core::fmt;
+
+use crate::text::Matches;
+use crate::ShortcodeTokenizer;
+
+/// A [`Display`][fmt::Display] wrapper that writes transformed text.
+///
+/// The transformed text is written directly to the formatter so no `String`
+/// is ever built.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Replaced;
+///
+/// let text = Replaced::expand_shortcodes("launch :rocket:");
+/// assert_eq!(text.to_string(), "launch 🚀");
+///
+/// let text = Replaced::strip_emojis("launch 🚀");
+/// assert_eq!(format!("<{}>", text), "<launch >");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Replaced<'a> {
+    text: &'a str,
+    kind: Kind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    ExpandShortcodes,
+    StripEmojis,
+}
+
+impl<'a> Replaced<'a> {
+    /// Wraps the text so that GitHub shortcodes, e.g. `:rocket:`, are replaced
+    /// with emojis.
+    pub fn expand_shortcodes(text: &'a str) -> Self {
+        Self {
+            text,
+            kind: Kind::ExpandShortcodes,
+        }
+    }
+
+    /// Wraps the text so that all emojis are removed.
+    pub fn strip_emojis(text: &'a str) -> Self {
+        Self {
+            text,
+            kind: Kind::StripEmojis,
+        }
+    }
+}
+
+impl fmt::Display for Replaced<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = self.text;
+        let mut last = 0;
+        match self.kind {
+            Kind::ExpandShortcodes => {
+                for token in ShortcodeTokenizer::new(text) {
+                    if let Some(emoji) = token.emoji() {
+                        let range = token.range();
+                        f.write_str(&text[last..range.start])?;
+                        f.write_str(emoji.as_str())?;
+                        last = range.end;
+                    }
+                }
+            }
+            Kind::StripEmojis => {
+                for (range, _) in Matches::new(text) {
+                    f.write_str(&text[last..range.start])?;
+                    last = range.end;
+                }
+            }
+        }
+        f.write_str(&text[last..])
+    }
+}
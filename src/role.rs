@@ -0,0 +1,119 @@
+//! Professions and roles represented by person emojis.
+
+use crate::Emoji;
+
+/// A profession or role represented by a person emoji.
+///
+/// Every role is represented by a gender-neutral emoji as well as its gendered
+/// and skin tone variations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Role {
+    HealthWorker,
+    Student,
+    Teacher,
+    Judge,
+    Farmer,
+    Cook,
+    Mechanic,
+    FactoryWorker,
+    OfficeWorker,
+    Scientist,
+    Technologist,
+    Singer,
+    Artist,
+    Pilot,
+    Astronaut,
+    Firefighter,
+    PoliceOfficer,
+    Detective,
+    Guard,
+    Ninja,
+    ConstructionWorker,
+}
+
+/// Each role and its gender-neutral emoji in the default skin tone.
+const ROLES: &[(Role, &str)] = &[
+    (Role::HealthWorker, "🧑\u{200d}⚕\u{fe0f}"),
+    (Role::Student, "🧑\u{200d}🎓"),
+    (Role::Teacher, "🧑\u{200d}🏫"),
+    (Role::Judge, "🧑\u{200d}⚖\u{fe0f}"),
+    (Role::Farmer, "🧑\u{200d}🌾"),
+    (Role::Cook, "🧑\u{200d}🍳"),
+    (Role::Mechanic, "🧑\u{200d}🔧"),
+    (Role::FactoryWorker, "🧑\u{200d}🏭"),
+    (Role::OfficeWorker, "🧑\u{200d}💼"),
+    (Role::Scientist, "🧑\u{200d}🔬"),
+    (Role::Technologist, "🧑\u{200d}💻"),
+    (Role::Singer, "🧑\u{200d}🎤"),
+    (Role::Artist, "🧑\u{200d}🎨"),
+    (Role::Pilot, "🧑\u{200d}✈\u{fe0f}"),
+    (Role::Astronaut, "🧑\u{200d}🚀"),
+    (Role::Firefighter, "🧑\u{200d}🚒"),
+    (Role::PoliceOfficer, "👮"),
+    (Role::Detective, "🕵\u{fe0f}"),
+    (Role::Guard, "💂"),
+    (Role::Ninja, "🥷"),
+    (Role::ConstructionWorker, "👷"),
+];
+
+/// Returns an iterator over all roles.
+///
+/// # Examples
+///
+/// ```
+/// let mut iter = emojis::iter_roles();
+/// assert_eq!(iter.next().unwrap(), emojis::Role::HealthWorker);
+/// assert_eq!(iter.next().unwrap(), emojis::Role::Student);
+/// ```
+pub fn iter_roles() -> impl Iterator<Item = Role> {
+    ROLES.iter().map(|(role, _)| *role)
+}
+
+impl Role {
+    /// Returns the gender-neutral emoji for this role.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(emojis::Role::Pilot.emoji(), "🧑‍✈️");
+    /// ```
+    pub fn emoji(self) -> &'static Emoji {
+        crate::get(ROLES[self as usize].1).unwrap()
+    }
+
+    /// Returns an iterator over all emojis for this role, including gendered
+    /// forms but excluding skin tone variations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let cooks: Vec<_> = emojis::Role::Cook.emojis().map(|e| e.as_str()).collect();
+    /// assert_eq!(cooks, ["🧑‍🍳", "👨‍🍳", "👩‍🍳"]);
+    /// ```
+    pub fn emojis(self) -> impl Iterator<Item = &'static Emoji> {
+        self.emoji().gendered_variants()
+    }
+}
+
+impl Emoji {
+    /// Returns the profession or role this emoji represents, if any.
+    ///
+    /// All gender and skin tone variations of a role return the same role.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Role;
+    ///
+    /// assert_eq!(emojis::get("👩🏾‍⚕️").unwrap().role(), Some(Role::HealthWorker));
+    /// assert_eq!(emojis::get("👮‍♂️").unwrap().role(), Some(Role::PoliceOfficer));
+    /// assert_eq!(emojis::get("😀").unwrap().role(), None);
+    /// ```
+    pub fn role(&self) -> Option<Role> {
+        let concept = self.concept_id();
+        ROLES
+            .iter()
+            .find(|(_, emoji)| *emoji == concept)
+            .map(|(role, _)| *role)
+    }
+}
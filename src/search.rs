@@ -0,0 +1,164 @@
+//! Searching for emojis by name and keyword.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::data::{self, Builtin};
+use crate::Emoji;
+
+/// Returns whether `haystack` contains `needle` on word boundaries, ignoring
+/// ASCII case.
+pub(crate) fn contains_words(haystack: &str, needle: &str) -> bool {
+    let (h, n) = (haystack.as_bytes(), needle.as_bytes());
+    if n.is_empty() || n.len() > h.len() {
+        return false;
+    }
+    let is_word = |i: usize| h.get(i).map_or(false, u8::is_ascii_alphanumeric);
+    (0..=h.len() - n.len()).any(|i| {
+        h[i..i + n.len()].eq_ignore_ascii_case(n)
+            && (i == 0 || !is_word(i - 1))
+            && !is_word(i + n.len())
+    })
+}
+
+/// Returns an iterator over the emojis whose CLDR name contains the given
+/// words.
+///
+/// Matching ignores ASCII case and only matches whole words, so "art" does
+/// not match "heart" or "artist". Only the default skin tone versions are
+/// included.
+///
+/// # Examples
+///
+/// ```
+/// let hearts: Vec<_> = emojis::find_by_name("heart").map(|e| e.name()).collect();
+/// assert!(hearts.contains(&"red heart"));
+/// assert!(hearts.contains(&"smiling face with heart-eyes"));
+///
+/// assert_eq!(emojis::find_by_name("art").next(), None);
+/// ```
+pub fn find_by_name(words: &str) -> impl Iterator<Item = &'static Emoji> + '_ {
+    data::find_by_name(&Builtin, words).filter_map(Builtin::emoji)
+}
+
+/// Compares a lowercase keyword to a query, ignoring ASCII case in the query.
+fn cmp_keyword(keyword: &[u8], query: &str) -> Ordering {
+    keyword
+        .iter()
+        .copied()
+        .cmp(query.bytes().map(|b| b.to_ascii_lowercase()))
+}
+
+/// A keyword index, sorted by keyword.
+type Keywords<'a> = [(&'a str, &'a [&'static Emoji])];
+
+/// Returns the entries in the keyword index with a keyword that starts with
+/// the query.
+pub(crate) fn with_prefix<'a>(keywords: &'a Keywords<'a>, query: &str) -> &'a Keywords<'a> {
+    if query.is_empty() {
+        return &[];
+    }
+    let start = keywords.partition_point(|(k, _)| cmp_keyword(k.as_bytes(), query).is_lt());
+    let len = keywords[start..]
+        .iter()
+        .map(|(k, _)| k.as_bytes())
+        .take_while(|k| k.len() >= query.len() && cmp_keyword(&k[..query.len()], query).is_eq())
+        .count();
+    &keywords[start..start + len]
+}
+
+/// Returns the entry in the keyword index for the keyword, if any.
+pub(crate) fn with_keyword_entry<'a>(
+    keywords: &'a Keywords<'a>,
+    keyword: &str,
+) -> &'a Keywords<'a> {
+    match keywords.binary_search_by(|(k, _)| cmp_keyword(k.as_bytes(), keyword)) {
+        Ok(i) => &keywords[i..=i],
+        Err(_) => &[],
+    }
+}
+
+/// Returns the emojis in the keyword index with a keyword that starts with
+/// the query.
+#[cfg(feature = "locales")]
+pub(crate) fn prefix_matches(keywords: &Keywords<'_>, query: &str) -> crate::EmojiSet {
+    with_prefix(keywords, query)
+        .iter()
+        .flat_map(|(_, emojis)| emojis.iter().copied())
+        .collect()
+}
+
+/// Returns the emojis with the given keyword.
+///
+/// Keywords are the words in the CLDR name and the CLDR keywords of each
+/// emoji. Matching ignores ASCII case and surrounding whitespace. The lookup
+/// uses an index built by the generator so it takes *Ο(log n)* time. Only the
+/// default skin tone versions are included.
+///
+/// # Examples
+///
+/// ```
+/// let rockets = emojis::with_keyword("Rocket");
+/// assert!(rockets.contains(&emojis::get("🚀").unwrap()));
+///
+/// assert!(emojis::with_keyword("not a keyword").is_empty());
+/// ```
+pub fn with_keyword(keyword: &str) -> &'static [&'static Emoji] {
+    match with_keyword_entry(crate::gen::KEYWORDS, keyword.trim()) {
+        [(_, emojis)] => emojis,
+        _ => &[],
+    }
+}
+
+/// Ranks search results for [`search_with()`].
+///
+/// This trait is implemented for closures with the same signature as
+/// [`score()`][Scorer::score].
+#[cfg(feature = "alloc")]
+pub trait Scorer {
+    /// Returns the score of the emoji for the query, higher is better.
+    ///
+    /// Returning `None` excludes the emoji from the results.
+    fn score(&self, query: &str, emoji: &'static Emoji) -> Option<i64>;
+}
+
+#[cfg(feature = "alloc")]
+impl<F> Scorer for F
+where
+    F: Fn(&str, &'static Emoji) -> Option<i64>,
+{
+    fn score(&self, query: &str, emoji: &'static Emoji) -> Option<i64> {
+        self(query, emoji)
+    }
+}
+
+/// Searches for emojis and ranks them using the given scorer.
+///
+/// Candidates are the emojis with a [keyword][with_keyword()] that starts
+/// with the query. The results are sorted by descending score and emojis with
+/// the same score are in Unicode CLDR order.
+///
+/// # Examples
+///
+/// Boost the emojis the user has used before.
+///
+/// ```
+/// let used = ["🚀"];
+/// let results = emojis::search_with("rock", &|_: &str, emoji: &'static emojis::Emoji| {
+///     Some(used.contains(&emoji.as_str()) as i64)
+/// });
+/// assert_eq!(results[0], "🚀");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn search_with<S>(query: &str, scorer: &S) -> Vec<&'static Emoji>
+where
+    S: Scorer + ?Sized,
+{
+    data::search_with(&Builtin, query, |query, index| {
+        scorer.score(query, Builtin::emoji(index)?)
+    })
+    .into_iter()
+    .filter_map(Builtin::emoji)
+    .collect()
+}
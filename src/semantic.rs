@@ -0,0 +1,100 @@
+//! Curated emojis for common application states.
+
+use crate::series::get_known;
+use crate::Emoji;
+
+/// A weather condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WeatherCondition {
+    Clear,
+    ClearNight,
+    MostlyClear,
+    PartlyCloudy,
+    MostlyCloudy,
+    Cloudy,
+    Showers,
+    Rain,
+    Thunderstorm,
+    Snow,
+    Fog,
+    Wind,
+    Tornado,
+}
+
+/// A mode of transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TransportMode {
+    Walk,
+    Bicycle,
+    Scooter,
+    Motorcycle,
+    Car,
+    Taxi,
+    Bus,
+    Tram,
+    Train,
+    Subway,
+    Ship,
+    Ferry,
+    Airplane,
+    Helicopter,
+}
+
+/// Returns the emoji for the given weather condition.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::WeatherCondition;
+///
+/// assert_eq!(emojis::weather(WeatherCondition::Clear), "☀️");
+/// assert_eq!(emojis::weather(WeatherCondition::Thunderstorm), "⛈️");
+/// ```
+pub fn weather(condition: WeatherCondition) -> &'static Emoji {
+    get_known(match condition {
+        WeatherCondition::Clear => '\u{2600}',
+        WeatherCondition::ClearNight => '🌙',
+        WeatherCondition::MostlyClear => '🌤',
+        WeatherCondition::PartlyCloudy => '⛅',
+        WeatherCondition::MostlyCloudy => '🌥',
+        WeatherCondition::Cloudy => '\u{2601}',
+        WeatherCondition::Showers => '🌦',
+        WeatherCondition::Rain => '🌧',
+        WeatherCondition::Thunderstorm => '⛈',
+        WeatherCondition::Snow => '🌨',
+        WeatherCondition::Fog => '🌫',
+        WeatherCondition::Wind => '🌬',
+        WeatherCondition::Tornado => '🌪',
+    })
+}
+
+/// Returns the emoji for the given mode of transport.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::TransportMode;
+///
+/// assert_eq!(emojis::transport(TransportMode::Bus), "🚌");
+/// assert_eq!(emojis::transport(TransportMode::Airplane), "✈️");
+/// ```
+pub fn transport(mode: TransportMode) -> &'static Emoji {
+    get_known(match mode {
+        TransportMode::Walk => '🚶',
+        TransportMode::Bicycle => '🚲',
+        TransportMode::Scooter => '🛴',
+        TransportMode::Motorcycle => '🏍',
+        TransportMode::Car => '🚗',
+        TransportMode::Taxi => '🚕',
+        TransportMode::Bus => '🚌',
+        TransportMode::Tram => '🚊',
+        TransportMode::Train => '🚆',
+        TransportMode::Subway => '🚇',
+        TransportMode::Ship => '🚢',
+        TransportMode::Ferry => '⛴',
+        TransportMode::Airplane => '\u{2708}',
+        TransportMode::Helicopter => '🚁',
+    })
+}
@@ -0,0 +1,62 @@
+//! Coarse sentiment polarity for smileys and emotions.
+
+use crate::{Emoji, Group};
+
+/// The sentiment polarity of an emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sentiment {
+    Positive,
+    Negative,
+    Neutral,
+}
+
+/// Emojis in the Smileys & Emotion group with a positive sentiment.
+#[rustfmt::skip]
+const POSITIVE: &[&str] = &[
+    "😀", "😃", "😄", "😁", "😆", "😅", "🤣", "😂", "🙂", "😉", "😊", "😇", "🥰", "😍", "🤩", "😘", "😗",
+    "☺\u{fe0f}", "😚", "😙", "🥲", "😋", "😛", "😜", "🤪", "😝", "🤑", "🤗", "🫡", "😌", "🤠", "🥳", "😎", "🤓",
+    "😺", "😸", "😹", "😻", "😽", "💌", "💘", "💝", "💖", "💗", "💓", "💞", "💕", "💟", "❣\u{fe0f}",
+    "❤\u{fe0f}\u{200d}🔥", "❤\u{fe0f}\u{200d}🩹", "❤\u{fe0f}", "🩷", "🧡", "💛", "💚", "💙", "🩵", "💜",
+    "🤎", "🤍", "💋", "💯",
+];
+
+/// Emojis in the Smileys & Emotion group with a negative sentiment.
+#[rustfmt::skip]
+const NEGATIVE: &[&str] = &[
+    "😒", "🙄", "😬", "🤥", "😔", "😪", "😷", "🤒", "🤕", "🤢", "🤮", "🤧", "🥵", "🥶", "🥴", "😵",
+    "😵\u{200d}💫", "🤯", "😕", "🫤", "😟", "🙁", "☹\u{fe0f}", "😦", "😧", "😨", "😰", "😥", "😢", "😭", "😱",
+    "😖", "😣", "😞", "😓", "😩", "😫", "😤", "😡", "😠", "🤬", "👿", "💀", "☠\u{fe0f}", "💩", "🙀", "😿", "😾",
+    "💔", "💢", "🗯\u{fe0f}",
+];
+
+impl Emoji {
+    /// Returns the coarse sentiment polarity of this emoji.
+    ///
+    /// Only emojis in the [Smileys & Emotion][Group::SmileysAndEmotion] group
+    /// have a sentiment, all other emojis return `None`. The polarity is a
+    /// rough guide to how the emoji is commonly used and does not take context
+    /// into account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::Sentiment;
+    ///
+    /// assert_eq!(emojis::get("😂").unwrap().sentiment(), Some(Sentiment::Positive));
+    /// assert_eq!(emojis::get("😭").unwrap().sentiment(), Some(Sentiment::Negative));
+    /// assert_eq!(emojis::get("🤔").unwrap().sentiment(), Some(Sentiment::Neutral));
+    /// assert_eq!(emojis::get("🚀").unwrap().sentiment(), None);
+    /// ```
+    pub fn sentiment(&self) -> Option<Sentiment> {
+        if self.group != Group::SmileysAndEmotion {
+            return None;
+        }
+        if POSITIVE.contains(&self.emoji) {
+            Some(Sentiment::Positive)
+        } else if NEGATIVE.contains(&self.emoji) {
+            Some(Sentiment::Negative)
+        } else {
+            Some(Sentiment::Neutral)
+        }
+    }
+}
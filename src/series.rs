@@ -0,0 +1,310 @@
+//! Emojis that form a series, such as the clock faces.
+
+use core::fmt;
+
+use crate::{Color, Emoji};
+
+/// The keycap emojis for the digits zero to nine.
+const KEYCAPS: [&str; 10] = [
+    "0\u{fe0f}\u{20e3}",
+    "1\u{fe0f}\u{20e3}",
+    "2\u{fe0f}\u{20e3}",
+    "3\u{fe0f}\u{20e3}",
+    "4\u{fe0f}\u{20e3}",
+    "5\u{fe0f}\u{20e3}",
+    "6\u{fe0f}\u{20e3}",
+    "7\u{fe0f}\u{20e3}",
+    "8\u{fe0f}\u{20e3}",
+    "9\u{fe0f}\u{20e3}",
+];
+
+/// A phase of the moon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoonPhase {
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+/// A sign of the zodiac.
+///
+/// The twelve signs are in the traditional order starting with Aries, followed
+/// by Ophiuchus which also has an emoji.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZodiacSign {
+    Aries,
+    Taurus,
+    Gemini,
+    Cancer,
+    Leo,
+    Virgo,
+    Libra,
+    Scorpio,
+    Sagittarius,
+    Capricorn,
+    Aquarius,
+    Pisces,
+    Ophiuchus,
+}
+
+/// Returns the emoji for the single character, which must be in the
+/// dataset.
+pub(crate) fn get_known(c: char) -> &'static Emoji {
+    // Every character in a series is an emoji so the fallback is never used.
+    crate::get_char(c).unwrap_or(&crate::gen::EMOJIS[0])
+}
+
+/// Returns the clock face emoji showing the given time.
+///
+/// The hour can be on a 12 or a 24 hour clock, i.e. `0` and `12` are both
+/// 🕛. If `half` is `true` the clock face shows thirty minutes past the hour.
+/// Returns `None` if the hour is greater than 23.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::clock(1, false).unwrap(), "🕐");
+/// assert_eq!(emojis::clock(13, true).unwrap(), "🕜");
+/// assert_eq!(emojis::clock(0, false).unwrap(), "🕛");
+/// assert!(emojis::clock(24, false).is_none());
+/// ```
+pub fn clock(hour: u8, half: bool) -> Option<&'static Emoji> {
+    if hour > 23 {
+        return None;
+    }
+    // The clock faces start at one o'clock and end at twelve o'clock.
+    let offset = (u32::from(hour) + 11) % 12;
+    let first = if half { 0x1f55c } else { 0x1f550 };
+    crate::get_char(char::from_u32(first + offset)?)
+}
+
+/// Returns the emoji for the given phase of the moon.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::MoonPhase;
+///
+/// assert_eq!(emojis::moon_phase(MoonPhase::FullMoon), "🌕");
+/// assert_eq!(emojis::moon_phase(MoonPhase::WaningCrescent), "🌘");
+/// ```
+pub fn moon_phase(phase: MoonPhase) -> &'static Emoji {
+    get_known(match phase {
+        MoonPhase::NewMoon => '🌑',
+        MoonPhase::WaxingCrescent => '🌒',
+        MoonPhase::FirstQuarter => '🌓',
+        MoonPhase::WaxingGibbous => '🌔',
+        MoonPhase::FullMoon => '🌕',
+        MoonPhase::WaningGibbous => '🌖',
+        MoonPhase::LastQuarter => '🌗',
+        MoonPhase::WaningCrescent => '🌘',
+    })
+}
+
+/// Returns the emoji for the given sign of the zodiac.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::ZodiacSign;
+///
+/// assert_eq!(emojis::zodiac(ZodiacSign::Leo), "♌");
+/// assert_eq!(emojis::zodiac(ZodiacSign::Ophiuchus), "⛎");
+/// ```
+pub fn zodiac(sign: ZodiacSign) -> &'static Emoji {
+    get_known(match sign {
+        ZodiacSign::Aries => '♈',
+        ZodiacSign::Taurus => '♉',
+        ZodiacSign::Gemini => '♊',
+        ZodiacSign::Cancer => '♋',
+        ZodiacSign::Leo => '♌',
+        ZodiacSign::Virgo => '♍',
+        ZodiacSign::Libra => '♎',
+        ZodiacSign::Scorpio => '♏',
+        ZodiacSign::Sagittarius => '♐',
+        ZodiacSign::Capricorn => '♑',
+        ZodiacSign::Aquarius => '♒',
+        ZodiacSign::Pisces => '♓',
+        ZodiacSign::Ophiuchus => '⛎',
+    })
+}
+
+/// Returns an iterator over the keycap emojis for the decimal digits of the
+/// number.
+///
+/// The digits are yielded from the most significant. The iterator also
+/// implements [`Display`][fmt::Display] which writes the whole sequence.
+///
+/// # Examples
+///
+/// ```
+/// let keycaps: Vec<_> = emojis::digits(42).map(|e| e.as_str()).collect();
+/// assert_eq!(keycaps, ["4\u{fe0f}\u{20e3}", "2\u{fe0f}\u{20e3}"]);
+///
+/// assert_eq!(emojis::digits(0).to_string(), "0️⃣");
+/// assert_eq!(format!("Round {}", emojis::digits(10)), "Round 1️⃣0️⃣");
+/// ```
+pub fn digits(n: u64) -> Digits {
+    let mut div = 1;
+    while n / div >= 10 {
+        div *= 10;
+    }
+    Digits { n, div }
+}
+
+/// An iterator over the keycap emojis for the digits of a number.
+///
+/// Returned by [`digits()`].
+#[derive(Debug, Clone)]
+pub struct Digits {
+    n: u64,
+    /// The place value of the next digit, or zero when there are no more.
+    div: u64,
+}
+
+impl Iterator for Digits {
+    type Item = &'static Emoji;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.div == 0 {
+            return None;
+        }
+        let digit = (self.n / self.div % 10) as usize;
+        self.div /= 10;
+        // Every keycap is an emoji so the fallback is never used.
+        Some(crate::get(KEYCAPS[digit]).unwrap_or(&crate::gen::EMOJIS[0]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut len = 0;
+        let mut div = self.div;
+        while div > 0 {
+            len += 1;
+            div /= 10;
+        }
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Digits {}
+
+impl fmt::Display for Digits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for emoji in self.clone() {
+            f.write_str(emoji.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the heart emoji with the given color.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Color;
+///
+/// assert_eq!(emojis::heart(Color::Red), "❤️");
+/// assert_eq!(emojis::heart(Color::Purple), "💜");
+/// ```
+pub fn heart(color: Color) -> &'static Emoji {
+    get_known(match color {
+        Color::Red => '\u{2764}',
+        Color::Orange => '🧡',
+        Color::Yellow => '💛',
+        Color::Green => '💚',
+        Color::Blue => '💙',
+        Color::Purple => '💜',
+        Color::Pink => '🩷',
+        Color::Brown => '🤎',
+        Color::Black => '🖤',
+        Color::Grey => '🩶',
+        Color::White => '🤍',
+    })
+}
+
+/// Returns the large square emoji with the given color.
+///
+/// Returns `None` if there is no square with the color, i.e. for pink and
+/// grey.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Color;
+///
+/// assert_eq!(emojis::square(Color::Green).unwrap(), "🟩");
+/// assert!(emojis::square(Color::Pink).is_none());
+/// ```
+pub fn square(color: Color) -> Option<&'static Emoji> {
+    let c = match color {
+        Color::Red => '🟥',
+        Color::Orange => '🟧',
+        Color::Yellow => '🟨',
+        Color::Green => '🟩',
+        Color::Blue => '🟦',
+        Color::Purple => '🟪',
+        Color::Brown => '🟫',
+        Color::Black => '⬛',
+        Color::White => '⬜',
+        Color::Pink | Color::Grey => return None,
+    };
+    crate::get_char(c)
+}
+
+/// Returns the circle emoji with the given color.
+///
+/// Returns `None` if there is no circle with the color, i.e. for pink and
+/// grey.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Color;
+///
+/// assert_eq!(emojis::circle(Color::Red).unwrap(), "🔴");
+/// assert!(emojis::circle(Color::Grey).is_none());
+/// ```
+pub fn circle(color: Color) -> Option<&'static Emoji> {
+    let c = match color {
+        Color::Red => '🔴',
+        Color::Orange => '🟠',
+        Color::Yellow => '🟡',
+        Color::Green => '🟢',
+        Color::Blue => '🔵',
+        Color::Purple => '🟣',
+        Color::Brown => '🟤',
+        Color::Black => '⚫',
+        Color::White => '⚪',
+        Color::Pink | Color::Grey => return None,
+    };
+    crate::get_char(c)
+}
+
+/// Returns the medal emoji for the given place, starting at `1` for first
+/// place.
+///
+/// Returns `None` if there is no medal for the place, i.e. after third place.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::medal(1).unwrap(), "🥇");
+/// assert_eq!(emojis::medal(3).unwrap(), "🥉");
+/// assert!(emojis::medal(0).is_none());
+/// assert!(emojis::medal(4).is_none());
+/// ```
+pub fn medal(place: u8) -> Option<&'static Emoji> {
+    let c = match place {
+        1 => '🥇',
+        2 => '🥈',
+        3 => '🥉',
+        _ => return None,
+    };
+    crate::get_char(c)
+}
@@ -0,0 +1,395 @@
+//! A compact set of emojis.
+
+use core::fmt;
+
+use crate::Emoji;
+
+const WORDS: usize = (crate::gen::EMOJIS.len() + 63) / 64;
+
+/// The version of the byte layout used by [`EmojiSet::to_bytes()`].
+const FORMAT_VERSION: u8 = 1;
+
+/// The length of the header before the bitmap in the byte layout.
+const HEADER_LEN: usize = 3;
+
+/// A set of emojis.
+///
+/// The set is stored as a fixed size bitmap with one bit for every emoji,
+/// including skin tone variations, so it does not allocate and membership
+/// operations take *Ο(1)* time. Iteration yields emojis in Unicode CLDR order.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::EmojiSet;
+///
+/// let mut set = EmojiSet::new();
+/// set.insert(emojis::get("🚀").unwrap());
+/// assert!(set.contains(emojis::get("🚀").unwrap()));
+/// assert_eq!(set.len(), 1);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmojiSet {
+    bits: [u64; WORDS],
+}
+
+impl EmojiSet {
+    /// Construct a new empty set.
+    pub const fn new() -> Self {
+        Self { bits: [0; WORDS] }
+    }
+
+    /// Construct a set from emojis and shortcodes at compile time.
+    ///
+    /// Used by the [`emoji_set!`][crate::emoji_set] macro.
+    #[doc(hidden)]
+    pub const fn __from_strs(strs: &[&str]) -> Self {
+        let mut bits = [0; WORDS];
+        let mut i = 0;
+        while i < strs.len() {
+            let (w, b) = locate(find(strs[i]));
+            bits[w] |= b;
+            i += 1;
+        }
+        Self { bits }
+    }
+
+    /// Adds an emoji to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, emoji: &Emoji) -> bool {
+        let (w, b) = locate(emoji.index());
+        let new = self.bits[w] & b == 0;
+        self.bits[w] |= b;
+        new
+    }
+
+    /// Removes an emoji from the set, returning whether it was present.
+    pub fn remove(&mut self, emoji: &Emoji) -> bool {
+        let (w, b) = locate(emoji.index());
+        let present = self.bits[w] & b != 0;
+        self.bits[w] &= !b;
+        present
+    }
+
+    /// Returns whether the set contains the given emoji.
+    pub fn contains(&self, emoji: &Emoji) -> bool {
+        let (w, b) = locate(emoji.index());
+        self.bits[w] & b != 0
+    }
+
+    /// Returns the number of emojis in the set.
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&w| w == 0)
+    }
+
+    /// Returns an iterator over the emojis in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let set: emojis::EmojiSet = ["🚀", "😀"].iter().filter_map(|s| emojis::get(s)).collect();
+    /// let v: Vec<_> = set.iter().map(|e| e.as_str()).collect();
+    /// assert_eq!(v, ["😀", "🚀"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &'static Emoji> + '_ {
+        crate::gen::EMOJIS
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| {
+                let (w, b) = locate(*i);
+                self.bits[w] & b != 0
+            })
+            .map(|(_, emoji)| emoji)
+    }
+
+    /// Returns the union of this set and another.
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Returns the intersection of this set and another.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Returns the emojis in this set that are not in the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & !b)
+    }
+
+    /// The length of the bytes returned by [`to_bytes()`][Self::to_bytes].
+    pub const ENCODED_LEN: usize = HEADER_LEN + (crate::gen::EMOJIS.len() + 7) / 8;
+
+    /// Returns the set encoded as a compact bitmap.
+    ///
+    /// The layout is stable and versioned so that the bytes can be persisted
+    /// and shared between programs using this crate:
+    ///
+    /// - byte 0 is the layout version, currently `1`.
+    /// - bytes 1..3 are the number of emojis in the dataset as a little-endian
+    ///   `u16`.
+    /// - the remaining bytes are the bitmap, the emoji with index `i` in the
+    ///   table of all emojis is bit `i % 8` of byte `i / 8`. The table includes
+    ///   every skin tone variation directly after its default skin tone
+    ///   emoji, the same order as [`dump()`][crate::dump].
+    ///
+    /// The bitmap is only meaningful to a crate version with the same dataset,
+    /// [`from_bytes()`][Self::from_bytes] rejects bytes from a dataset with a
+    /// different number of emojis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::EmojiSet;
+    ///
+    /// let set: EmojiSet = emojis::get("🚀").into_iter().collect();
+    /// let bytes = set.to_bytes();
+    /// assert_eq!(bytes.len(), EmojiSet::ENCODED_LEN);
+    /// assert_eq!(EmojiSet::from_bytes(&bytes), Ok(set));
+    /// ```
+    pub fn to_bytes(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut bytes = [0; Self::ENCODED_LEN];
+        bytes[0] = FORMAT_VERSION;
+        bytes[1..HEADER_LEN].copy_from_slice(&(crate::gen::EMOJIS.len() as u16).to_le_bytes());
+        let words = self.bits.iter().flat_map(|w| w.to_le_bytes());
+        for (b, w) in bytes[HEADER_LEN..].iter_mut().zip(words) {
+            *b = w;
+        }
+        bytes
+    }
+
+    /// Decodes a set from the bytes returned by
+    /// [`to_bytes()`][Self::to_bytes].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeSetError> {
+        let version = *bytes.first().ok_or(DecodeSetError::InvalidLength)?;
+        if version != FORMAT_VERSION {
+            return Err(DecodeSetError::UnsupportedVersion(version));
+        }
+        let count = match bytes.get(1..HEADER_LEN) {
+            Some(&[a, b]) => u16::from_le_bytes([a, b]),
+            _ => return Err(DecodeSetError::InvalidLength),
+        };
+        if count as usize != crate::gen::EMOJIS.len() {
+            return Err(DecodeSetError::DatasetMismatch { count });
+        }
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(DecodeSetError::InvalidLength);
+        }
+        let mut set = Self::new();
+        for (i, &b) in bytes[HEADER_LEN..].iter().enumerate() {
+            set.bits[i / 8] |= (b as u64) << (i % 8 * 8);
+        }
+        if set.iter().count() != set.len() {
+            return Err(DecodeSetError::InvalidBitmap);
+        }
+        Ok(set)
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        let mut bits = self.bits;
+        for (a, b) in bits.iter_mut().zip(&other.bits) {
+            *a = f(*a, *b);
+        }
+        Self { bits }
+    }
+}
+
+const fn locate(index: usize) -> (usize, u64) {
+    (index / 64, 1 << (index % 64))
+}
+
+/// Returns the index of the emoji with the given Unicode value or shortcode
+/// surrounded by colons.
+const fn find(s: &str) -> usize {
+    match position(s) {
+        Some(i) => i,
+        None => panic!("unknown emoji or shortcode in `emoji_set!`"),
+    }
+}
+
+/// Returns the index of the emoji with the given Unicode value or shortcode
+/// surrounded by colons, if there is one.
+pub(crate) const fn position(s: &str) -> Option<usize> {
+    let s = s.as_bytes();
+    let shortcode = s.len() > 2 && s[0] == b':' && s[s.len() - 1] == b':';
+    let emojis = crate::gen::EMOJIS;
+    let mut i = 0;
+    while i < emojis.len() {
+        let emoji = &emojis[i];
+        if shortcode {
+            #[cfg(feature = "shortcodes")]
+            {
+                let shortcodes = crate::gen::SHORTCODES[i];
+                let mut j = 0;
+                while j < shortcodes.len() {
+                    if eq(shortcodes[j].as_bytes(), s, 1) {
+                        return Some(i);
+                    }
+                    j += 1;
+                }
+            }
+        } else if eq(emoji.emoji.as_bytes(), s, 0) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Returns whether `a` is equal to `b` with `trim` bytes removed from each
+/// end.
+const fn eq(a: &[u8], b: &[u8], trim: usize) -> bool {
+    if a.len() + 2 * trim != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i + trim] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Construct an [`EmojiSet`] at compile time.
+///
+/// Each element is either an emoji or a GitHub shortcode surrounded by colons.
+/// Unknown emojis and shortcodes are a compile time error, and since the set
+/// is a constant there is no runtime construction cost.
+///
+/// Emojis must be fully-qualified and skin tones are not expanded, e.g. `"👍"`
+/// does not include `"👍🏽"`.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{emoji_set, EmojiSet};
+///
+/// const ALLOWED: EmojiSet = emoji_set!["🚀", "🎉", ":+1:"];
+/// assert!(ALLOWED.contains(emojis::get("👍").unwrap()));
+/// assert_eq!(ALLOWED.len(), 3);
+/// ```
+///
+/// ```compile_fail
+/// const ALLOWED: emojis::EmojiSet = emojis::emoji_set![":not_a_shortcode:"];
+/// ```
+#[macro_export]
+macro_rules! emoji_set {
+    ($($emoji:expr),* $(,)?) => {{
+        const SET: $crate::EmojiSet = $crate::EmojiSet::__from_strs(&[$($emoji),*]);
+        SET
+    }};
+}
+
+/// An error returned by [`EmojiSet::from_bytes()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodeSetError {
+    /// The bytes are not the expected length.
+    InvalidLength,
+    /// The bytes use a layout version not supported by this crate.
+    UnsupportedVersion(u8),
+    /// The bytes were encoded with a dataset with a different number of
+    /// emojis.
+    DatasetMismatch { count: u16 },
+    /// The bitmap has bits set past the last emoji.
+    InvalidBitmap,
+}
+
+impl fmt::Display for DecodeSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => f.write_str("invalid emoji set length"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported emoji set version `{}`", v),
+            Self::DatasetMismatch { count } => write!(
+                f,
+                "emoji set encoded with {} emojis, expected {}",
+                count,
+                crate::gen::EMOJIS.len()
+            ),
+            Self::InvalidBitmap => f.write_str("emoji set contains unknown emojis"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeSetError {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for EmojiSet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EmojiSet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = EmojiSet;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an encoded emoji set")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                EmojiSet::from_bytes(v).map_err(E::custom)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error> {
+                use serde::de::Error;
+
+                let mut bytes = [0; EmojiSet::ENCODED_LEN];
+                let mut len = 0;
+                while let Some(b) = seq.next_element()? {
+                    *bytes
+                        .get_mut(len)
+                        .ok_or_else(|| A::Error::custom(DecodeSetError::InvalidLength))? = b;
+                    len += 1;
+                }
+                EmojiSet::from_bytes(&bytes[..len]).map_err(A::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_bytes(Visitor)
+    }
+}
+
+impl Default for EmojiSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for EmojiSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.iter().map(Emoji::as_str))
+            .finish()
+    }
+}
+
+impl<'a> Extend<&'a Emoji> for EmojiSet {
+    fn extend<I: IntoIterator<Item = &'a Emoji>>(&mut self, iter: I) {
+        for emoji in iter {
+            self.insert(emoji);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a Emoji> for EmojiSet {
+    fn from_iter<I: IntoIterator<Item = &'a Emoji>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
@@ -0,0 +1,83 @@
+//! Pluggable shortcode tables.
+
+use crate::Emoji;
+
+/// A table of shortcodes, e.g. GitHub's, Slack's, or Discord's.
+///
+/// The built-in [`GitHubShortcodes`] table is used by default. Implement this
+/// trait to lookup shortcodes from another source, e.g. a dialect table
+/// loaded at runtime, and pass it to [`ShortcodeTokenizer::table()`]. When
+/// only custom tables are used, disabling the **shortcodes** feature removes
+/// the built-in table from the binary.
+///
+/// [`ShortcodeTokenizer::table()`]: crate::ShortcodeTokenizer::table
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{Emoji, ShortcodeTable, ShortcodeTokenizer};
+///
+/// struct Slack;
+///
+/// impl ShortcodeTable for Slack {
+///     fn get(&self, shortcode: &str) -> Option<&'static Emoji> {
+///         match shortcode {
+///             "simple_smile" => emojis::get("🙂"),
+///             _ => None,
+///         }
+///     }
+///
+///     fn shortcode(&self, emoji: &Emoji) -> Option<&str> {
+///         (emoji == "🙂").then(|| "simple_smile")
+///     }
+/// }
+///
+/// let mut tokens = ShortcodeTokenizer::new(":simple_smile: :rocket:").table(&Slack);
+/// assert_eq!(tokens.next().unwrap().emoji().unwrap(), "🙂");
+/// assert_eq!(tokens.next().unwrap().emoji(), None);
+/// ```
+pub trait ShortcodeTable {
+    /// Lookup an emoji by shortcode, excluding the colons.
+    fn get(&self, shortcode: &str) -> Option<&'static Emoji>;
+
+    /// Returns the primary shortcode for the given emoji, if any.
+    fn shortcode(&self, emoji: &Emoji) -> Option<&str>;
+}
+
+impl<T: ShortcodeTable + ?Sized> ShortcodeTable for &T {
+    fn get(&self, shortcode: &str) -> Option<&'static Emoji> {
+        (**self).get(shortcode)
+    }
+
+    fn shortcode(&self, emoji: &Emoji) -> Option<&str> {
+        (**self).shortcode(emoji)
+    }
+}
+
+/// The built-in table of [gemoji] shortcodes.
+///
+/// This delegates to [`get_by_shortcode()`][crate::get_by_shortcode] and
+/// [`Emoji::shortcode()`], so it is empty without the **shortcodes** feature.
+///
+/// [gemoji]: https://github.com/github/gemoji
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{GitHubShortcodes, ShortcodeTable};
+///
+/// let rocket = GitHubShortcodes.get("rocket").unwrap();
+/// assert_eq!(GitHubShortcodes.shortcode(rocket), Some("rocket"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GitHubShortcodes;
+
+impl ShortcodeTable for GitHubShortcodes {
+    fn get(&self, shortcode: &str) -> Option<&'static Emoji> {
+        crate::get_by_shortcode(shortcode)
+    }
+
+    fn shortcode(&self, emoji: &Emoji) -> Option<&str> {
+        emoji.shortcode_slice().first().copied()
+    }
+}
@@ -0,0 +1,52 @@
+//! Sorting collections of emojis.
+
+use crate::Emoji;
+
+/// Sorts the emojis in Unicode CLDR order.
+///
+/// This is the same order as [`Emoji::order_key()`]. With the **alloc**
+/// feature enabled the key for each emoji is computed once, otherwise it is
+/// computed for every comparison.
+///
+/// # Examples
+///
+/// ```
+/// let mut recent: Vec<_> = ["🚀", "😀", "👍🏽", "👍"].iter().filter_map(|s| emojis::get(s)).collect();
+/// emojis::sort(&mut recent);
+/// assert_eq!(recent, ["😀", "👍", "👍🏽", "🚀"]);
+/// ```
+pub fn sort(emojis: &mut [&Emoji]) {
+    #[cfg(feature = "alloc")]
+    emojis.sort_by_cached_key(|emoji| emoji.order_key());
+    #[cfg(not(feature = "alloc"))]
+    emojis.sort_unstable_by_key(|emoji| emoji.order_key());
+}
+
+/// Sorts the emojis by their [name][Emoji::name].
+///
+/// # Examples
+///
+/// ```
+/// let mut recent: Vec<_> = ["🚀", "😀", "👍"].iter().filter_map(|s| emojis::get(s)).collect();
+/// emojis::sort_by_name(&mut recent);
+/// assert_eq!(recent, ["😀", "🚀", "👍"]);
+/// ```
+pub fn sort_by_name(emojis: &mut [&Emoji]) {
+    emojis.sort_unstable_by_key(|emoji| emoji.name());
+}
+
+/// Returns whether the emojis are sorted in Unicode CLDR order.
+///
+/// See [`sort()`] for more information.
+pub fn is_sorted(emojis: &[&Emoji]) -> bool {
+    emojis
+        .windows(2)
+        .all(|w| w[0].order_key() <= w[1].order_key())
+}
+
+/// Returns whether the emojis are sorted by their [name][Emoji::name].
+///
+/// See [`sort_by_name()`] for more information.
+pub fn is_sorted_by_name(emojis: &[&Emoji]) -> bool {
+    emojis.windows(2).all(|w| w[0].name() <= w[1].name())
+}
@@ -0,0 +1,88 @@
+//! Sprite sheet positions for emoji images.
+
+use crate::Emoji;
+
+/// A sprite sheet in the [emoji-datasource] layout.
+///
+/// All vendors' sheets (Apple, Google, Twitter, Facebook) share the same
+/// layout so only the size of each image needs to be known. Each image has
+/// one pixel of padding on every side.
+///
+/// [emoji-datasource]: https://github.com/iamcal/emoji-data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpriteSheet {
+    Size16,
+    Size20,
+    Size32,
+    Size64,
+}
+
+/// The position of an emoji in a sprite sheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpritePosition {
+    column: u8,
+    row: u8,
+    size: u32,
+}
+
+impl SpriteSheet {
+    /// Returns the width and height of each image in pixels.
+    pub const fn image_size(self) -> u32 {
+        match self {
+            Self::Size16 => 16,
+            Self::Size20 => 20,
+            Self::Size32 => 32,
+            Self::Size64 => 64,
+        }
+    }
+}
+
+impl SpritePosition {
+    /// Returns the column of the image in the sheet.
+    pub const fn column(self) -> u8 {
+        self.column
+    }
+
+    /// Returns the row of the image in the sheet.
+    pub const fn row(self) -> u8 {
+        self.row
+    }
+
+    /// Returns the horizontal offset of the image in pixels.
+    pub const fn x(self) -> u32 {
+        self.column as u32 * (self.size + 2) + 1
+    }
+
+    /// Returns the vertical offset of the image in pixels.
+    pub const fn y(self) -> u32 {
+        self.row as u32 * (self.size + 2) + 1
+    }
+}
+
+impl Emoji {
+    /// Returns the position of this emoji in the given sprite sheet.
+    ///
+    /// Returns `None` if the sprite sheet does not contain this emoji.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::SpriteSheet;
+    ///
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// if let Some(pos) = rocket.sprite_position(SpriteSheet::Size32) {
+    ///     println!("background-position: -{}px -{}px", pos.x(), pos.y());
+    /// }
+    /// ```
+    pub fn sprite_position(&self, sheet: SpriteSheet) -> Option<SpritePosition> {
+        let sprites = crate::gen::SPRITES;
+        let i = self.index();
+        let k = sprites.binary_search_by_key(&i, |&(j, ..)| j).ok()?;
+        let (_, column, row) = sprites[k];
+        Some(SpritePosition {
+            column,
+            row,
+            size: sheet.image_size(),
+        })
+    }
+}
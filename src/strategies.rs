@@ -0,0 +1,58 @@
+//! [`proptest`] strategies for emojis and text containing emojis.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(text in emojis::strategies::text_with_emojis(16))| {
+//!     for (start, s) in emojis::pattern().find_in(&text) {
+//!         prop_assert_eq!(&text[start..start + s.len()], s);
+//!     }
+//! });
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use proptest::prelude::*;
+
+use crate::{Emoji, SkinTone};
+
+/// Returns a strategy that generates any emoji with the default skin tone.
+///
+/// Emojis are chosen uniformly from [`iter()`][crate::iter].
+pub fn emoji() -> impl Strategy<Value = &'static Emoji> {
+    proptest::sample::select(crate::iter().collect::<Vec<_>>())
+}
+
+/// Returns a strategy that generates any emoji with a skin tone other than
+/// the default, e.g. 👍🏽.
+pub fn toned_emoji() -> impl Strategy<Value = &'static Emoji> {
+    let toned: Vec<_> = crate::iter()
+        .filter_map(Emoji::skin_tones)
+        .flatten()
+        .filter(|e| !matches!(e.skin_tone(), Some(SkinTone::Default) | None))
+        .collect();
+    proptest::sample::select(toned)
+}
+
+/// Returns a strategy that generates text made of up to `len` pieces, where
+/// each piece is an emoji, a skin toned emoji, an ASCII word, whitespace,
+/// punctuation, or any other character.
+///
+/// Adjacent pieces are not separated, so the text also contains emojis
+/// directly followed by other emojis, modifiers, or combining characters.
+pub fn text_with_emojis(len: usize) -> impl Strategy<Value = String> {
+    let word = proptest::collection::vec(proptest::char::range('a', 'z'), 1..8)
+        .prop_map(|chars| chars.into_iter().collect::<String>());
+    let piece = prop_oneof![
+        4 => emoji().prop_map(|e| String::from(e.as_str())),
+        2 => toned_emoji().prop_map(|e| String::from(e.as_str())),
+        4 => word,
+        2 => proptest::sample::select(&[" ", "\n", "\t"][..]).prop_map(String::from),
+        1 => proptest::sample::select(&[".", ",", "!", ":", "#", "*", "1"][..]).prop_map(String::from),
+        1 => any::<char>().prop_map(String::from),
+    ];
+    proptest::collection::vec(piece, 0..=len).prop_map(|pieces| pieces.concat())
+}
@@ -0,0 +1,178 @@
+//! Rendering emojis on terminals with limited support.
+//!
+//! # Examples
+//!
+//! ```
+//! use emojis::term::{self, Capability};
+//!
+//! let heart = emojis::get("❤️").unwrap();
+//! assert_eq!(term::render(heart, Capability::Emoji).to_string(), "❤️");
+//! assert_eq!(term::render(heart, Capability::TextPresentation).to_string(), "❤\u{fe0e}");
+//! assert_eq!(term::render(heart, Capability::Shortcodes).to_string(), ":heart:");
+//! assert_eq!(term::render(heart, Capability::Ascii).to_string(), "<3");
+//! ```
+
+use core::fmt;
+use core::fmt::Write;
+
+use crate::Emoji;
+
+/// What a terminal is able to display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Capability {
+    /// Color emojis.
+    Emoji,
+    /// Monochrome symbols, i.e. the text presentation of emojis that have
+    /// one, but not color emojis.
+    TextPresentation,
+    /// Shortcodes in place of emojis.
+    Shortcodes,
+    /// Only ASCII characters.
+    Ascii,
+}
+
+/// Emoticons used as ASCII fallbacks.
+const EMOTICONS: &[(&str, &str)] = &[
+    ("😀", ":D"),
+    ("😃", ":D"),
+    ("😄", ":D"),
+    ("😉", ";)"),
+    ("😛", ":P"),
+    ("😘", ":*"),
+    ("😐", ":|"),
+    ("😎", "B)"),
+    ("😕", ":/"),
+    ("🙁", ":("),
+    ("☹️", ":("),
+    ("😮", ":O"),
+    ("😢", ":'("),
+    ("🙂", ":)"),
+    ("❤️", "<3"),
+    ("💔", "</3"),
+];
+
+impl Capability {
+    /// Detects the capability of the terminal from the environment.
+    ///
+    /// This looks at the following environment variables:
+    ///
+    /// - `LC_ALL`, `LC_CTYPE`, or `LANG`: a locale that is set but is not
+    ///   UTF-8 means [`Capability::Ascii`].
+    /// - `TERM`: `dumb` means [`Capability::Ascii`], and `linux`, the Linux
+    ///   console, means [`Capability::Shortcodes`].
+    ///
+    /// Otherwise the terminal is assumed to support color emojis, except on
+    /// Windows outside of Windows Terminal where this returns
+    /// [`Capability::Shortcodes`].
+    #[cfg(feature = "std")]
+    pub fn detect() -> Self {
+        let var = |name| std::env::var(name).ok().filter(|v| !v.is_empty());
+        let locale = var("LC_ALL")
+            .or_else(|| var("LC_CTYPE"))
+            .or_else(|| var("LANG"));
+        if let Some(locale) = locale {
+            let locale = locale.to_ascii_lowercase();
+            if !locale.contains("utf-8") && !locale.contains("utf8") {
+                return Self::Ascii;
+            }
+        }
+        match var("TERM").as_deref() {
+            Some("dumb") => return Self::Ascii,
+            Some("linux") => return Self::Shortcodes,
+            _ => {}
+        }
+        if cfg!(windows) && var("WT_SESSION").is_none() {
+            return Self::Shortcodes;
+        }
+        Self::Emoji
+    }
+}
+
+/// Returns a value that writes the emoji in a form the terminal can display.
+///
+/// Each capability falls back to the next one when the emoji has no such
+/// form:
+///
+/// - [`Capability::Emoji`] writes the emoji.
+/// - [`Capability::TextPresentation`] writes the emoji followed by the U+FE0E
+///   text presentation selector if it is a single character that is
+///   fully-qualified with U+FE0F, e.g. ❤️, otherwise the shortcode.
+/// - [`Capability::Shortcodes`] writes the GitHub shortcode, e.g.
+///   `:rocket:`, otherwise the ASCII fallback.
+/// - [`Capability::Ascii`] writes an emoticon for a few emojis, e.g. `:)`,
+///   otherwise the shortcode, otherwise the name in square brackets with any
+///   non-ASCII characters replaced, e.g. `[shaking face]`.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::term::{self, Capability};
+///
+/// let rocket = emojis::get("🚀").unwrap();
+/// assert_eq!(term::render(rocket, Capability::TextPresentation).to_string(), ":rocket:");
+/// assert_eq!(term::render(rocket, Capability::Ascii).to_string(), ":rocket:");
+/// ```
+pub fn render(emoji: &Emoji, capability: Capability) -> Rendered<'_> {
+    Rendered { emoji, capability }
+}
+
+/// An emoji rendered for a terminal.
+///
+/// Returned by [`render()`].
+#[derive(Debug, Clone, Copy)]
+pub struct Rendered<'a> {
+    emoji: &'a Emoji,
+    capability: Capability,
+}
+
+/// Returns the character of the emoji if it is a single character followed
+/// by U+FE0F.
+fn text_presentation(emoji: &Emoji) -> Option<char> {
+    let mut chars = emoji.as_str().chars();
+    let c = chars.next()?;
+    (chars.next() == Some('\u{fe0f}') && chars.next().is_none()).then(|| c)
+}
+
+fn write_ascii(f: &mut fmt::Formatter<'_>, emoji: &Emoji) -> fmt::Result {
+    if let Some((_, emoticon)) = EMOTICONS.iter().find(|(e, _)| *e == emoji.as_str()) {
+        return f.write_str(emoticon);
+    }
+    write_shortcode_or_name(f, emoji)
+}
+
+fn write_shortcode_or_name(f: &mut fmt::Formatter<'_>, emoji: &Emoji) -> fmt::Result {
+    if let Some(shortcode) = emoji.shortcode() {
+        return write!(f, ":{shortcode}:");
+    }
+    f.write_char('[')?;
+    for c in emoji.name().chars() {
+        f.write_char(match c {
+            '’' => '\'',
+            c if c.is_ascii() => c,
+            _ => '?',
+        })?;
+    }
+    f.write_char(']')
+}
+
+impl fmt::Display for Rendered<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let emoji = self.emoji;
+        match self.capability {
+            Capability::Emoji => f.write_str(emoji.as_str()),
+            Capability::TextPresentation => match text_presentation(emoji) {
+                Some(c) => {
+                    f.write_char(c)?;
+                    f.write_char('\u{fe0e}')
+                }
+                None if emoji.shortcode().is_some() => write_shortcode_or_name(f, emoji),
+                None => write_ascii(f, emoji),
+            },
+            Capability::Shortcodes if emoji.shortcode().is_some() => {
+                write_shortcode_or_name(f, emoji)
+            }
+            Capability::Shortcodes | Capability::Ascii => write_ascii(f, emoji),
+        }
+    }
+}
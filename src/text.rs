@@ -0,0 +1,672 @@
+//! Functions that operate on emojis in text.
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::ops::Range;
+
+use crate::data::{self, Builtin};
+use crate::{Emoji, SkinTone, MAX_BYTES};
+#[cfg(feature = "alloc")]
+use crate::{EmojiSet, Group};
+
+/// Returns whether an emoji could start with the given byte.
+pub(crate) fn is_emoji_start_byte(b: u8) -> bool {
+    // Keycap sequences start with an ASCII character, otherwise all emojis
+    // start with a non-ASCII character.
+    matches!(b, b'#' | b'*' | b'0'..=b'9') || b >= 0x80
+}
+
+/// Returns whether an emoji or emoji variation could start with the given
+/// character.
+///
+/// This is backed by a small table of character ranges so it is much cheaper
+/// than attempting a match. Scanners can use it to quickly skip over text
+/// that can not contain an emoji.
+///
+/// This takes *Ο(log n)* time in the number of ranges, of which there are
+/// about 150.
+///
+/// # Examples
+///
+/// ```
+/// assert!(emojis::could_start_emoji('🚀'));
+/// assert!(emojis::could_start_emoji('1'));
+/// assert!(!emojis::could_start_emoji('a'));
+/// ```
+pub fn could_start_emoji(c: char) -> bool {
+    crate::codepoint_table::contains(crate::codepoint_table::STARTS, c)
+}
+
+/// Returns whether a builtin emoji could start at the start of the given
+/// string.
+pub(crate) fn could_start(s: &str) -> bool {
+    if !s.bytes().next().map_or(false, is_emoji_start_byte) {
+        return false;
+    }
+    if !s.chars().next().map_or(false, could_start_emoji) {
+        return false;
+    }
+    // Keycaps are the only emojis that start with an ASCII character and they
+    // are always followed by U+FE0F or U+20E3.
+    !s.as_bytes()[0].is_ascii() || matches!(s.as_bytes().get(1), Some(0xef | 0xe2))
+}
+
+/// Returns the longest emoji at the start of the given string.
+pub(crate) fn longest_prefix(s: &str) -> Option<(usize, &'static Emoji)> {
+    let (n, index) = data::longest_prefix(&Builtin, s, MAX_BYTES)?;
+    Some((n, Builtin::emoji(index)?))
+}
+
+/// Returns whether the character is ignored between emojis, i.e. whitespace
+/// and variation selectors.
+pub(crate) fn is_ignored(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '\u{fe00}'..='\u{fe0f}')
+}
+
+/// An iterator over the builtin emojis in a string and their byte ranges.
+#[derive(Debug, Clone)]
+pub(crate) struct Matches<'a>(data::Matches<'a, Builtin>);
+
+impl<'a> Matches<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Self(data::matches(&Builtin, text))
+    }
+}
+
+/// Returns the first position at or after `pos` where an emoji could start.
+///
+/// Every emoji contains a non-ASCII byte in its first two bytes, either at the
+/// start or after the ASCII base of a keycap, so ASCII text is skipped a word
+/// at a time looking for a byte with the high bit set.
+#[cfg(feature = "simd")]
+pub(crate) fn skip_ascii(bytes: &[u8], pos: usize) -> usize {
+    const N: usize = core::mem::size_of::<usize>();
+    const HIGH: usize = usize::from_ne_bytes([0x80; N]);
+    let mut i = pos;
+    while let Some(chunk) = bytes.get(i..i + N) {
+        if usize::from_ne_bytes(chunk.try_into().unwrap()) & HIGH != 0 {
+            break;
+        }
+        i += N;
+    }
+    while i < bytes.len() && bytes[i].is_ascii() {
+        i += 1;
+    }
+    if i > pos && matches!(bytes[i - 1], b'#' | b'*' | b'0'..=b'9') {
+        i -= 1;
+    }
+    i
+}
+
+impl Iterator for Matches<'_> {
+    type Item = (Range<usize>, &'static Emoji);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Every index from the builtin dataset is in range so no match is
+        // skipped.
+        self.0
+            .by_ref()
+            .find_map(|(range, index)| Some((range, Builtin::emoji(index)?)))
+    }
+}
+
+/// Replaces the given byte ranges of the text, which must be in order and not
+/// overlap.
+#[cfg(feature = "alloc")]
+pub(crate) fn replace_ranges<'a, 'b, I>(text: &'a str, replacements: I) -> Cow<'a, str>
+where
+    I: IntoIterator<Item = (Range<usize>, &'b str)>,
+{
+    let mut out = String::new();
+    let mut last = 0;
+    for (range, replacement) in replacements {
+        out.push_str(&text[last..range.start]);
+        out.push_str(replacement);
+        last = range.end;
+    }
+    if last == 0 {
+        return Cow::Borrowed(text);
+    }
+    out.push_str(&text[last..]);
+    Cow::Owned(out)
+}
+
+/// Removes all emojis in the given groups from the text.
+///
+/// This takes *Ο(n)* time in the length of the text.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::Group;
+///
+/// let text = emojis::strip_groups("go 🇿🇦 team 😀", &[Group::Flags]);
+/// assert_eq!(text, "go  team 😀");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn strip_groups<'a>(text: &'a str, groups: &[Group]) -> Cow<'a, str> {
+    data::strip_groups(&Builtin, text, groups)
+}
+
+/// Removes all emojis in the given set from the text.
+///
+/// Emojis with a skin tone are only removed if that exact skin tone is in the
+/// set.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::EmojiSet;
+///
+/// let set: EmojiSet = emojis::get("🚀").into_iter().collect();
+/// let text = emojis::strip_set("launch 🚀!", &set);
+/// assert_eq!(text, "launch !");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn strip_set<'a>(text: &'a str, set: &EmojiSet) -> Cow<'a, str> {
+    data::strip(&Builtin, text, |index| {
+        Builtin::emoji(index).map_or(false, |emoji| set.contains(emoji))
+    })
+}
+
+/// Replaces GitHub shortcodes, e.g. `:rocket:`, with emojis in Markdown text.
+///
+/// Like GitHub, shortcodes in fenced code blocks, inline code, and link URLs
+/// are left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// let text = emojis::replace_shortcodes_markdown(
+///     "launch :rocket: with `:rocket:` [docs](https://a.io/:rocket:)"
+/// );
+/// assert_eq!(text, "launch 🚀 with `:rocket:` [docs](https://a.io/:rocket:)");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn replace_shortcodes_markdown(text: &str) -> Cow<'_, str> {
+    data::replace_shortcodes_markdown(&Builtin, text)
+}
+
+/// The UTF-16 code units in a single part SMS encoded as UCS-2.
+const SMS_SINGLE_UNITS: usize = 70;
+
+/// The UTF-16 code units in each part of a multipart SMS encoded as UCS-2.
+const SMS_MULTI_UNITS: usize = 67;
+
+/// The cost of sending text as an SMS encoded as UCS-2.
+///
+/// Returned by [`sms_units()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SmsUnits {
+    units: usize,
+    segments: usize,
+}
+
+impl SmsUnits {
+    /// Returns the number of UCS-2 code units in the text.
+    ///
+    /// Characters outside the Basic Multilingual Plane, which includes most
+    /// emojis, take two code units.
+    pub const fn units(&self) -> usize {
+        self.units
+    }
+
+    /// Returns the number of SMS segments required to send the text.
+    pub const fn segments(&self) -> usize {
+        self.segments
+    }
+}
+
+/// Returns the cost of sending the given text as an SMS encoded as UCS-2.
+///
+/// Any emoji forces an SMS to be encoded as UCS-2, which fits 70 code units in
+/// a single segment or 67 code units in each segment of a multipart message.
+/// When splitting a message into segments emojis are never split, so a
+/// segment may contain fewer code units than the maximum.
+///
+/// # Examples
+///
+/// ```
+/// let cost = emojis::sms_units("launch 🚀");
+/// assert_eq!(cost.units(), 9);
+/// assert_eq!(cost.segments(), 1);
+///
+/// let cost = emojis::sms_units(&"🚀".repeat(36));
+/// assert_eq!(cost.units(), 72);
+/// assert_eq!(cost.segments(), 2);
+/// ```
+pub fn sms_units(text: &str) -> SmsUnits {
+    let mut units = 0;
+    let mut segments = 1;
+    let mut segment = 0;
+    let mut add = |n| {
+        units += n;
+        if segment + n > SMS_MULTI_UNITS {
+            segments += 1;
+            segment = 0;
+        }
+        segment += n;
+    };
+    let mut last = 0;
+    for (range, _) in Matches::new(text) {
+        text[last..range.start]
+            .chars()
+            .for_each(|c| add(c.len_utf16()));
+        add(text[range.clone()].encode_utf16().count());
+        last = range.end;
+    }
+    text[last..].chars().for_each(|c| add(c.len_utf16()));
+    if units <= SMS_SINGLE_UNITS {
+        segments = 1;
+    }
+    SmsUnits { units, segments }
+}
+
+/// The weights used by [`weighted_len()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeightConfig {
+    emoji: usize,
+    other: usize,
+}
+
+impl WeightConfig {
+    /// Construct a new config with the weight of each emoji and the weight of
+    /// every other character.
+    pub const fn new(emoji: usize, other: usize) -> Self {
+        Self { emoji, other }
+    }
+}
+
+impl Default for WeightConfig {
+    /// Returns the common platform rule where every emoji has a weight of two
+    /// and every other character has a weight of one.
+    fn default() -> Self {
+        Self::new(2, 1)
+    }
+}
+
+/// Returns the weighted length of the given text.
+///
+/// Every emoji counts as a fixed weight regardless of the number of characters
+/// it is made of, which matches the character counters of platforms like
+/// Twitter.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::WeightConfig;
+///
+/// assert_eq!(emojis::weighted_len("hi 👨‍👩‍👧", WeightConfig::default()), 5);
+/// assert_eq!(emojis::weighted_len("hi 👨‍👩‍👧", WeightConfig::new(1, 1)), 4);
+/// ```
+pub fn weighted_len(text: &str, config: WeightConfig) -> usize {
+    let mut len = 0;
+    let mut last = 0;
+    for (range, _) in Matches::new(text) {
+        len += text[last..range.start].chars().count() * config.other;
+        len += config.emoji;
+        last = range.end;
+    }
+    len + text[last..].chars().count() * config.other
+}
+
+/// Returns whether the character extends the previous grapheme cluster.
+///
+/// This covers the common combining marks, variation selectors, joiners, skin
+/// tone modifiers, and tag characters.
+fn is_extend(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36f}'
+            | '\u{1ab0}'..='\u{1aff}'
+            | '\u{1dc0}'..='\u{1dff}'
+            | '\u{200c}'..='\u{200d}'
+            | '\u{20d0}'..='\u{20ff}'
+            | '\u{fe00}'..='\u{fe0f}'
+            | '\u{fe20}'..='\u{fe2f}'
+            | '\u{1f3fb}'..='\u{1f3ff}'
+            | '\u{e0020}'..='\u{e007f}'
+    )
+}
+
+/// Counts the grapheme clusters in text that contains no emojis.
+fn count_graphemes(s: &str, prev: &mut Option<char>) -> usize {
+    let mut count = 0;
+    for c in s.chars() {
+        let extends = match *prev {
+            Some('\r') => c == '\n',
+            Some(_) => is_extend(c),
+            None => false,
+        };
+        if !extends {
+            count += 1;
+        }
+        *prev = Some(c);
+    }
+    count
+}
+
+/// Returns the number of user-perceived characters in the given text.
+///
+/// Each emoji, including sequences made of many characters, counts as one.
+/// Other text is counted using a simplified version of the Unicode grapheme
+/// cluster rules which handles common combining marks, so this is only exact
+/// for emoji-heavy and Latin text. For full support use a crate like
+/// [unicode-segmentation](https://docs.rs/unicode-segmentation).
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::grapheme_count("hi 👨‍👩‍👧 🇿🇦"), 6);
+/// assert_eq!(emojis::grapheme_count("cafe\u{301}"), 4);
+/// ```
+pub fn grapheme_count(text: &str) -> usize {
+    let mut count = 0;
+    let mut prev = None;
+    let mut last = 0;
+    for (range, _) in Matches::new(text) {
+        count += count_graphemes(&text[last..range.start], &mut prev) + 1;
+        prev = text[range.clone()].chars().next_back();
+        last = range.end;
+    }
+    count + count_graphemes(&text[last..], &mut prev)
+}
+
+/// Returns the length of the ANSI escape sequence at the start of the string.
+///
+/// This covers CSI sequences, e.g. `"\x1b[1m"`, OSC sequences terminated by
+/// BEL or ST, e.g. hyperlinks, and two character escapes. An unterminated
+/// sequence extends to the end of the string.
+fn escape_len(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    if bytes.first() != Some(&0x1b) {
+        return None;
+    }
+    let len = match bytes.get(1) {
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map(|i| i + 3),
+        Some(b']') => bytes[2..].iter().enumerate().find_map(|(i, &b)| match b {
+            0x07 => Some(i + 3),
+            0x1b if bytes.get(i + 3) == Some(&b'\\') => Some(i + 4),
+            _ => None,
+        }),
+        Some(_) => Some(1 + s[1..].chars().next().map_or(0, char::len_utf8)),
+        None => Some(1),
+    };
+    Some(len.unwrap_or(s.len()))
+}
+
+/// Returns the number of terminal cells taken by a character that is not part
+/// of an emoji.
+fn char_width(c: char) -> usize {
+    match c {
+        _ if c.is_control() || is_extend(c) => 0,
+        '\u{1100}'..='\u{115f}'
+        | '\u{2e80}'..='\u{303e}'
+        | '\u{3041}'..='\u{a4cf}'
+        | '\u{ac00}'..='\u{d7a3}'
+        | '\u{f900}'..='\u{faff}'
+        | '\u{fe30}'..='\u{fe4f}'
+        | '\u{ff00}'..='\u{ff60}'
+        | '\u{ffe0}'..='\u{ffe6}'
+        | '\u{20000}'..='\u{3fffd}' => 2,
+        _ => 1,
+    }
+}
+
+/// Truncates the text to fit in the given number of terminal cells.
+///
+/// Returns the longest prefix of the text that fits and whether anything was
+/// cut off. The text is never cut inside an emoji sequence, a character and
+/// its combining marks, or an ANSI escape sequence, so the result never ends
+/// in half an emoji like a lone 🇿 or 👨 from 👨‍👩‍👧.
+///
+/// Each emoji takes two cells and escape sequences take none. Other text is
+/// measured using a simplified version of the Unicode East Asian Width
+/// property that handles combining marks and wide CJK characters. For full
+/// support use a crate like [unicode-width](https://docs.rs/unicode-width).
+///
+/// Escape sequences after the cut are dropped, so text that ends with a
+/// reset like `"\x1b[0m"` should have it added back after truncating.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(emojis::truncate("hi 👨‍👩‍👧 there", 6), ("hi 👨‍👩‍👧 ", true));
+/// assert_eq!(emojis::truncate("hi 👨‍👩‍👧 there", 4), ("hi ", true));
+/// assert_eq!(emojis::truncate("\x1b[1mok\x1b[0m 🚀", 5), ("\x1b[1mok\x1b[0m 🚀", false));
+/// ```
+pub fn truncate(text: &str, max_cells: usize) -> (&str, bool) {
+    let mut cells = 0;
+    let mut pos = 0;
+    while let Some((len, width)) = next_unit(&text[pos..]) {
+        if cells + width > max_cells {
+            return (&text[..pos], true);
+        }
+        cells += width;
+        pos += len;
+    }
+    (text, false)
+}
+
+/// Returns the length in bytes and the width in terminal cells of the ANSI
+/// escape sequence, emoji, or character at the start of the string.
+fn next_unit(s: &str) -> Option<(usize, usize)> {
+    let c = s.chars().next()?;
+    Some(match (escape_len(s), longest_prefix(s)) {
+        (Some(len), _) => (len, 0),
+        (None, Some((len, _))) => (len, 2),
+        (None, None) => (c.len_utf8(), char_width(c)),
+    })
+}
+
+/// Returns an iterator over the byte offsets at which to break the text into
+/// lines that fit in the given number of terminal cells.
+///
+/// Each offset is the start of a new line, so `n` offsets split the text into
+/// `n + 1` lines. Lines are filled greedily and are only broken between
+/// emojis and characters, never inside an emoji sequence, a character and its
+/// combining marks, or an ANSI escape sequence. Widths are measured like
+/// [`truncate()`]. A unit that is wider than the line, e.g. an emoji when the
+/// width is one, is put on a line of its own.
+///
+/// This does not look for word boundaries, it is intended as a building block
+/// for word wrapping or splitting text into fixed-width chunks.
+///
+/// # Examples
+///
+/// ```
+/// let text = "ab👨‍👩‍👧cd🇿🇦";
+/// let breaks: Vec<_> = emojis::wrap_indices(text, 3).collect();
+/// assert_eq!(breaks, [2, 21]);
+/// assert_eq!(&text[2..21], "👨‍👩‍👧c");
+/// ```
+pub fn wrap_indices(text: &str, width: usize) -> WrapIndices<'_> {
+    WrapIndices {
+        text,
+        width,
+        pos: 0,
+    }
+}
+
+/// An iterator over the line break offsets in a string.
+///
+/// Returned by [`wrap_indices()`].
+#[derive(Debug, Clone)]
+pub struct WrapIndices<'a> {
+    text: &'a str,
+    width: usize,
+    pos: usize,
+}
+
+impl Iterator for WrapIndices<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut cells = 0;
+        while let Some((len, width)) = next_unit(&self.text[self.pos..]) {
+            if cells > 0 && cells + width > self.width {
+                return Some(self.pos);
+            }
+            cells += width;
+            self.pos += len;
+        }
+        None
+    }
+}
+
+/// Returns whether the text only contains emojis, ignoring whitespace and
+/// variation selectors.
+///
+/// This is useful for the "jumbo emoji" rule that many messaging apps use to
+/// render short messages that only contain emojis in a larger font.
+///
+/// # Examples
+///
+/// ```
+/// assert!(emojis::is_emoji_only("🚀"));
+/// assert!(emojis::is_emoji_only(" 👍🏽 🎉\n"));
+/// assert!(!emojis::is_emoji_only("launch 🚀"));
+/// assert!(!emojis::is_emoji_only(" "));
+/// ```
+pub fn is_emoji_only(text: &str) -> bool {
+    let mut any = false;
+    let mut last = 0;
+    for (range, _) in Matches::new(text) {
+        if !text[last..range.start].chars().all(is_ignored) {
+            return false;
+        }
+        any = true;
+        last = range.end;
+    }
+    any && text[last..].chars().all(is_ignored)
+}
+
+/// Returns the first emoji in the text and its byte range.
+///
+/// # Examples
+///
+/// ```
+/// let (range, emoji) = emojis::first("Release 🚀 notes 📝").unwrap();
+/// assert_eq!(range, 8..12);
+/// assert_eq!(emoji, "🚀");
+/// ```
+pub fn first(text: &str) -> Option<(Range<usize>, &'static Emoji)> {
+    Matches::new(text).next()
+}
+
+/// Returns the last emoji in the text and its byte range.
+///
+/// Emojis are matched from the start of the text, so this takes *Ο(n)* time
+/// but does not allocate.
+///
+/// # Examples
+///
+/// ```
+/// let (range, emoji) = emojis::last("Release 🚀 notes 📝").unwrap();
+/// assert_eq!(range, 19..23);
+/// assert_eq!(emoji, "📝");
+/// ```
+pub fn last(text: &str) -> Option<(Range<usize>, &'static Emoji)> {
+    Matches::new(text).last()
+}
+
+/// Returns an iterator over the emojis in the text and the number of times
+/// each occurs.
+///
+/// The text is scanned once and the counts are stored in a table of `u32`
+/// counters on the stack, about 13 KB, without allocating. Counts saturate at
+/// `u32::MAX`. Emojis are yielded in Unicode CLDR order and skin tone
+/// variations are counted separately.
+///
+/// # Examples
+///
+/// ```
+/// let counts: Vec<_> = emojis::histogram("🎉 ship it 🚀🎉")
+///     .map(|(emoji, n)| (emoji.as_str(), n))
+///     .collect();
+/// assert_eq!(counts, [("🚀", 1), ("🎉", 2)]);
+/// ```
+pub fn histogram(text: &str) -> impl Iterator<Item = (&'static Emoji, usize)> {
+    let mut counts = [0u32; crate::gen::EMOJIS.len()];
+    for (_, emoji) in Matches::new(text) {
+        let n = &mut counts[emoji.index()];
+        *n = n.saturating_add(1);
+    }
+    crate::gen::EMOJIS
+        .iter()
+        .zip(counts)
+        .filter(|&(_, n)| n > 0)
+        .map(|(emoji, n)| (emoji, n as usize))
+}
+
+/// Returns the skin tone of the first skin tone modifier in the text.
+///
+/// Unlike [`Emoji::skin_tone()`] this does not require the modifier to be
+/// part of a known emoji, so it also works for sequences that are not in the
+/// database or for a lone modifier. This is useful for guessing a user's
+/// preferred skin tone from the messages they have sent.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::SkinTone;
+///
+/// assert_eq!(emojis::detect_skin_tone("nice 👍🏾"), Some(SkinTone::MediumDark));
+/// assert_eq!(emojis::detect_skin_tone("🦄\u{1f3fb}"), Some(SkinTone::Light));
+/// assert_eq!(emojis::detect_skin_tone("nice 👍"), None);
+/// ```
+pub fn detect_skin_tone(text: &str) -> Option<SkinTone> {
+    text.chars().find_map(SkinTone::from_modifier)
+}
+
+/// Returns a pattern that matches every emoji in a string.
+///
+/// The `Pattern` trait used by methods like [`str::match_indices()`] can not
+/// be implemented outside the standard library, so the pattern provides
+/// equivalent methods instead.
+///
+/// # Examples
+///
+/// ```
+/// let text = "launch 🚀 now 🎉";
+/// let std: Vec<_> = text.match_indices('🚀').collect();
+/// let emojis: Vec<_> = emojis::pattern().find_in(text).take(1).collect();
+/// assert_eq!(std, emojis);
+/// ```
+pub fn pattern() -> EmojiPattern {
+    EmojiPattern { _priv: () }
+}
+
+/// A pattern that matches emojis in a string.
+///
+/// Returned by [`pattern()`].
+#[derive(Debug, Clone, Copy)]
+pub struct EmojiPattern {
+    _priv: (),
+}
+
+impl EmojiPattern {
+    /// Returns an iterator over the emojis in the haystack and the byte index
+    /// at which each one starts.
+    ///
+    /// Like [`str::match_indices()`] this yields non-overlapping matches from
+    /// left to right. Where emojis overlap the longest one is matched, e.g.
+    /// 👍🏽 instead of 👍.
+    ///
+    /// This takes *Ο(n)* time in the length of the haystack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let found: Vec<_> = emojis::pattern().find_in("a👍🏽b🇿🇦").collect();
+    /// assert_eq!(found, [(1, "👍🏽"), (10, "🇿🇦")]);
+    /// ```
+    pub fn find_in(self, haystack: &str) -> impl Iterator<Item = (usize, &str)> {
+        Matches::new(haystack).map(move |(range, _)| (range.start, &haystack[range]))
+    }
+}
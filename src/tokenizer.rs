@@ -0,0 +1,407 @@
+//! Tokenizing shortcodes in text.
+
+use core::fmt;
+use core::ops::Range;
+
+use crate::{Emoji, GitHubShortcodes, ShortcodeTable, SkinTone, MAX_SHORTCODE_LEN};
+
+/// Returns whether the byte can be part of a GitHub shortcode.
+pub(crate) fn is_shortcode_byte(b: u8) -> bool {
+    matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'+' | b'-')
+}
+
+/// The syntax of a [`ShortcodeToken`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// A shortcode surrounded by colons, e.g. `:rocket:`.
+    Shortcode,
+    /// A Discord custom emoji, e.g. `<:name:123>`, or an animated one, e.g.
+    /// `<a:name:123>`.
+    DiscordCustom { id: u64, animated: bool },
+}
+
+/// A candidate shortcode in text, e.g. `:rocket:`.
+///
+/// Yielded by [`ShortcodeTokenizer`], and by
+/// [`data::ShortcodeTokenizer`][crate::data::ShortcodeTokenizer] which
+/// resolves shortcodes to the index of an emoji in a dataset instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortcodeToken<'a, E = &'static Emoji> {
+    range: Range<usize>,
+    shortcode: &'a str,
+    emoji: Option<E>,
+    kind: TokenKind,
+}
+
+impl<'a, E: Copy> ShortcodeToken<'a, E> {
+    /// Returns the byte range of this token in the text, including the colons
+    /// or angle brackets.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the shortcode, excluding the colons.
+    pub fn shortcode(&self) -> &'a str {
+        self.shortcode
+    }
+
+    /// Returns the emoji for this shortcode or `None` if the shortcode is not
+    /// known.
+    ///
+    /// Discord custom emojis are never resolved.
+    pub fn emoji(&self) -> Option<E> {
+        self.emoji
+    }
+
+    /// Returns the syntax of this token.
+    pub fn kind(&self) -> TokenKind {
+        self.kind
+    }
+}
+
+/// An iterator over the candidate shortcodes in text.
+///
+/// Every span that looks like `:shortcode:` is yielded along with the
+/// resolved emoji, if any. When a candidate does not resolve, its closing
+/// colon can be the start of the next candidate.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::ShortcodeTokenizer;
+///
+/// let text = "launch :rocket: at 10:30:00 `:tada:`";
+/// let tokens: Vec<_> = ShortcodeTokenizer::new(text)
+///     .skip_code_spans(true)
+///     .map(|t| (t.shortcode(), t.emoji().map(|e| e.as_str())))
+///     .collect();
+/// assert_eq!(tokens, [("rocket", Some("🚀")), ("30", None)]);
+/// ```
+#[derive(Clone)]
+pub struct ShortcodeTokenizer<'a> {
+    cursor: Cursor<'a>,
+    table: &'a dyn ShortcodeTable,
+}
+
+impl<'a> ShortcodeTokenizer<'a> {
+    /// Construct a new tokenizer over the given text.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            cursor: Cursor::new(text, MAX_SHORTCODE_LEN),
+            table: &GitHubShortcodes,
+        }
+    }
+
+    /// Sets the table used to resolve shortcodes.
+    ///
+    /// Defaults to [`GitHubShortcodes`]. See [`ShortcodeTable`] for an
+    /// example.
+    pub fn table(mut self, table: &'a dyn ShortcodeTable) -> Self {
+        self.table = table;
+        self
+    }
+
+    /// Sets whether a shortcode must not be directly preceded or followed by
+    /// an alphanumeric character, e.g. `a:rocket:` is not a shortcode.
+    ///
+    /// Defaults to `false`.
+    pub fn require_word_boundaries(mut self, yes: bool) -> Self {
+        self.cursor.word_boundaries = yes;
+        self
+    }
+
+    /// Sets whether to skip Markdown code spans delimited by backticks,
+    /// including fenced code blocks delimited by backticks or tildes.
+    ///
+    /// Defaults to `false`.
+    pub fn skip_code_spans(mut self, yes: bool) -> Self {
+        self.cursor.code_spans = yes;
+        self
+    }
+
+    /// Sets whether to skip Markdown link destinations, e.g. `(url)` in
+    /// `[text](url)`, and autolinks, e.g. `<https://example.com>`.
+    ///
+    /// Defaults to `false`.
+    pub fn skip_link_urls(mut self, yes: bool) -> Self {
+        self.cursor.link_urls = yes;
+        self
+    }
+
+    /// Sets whether to recognize Discord custom emojis, e.g. `<:name:123>`,
+    /// which are yielded as [`TokenKind::DiscordCustom`] tokens, and Discord
+    /// skin tone shortcodes, e.g. `:thumbsup_tone2:`.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use emojis::{ShortcodeTokenizer, TokenKind};
+    ///
+    /// let mut tokens = ShortcodeTokenizer::new("<a:party:42> :tada:").discord_custom_emojis(true);
+    /// let token = tokens.next().unwrap();
+    /// assert_eq!(token.shortcode(), "party");
+    /// assert_eq!(token.kind(), TokenKind::DiscordCustom { id: 42, animated: true });
+    /// assert_eq!(tokens.next().unwrap().kind(), TokenKind::Shortcode);
+    /// ```
+    pub fn discord_custom_emojis(mut self, yes: bool) -> Self {
+        self.cursor.discord = yes;
+        self
+    }
+}
+
+impl fmt::Debug for ShortcodeTokenizer<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShortcodeTokenizer")
+            .field("cursor", &self.cursor)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> Iterator for ShortcodeTokenizer<'a> {
+    type Item = ShortcodeToken<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let table = self.table;
+        self.cursor.next_token(
+            |shortcode| table.get(shortcode),
+            |emoji, tone| emoji.with_skin_tone(tone),
+        )
+    }
+}
+
+/// The text, position, and options of a shortcode tokenizer.
+///
+/// This implements tokenizing for both [`ShortcodeTokenizer`] and
+/// [`data::ShortcodeTokenizer`][crate::data::ShortcodeTokenizer], which only
+/// differ in how shortcodes are resolved.
+#[derive(Debug, Clone)]
+pub(crate) struct Cursor<'a> {
+    pub(crate) text: &'a str,
+    pos: usize,
+    max_len: usize,
+    pub(crate) word_boundaries: bool,
+    pub(crate) code_spans: bool,
+    pub(crate) link_urls: bool,
+    pub(crate) discord: bool,
+}
+
+impl<'a> Cursor<'a> {
+    /// Construct a new cursor over the given text which finds shortcodes up
+    /// to the given length.
+    pub(crate) fn new(text: &'a str, max_len: usize) -> Self {
+        Self {
+            text,
+            pos: 0,
+            max_len,
+            word_boundaries: false,
+            code_spans: false,
+            link_urls: false,
+            discord: false,
+        }
+    }
+
+    /// Returns whether the candidate at the given range is on word
+    /// boundaries.
+    fn is_bounded(&self, range: Range<usize>) -> bool {
+        let before = self.text[..range.start].chars().next_back();
+        let after = self.text[range.end..].chars().next();
+        !before.map_or(false, char::is_alphanumeric) && !after.map_or(false, char::is_alphanumeric)
+    }
+
+    /// Returns the next token, resolving shortcodes with `get` and Discord
+    /// skin tone shortcodes with `with_skin_tone`.
+    pub(crate) fn next_token<E, G, T>(
+        &mut self,
+        get: G,
+        with_skin_tone: T,
+    ) -> Option<ShortcodeToken<'a, E>>
+    where
+        E: Copy,
+        G: Fn(&str) -> Option<E>,
+        T: Fn(E, SkinTone) -> Option<E>,
+    {
+        let bytes = self.text.as_bytes();
+        while self.pos < bytes.len() {
+            let start = self.pos;
+            if self.discord && bytes[start] == b'<' {
+                if let Some(token) = parse_discord(self.text, start) {
+                    self.pos = token.range.end;
+                    return Some(token);
+                }
+            }
+            match bytes[start] {
+                b'`' | b'~' if self.code_spans => {
+                    self.pos = skip_code_span(bytes, start);
+                    continue;
+                }
+                b']' | b'<' if self.link_urls => {
+                    self.pos = skip_link_url(bytes, start).unwrap_or(start + 1);
+                    continue;
+                }
+                b':' => {}
+                _ => {
+                    self.pos += 1;
+                    continue;
+                }
+            }
+            let max_len = if self.discord {
+                self.max_len.saturating_add("_tone1".len())
+            } else {
+                self.max_len
+            };
+            let len = bytes[start + 1..]
+                .iter()
+                .take(max_len.saturating_add(1))
+                .take_while(|&&b| is_shortcode_byte(b))
+                .count();
+            let end = start + 1 + len;
+            if len == 0 || len > max_len || bytes.get(end) != Some(&b':') {
+                self.pos = end;
+                continue;
+            }
+            let range = start..end + 1;
+            if self.word_boundaries && !self.is_bounded(range.clone()) {
+                self.pos = end;
+                continue;
+            }
+            let mut range = range;
+            let mut shortcode = &self.text[start + 1..end];
+            let mut emoji = get(shortcode);
+            if emoji.is_none() && self.discord {
+                emoji = discord_toned(shortcode, &get, &with_skin_tone);
+            }
+            if emoji.is_some() {
+                if let Some(n) = slack_skin_tone_len(&bytes[end..]) {
+                    let toned = &self.text[start + 1..end + n - 1];
+                    if let Some(e) = get(toned) {
+                        range = start..end + n;
+                        shortcode = toned;
+                        emoji = Some(e);
+                    }
+                }
+            }
+            self.pos = if emoji.is_some() { range.end } else { end };
+            return Some(ShortcodeToken {
+                range,
+                shortcode,
+                emoji,
+                kind: TokenKind::Shortcode,
+            });
+        }
+        None
+    }
+}
+
+/// Returns the position after the code span starting at the given position.
+///
+/// A code span is closed by a run of the same delimiter and length, if there
+/// is no such run then only the opening run is skipped.
+fn skip_code_span(bytes: &[u8], start: usize) -> usize {
+    let delim = bytes[start];
+    let run = |i: usize| bytes[i..].iter().take_while(|&&b| b == delim).count();
+    let n = run(start);
+    if delim == b'~' && n < 3 {
+        return start + n;
+    }
+    let mut i = start + n;
+    while i < bytes.len() {
+        if bytes[i] == delim {
+            let m = run(i);
+            if m == n {
+                return i + m;
+            }
+            i += m;
+        } else {
+            i += 1;
+        }
+    }
+    start + n
+}
+
+/// Returns the position after the link destination or autolink starting at
+/// the given position, if there is one.
+fn skip_link_url(bytes: &[u8], start: usize) -> Option<usize> {
+    let (open, close) = match &bytes[start..] {
+        [b']', b'(', ..] => (2, b')'),
+        [b'<', ..] => (1, b'>'),
+        _ => return None,
+    };
+    let url_start = start + open;
+    let len = bytes[url_start..].iter().position(|&b| b == close)?;
+    let url = &bytes[url_start..url_start + len];
+    if close == b'>' && (url.iter().any(u8::is_ascii_whitespace) || !contains(url, b"://")) {
+        return None;
+    }
+    Some(url_start + len + 1)
+}
+
+/// Parses the Discord custom emoji starting at the given position, if there
+/// is one.
+fn parse_discord<E>(text: &str, start: usize) -> Option<ShortcodeToken<'_, E>> {
+    let bytes = text.as_bytes();
+    let (animated, name_start) = match &bytes[start..] {
+        [b'<', b':', ..] => (false, start + 2),
+        [b'<', b'a', b':', ..] => (true, start + 3),
+        _ => return None,
+    };
+    let name_len = bytes[name_start..]
+        .iter()
+        .take_while(|b| b.is_ascii_alphanumeric() || **b == b'_')
+        .count();
+    let name_end = name_start + name_len;
+    if !(2..=32).contains(&name_len) || bytes.get(name_end) != Some(&b':') {
+        return None;
+    }
+    let id_start = name_end + 1;
+    let id_len = bytes[id_start..]
+        .iter()
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    let id_end = id_start + id_len;
+    if bytes.get(id_end) != Some(&b'>') {
+        return None;
+    }
+    let id = text[id_start..id_end].parse().ok()?;
+    Some(ShortcodeToken {
+        range: start..id_end + 1,
+        shortcode: &text[name_start..name_end],
+        emoji: None,
+        kind: TokenKind::DiscordCustom { id, animated },
+    })
+}
+
+/// Returns the length of the Slack skin tone suffix at the start of the given
+/// bytes, e.g. `::skin-tone-3:`, if there is one.
+///
+/// The bytes start at the closing colon of the preceding shortcode.
+fn slack_skin_tone_len(bytes: &[u8]) -> Option<usize> {
+    const PREFIX: &[u8] = b"::skin-tone-";
+    match bytes.strip_prefix(PREFIX)? {
+        [b'0'..=b'9', b':', ..] => Some(PREFIX.len() + 2),
+        _ => None,
+    }
+}
+
+/// Resolves a Discord skin tone shortcode, e.g. `thumbsup_tone2`.
+fn discord_toned<E, G, T>(shortcode: &str, get: G, with_skin_tone: T) -> Option<E>
+where
+    G: Fn(&str) -> Option<E>,
+    T: Fn(E, SkinTone) -> Option<E>,
+{
+    let (base, n) = shortcode.rsplit_once("_tone")?;
+    let tone = match n {
+        "1" => SkinTone::Light,
+        "2" => SkinTone::MediumLight,
+        "3" => SkinTone::Medium,
+        "4" => SkinTone::MediumDark,
+        "5" => SkinTone::Dark,
+        _ => return None,
+    };
+    with_skin_tone(get(base)?, tone)
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
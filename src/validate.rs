@@ -0,0 +1,102 @@
+//! Validation of user input that should be a single emoji.
+
+use core::fmt;
+
+use crate::text::{is_ignored, Matches};
+use crate::{Emoji, Group};
+
+/// An error returned by [`validate_reaction()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ValidationError {
+    /// The input is empty or only contains whitespace.
+    Empty,
+    /// The input contains text that is not an emoji.
+    NotEmoji,
+    /// The input contains more than one emoji.
+    MultipleEmojis,
+    /// The emoji is not in one of the allowed groups.
+    GroupNotAllowed(Group),
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("input is empty"),
+            Self::NotEmoji => f.write_str("input is not an emoji"),
+            Self::MultipleEmojis => f.write_str("input contains more than one emoji"),
+            Self::GroupNotAllowed(group) => {
+                write!(f, "emojis in group {:?} are not allowed", group)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// Validates that the input is a single emoji, e.g. a reaction.
+///
+/// Surrounding whitespace is ignored and unqualified or over-qualified emojis
+/// are normalized to the fully-qualified emoji.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::ValidationError;
+///
+/// assert_eq!(emojis::validate_reaction(" ❤ \n").unwrap(), "❤️");
+/// assert_eq!(emojis::validate_reaction(""), Err(ValidationError::Empty));
+/// assert_eq!(emojis::validate_reaction("🚀!"), Err(ValidationError::NotEmoji));
+/// assert_eq!(emojis::validate_reaction("🚀🚀"), Err(ValidationError::MultipleEmojis));
+/// ```
+pub fn validate_reaction(input: &str) -> Result<&'static Emoji, ValidationError> {
+    let s = input.trim();
+    if s.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+    if let Some(emoji) = crate::get_normalized(s) {
+        return Ok(emoji);
+    }
+    let mut count = 0;
+    let mut other = false;
+    let mut last = 0;
+    for (range, _) in Matches::new(s) {
+        other |= !s[last..range.start].chars().all(is_ignored);
+        count += 1;
+        last = range.end;
+    }
+    other |= !s[last..].chars().all(is_ignored);
+    if count > 1 && !other {
+        Err(ValidationError::MultipleEmojis)
+    } else {
+        Err(ValidationError::NotEmoji)
+    }
+}
+
+/// Validates that the input is a single emoji in one of the given groups.
+///
+/// See [`validate_reaction()`] for more information.
+///
+/// # Examples
+///
+/// ```
+/// use emojis::{Group, ValidationError};
+///
+/// let allowed = [Group::SmileysAndEmotion, Group::PeopleAndBody];
+/// assert_eq!(emojis::validate_reaction_in("👍", &allowed).unwrap(), "👍");
+/// assert_eq!(
+///     emojis::validate_reaction_in("🇿🇦", &allowed),
+///     Err(ValidationError::GroupNotAllowed(Group::Flags))
+/// );
+/// ```
+pub fn validate_reaction_in(
+    input: &str,
+    groups: &[Group],
+) -> Result<&'static Emoji, ValidationError> {
+    let emoji = validate_reaction(input)?;
+    if groups.contains(&emoji.group()) {
+        Ok(emoji)
+    } else {
+        Err(ValidationError::GroupNotAllowed(emoji.group()))
+    }
+}
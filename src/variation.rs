@@ -0,0 +1,58 @@
+//! Textual variations of emojis that differ only in qualification.
+
+use crate::{Emoji, MAX_BYTES};
+
+const VS16: char = '\u{fe0f}';
+
+impl Emoji {
+    /// Returns an iterator over every spelling of this emoji listed by
+    /// Unicode.
+    ///
+    /// The fully-qualified form is yielded first, followed by the
+    /// minimally-qualified and unqualified forms which omit some or all of the
+    /// U+FE0F variation selectors. All of these are recognized by
+    /// [`get()`][crate::get]. This is useful for registering every spelling of
+    /// an emoji with an external system, e.g. as search synonyms.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let smiling = emojis::get("☺️").unwrap();
+    /// let variations: Vec<_> = smiling.variations().collect();
+    /// assert_eq!(variations, ["☺\u{fe0f}", "☺"]);
+    ///
+    /// let rocket = emojis::get("🚀").unwrap();
+    /// assert_eq!(rocket.variations().collect::<Vec<_>>(), ["🚀"]);
+    /// ```
+    pub fn variations(&self) -> impl Iterator<Item = &'static str> {
+        let emoji = self.emoji;
+        let index = self.index();
+        let n = emoji.matches(VS16).count() as u32;
+        // Each bit of the mask says whether to keep the corresponding variation
+        // selector, masks that keep more of them are tried first.
+        (0..=n)
+            .rev()
+            .flat_map(move |kept| {
+                (0..1u32 << n)
+                    .rev()
+                    .filter(move |mask| mask.count_ones() == kept)
+            })
+            .filter_map(move |mask| {
+                let mut buf = [0; MAX_BYTES];
+                let mut len = 0;
+                let mut selectors = 0;
+                for c in emoji.chars() {
+                    if c == VS16 {
+                        selectors += 1;
+                        if mask & (1 << (selectors - 1)) == 0 {
+                            continue;
+                        }
+                    }
+                    len += c.encode_utf8(&mut buf[len..]).len();
+                }
+                let s = core::str::from_utf8(&buf[..len]).ok()?;
+                let (&key, &i) = crate::gen::unicode::MAP.get_entry(s)?;
+                (i == index).then(|| key)
+            })
+    }
+}
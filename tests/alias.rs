@@ -0,0 +1,17 @@
+#![cfg(feature = "aliases")]
+
+#[test]
+fn aliases_animals_and_nature() {
+    for emoji in emojis::Group::AnimalsAndNature.emojis() {
+        assert!(emoji.aliases().next().is_some(), "{}", emoji.name());
+        for alias in emoji.aliases() {
+            assert_ne!(alias, emoji.name());
+            assert_eq!(alias, alias.to_lowercase());
+        }
+    }
+    for group in emojis::Group::iter().filter(|g| *g != emojis::Group::AnimalsAndNature) {
+        for emoji in group.emojis() {
+            assert_eq!(emoji.aliases().next(), None);
+        }
+    }
+}
@@ -0,0 +1,347 @@
+use emojis::data::{self, Builtin, EmojiData};
+use emojis::{Group, UnicodeVersion};
+
+/// A small dataset with an emoji that is not in the builtin data.
+struct Custom;
+
+const CUSTOM: &[(&str, &str, &str)] = &[
+    ("🚀", "rocket", "rocket"),
+    ("🫎", "moose", "moose"),
+    ("🫎🚀", "space moose", "space_moose"),
+];
+
+impl EmojiData for Custom {
+    fn len(&self) -> usize {
+        CUSTOM.len()
+    }
+
+    fn as_str(&self, index: usize) -> Option<&str> {
+        CUSTOM.get(index).map(|e| e.0)
+    }
+
+    fn name(&self, index: usize) -> Option<&str> {
+        CUSTOM.get(index).map(|e| e.1)
+    }
+
+    fn unicode_version(&self, index: usize) -> Option<UnicodeVersion> {
+        CUSTOM.get(index).map(|_| UnicodeVersion::new(15, 0))
+    }
+
+    fn group(&self, index: usize) -> Option<Group> {
+        CUSTOM.get(index).map(|_| Group::AnimalsAndNature)
+    }
+
+    fn shortcode(&self, index: usize) -> Option<&str> {
+        CUSTOM.get(index).map(|e| e.2)
+    }
+
+    fn get(&self, emoji: &str) -> Option<usize> {
+        CUSTOM.iter().position(|e| e.0 == emoji)
+    }
+}
+
+#[test]
+fn data_custom() {
+    assert!(!Custom.is_empty());
+    assert_eq!(Custom.max_len(), 8);
+    assert_eq!(Custom.get_by_shortcode("moose"), Some(1));
+    assert_eq!(Custom.get_by_shortcode("nope"), None);
+
+    let found: Vec<_> = data::matches(&Custom, "a 🫎🚀 and a 🫎 🎉").collect();
+    assert_eq!(found, [(2..10, 2), (17..21, 1)]);
+
+    let found: Vec<_> = data::find_by_name(&Custom, "MOOSE").collect();
+    assert_eq!(found, [1, 2]);
+
+    // The trait is object safe.
+    let dynamic: &dyn EmojiData = &Custom;
+    assert_eq!(data::matches(dynamic, "🫎").count(), 1);
+}
+
+/// A dataset that returns the given offset from `next_candidate()`.
+struct BadCandidate(usize);
+
+impl EmojiData for BadCandidate {
+    fn len(&self) -> usize {
+        Custom.len()
+    }
+
+    fn as_str(&self, index: usize) -> Option<&str> {
+        Custom.as_str(index)
+    }
+
+    fn name(&self, index: usize) -> Option<&str> {
+        Custom.name(index)
+    }
+
+    fn unicode_version(&self, index: usize) -> Option<UnicodeVersion> {
+        Custom.unicode_version(index)
+    }
+
+    fn group(&self, index: usize) -> Option<Group> {
+        Custom.group(index)
+    }
+
+    fn get(&self, emoji: &str) -> Option<usize> {
+        Custom.get(emoji)
+    }
+
+    fn next_candidate(&self, _: &str, _: usize) -> usize {
+        self.0
+    }
+}
+
+#[test]
+fn data_bad_next_candidate() {
+    // Out of range, inside a character, and moving backwards.
+    for pos in [100, 3, 0] {
+        let found: Vec<_> = data::matches(&BadCandidate(pos), "a 🫎 b 🚀").collect();
+        assert!(found.len() <= 1, "{}", pos);
+    }
+}
+
+#[test]
+fn data_builtin() {
+    assert_eq!(Builtin.len(), emojis::dump().count());
+    assert_eq!(Builtin.as_str(Builtin.len()), None);
+    assert_eq!(Builtin::emoji(Builtin.len()), None);
+    for (index, record) in emojis::dump().enumerate() {
+        let emoji = Builtin::emoji(index).unwrap();
+        assert_eq!(Builtin.get(emoji.as_str()), Some(index));
+        assert_eq!(Builtin.as_str(index), Some(record.emoji));
+        assert_eq!(Builtin.name(index), Some(record.name));
+        assert_eq!(Builtin.group(index), Some(record.group));
+        assert_eq!(Builtin.subgroup(index), Some(record.subgroup));
+        assert_eq!(Builtin.skin_tone(index), record.skin_tone);
+        assert_eq!(Builtin.shortcode(index), record.shortcodes.first().copied());
+        assert!(emoji.as_str().len() <= Builtin.max_len());
+    }
+
+    let text = "launch :rocket: 🚀 at 10:30:00 ❤ :+1::nope:";
+    let expected: Vec<_> = emojis::EmojiStrExt::emojis(text).collect();
+    let found: Vec<_> = data::matches(&Builtin, text)
+        .map(|(range, index)| (&text[range], Builtin::emoji(index).unwrap()))
+        .collect();
+    assert_eq!(found.len(), expected.len());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn data_replace_shortcodes() {
+    assert_eq!(
+        data::replace_shortcodes(&Custom, ":space_moose: :tada: :rocket:"),
+        "🫎🚀 :tada: 🚀"
+    );
+    assert_eq!(
+        data::replace_shortcodes(&Builtin, "launch :rocket: at 10:30:00 :+1::nope:"),
+        "launch 🚀 at 10:30:00 👍:nope:"
+    );
+    assert_eq!(data::replace_shortcodes(&Builtin, "a:b"), "a:b");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn data_algorithms() {
+    assert_eq!(data::with_keyword(&Custom, " MOOSE "), [1, 2]);
+    assert_eq!(data::with_keyword(&Custom, "moo"), []);
+    assert_eq!(
+        data::search_with(&Custom, "moo", |_, i| Some(i as i64)),
+        [2, 1]
+    );
+    assert_eq!(
+        data::strip_groups(&Custom, "a 🫎 b", &[Group::AnimalsAndNature]),
+        "a  b"
+    );
+    assert_eq!(
+        data::replace_shortcodes_markdown(&Custom, "`:moose:` :moose:"),
+        "`:moose:` 🫎"
+    );
+
+    let rockets: Vec<_> = emojis::with_keyword("rocket")
+        .iter()
+        .map(|e| e.as_str())
+        .collect();
+    let found: Vec<_> = data::with_keyword(&Builtin, "rocket")
+        .into_iter()
+        .filter_map(|i| Builtin.as_str(i))
+        .collect();
+    assert_eq!(found, rockets);
+
+    let scorer = |_: &str, emoji: &'static emojis::Emoji| Some(emoji.name().len() as i64);
+    let expected = emojis::search_with("hea", &scorer);
+    let found: Vec<_> = data::search_with(&Builtin, "hea", |q, i| scorer(q, Builtin::emoji(i)?))
+        .into_iter()
+        .filter_map(Builtin::emoji)
+        .collect();
+    assert_eq!(found, expected);
+}
+
+#[cfg(feature = "shortcodes")]
+#[test]
+fn data_tokenizer() {
+    let tokens: Vec<_> = data::ShortcodeTokenizer::new(&Custom, "`:moose:` :moose: :nope:")
+        .skip_code_spans(true)
+        .map(|t| (t.shortcode(), t.emoji()))
+        .collect();
+    assert_eq!(tokens, [("moose", Some(1)), ("nope", None)]);
+
+    let text = ":thumbsup_tone2: :wave::skin-tone-3:";
+    let expected: Vec<_> = emojis::ShortcodeTokenizer::new(text)
+        .discord_custom_emojis(true)
+        .map(|t| (t.range(), t.emoji().map(|e| e.as_str())))
+        .collect();
+    let found: Vec<_> = data::ShortcodeTokenizer::new(&Builtin, text)
+        .discord_custom_emojis(true)
+        .map(|t| (t.range(), t.emoji().and_then(|i| Builtin.as_str(i))))
+        .collect();
+    assert_eq!(found, expected);
+    assert_eq!(found[0].1, Some("👍🏼"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn dataset_roundtrip() {
+    use emojis::{Dataset, SkinTone};
+
+    let builtin = Dataset::builtin();
+    assert_eq!(builtin.len(), Builtin.len());
+    let thumbs = builtin.get("👍").unwrap();
+    assert_eq!(
+        builtin.shortcodes(thumbs).collect::<Vec<_>>(),
+        ["+1", "thumbsup"]
+    );
+    assert_eq!(builtin.get_by_shortcode("thumbsup"), Some(thumbs));
+    assert_eq!(builtin.subgroup(thumbs), Builtin.subgroup(thumbs));
+    assert_eq!(builtin.skin_tone(thumbs), Some(SkinTone::Default));
+    assert_eq!(
+        builtin.with_skin_tone(thumbs, SkinTone::Dark),
+        Builtin.with_skin_tone(thumbs, SkinTone::Dark)
+    );
+    assert_eq!(
+        data::with_keyword(&builtin, "Rocket"),
+        data::with_keyword(&Builtin, "rocket")
+    );
+    assert_eq!(
+        data::search_with(&builtin, "hea", |_, i| Some(i as i64)),
+        data::search_with(&Builtin, "hea", |_, i| Some(i as i64))
+    );
+    assert_eq!(Dataset::from_bytes(&builtin.to_bytes()).unwrap(), builtin);
+
+    let copy = Dataset::from_data(&Builtin);
+    assert_eq!(copy.len(), Builtin.len());
+    assert_eq!(copy.shortcodes(thumbs).collect::<Vec<_>>(), ["+1"]);
+    assert_eq!(copy.keywords(thumbs).collect::<Vec<_>>(), ["thumbs", "up"]);
+    assert_eq!(
+        copy.with_skin_tone(thumbs, SkinTone::Dark),
+        builtin.with_skin_tone(thumbs, SkinTone::Dark)
+    );
+    assert_eq!(Dataset::from_bytes(&copy.to_bytes()).unwrap(), copy);
+
+    let custom = Dataset::from_data(&Custom);
+    assert_eq!(custom.group(0), Some(Group::AnimalsAndNature));
+    assert_eq!(custom.subgroup(0), None);
+    assert_eq!(custom.skin_tone(0), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn dataset_from_bytes() {
+    use emojis::{Dataset, DecodeDatasetError};
+
+    fn string(out: &mut Vec<u8>, s: &str) {
+        assert!(s.len() < 0x80);
+        out.push(s.len() as u8);
+        out.extend(s.as_bytes());
+    }
+
+    // An emoji from a newer Unicode version than the builtin dataset.
+    assert_eq!(emojis::get("🫟"), None);
+    let mut bytes = b"EMDS\x01\x01".to_vec();
+    string(&mut bytes, "🫟");
+    string(&mut bytes, "splatter");
+    let group = Group::ALL
+        .iter()
+        .position(|&g| g == Group::Objects)
+        .unwrap() as u8
+        + 1;
+    bytes.extend([16, 0, group, 0, 0, 1]);
+    string(&mut bytes, "splatter");
+    bytes.push(1);
+    string(&mut bytes, "paint");
+
+    let dataset = Dataset::from_bytes(&bytes).unwrap();
+    assert_eq!(dataset.len(), 1);
+    assert_eq!(dataset.unicode_version(0), Some(UnicodeVersion::new(16, 0)));
+    assert_eq!(dataset.group(0), Some(Group::Objects));
+    assert_eq!(dataset.to_bytes(), bytes);
+    assert_eq!(
+        data::matches(&dataset, "oops 🫟").collect::<Vec<_>>(),
+        [(5..9, 0)]
+    );
+    assert_eq!(
+        data::replace_shortcodes(&dataset, "oops :splatter:"),
+        "oops 🫟"
+    );
+    assert_eq!(data::with_keyword(&dataset, "PAINT"), [0]);
+
+    // Groups from a newer version of this crate are kept as is.
+    let mut newer = bytes.clone();
+    newer[22] = 0x7f;
+    let dataset = Dataset::from_bytes(&newer).unwrap();
+    assert_eq!(dataset.group(0), None);
+    assert_eq!(dataset.to_bytes(), newer);
+
+    // Nothing is truncated.
+    let mut large = b"EMDS\x01\x01\x04".to_vec();
+    large.extend("🫟".as_bytes());
+    large.extend([0x80, 0x80, 0x02]);
+    large.extend("a".repeat(0x8000).as_bytes());
+    large.extend([0x80, 0x02, 0, 0, 0, 0, 0xac, 0x02]);
+    for i in 0..300 {
+        let shortcode = format!("s{}", i);
+        string(&mut large, &shortcode);
+    }
+    large.push(0);
+    let dataset = Dataset::from_bytes(&large).unwrap();
+    assert_eq!(dataset.name(0).map(str::len), Some(0x8000));
+    assert_eq!(
+        dataset.unicode_version(0),
+        Some(UnicodeVersion::new(256, 0))
+    );
+    assert_eq!(dataset.shortcodes(0).count(), 300);
+    assert_eq!(dataset.to_bytes(), large);
+
+    let err = |bytes: &[u8]| Dataset::from_bytes(bytes).unwrap_err();
+    assert_eq!(err(b""), DecodeDatasetError::UnexpectedEnd);
+    assert_eq!(err(b"EMOJ\x01"), DecodeDatasetError::InvalidMagic);
+    assert_eq!(err(b"EMDS\x02"), DecodeDatasetError::UnsupportedVersion(2));
+    assert_eq!(
+        err(b"EMDS\x01\xff\xff\xff\xff\x0f"),
+        DecodeDatasetError::UnexpectedEnd
+    );
+    assert_eq!(
+        err(&[&b"EMDS\x01"[..], &[0xff; 10], b"\x01"].concat()),
+        DecodeDatasetError::InvalidInteger
+    );
+    assert_eq!(
+        err(&bytes[..bytes.len() - 1]),
+        DecodeDatasetError::UnexpectedEnd
+    );
+    assert_eq!(
+        err(&[&bytes[..], b"\0"].concat()),
+        DecodeDatasetError::TrailingBytes
+    );
+
+    let mut invalid = bytes.clone();
+    invalid[7] = 0xff;
+    assert_eq!(err(&invalid), DecodeDatasetError::InvalidUtf8);
+
+    // A skin tone whose default skin tone emoji is out of range.
+    let mut invalid = bytes.clone();
+    invalid[24] = 1;
+    assert_eq!(err(&invalid), DecodeDatasetError::InvalidIndex);
+    assert_eq!(
+        DecodeDatasetError::InvalidIndex.to_string(),
+        "emoji dataset refers to an emoji that does not exist"
+    );
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "std")]
+
+#[test]
+fn export_csv_and_tsv() {
+    use emojis::export::{to_csv, to_tsv, Column};
+
+    let columns = [
+        Column::Id,
+        Column::Emoji,
+        Column::Name,
+        Column::Tts,
+        Column::Group,
+        Column::Subgroup,
+        Column::UnicodeVersion,
+        Column::Shortcodes,
+        Column::SkinTone,
+    ];
+    let mut buf = Vec::new();
+    to_tsv(&mut buf, &columns).unwrap();
+    let tsv = String::from_utf8(buf).unwrap();
+    let mut lines = tsv.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "id\temoji\tname\ttts\tgroup\tsubgroup\tunicode_version\tshortcodes\tskin_tone"
+    );
+    let records: Vec<_> = emojis::dump().collect();
+    assert_eq!(lines.clone().count(), records.len());
+    for (line, record) in lines.zip(&records) {
+        let fields: Vec<_> = line.split('\t').collect();
+        assert_eq!(fields.len(), columns.len());
+        assert_eq!(fields[1], record.emoji);
+        assert_eq!(fields[2], record.name);
+    }
+
+    let mut buf = Vec::new();
+    to_csv(&mut buf, &[Column::Name]).unwrap();
+    let csv = String::from_utf8(buf).unwrap();
+    assert!(csv.contains("\n\"kiss: woman, man\"\n"));
+}
+
+#[test]
+fn export_sql() {
+    let mut buf = Vec::new();
+    emojis::export::to_sql(&mut buf).unwrap();
+    let sql = String::from_utf8(buf).unwrap();
+    assert!(sql.ends_with("COMMIT;\n"));
+    let inserts = sql
+        .lines()
+        .filter(|l| l.starts_with("INSERT INTO emojis "))
+        .count();
+    assert_eq!(inserts, emojis::dump().count());
+    for line in sql.lines().filter(|l| l.starts_with("INSERT")) {
+        assert!(line.ends_with(");"), "{line}");
+        assert_eq!(line.matches('\'').count() % 2, 0, "{line}");
+    }
+}
+
+#[test]
+#[cfg(feature = "rusqlite")]
+fn export_sqlite() {
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    emojis::export::to_sqlite(&conn).unwrap();
+
+    let count = |sql: &str| -> usize {
+        conn.query_row(sql, [], |row| row.get::<_, i64>(0)).unwrap() as usize
+    };
+    assert_eq!(count("SELECT COUNT(*) FROM emojis"), emojis::dump().count());
+    assert_eq!(
+        count("SELECT COUNT(*) FROM groups"),
+        emojis::Group::iter().count()
+    );
+    assert_eq!(
+        count("SELECT COUNT(*) FROM shortcodes"),
+        emojis::dump().map(|r| r.shortcodes.len()).sum::<usize>()
+    );
+
+    let (emoji, group): (String, String) = conn
+        .query_row(
+            "SELECT emoji, groups.name FROM emojis \
+             JOIN subgroups ON subgroup_id = subgroups.id \
+             JOIN groups ON group_id = groups.id \
+             WHERE emojis.name = 'thumbs up: medium skin tone'",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(emoji, "👍🏽");
+    assert_eq!(group, "PeopleAndBody");
+
+    // The tables already exist so running it again fails and rolls back.
+    assert!(emojis::export::to_sqlite(&conn).is_err());
+    assert!(conn.is_autocommit());
+    assert_eq!(count("SELECT COUNT(*) FROM emojis"), emojis::dump().count());
+}
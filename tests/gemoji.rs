@@ -0,0 +1,18 @@
+#![cfg(feature = "gemoji")]
+
+#[test]
+fn gemoji_get() {
+    let all: Vec<_> = emojis::gemoji::iter().collect();
+    assert_eq!(all.len(), 20);
+    assert!(all.windows(2).all(|w| w[0].name() < w[1].name()));
+    for custom in all {
+        assert_eq!(emojis::gemoji::get(custom.name()), Some(custom));
+        assert!(emojis::get_by_shortcode(custom.name()).is_none());
+        assert!(custom
+            .image_url()
+            .ends_with(&format!("/{}.png", custom.name())));
+    }
+    assert_eq!(emojis::gemoji::get("shipit").unwrap().name(), "shipit");
+    assert!(emojis::gemoji::get("").is_none());
+    assert!(emojis::gemoji::get(":octocat:").is_none());
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "alloc")]
+
+fn img(emoji: &emojis::Emoji) -> String {
+    format!("<img alt=\"{}\">", emoji.shortcode().unwrap())
+}
+
+#[test]
+fn emojify() {
+    let tests = [
+        ("", ""),
+        ("no emojis", "no emojis"),
+        ("<b>🚀</b>", "<b><img alt=\"rocket\"></b>"),
+        (
+            "<a href='x' title=\"a > 🚀\">🎉</a>",
+            "<a href='x' title=\"a > 🚀\"><img alt=\"tada\"></a>",
+        ),
+        ("<!-- 🚀 --> 🎉", "<!-- 🚀 --> <img alt=\"tada\">"),
+        (
+            "<script>let s = '🚀';</script>🎉<STYLE>/* 🚀 */</style>",
+            "<script>let s = '🚀';</script><img alt=\"tada\"><STYLE>/* 🚀 */</style>",
+        ),
+        ("1 < 2 🚀", "1 < 2 <img alt=\"rocket\">"),
+        ("<p 🚀", "<p 🚀"),
+    ];
+    for (html, expected) in tests {
+        assert_eq!(emojis::html::emojify(html, img), expected);
+    }
+    assert!(matches!(
+        emojis::html::emojify("<p title=\"🚀\"></p>", img),
+        std::borrow::Cow::Borrowed(_)
+    ));
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+use std::io::Write;
+
+use emojis::io::{Demojifier, ShortcodeExpander};
+
+fn expand_in_chunks(input: &str, size: usize) -> String {
+    let mut w = ShortcodeExpander::new(Vec::new());
+    for chunk in input.as_bytes().chunks(size) {
+        w.write_all(chunk).unwrap();
+    }
+    String::from_utf8(w.finish().unwrap()).unwrap()
+}
+
+fn demojify_in_chunks(input: &str, size: usize) -> String {
+    let mut w = Demojifier::new(Vec::new());
+    for chunk in input.as_bytes().chunks(size) {
+        w.write_all(chunk).unwrap();
+    }
+    String::from_utf8(w.finish().unwrap()).unwrap()
+}
+
+#[test]
+fn shortcode_expander() {
+    let tests = [
+        ("launch nothing", "launch nothing"),
+        ("launch :rocket: something", "launch 🚀 something"),
+        ("? :unknown: emoji", "? :unknown: emoji"),
+        ("::very:naughty::", "::very:naughty::"),
+        (":maybe:rocket:", ":maybe🚀"),
+        (":rocket::rocket:", "🚀🚀"),
+        (":+1: :t-rex:", "👍 🦖"),
+        ("trailing :rocket", "trailing :rocket"),
+    ];
+    for (i, o) in tests {
+        for size in 1..=i.len() {
+            assert_eq!(expand_in_chunks(i, size), o, "chunk size {size}");
+        }
+    }
+}
+
+#[test]
+fn demojifier() {
+    let tests = [
+        ("launch nothing", "launch nothing"),
+        ("launch 🚀 something", "launch :rocket: something"),
+        ("👍🏽 tone", "👍🏽 tone"),
+        ("🏴‍☠️ #️⃣ #1", ":pirate_flag: :hash: #1"),
+        ("☹", ":frowning_face:"),
+    ];
+    for (i, o) in tests {
+        for size in 1..=i.len() {
+            assert_eq!(demojify_in_chunks(i, size), o, "chunk size {size}");
+        }
+    }
+}
+
+#[test]
+fn roundtrip() {
+    let text: String = emojis::iter().map(|e| e.as_str()).collect();
+    let demojified = demojify_in_chunks(&text, 7);
+    assert_eq!(expand_in_chunks(&demojified, 5), text);
+}
+
+#[test]
+fn drop_writes_pending() {
+    let mut buf = Vec::new();
+    {
+        let mut w = ShortcodeExpander::new(&mut buf);
+        w.write_all(b"end :rock").unwrap();
+    }
+    assert_eq!(buf, b"end :rock");
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "locales")]
+
+use emojis::Locale;
+
+#[test]
+fn search_in() {
+    let names = |locale, q| {
+        emojis::search_in(locale, q)
+            .map(|e| e.name())
+            .collect::<Vec<_>>()
+    };
+
+    assert!(names(Locale::En, "").is_empty());
+    assert!(names(Locale::En, "zzzz").is_empty());
+    let rockets = names(Locale::En, " ROCKET");
+    assert!(rockets.contains(&"rocket"));
+    assert!(rockets.contains(&"astronaut"));
+
+    let hearts = names(Locale::En, "heart");
+    assert!(hearts.contains(&"red heart"));
+    assert!(hearts.contains(&"heart with arrow"));
+    let mut sorted = hearts.clone();
+    sorted.dedup();
+    assert_eq!(sorted, hearts);
+}
+
+#[test]
+fn search_in_translations() {
+    for (locale, query) in [
+        (Locale::De, "rakete"),
+        (Locale::Es, "cohete"),
+        (Locale::Fr, "fusée"),
+        (Locale::Ja, "ロケット"),
+        (Locale::Pt, "foguete"),
+        (Locale::Zh, "火箭"),
+    ] {
+        let found: Vec<_> = emojis::search_in(locale, query).collect();
+        assert!(found.iter().any(|&e| e == "🚀"), "{:?} {:?}", locale, query);
+    }
+    assert!(emojis::search_in(Locale::De, " RAKETE").any(|e| e == "🚀"));
+}
@@ -0,0 +1,256 @@
+//! Runs every function that takes arbitrary input over adversarial text to
+//! check that none of them panic.
+
+use emojis::{Group, Profile, ShortcodeTokenizer, SkinTone, WeightConfig};
+
+/// Fragments that exercise the edge cases of the parsers.
+const PIECES: &[&str] = &[
+    "a",
+    "1",
+    "#",
+    "*",
+    " ",
+    "\n",
+    ":",
+    "::",
+    "<",
+    ">",
+    "`",
+    "```",
+    "~~~",
+    "[",
+    "]",
+    "(",
+    ")",
+    "](",
+    "<a:",
+    "<:",
+    "_tone",
+    "skin-tone-",
+    "2",
+    "9",
+    "&",
+    "é",
+    "\u{fe0f}",
+    "\u{fe0e}",
+    "\u{20e3}",
+    "\u{200d}",
+    "\u{1f3fb}",
+    "\u{1f3ff}",
+    "\u{e0067}",
+    "\u{e007f}",
+    "\u{1f1ff}",
+    "\u{1f1e6}",
+    "🚀",
+    "👨",
+    "👩",
+    "❤",
+    "💋",
+    "🏴",
+    "🤝",
+    "🫱",
+    ":rocket:",
+    ":+1:",
+    "<!--",
+    "-->",
+    "<script>",
+    "</script>",
+    "\u{10ffff}",
+    "\u{0}",
+];
+
+/// Returns deterministic pseudo-random strings built from the pieces and
+/// every prefix of every emoji.
+fn inputs() -> Vec<String> {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let mut inputs = Vec::new();
+    for _ in 0..2000 {
+        let len = next() % 12;
+        let s: String = (0..len)
+            .map(|_| PIECES[(next() % PIECES.len() as u64) as usize])
+            .collect();
+        inputs.push(s);
+    }
+    for emoji in emojis::iter().flat_map(|e| e.skin_tones().into_iter().flatten().chain([e])) {
+        let s = emoji.as_str();
+        for (i, _) in s.char_indices().skip(1) {
+            inputs.push(s[..i].to_owned());
+            inputs.push(s[i..].to_owned());
+        }
+    }
+    inputs
+}
+
+#[test]
+fn text_functions_do_not_panic() {
+    for s in inputs() {
+        let s = s.as_str();
+        emojis::get(s);
+        emojis::get_normalized(s);
+        emojis::get_by_shortcode(s);
+        let utf16: Vec<u16> = s.encode_utf16().collect();
+        emojis::get_utf16(&utf16);
+        emojis::get_utf16(&utf16[..utf16.len() / 2]);
+        emojis::from_chars(s.chars()).for_each(drop);
+        for c in s.chars() {
+            emojis::get_char(c);
+            emojis::could_start_emoji(c);
+            emojis::regional_indicator(c);
+        }
+        emojis::letters_to_flag(s);
+        emojis::flag_to_letters(s);
+        emojis::spell(s).for_each(drop);
+        emojis::detect_skin_tone(s);
+        emojis::first(s);
+        emojis::last(s);
+        emojis::grapheme_count(s);
+        emojis::histogram(s).for_each(drop);
+        emojis::is_emoji_only(s);
+        emojis::sms_units(s);
+        emojis::weighted_len(s, WeightConfig::default());
+        emojis::pattern().find_in(s).for_each(drop);
+        emojis::validate_reaction(s).ok();
+        emojis::validate_reaction_in(s, &[Group::Flags]).ok();
+        emojis::find_by_name(s).for_each(drop);
+        emojis::with_keyword(s);
+        for flags in 0..16 {
+            ShortcodeTokenizer::new(s)
+                .require_word_boundaries(flags & 1 != 0)
+                .skip_code_spans(flags & 2 != 0)
+                .skip_link_urls(flags & 4 != 0)
+                .discord_custom_emojis(flags & 8 != 0)
+                .for_each(drop);
+        }
+        for profile in [
+            Profile::GitHub,
+            Profile::Slack,
+            Profile::Discord,
+            Profile::Matrix,
+        ] {
+            profile.parse(s).for_each(drop);
+        }
+        emojis::Replaced::expand_shortcodes(s).to_string();
+        emojis::Replaced::strip_emojis(s).to_string();
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn alloc_functions_do_not_panic() {
+    use emojis::{EmojiSet, Filter, FilterPolicy};
+
+    let set: EmojiSet = emojis::iter().step_by(3).collect();
+    for s in inputs() {
+        let s = s.as_str();
+        emojis::strip_groups(s, &[Group::Flags]);
+        emojis::strip_set(s, &set);
+        emojis::replace_shortcodes_markdown(s);
+        emojis::convert_shortcodes(s, Profile::Slack, Profile::Discord);
+        emojis::convert_shortcodes(s, Profile::Discord, Profile::Matrix);
+        emojis::html::emojify(s, |e| e.name());
+        for policy in [
+            FilterPolicy::Remove,
+            FilterPolicy::ReplaceWithName,
+            FilterPolicy::ReplaceWithShortcode,
+        ] {
+            Filter::new(set, policy).apply(s);
+        }
+        let score =
+            |q: &str, e: &'static emojis::Emoji| Some(e.name().len() as i64 - q.len() as i64);
+        emojis::search_with(s, &score);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn writers_do_not_panic() {
+    use std::io::Write;
+
+    for s in inputs() {
+        for split in 0..=s.len() {
+            let (a, b) = s.as_bytes().split_at(split);
+            let mut w = emojis::io::ShortcodeExpander::new(Vec::new());
+            w.write_all(a).unwrap();
+            w.write_all(b).unwrap();
+            w.finish().unwrap();
+            let mut w = emojis::io::Demojifier::new(Vec::new());
+            w.write_all(a).unwrap();
+            w.write_all(b).unwrap();
+            w.finish().unwrap();
+        }
+    }
+}
+
+#[test]
+fn integer_conversions_do_not_panic() {
+    let fingerprint = emojis::dataset_fingerprint();
+    for value in 0..=u16::MAX {
+        emojis::EmojiId::new(value);
+        emojis::EmojiId::migrate_from(fingerprint, value);
+        emojis::EmojiId::migrate_from(0, value);
+    }
+    for value in 0..=u8::MAX {
+        Group::try_from(value).ok();
+        emojis::Subgroup::try_from(value).ok();
+    }
+    for index in [0, 1, usize::MAX / 2, usize::MAX] {
+        emojis::nth(index);
+        emojis::pages(index).for_each(drop);
+        Group::Flags.pages(index).for_each(drop);
+    }
+}
+
+#[test]
+fn buffers_do_not_panic() {
+    for emoji in emojis::iter() {
+        for len in 0..=emoji.as_str().len() {
+            emoji.copy_to_slice(&mut vec![0; len]).ok();
+            emoji.try_encode_utf16(&mut vec![0; len]).ok();
+        }
+        emojis::EmojiSet::from_bytes(emoji.as_str().as_bytes()).ok();
+    }
+    let mut bytes = emojis::EmojiSet::new().to_bytes();
+    for i in 0..bytes.len() {
+        bytes[i] = 0xff;
+        emojis::EmojiSet::from_bytes(&bytes).ok();
+        emojis::EmojiSet::from_bytes(&bytes[..i]).ok();
+    }
+}
+
+#[test]
+fn skin_tone_functions_do_not_panic() {
+    let tones = [
+        SkinTone::Default,
+        SkinTone::Light,
+        SkinTone::MediumLight,
+        SkinTone::Medium,
+        SkinTone::MediumDark,
+        SkinTone::Dark,
+    ];
+    for &a in &tones {
+        for &b in &tones {
+            emojis::handshake(a, b);
+        }
+        a.apply_to_all(emojis::iter()).for_each(drop);
+    }
+}
+
+#[cfg(feature = "fuzzing")]
+#[test]
+fn fuzz_properties_hold() {
+    for s in inputs() {
+        emojis::fuzz::check_all(&s);
+    }
+    for emoji in emojis::iter() {
+        emojis::fuzz::check_all(emoji.as_str());
+        for shortcode in emoji.shortcodes() {
+            emojis::fuzz::check_all(shortcode);
+        }
+    }
+}
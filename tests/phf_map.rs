@@ -0,0 +1,23 @@
+#![cfg(feature = "phf-map")]
+
+#[test]
+fn unicode_map() {
+    assert_eq!(emojis::UNICODE_MAP.len(), 4724);
+    for (key, emoji) in emojis::UNICODE_MAP.entries() {
+        assert_eq!(emojis::get(key), Some(*emoji));
+    }
+    assert_eq!(emojis::UNICODE_MAP["🚀"], "🚀");
+    assert_eq!(emojis::UNICODE_MAP["☺"], "☺️");
+}
+
+#[test]
+fn shortcode_map() {
+    for (key, emoji) in emojis::SHORTCODE_MAP.entries() {
+        assert_eq!(emojis::get_by_shortcode(key), Some(*emoji));
+    }
+    for emoji in emojis::iter() {
+        for shortcode in emoji.shortcodes() {
+            assert_eq!(emojis::SHORTCODE_MAP.get(shortcode), Some(&emoji));
+        }
+    }
+}
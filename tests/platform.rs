@@ -0,0 +1,40 @@
+#![cfg(feature = "platform-support")]
+
+use emojis::{Platform, PlatformVersion};
+
+#[test]
+fn supported_on_monotonic() {
+    let platforms = [Platform::Ios, Platform::Android, Platform::Windows];
+    for platform in platforms {
+        let mut emojis: Vec<_> = emojis::iter().collect();
+        emojis.sort_by_key(|e| e.unicode_version());
+        for pair in emojis.windows(2) {
+            assert!(
+                pair[0].min_platform_version(platform) <= pair[1].min_platform_version(platform)
+            );
+        }
+    }
+}
+
+#[test]
+fn min_platform_version_covers_every_unicode_version() {
+    let platforms = [Platform::Ios, Platform::Android, Platform::Windows];
+    for emoji in emojis::Group::iter().flat_map(|g| g.as_slice()) {
+        for platform in platforms {
+            assert!(
+                emoji.min_platform_version(platform).is_some(),
+                "{:?} has no {:?} release",
+                emoji.unicode_version(),
+                platform
+            );
+        }
+    }
+}
+
+#[test]
+fn supported_on_latest() {
+    for emoji in emojis::iter() {
+        assert!(emoji.supported_on(Platform::Ios, PlatformVersion::new(17, 0)));
+        assert!(emoji.supported_on(Platform::Windows, PlatformVersion::new(26100, 0)));
+    }
+}
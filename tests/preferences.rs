@@ -0,0 +1,39 @@
+#![cfg(feature = "std")]
+
+use emojis::{preferences, Gender, SkinTone};
+
+// The preferences are process-wide so everything is checked in one test.
+#[test]
+fn preferred() {
+    let get = |s| emojis::get(s).unwrap();
+
+    assert_eq!(preferences::skin_tone(), None);
+    assert_eq!(preferences::gender(), None);
+    for emoji in emojis::iter() {
+        assert_eq!(emoji.preferred(), emoji);
+    }
+
+    preferences::set_skin_tone(Some(SkinTone::Dark));
+    assert_eq!(preferences::skin_tone(), Some(SkinTone::Dark));
+    assert_eq!(get("👍").preferred(), "👍🏿");
+    assert_eq!(get("👍🏻").preferred(), "👍🏿");
+    assert_eq!(get("🚀").preferred(), "🚀");
+
+    preferences::set_gender(Some(Gender::Male));
+    assert_eq!(preferences::gender(), Some(Gender::Male));
+    assert_eq!(get("🧑‍🍳").preferred(), "👨🏿‍🍳");
+    assert_eq!(get("👮‍♀️").preferred(), "👮🏿‍♂️");
+    assert_eq!(
+        get("💑").preferred(),
+        get("💑").with_skin_tone(SkinTone::Dark).unwrap()
+    );
+
+    preferences::set_skin_tone(Some(SkinTone::Default));
+    preferences::set_gender(Some(Gender::Neutral));
+    assert_eq!(get("👮🏽‍♀️").preferred(), "👮");
+
+    preferences::reset();
+    assert_eq!(preferences::skin_tone(), None);
+    assert_eq!(preferences::gender(), None);
+    assert_eq!(get("👮🏽‍♀️").preferred(), "👮🏽‍♀️");
+}
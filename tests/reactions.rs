@@ -0,0 +1,50 @@
+#![cfg(feature = "alloc")]
+
+use emojis::reactions::Tally;
+
+#[test]
+fn tally_add_remove_count() {
+    let mut tally = Tally::new();
+    assert!(tally.is_empty());
+    assert_eq!(tally.add("👍🏽").unwrap(), "👍🏽");
+    assert_eq!(tally.add("\u{2764}").unwrap(), "❤️");
+    assert_eq!(tally.add("not an emoji"), None);
+    tally.extend(["👍", "❤️"]);
+    assert_eq!(tally.count("👍🏽"), 1);
+    assert_eq!(tally.count("👍"), 1);
+    assert_eq!(tally.count("❤"), 2);
+    assert_eq!(tally.count("🚀"), 0);
+    assert_eq!(tally.len(), 3);
+    assert_eq!(tally.total(), 4);
+
+    assert!(tally.remove("❤️"));
+    assert!(tally.remove("❤️"));
+    assert!(!tally.remove("❤️"));
+    assert!(!tally.remove("nope"));
+    assert_eq!(tally.len(), 2);
+    tally.clear();
+    assert!(tally.is_empty());
+}
+
+#[test]
+fn tally_tone_insensitive() {
+    let mut tally = Tally::new().tone_insensitive(true);
+    assert_eq!(tally.add("👍🏿").unwrap(), "👍");
+    tally.extend(["👍", "👍🏻", "🚀"]);
+    assert_eq!(tally.count("👍🏽"), 3);
+    let counts: Vec<_> = tally.iter().map(|(e, n)| (e.as_str(), n)).collect();
+    assert_eq!(counts, [("👍", 3), ("🚀", 1)]);
+}
+
+#[test]
+fn tally_top() {
+    let tally: Tally = ["🚀", "🎉", "😀", "🎉", "🚀", "👋"].into_iter().collect();
+    let top: Vec<_> = tally
+        .top(3)
+        .into_iter()
+        .map(|(e, n)| (e.as_str(), n))
+        .collect();
+    assert_eq!(top, [("🚀", 2), ("🎉", 2), ("😀", 1)]);
+    assert_eq!(tally.top(10).len(), 4);
+    assert!(Tally::new().top(3).is_empty());
+}
@@ -0,0 +1,84 @@
+#[test]
+fn find_by_name() {
+    let names = |q| {
+        emojis::find_by_name(q)
+            .map(|e| e.name())
+            .collect::<Vec<_>>()
+    };
+
+    assert!(names("").is_empty());
+    assert!(names("   ").is_empty());
+    assert_eq!(names("ROCKET"), ["rocket"]);
+    assert_eq!(names(" red heart "), ["red heart"]);
+    assert_eq!(names("t-rex"), ["T-Rex"]);
+
+    let hearts = names("heart");
+    assert!(hearts.contains(&"heart with arrow"));
+    assert!(hearts.contains(&"smiling face with heart-eyes"));
+    assert!(!hearts.contains(&"hearts"));
+
+    assert!(names("art").is_empty());
+    assert!(names("artist").contains(&"artist palette"));
+}
+
+#[test]
+fn with_keyword() {
+    assert!(emojis::with_keyword("").is_empty());
+    assert!(emojis::with_keyword("with").is_empty());
+
+    let hearts = emojis::with_keyword(" HEART ");
+    assert!(hearts.contains(&emojis::get("❤️").unwrap()));
+    assert!(hearts
+        .iter()
+        .all(|e| e.skin_tone().is_none() || e.skin_tone() == Some(emojis::SkinTone::Default)));
+
+    for emoji in emojis::iter() {
+        for word in emoji.name().split_whitespace() {
+            let word = word
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase();
+            if word.is_empty() || ["a", "and", "in", "of", "on", "the", "with"].contains(&&*word) {
+                continue;
+            }
+            assert!(emojis::with_keyword(&word).contains(&emoji), "{}", word);
+        }
+    }
+}
+
+#[test]
+fn with_keyword_cldr() {
+    for (keyword, emoji) in [("love", "😍"), ("space", "🚀"), ("celebration", "🎉")] {
+        let found = emojis::with_keyword(keyword);
+        assert!(found.contains(&emojis::get(emoji).unwrap()), "{}", keyword);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn search_with() {
+    use emojis::{Emoji, Scorer};
+
+    struct Shortest;
+
+    impl Scorer for Shortest {
+        fn score(&self, _: &str, emoji: &'static Emoji) -> Option<i64> {
+            Some(-(emoji.name().len() as i64))
+        }
+    }
+
+    assert!(emojis::search_with("", &Shortest).is_empty());
+
+    let results = emojis::search_with("hear", &Shortest);
+    assert!(results.contains(&emojis::get("❤️").unwrap()));
+    assert!(results.contains(&emojis::get("🙉").unwrap()));
+    for pair in results.windows(2) {
+        assert!(pair[0].name().len() <= pair[1].name().len());
+    }
+
+    let none = |_: &str, _: &'static Emoji| None;
+    assert!(emojis::search_with("heart", &none).is_empty());
+
+    let scorer: &dyn Scorer = &|_: &str, e: &'static Emoji| (e.as_str() == "💔").then(|| 1);
+    let results = emojis::search_with("broken", scorer);
+    assert_eq!(results, [emojis::get("💔").unwrap()]);
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "sentiment")]
+
+use emojis::{Group, Sentiment};
+
+#[test]
+fn sentiment_smileys_and_emotion() {
+    let mut positive = 0;
+    let mut negative = 0;
+    for group in Group::iter() {
+        for emoji in group.emojis() {
+            match emoji.sentiment() {
+                Some(Sentiment::Positive) => positive += 1,
+                Some(Sentiment::Negative) => negative += 1,
+                Some(Sentiment::Neutral) => {}
+                None => assert_ne!(group, Group::SmileysAndEmotion),
+            }
+            if group != Group::SmileysAndEmotion {
+                assert_eq!(emoji.sentiment(), None);
+            }
+        }
+    }
+    assert_eq!(positive, 63);
+    assert_eq!(negative, 51);
+}
@@ -0,0 +1,202 @@
+use emojis::{DecodeSetError, EmojiSet, RecentList, UnicodeVersion};
+
+#[test]
+fn set_insert_remove_contains() {
+    let rocket = emojis::get("🚀").unwrap();
+    let wave = emojis::get("👋🏽").unwrap();
+    let mut set = EmojiSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(rocket));
+    assert!(!set.insert(rocket));
+    assert!(set.insert(wave));
+    assert!(set.contains(wave));
+    assert!(!set.contains(emojis::get("👋").unwrap()));
+    assert_eq!(set.len(), 2);
+    assert!(set.remove(rocket));
+    assert!(!set.remove(rocket));
+    assert_eq!(set.iter().collect::<Vec<_>>(), [wave]);
+}
+
+#[test]
+fn set_operations() {
+    let a: EmojiSet = emojis::Group::Flags.emojis().collect();
+    let b: EmojiSet = emojis::Subgroup::CountryFlag
+        .emojis()
+        .iter()
+        .copied()
+        .collect();
+    assert_eq!(a.union(&b), a);
+    assert_eq!(a.intersection(&b), b);
+    assert_eq!(
+        a.difference(&b).len(),
+        emojis::Group::Flags.emojis().count() - b.len()
+    );
+}
+
+#[test]
+fn supported_at() {
+    let set = emojis::supported_at(UnicodeVersion::new(15, 0));
+    assert_eq!(
+        set.len(),
+        emojis::Group::iter()
+            .map(|g| g.as_slice().len())
+            .sum::<usize>()
+    );
+    let set = emojis::supported_at(UnicodeVersion::new(12, 0));
+    assert!(set
+        .iter()
+        .all(|e| e.unicode_version() <= UnicodeVersion::new(12, 0)));
+}
+
+#[cfg(feature = "shortcodes")]
+#[test]
+fn emoji_set_macro() {
+    const EMPTY: EmojiSet = emojis::emoji_set![];
+    assert!(EMPTY.is_empty());
+
+    const SET: EmojiSet = emojis::emoji_set!["🚀", "👍🏽", ":tada:", ":+1:", "🚀",];
+    let expected: EmojiSet = ["🚀", "👍🏽", "🎉", "👍"]
+        .iter()
+        .filter_map(|s| emojis::get(s))
+        .collect();
+    assert_eq!(SET, expected);
+
+    let set = emojis::emoji_set!["🏴‍☠️", ":zzz:"];
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn set_bytes_roundtrip() {
+    let set: EmojiSet = emojis::iter().step_by(7).collect();
+    let bytes = set.to_bytes();
+    assert_eq!(bytes[0], 1);
+    assert_eq!(EmojiSet::from_bytes(&bytes), Ok(set));
+
+    let full: EmojiSet = emojis::iter()
+        .flat_map(|e| e.skin_tones().into_iter().flatten().chain([e]))
+        .collect();
+    assert_eq!(EmojiSet::from_bytes(&full.to_bytes()), Ok(full));
+}
+
+#[test]
+fn set_bytes_errors() {
+    let bytes = EmojiSet::new().to_bytes();
+    assert_eq!(
+        EmojiSet::from_bytes(&[]),
+        Err(DecodeSetError::InvalidLength)
+    );
+    assert_eq!(
+        EmojiSet::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(DecodeSetError::InvalidLength)
+    );
+
+    let mut b = bytes;
+    b[0] = 2;
+    assert_eq!(
+        EmojiSet::from_bytes(&b),
+        Err(DecodeSetError::UnsupportedVersion(2))
+    );
+
+    let mut b = bytes;
+    b[1] = b[1].wrapping_add(1);
+    assert!(matches!(
+        EmojiSet::from_bytes(&b),
+        Err(DecodeSetError::DatasetMismatch { .. })
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn set_serde() {
+    let set: EmojiSet = ["🚀", "👍🏽"].iter().filter_map(|s| emojis::get(s)).collect();
+    let json = serde_json::to_string(&set).unwrap();
+    assert_eq!(serde_json::from_str::<EmojiSet>(&json).unwrap(), set);
+    assert!(serde_json::from_str::<EmojiSet>("[1, 2, 3]").is_err());
+}
+
+#[test]
+fn recent_list() {
+    let get = |s| emojis::get(s).unwrap();
+    let collect = |list: &RecentList<3>| list.iter().map(|e| e.as_str()).collect::<Vec<_>>();
+
+    let mut recent = RecentList::<3>::new();
+    assert!(recent.is_empty());
+    assert_eq!(recent.capacity(), 3);
+    assert_eq!(recent.push(get("🚀")), None);
+    assert_eq!(recent.push(get("🎉")), None);
+    assert_eq!(recent.push(get("🚀")), None);
+    assert_eq!(collect(&recent), ["🚀", "🎉"]);
+    assert_eq!(recent.push(get("👍")), None);
+    assert_eq!(recent.push(get("👍🏽")), Some(get("🎉")));
+    assert_eq!(collect(&recent), ["👍🏽", "👍", "🚀"]);
+    assert!(recent.contains(get("🚀")));
+    assert!(!recent.contains(get("🎉")));
+
+    assert!(recent.remove(get("👍")));
+    assert!(!recent.remove(get("👍")));
+    assert_eq!(collect(&recent), ["👍🏽", "🚀"]);
+    assert_eq!(recent.ids().next(), Some(get("👍🏽").id()));
+    recent.clear();
+    assert_eq!(recent, RecentList::default());
+
+    let mut empty = RecentList::<0>::new();
+    assert_eq!(empty.push(get("🚀")), Some(get("🚀")));
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn recent_list_serde() {
+    let mut recent = RecentList::<3>::new();
+    recent.extend(["🚀", "👍🏽"].iter().filter_map(|s| emojis::get(s)));
+    let json = serde_json::to_string(&recent).unwrap();
+    assert_eq!(json, r#"["👍🏽","🚀"]"#);
+    assert_eq!(
+        serde_json::from_str::<RecentList<3>>(&json).unwrap(),
+        recent
+    );
+
+    let list: RecentList<2> = serde_json::from_str(r#"["🚀","nope","🚀","🎉","😀"]"#).unwrap();
+    assert_eq!(
+        list.iter().map(|e| e.as_str()).collect::<Vec<_>>(),
+        ["🚀", "🎉"]
+    );
+    assert!(serde_json::from_str::<RecentList<2>>("[1]").is_err());
+}
+
+#[test]
+fn groups() {
+    use emojis::{Group, Groups};
+
+    assert!(Groups::new().is_empty());
+    assert_eq!(Groups::all().len(), Group::ALL.len());
+    assert!(Group::iter().eq(Groups::all().iter()));
+
+    for group in Group::iter() {
+        let single = Groups::from(group);
+        assert_eq!(single.len(), 1);
+        assert_eq!(single.bits().count_ones(), 1);
+        for emoji in emojis::iter() {
+            assert_eq!(single.contains(emoji), emoji.group() == group);
+            assert_eq!(Groups::from(emoji.group()), emoji.group_bit());
+        }
+    }
+
+    const FLAGS_AND_SYMBOLS: Groups = Groups::new().with(Group::Flags).with(Group::Symbols);
+    let mut groups: Groups = [Group::Symbols, Group::Flags].into_iter().collect();
+    assert_eq!(groups, FLAGS_AND_SYMBOLS);
+    assert!(!groups.insert(Group::Flags));
+    assert!(groups.insert(Group::Objects));
+    assert!(groups.remove(Group::Objects));
+    assert!(!groups.remove(Group::Objects));
+    assert_eq!(format!("{:?}", groups), "{Symbols, Flags}");
+
+    let flags = Groups::from(Group::Flags);
+    assert_eq!(groups.intersection(&flags), flags);
+    assert_eq!(groups.difference(&flags), Groups::from(Group::Symbols));
+    assert_eq!(
+        flags.union(&Groups::from(Group::Symbols)),
+        FLAGS_AND_SYMBOLS
+    );
+    assert_eq!(Groups::all().difference(&Groups::all()), Groups::default());
+}
@@ -78,3 +78,1078 @@ fn group_iter_and_emojis() {
     let right: Vec<_> = emojis::iter().collect();
     assert_eq!(left, right);
 }
+
+#[test]
+fn emoji_utf16_roundtrip() {
+    for emoji in emojis::iter() {
+        let encoded: Vec<u16> = emoji.as_str().encode_utf16().collect();
+        assert_eq!(emoji.utf16_len(), encoded.len());
+        let mut buf = vec![0; emoji.utf16_len()];
+        assert_eq!(emoji.encode_utf16(&mut buf), encoded.len());
+        assert_eq!(buf, encoded);
+        assert_eq!(emojis::get_utf16(&buf), Some(emoji));
+    }
+    assert_eq!(emojis::get_utf16(&[0xd83d]), None);
+}
+
+#[test]
+fn emoji_scalars() {
+    for emoji in emojis::Group::iter().flat_map(|g| g.as_slice()) {
+        assert!(emoji.scalar_count() >= 1);
+        assert_eq!(emoji.is_bmp(), emoji.utf16_len() == emoji.scalar_count());
+        assert!(emoji.as_str().chars().all(|c| c <= emoji.max_scalar()));
+    }
+    assert!(emojis::get("❤️").unwrap().is_bmp());
+    assert!(!emojis::get("❤️‍🔥").unwrap().is_bmp());
+}
+
+#[test]
+fn from_chars_all_emojis() {
+    let all: Vec<_> = emojis::iter()
+        .flat_map(|emoji| emoji.skin_tones().into_iter().flatten().chain(Some(emoji)))
+        .collect();
+    let text: String = all.iter().map(|emoji| emoji.as_str()).collect();
+    let found: Vec<_> = emojis::from_chars(text.chars()).collect();
+    assert_eq!(found, all);
+}
+
+#[test]
+fn from_chars_skips_text() {
+    let found: Vec<_> = emojis::from_chars("a☹b🇿🇦c🏴‍☠️".chars())
+        .map(|e| e.as_str())
+        .collect();
+    assert_eq!(found, ["☹️", "🇿🇦", "🏴‍☠️"]);
+}
+
+#[test]
+fn group_slices() {
+    let mut all = Vec::new();
+    for group in emojis::Group::iter() {
+        let slice = group.as_slice();
+        assert!(slice.iter().all(|emoji| emojis::Group::of(emoji) == group));
+        assert_eq!(group.first(), group.emojis().next().unwrap());
+        assert_eq!(group.last(), group.emojis().last().unwrap());
+        all.extend(slice);
+    }
+    let count = emojis::iter()
+        .map(|emoji| emoji.skin_tones().map_or(1, |tones| tones.count()))
+        .sum::<usize>();
+    assert_eq!(all.len(), count);
+}
+
+#[test]
+fn subgroup_iter_and_emojis() {
+    let left: Vec<_> = emojis::Subgroup::iter()
+        .flat_map(|s| s.emojis().iter().copied())
+        .collect();
+    let right: Vec<_> = emojis::iter().collect();
+    assert_eq!(left, right);
+
+    for group in emojis::Group::iter() {
+        let left: Vec<_> = group
+            .subgroups()
+            .flat_map(|s| s.emojis().iter().copied())
+            .collect();
+        let right: Vec<_> = group.emojis().collect();
+        assert_eq!(left, right);
+    }
+}
+
+#[test]
+fn all_and_nth() {
+    let all = emojis::all();
+    assert_eq!(all.len(), emojis::iter().count());
+    for (i, emoji) in emojis::iter().enumerate() {
+        assert_eq!(emojis::nth(i), Some(emoji));
+    }
+    assert_eq!(emojis::nth(all.len()), None);
+}
+
+#[test]
+fn layout() {
+    let layout = emojis::layout();
+    assert!(layout.iter().map(|g| g.group()).eq(emojis::Group::iter()));
+    for group in layout {
+        assert!(group.emojis().eq(group.group().emojis()));
+        let subgroups = group.subgroups().iter().map(|s| s.subgroup());
+        assert!(subgroups.eq(group.group().subgroups()));
+        for subgroup in group.subgroups() {
+            assert_eq!(subgroup.emojis(), subgroup.subgroup().emojis());
+        }
+    }
+    let emojis = layout.iter().flat_map(|g| g.emojis());
+    assert!(emojis.eq(emojis::iter()));
+}
+
+#[test]
+fn pages() {
+    for page_size in [1, 7, 40, 10_000] {
+        let pages: Vec<_> = emojis::pages(page_size).collect();
+        assert!(pages
+            .iter()
+            .all(|page| !page.is_empty() && page.len() <= page_size));
+        assert!(pages
+            .iter()
+            .rev()
+            .skip(1)
+            .all(|page| page.len() == page_size));
+        assert!(pages.concat().into_iter().eq(emojis::iter()));
+        for group in emojis::Group::iter() {
+            let pages: Vec<_> = group.pages(page_size).collect();
+            assert!(pages.concat().into_iter().eq(group.emojis()));
+        }
+    }
+    assert_eq!(emojis::pages(0).count(), 0);
+    assert_eq!(emojis::Group::Flags.pages(0).count(), 0);
+}
+
+#[test]
+fn emoji_tts() {
+    for emoji in emojis::iter() {
+        assert!(!emoji.tts().is_empty());
+    }
+    assert_eq!(emojis::get("🇿🇦").unwrap().tts(), "flag: South Africa");
+}
+
+#[test]
+#[cfg(feature = "sprite-sheet")]
+#[ignore = "the sprite table has not been generated from emoji-data yet"]
+fn emoji_sprite_position() {
+    use emojis::SpriteSheet;
+
+    let rocket = emojis::get("🚀").unwrap();
+    let pos = rocket.sprite_position(SpriteSheet::Size32).unwrap();
+    assert_eq!(pos.x(), u32::from(pos.column()) * 34 + 1);
+    assert_eq!(pos.y(), u32::from(pos.row()) * 34 + 1);
+
+    let mut seen = std::collections::HashSet::new();
+    for emoji in emojis::iter() {
+        if let Some(pos) = emoji.sprite_position(SpriteSheet::Size16) {
+            assert!(seen.insert((pos.column(), pos.row())), "{}", emoji);
+        }
+    }
+    assert!(seen.len() > 1000);
+}
+
+#[test]
+fn emoji_tts_renamed() {
+    for (emoji, tts) in [
+        ("🤗", "smiling face with open hands"),
+        ("😵", "face with crossed-out eyes"),
+        ("😡", "enraged face"),
+        ("💤", "ZZZ"),
+    ] {
+        assert_eq!(emojis::get(emoji).unwrap().tts(), tts);
+    }
+}
+
+#[test]
+fn emoji_neutral_and_gendered_variants() {
+    for (gendered, neutral) in [
+        ("👮‍♀️", "👮"),
+        ("👮🏿‍♂️", "👮🏿"),
+        ("👨‍⚕️", "🧑‍⚕️"),
+        ("👩🏽‍🚀", "🧑🏽‍🚀"),
+        ("👴🏻", "🧓🏻"),
+        ("🤶", "🧑‍🎄"),
+        ("👫", "🧑‍🤝‍🧑"),
+        ("👩‍❤️‍💋‍👨", "💏"),
+        ("🕺", "🕺"),
+        ("😀", "😀"),
+    ] {
+        assert_eq!(emojis::get(gendered).unwrap().neutral(), neutral);
+    }
+
+    for emoji in emojis::iter() {
+        let variants: Vec<_> = emoji.gendered_variants().collect();
+        assert_eq!(variants[0], emoji.neutral());
+        assert!(variants.contains(&emojis::get(emoji.as_str()).unwrap()));
+        for variant in variants {
+            assert_eq!(variant.neutral(), emoji.neutral());
+        }
+    }
+}
+
+#[test]
+fn emoji_role() {
+    for role in emojis::iter_roles() {
+        assert_eq!(role.emoji().role(), Some(role));
+        for emoji in role.emojis() {
+            assert_eq!(emoji.role(), Some(role));
+            for tone in emoji.skin_tones().into_iter().flatten() {
+                assert_eq!(tone.role(), Some(role));
+            }
+        }
+        let expected = if role == emojis::Role::Ninja { 1 } else { 3 };
+        assert_eq!(role.emojis().count(), expected);
+    }
+    assert_eq!(emojis::get("🤴").unwrap().role(), None);
+}
+
+#[test]
+fn added_between() {
+    use emojis::UnicodeVersion;
+
+    let versions = [
+        UnicodeVersion::new(0, 0),
+        UnicodeVersion::new(1, 0),
+        UnicodeVersion::new(5, 0),
+        UnicodeVersion::new(13, 1),
+        UnicodeVersion::new(15, 0),
+    ];
+    let mut total = 0;
+    for pair in versions.windows(2) {
+        for emoji in emojis::added_between(pair[0], pair[1]) {
+            assert!(pair[0] < emoji.unicode_version() && emoji.unicode_version() <= pair[1]);
+            total += 1;
+        }
+    }
+    assert_eq!(total, emojis::iter().count());
+    assert_eq!(emojis::added_between(versions[4], versions[0]).next(), None);
+}
+
+#[test]
+fn emoji_id() {
+    use emojis::EmojiId;
+    use std::mem::size_of;
+
+    assert_eq!(size_of::<EmojiId>(), 2);
+    assert_eq!(size_of::<Option<EmojiId>>(), 2);
+
+    let mut count = 0;
+    for emoji in emojis::Group::iter().flat_map(|g| g.as_slice()) {
+        let id = emoji.id();
+        assert_eq!(id.emoji(), emoji);
+        assert_eq!(EmojiId::new(id.get().get()), Some(id));
+        count += 1;
+    }
+    assert_eq!(EmojiId::new(0), None);
+    assert!(EmojiId::new(count).is_some());
+    assert_eq!(EmojiId::new(count + 1), None);
+}
+
+#[test]
+fn skin_tone_apply_to_all() {
+    use emojis::SkinTone;
+
+    for emoji in SkinTone::Dark.apply_to_all(emojis::iter()) {
+        match emoji.skin_tones() {
+            Some(_) => assert_eq!(emoji.skin_tone(), Some(SkinTone::Dark), "{emoji:?}"),
+            None => assert_eq!(emoji.skin_tone(), None),
+        }
+    }
+    let defaults: Vec<_> = SkinTone::Default.apply_to_all(emojis::iter()).collect();
+    assert_eq!(defaults, emojis::iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn emoji_variations() {
+    for emoji in emojis::iter().chain(emojis::iter().filter_map(|e| e.skin_tones()).flatten()) {
+        let variations: Vec<_> = emoji.variations().collect();
+        assert_eq!(variations[0], emoji.as_str());
+        for v in &variations {
+            assert_eq!(emojis::get(v), Some(emoji), "{v:?}");
+            assert_eq!(
+                v.replace('\u{fe0f}', ""),
+                emoji.as_str().replace('\u{fe0f}', "")
+            );
+        }
+    }
+
+    let eye: Vec<_> = emojis::get("👁️‍🗨️").unwrap().variations().collect();
+    assert_eq!(eye[0], "👁\u{fe0f}\u{200d}🗨\u{fe0f}");
+    assert!(eye.contains(&"👁\u{200d}🗨"));
+}
+
+#[test]
+fn get_normalized() {
+    for emoji in emojis::iter().chain(emojis::iter().filter_map(|e| e.skin_tones()).flatten()) {
+        let s = emoji.as_str();
+        assert_eq!(emojis::get_normalized(s), Some(emoji));
+        assert_eq!(
+            emojis::get_normalized(&s.replace('\u{fe0f}', "")),
+            Some(emoji)
+        );
+        assert_eq!(
+            emojis::get_normalized(&s.replace('\u{fe0f}', "\u{fe0e}")),
+            Some(emoji)
+        );
+    }
+    assert_eq!(emojis::get_normalized("\u{fe0f}"), None);
+    assert_eq!(emojis::get_normalized("TM"), None);
+    assert_eq!(emojis::get_normalized("🚀🚀"), None);
+}
+
+#[test]
+fn emoji_concept_id() {
+    for emoji in emojis::iter().chain(emojis::iter().filter_map(|e| e.skin_tones()).flatten()) {
+        let concept = emojis::get(emoji.concept_id()).unwrap();
+        assert_eq!(concept.concept_id(), emoji.concept_id());
+        assert!(emoji.same_concept_as(concept));
+        assert!(matches!(
+            concept.skin_tone(),
+            None | Some(emojis::SkinTone::Default)
+        ));
+        for v in emoji.gendered_variants() {
+            assert!(v.same_concept_as(emoji));
+        }
+    }
+    let dancers = ["💃🏽", "🕺"].map(|s| emojis::get(s).unwrap());
+    assert!(!dancers[0].same_concept_as(dancers[1]));
+    let kiss = ["💏🏻", "👩🏾‍❤️‍💋‍👨🏼"].map(|s| emojis::get(s).unwrap());
+    assert!(kiss[0].same_concept_as(kiss[1]));
+}
+
+#[test]
+fn handshake() {
+    use emojis::SkinTone::*;
+
+    let tones = [Default, Light, MediumLight, Medium, MediumDark, Dark];
+    let mut count = 0;
+    for right in tones {
+        for left in tones {
+            let s = match emojis::handshake(right, left) {
+                Some(s) => s,
+                None => {
+                    assert!(right != left && (right == Default || left == Default));
+                    continue;
+                }
+            };
+            count += 1;
+            assert_eq!(emojis::get(s).unwrap().concept_id(), "🤝");
+            assert_eq!(
+                emojis::detect_skin_tone(s),
+                (right != Default).then(|| right)
+            );
+        }
+    }
+    assert_eq!(count, 26);
+}
+
+#[test]
+fn couple_with_heart() {
+    use emojis::Gender::*;
+    use emojis::SkinTone::*;
+
+    let genders = [Neutral, Male, Female];
+    let tones = [Default, Light, MediumLight, Medium, MediumDark, Dark];
+    let mut count = 0;
+    for g1 in genders {
+        for t1 in tones {
+            for g2 in genders {
+                for t2 in tones {
+                    if let Some(s) = emojis::couple_with_heart(g1, t1, g2, t2) {
+                        count += 1;
+                        let emoji = emojis::get(s).unwrap();
+                        assert!(emoji.name().starts_with("couple with heart"));
+                        assert!(emoji.same_concept_as(emojis::get("💑").unwrap()));
+                    }
+                }
+            }
+        }
+    }
+    // 26 neutral, and 26 for each of woman-man, man-man, and woman-woman.
+    assert_eq!(count, 4 * 26);
+}
+
+#[test]
+fn regional_indicators() {
+    for (i, letter) in ('A'..='Z').enumerate() {
+        let c = emojis::regional_indicator(letter).unwrap();
+        assert_eq!(c as u32, 0x1f1e6 + i as u32);
+        assert_eq!(
+            emojis::regional_indicator(letter.to_ascii_lowercase()),
+            Some(c)
+        );
+    }
+    for c in ['@', '[', '`', '{', 'é', '🇦'] {
+        assert_eq!(emojis::regional_indicator(c), None);
+    }
+
+    let flags: Vec<_> = emojis::Subgroup::CountryFlag.emojis().to_vec();
+    assert_eq!(flags.len(), 258);
+    for flag in flags {
+        let [a, b] = emojis::flag_to_letters(flag.as_str()).unwrap();
+        let code: String = [a, b].iter().collect();
+        assert_eq!(emojis::letters_to_flag(&code), Some(flag));
+    }
+    for code in ["", "J", "JPN", "J1", "🇯🇵"] {
+        assert_eq!(emojis::letters_to_flag(code), None);
+    }
+    for flag in ["", "🇯", "🇯🇵🇯", "JP", "🇯\u{fe0f}"] {
+        assert_eq!(emojis::flag_to_letters(flag), None);
+    }
+}
+
+#[test]
+fn spell() {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let ri = |c| emojis::regional_indicator(c).unwrap();
+    let spelled: Vec<_> = emojis::spell("OK go").collect();
+    assert_eq!(spelled, [ri('O'), ri('K'), ' ', ri('G'), ri('O')]);
+
+    // 🇺🇸 and 🇸🇦 are both flags.
+    let spelled: String = emojis::spell("USA").flag_separator(Some('|')).collect();
+    assert_eq!(spelled, "🇺|🇸|🇦");
+    let spelled = emojis::spell("USA").flag_separator(None).to_string();
+    assert_eq!(spelled, "🇺🇸🇦");
+    // 🇰🇺 would be a flag, but 🇰 is displayed with 🇴 so only 🇺🇸 is separated.
+    let spelled = emojis::spell("OKUS").flag_separator(Some('|')).to_string();
+    assert_eq!(spelled, "🇴🇰🇺|🇸");
+    assert_eq!(emojis::spell("a-u").to_string(), "🇦-🇺");
+
+    for text in ["", "hello world", "UNITED STATES", "ñandú", "usausausa"] {
+        let spelled = emojis::spell(text).to_string();
+        // Regional indicators are displayed in pairs from the start of a run.
+        for grapheme in spelled.graphemes(true) {
+            assert_eq!(emojis::get(grapheme), None, "{grapheme}");
+        }
+        let letters: String = spelled
+            .chars()
+            .filter(|&c| c != '\u{200b}')
+            .map(|c| match (c as u32).checked_sub(0x1f1e6) {
+                Some(offset) => char::from_u32('A' as u32 + offset).unwrap(),
+                None => c,
+            })
+            .collect();
+        assert_eq!(letters, text.to_ascii_uppercase());
+    }
+}
+
+#[test]
+fn group_and_subgroup_integers() {
+    use emojis::{Group, Subgroup};
+    use std::convert::TryFrom;
+
+    assert_eq!(Group::ALL.len(), 9);
+    for (i, &group) in Group::ALL.iter().enumerate() {
+        assert_eq!(group as usize, i);
+        assert_eq!(Group::try_from(i as u8), Ok(group));
+    }
+    assert_eq!(Group::LATEST_KNOWN, Group::Flags);
+    let err = Group::try_from(Group::LATEST_KNOWN as u8 + 1).unwrap_err();
+    assert_eq!(err.value(), 9);
+    assert_eq!(err.to_string(), "unknown group or subgroup `9`");
+
+    assert!(Subgroup::ALL.len() < 256);
+    for (i, &subgroup) in Subgroup::ALL.iter().enumerate() {
+        assert_eq!(subgroup as usize, i);
+        assert_eq!(Subgroup::try_from(i as u8), Ok(subgroup));
+    }
+    assert_eq!(Subgroup::LATEST_KNOWN, Subgroup::SubdivisionFlag);
+    assert!(Subgroup::try_from(Subgroup::LATEST_KNOWN as u8 + 1).is_err());
+    assert!(Subgroup::try_from(u8::MAX).is_err());
+}
+
+#[test]
+fn emoji_order_key() {
+    let all: Vec<_> = emojis::Group::iter()
+        .flat_map(|g| g.as_slice())
+        .map(|e| e.order_key())
+        .collect();
+    assert_eq!(all, (0..all.len() as u32).collect::<Vec<_>>());
+    for emoji in emojis::iter() {
+        if let Some(tones) = emoji.skin_tones() {
+            let keys: Vec<_> = tones.map(|e| e.order_key()).collect();
+            assert!(keys.windows(2).all(|w| w[0] < w[1]));
+            assert_eq!(keys[0], emoji.order_key());
+        }
+    }
+}
+
+#[test]
+fn sort() {
+    let mut all: Vec<_> = emojis::Group::iter().flat_map(|g| g.as_slice()).collect();
+    all.reverse();
+    assert!(!emojis::is_sorted(&all));
+    emojis::sort(&mut all);
+    assert!(emojis::is_sorted(&all));
+    assert_eq!(
+        all,
+        emojis::Group::iter()
+            .flat_map(|g| g.as_slice())
+            .collect::<Vec<_>>()
+    );
+
+    assert!(!emojis::is_sorted_by_name(&all));
+    emojis::sort_by_name(&mut all);
+    assert!(emojis::is_sorted_by_name(&all));
+    assert_eq!(all[0].name(), "1st place medal");
+
+    let mut empty: [&emojis::Emoji; 0] = [];
+    emojis::sort(&mut empty);
+    assert!(emojis::is_sorted(&empty) && emojis::is_sorted_by_name(&empty));
+}
+
+#[test]
+fn get_char() {
+    for c in (0..=0x10ffff).filter_map(char::from_u32) {
+        let s = c.encode_utf8(&mut [0; 4]).to_owned();
+        assert_eq!(emojis::get_char(c), emojis::get(&s), "{c:?}");
+    }
+}
+
+#[test]
+fn emoji_write_to_and_copy_to_slice() {
+    struct Fixed<const N: usize> {
+        buf: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> std::fmt::Write for Fixed<N> {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            let end = self.len + s.len();
+            self.buf
+                .get_mut(self.len..end)
+                .ok_or(std::fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    for emoji in emojis::Group::iter().flat_map(|g| g.as_slice()) {
+        let mut w = Fixed {
+            buf: [0; 35],
+            len: 0,
+        };
+        emoji.write_to(&mut w).unwrap();
+        assert_eq!(&w.buf[..w.len], emoji.as_bytes());
+
+        let mut buf = [0; 35];
+        assert_eq!(emoji.copy_to_slice(&mut buf), Ok(emoji.as_bytes().len()));
+        assert_eq!(&buf[..emoji.as_bytes().len()], emoji.as_bytes());
+
+        let mut small = vec![0; emoji.as_bytes().len() - 1];
+        let err = emoji.copy_to_slice(&mut small).unwrap_err();
+        assert_eq!(err.required(), emoji.as_bytes().len());
+        assert!(small.iter().all(|&b| b == 0));
+    }
+
+    let mut w = Fixed {
+        buf: [0; 3],
+        len: 0,
+    };
+    assert!(emojis::get("🚀").unwrap().write_to(&mut w).is_err());
+}
+
+#[test]
+fn emoji_buf() {
+    use emojis::EmojiBuf;
+
+    for emoji in emojis::Group::iter().flat_map(|g| g.as_slice()) {
+        let buf = EmojiBuf::from(emoji);
+        assert_eq!(buf.as_str(), emoji.as_str());
+        assert_eq!(buf.as_bytes(), emoji.as_bytes());
+        assert_eq!(buf.emoji(), emoji);
+        assert_eq!(&*buf, emoji);
+        assert_eq!(buf.to_string(), emoji.to_string());
+    }
+    let a = EmojiBuf::new(emojis::get("🚀").unwrap());
+    let b = a;
+    assert_eq!(a, b);
+    assert_ne!(a, EmojiBuf::new(emojis::get("🎉").unwrap()));
+    assert_eq!(format!("{a:?}"), "EmojiBuf(\"🚀\")");
+    assert_eq!(std::mem::size_of::<EmojiBuf>(), 32);
+}
+
+#[test]
+fn get_by_shortcode_slack_skin_tone() {
+    use emojis::SkinTone;
+
+    let tones = [
+        SkinTone::Light,
+        SkinTone::MediumLight,
+        SkinTone::Medium,
+        SkinTone::MediumDark,
+        SkinTone::Dark,
+    ];
+    for emoji in emojis::iter() {
+        for shortcode in emoji.shortcodes() {
+            for (n, tone) in (2..).zip(tones) {
+                let slack = format!("{shortcode}::skin-tone-{n}");
+                assert_eq!(emojis::get_by_shortcode(&slack), emoji.with_skin_tone(tone));
+            }
+        }
+    }
+    assert_eq!(emojis::get_by_shortcode("wave::skin-tone-1"), None);
+    assert_eq!(emojis::get_by_shortcode("wave::skin-tone-7"), None);
+    assert_eq!(emojis::get_by_shortcode("::skin-tone-2"), None);
+}
+
+#[test]
+fn dataset_fingerprint() {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let ids = (1..).map_while(emojis::EmojiId::new);
+    for byte in ids.flat_map(|id| id.emoji().as_str().bytes().chain([0xff])) {
+        hash = (hash ^ u64::from(byte)).wrapping_mul(0x100_0000_01b3);
+    }
+    assert_eq!(emojis::dataset_fingerprint(), hash);
+}
+
+#[test]
+fn migrate_from() {
+    use emojis::EmojiId;
+
+    let current = emojis::dataset_fingerprint();
+    for id in (1..).map_while(EmojiId::new) {
+        assert_eq!(EmojiId::migrate_from(current, id.get().get()), Some(id));
+    }
+    assert_eq!(EmojiId::migrate_from(current, 0), None);
+    for migration in emojis::migrations::iter() {
+        assert_ne!(migration.fingerprint(), current);
+        assert!(emojis::migrations::find(migration.fingerprint()).is_some());
+    }
+}
+
+#[cfg(feature = "source-lines")]
+#[test]
+fn source_line() {
+    for emoji in emojis::iter().flat_map(|e| e.skin_tones().into_iter().flatten().chain([e])) {
+        let line = emoji.source_line();
+        let (code_points, rest) = line.split_once(';').unwrap();
+        let s: String = code_points
+            .split_whitespace()
+            .map(|c| char::from_u32(u32::from_str_radix(c, 16).unwrap()).unwrap())
+            .collect();
+        assert_eq!(s, emoji.as_str());
+        let (status, comment) = rest.split_once('#').unwrap();
+        assert_eq!(status.trim(), "fully-qualified");
+        assert!(comment.ends_with(emoji.name()), "{line}");
+    }
+}
+
+#[cfg(all(feature = "source-lines", feature = "compressed-data"))]
+#[test]
+fn source_line_compressed_threads() {
+    let rocket = emojis::get("🚀").unwrap();
+    let lines: Vec<_> = (0..4)
+        .map(|_| std::thread::spawn(move || rocket.source_line()))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|t| t.join().unwrap())
+        .collect();
+    for line in lines {
+        assert_eq!(line, rocket.source_line());
+        assert!(line.ends_with("# 🚀 E0.6 rocket"));
+    }
+}
+
+#[cfg(feature = "descriptions")]
+#[test]
+fn description() {
+    let mut described = 0;
+    for emoji in emojis::iter() {
+        if let Some(description) = emoji.description() {
+            described += 1;
+            assert!(description.ends_with('.'), "{description}");
+            for toned in emoji.skin_tones().into_iter().flatten() {
+                assert_eq!(toned.description(), Some(description));
+            }
+        }
+    }
+    assert!(described > 0);
+    assert_eq!(emojis::get("🦄").unwrap().description(), None);
+}
+
+#[test]
+fn introduced_year() {
+    for emoji in emojis::iter().flat_map(|e| e.skin_tones().into_iter().flatten().chain([e])) {
+        let year = emoji.unicode_version().release_year();
+        assert_eq!(year, Some(emoji.introduced_year()), "{emoji:?}");
+        assert!((2010..=2030).contains(&emoji.introduced_year()));
+    }
+    assert_eq!(emojis::get("😀").unwrap().introduced_year(), 2015);
+    assert_eq!(emojis::get("🚀").unwrap().introduced_year(), 2010);
+}
+
+#[test]
+fn iter_skin_tone_bases() {
+    let bases: Vec<_> = emojis::iter_skin_tone_bases().collect();
+    let expected: Vec<_> = emojis::iter()
+        .filter(|e| e.skin_tone() == Some(emojis::SkinTone::Default))
+        .collect();
+    assert_eq!(bases, expected);
+    assert!(bases.contains(&emojis::get("👍").unwrap()));
+    assert!(!bases.contains(&emojis::get("😀").unwrap()));
+}
+
+#[test]
+fn group_emojis_with() {
+    use emojis::{Group, IterOptions, UnicodeVersion};
+
+    for group in Group::iter() {
+        assert!(group.emojis_with(IterOptions::default()).eq(group.emojis()));
+        let all = IterOptions::new().skin_tones(true);
+        assert!(group.emojis_with(all).eq(group.as_slice().iter()));
+    }
+
+    let v = UnicodeVersion::new(12, 0);
+    let options = IterOptions::new()
+        .gendered_variants(false)
+        .max_unicode_version(v);
+    for emoji in Group::PeopleAndBody.emojis_with(options) {
+        assert!(emoji.unicode_version() <= v);
+        assert_eq!(emoji.neutral(), emoji);
+        assert_eq!(
+            emoji.skin_tone(),
+            emoji.skin_tone().map(|_| emojis::SkinTone::Default)
+        );
+    }
+}
+
+#[test]
+fn name_words_and_title_name() {
+    let blond = emojis::get("👱🏼").unwrap();
+    let words: Vec<_> = blond.name_words().collect();
+    assert_eq!(
+        words,
+        ["person", "medium-light", "skin", "tone", "blond", "hair"]
+    );
+    assert_eq!(
+        blond.title_name().to_string(),
+        "Person: Medium-Light Skin Tone, Blond Hair"
+    );
+    let button = emojis::get("🅰️").unwrap();
+    assert_eq!(button.title_name().to_string(), "A Button (Blood Type)");
+
+    for emoji in emojis::iter() {
+        let title = emoji.title_name().to_string();
+        assert_eq!(title.to_lowercase(), emoji.name().to_lowercase());
+        assert!(emoji
+            .name_words()
+            .all(|w| !w.is_empty() && !w.contains(' ')));
+    }
+}
+
+#[test]
+fn dump() {
+    let records: Vec<_> = emojis::dump().collect();
+    let total: usize = emojis::iter()
+        .map(|e| e.skin_tones().map_or(1, Iterator::count))
+        .sum();
+    assert_eq!(records.len(), total);
+    for (i, record) in records.iter().enumerate() {
+        let emoji = record.to_emoji();
+        assert_eq!(emoji.order_key() as usize, i);
+        assert_eq!(record.emoji, emoji.as_str());
+        assert_eq!(record.group, record.subgroup.group());
+        assert!(record.shortcodes.iter().copied().eq(emoji.shortcodes()));
+        let base = record.skin_tone_base.map_or(emoji, |id| id.emoji());
+        assert!(record.subgroup.emojis().contains(&base));
+    }
+}
+
+#[test]
+fn series() {
+    use emojis::{MoonPhase, ZodiacSign};
+
+    let clocks = emojis::Subgroup::Time.emojis();
+    let faces: Vec<_> = clocks
+        .iter()
+        .filter(|e| e.name().contains("o’clock") || e.name().contains("-thirty"))
+        .collect();
+    assert_eq!(faces.len(), 24);
+    let words = [
+        "twelve", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        "eleven",
+    ];
+    for hour in 0..24 {
+        for half in [false, true] {
+            let face = emojis::clock(hour, half).unwrap();
+            let word = face.name().split(['-', ' ']).next().unwrap();
+            assert_eq!(word, words[usize::from(hour % 12)]);
+            assert_eq!(emojis::clock(hour % 12, half), Some(face));
+            assert_eq!(face.name().ends_with("-thirty"), half, "{}", face.name());
+        }
+    }
+    assert!(emojis::clock(u8::MAX, true).is_none());
+
+    assert_eq!(emojis::moon_phase(MoonPhase::NewMoon).name(), "new moon");
+    assert_eq!(
+        emojis::moon_phase(MoonPhase::LastQuarter).name(),
+        "last quarter moon"
+    );
+    assert_eq!(emojis::zodiac(ZodiacSign::Aries).name(), "Aries");
+    assert_eq!(emojis::zodiac(ZodiacSign::Pisces).name(), "Pisces");
+    assert_eq!(emojis::zodiac(ZodiacSign::Ophiuchus).name(), "Ophiuchus");
+}
+
+#[test]
+fn digits() {
+    for n in [0, 1, 9, 10, 42, 100, 907, 123_456_789, u64::MAX] {
+        let expected = n.to_string();
+        let keycaps: Vec<_> = emojis::digits(n).collect();
+        assert_eq!(keycaps.len(), expected.len());
+        assert_eq!(emojis::digits(n).len(), expected.len());
+        for (keycap, digit) in keycaps.iter().zip(expected.chars()) {
+            assert_eq!(keycap.as_str().chars().next(), Some(digit));
+            assert!(emojis::Subgroup::Keycap.emojis().contains(keycap));
+        }
+        let rendered = emojis::digits(n).to_string();
+        assert_eq!(rendered.replace("\u{fe0f}\u{20e3}", ""), expected);
+    }
+}
+
+#[cfg(feature = "colors")]
+#[test]
+fn color() {
+    use emojis::Color;
+
+    let squares = ["🟥", "🟧", "🟨", "🟩", "🟦", "🟪", "🟫", "⬛", "⬜"];
+    let colors = [
+        Color::Red,
+        Color::Orange,
+        Color::Yellow,
+        Color::Green,
+        Color::Blue,
+        Color::Purple,
+        Color::Brown,
+        Color::Black,
+        Color::White,
+    ];
+    for (square, color) in squares.iter().zip(colors) {
+        assert_eq!(emojis::get(square).unwrap().color(), Some(color));
+        assert!(emojis::with_color(color).any(|e| e == *square));
+    }
+    assert_eq!(emojis::get("🩷").unwrap().color(), Some(Color::Pink));
+    assert_eq!(emojis::get("🩶").unwrap().color(), Some(Color::Grey));
+    assert_eq!(emojis::get("🧑‍🦰").unwrap().color(), None);
+
+    for emoji in emojis::iter() {
+        if let Some(color) = emoji.color() {
+            assert!(emojis::with_color(color).any(|e| e == emoji));
+        }
+    }
+    let red: Vec<_> = emojis::with_color(Color::Red).collect();
+    assert!(red.windows(2).all(|w| w[0].id() < w[1].id()));
+}
+
+#[test]
+fn color_families() {
+    use emojis::Color;
+
+    let colors = [
+        Color::Red,
+        Color::Orange,
+        Color::Yellow,
+        Color::Green,
+        Color::Blue,
+        Color::Purple,
+        Color::Pink,
+        Color::Brown,
+        Color::Black,
+        Color::Grey,
+        Color::White,
+    ];
+    for color in colors {
+        let heart = emojis::heart(color);
+        assert!(heart.name().ends_with(" heart"), "{}", heart.name());
+        let name = format!("{color:?}").to_lowercase();
+        assert!(heart.name().starts_with(&name), "{}", heart.name());
+        for emoji in [emojis::square(color), emojis::circle(color)]
+            .into_iter()
+            .flatten()
+        {
+            assert!(emoji.name().starts_with(&name), "{}", emoji.name());
+        }
+        let shapes = emojis::square(color).is_some() && emojis::circle(color).is_some();
+        assert_eq!(shapes, !matches!(color, Color::Pink | Color::Grey));
+    }
+    let medals: Vec<_> = (1..=3)
+        .filter_map(emojis::medal)
+        .map(|e| e.name())
+        .collect();
+    assert_eq!(
+        medals,
+        ["1st place medal", "2nd place medal", "3rd place medal"]
+    );
+}
+
+#[test]
+fn semantic() {
+    use emojis::{TransportMode, WeatherCondition};
+
+    let conditions = [
+        WeatherCondition::Clear,
+        WeatherCondition::ClearNight,
+        WeatherCondition::MostlyClear,
+        WeatherCondition::PartlyCloudy,
+        WeatherCondition::MostlyCloudy,
+        WeatherCondition::Cloudy,
+        WeatherCondition::Showers,
+        WeatherCondition::Rain,
+        WeatherCondition::Thunderstorm,
+        WeatherCondition::Snow,
+        WeatherCondition::Fog,
+        WeatherCondition::Wind,
+        WeatherCondition::Tornado,
+    ];
+    let emojis: Vec<_> = conditions.iter().map(|&c| emojis::weather(c)).collect();
+    assert!(emojis
+        .iter()
+        .all(|e| e.group() == emojis::Group::TravelAndPlaces));
+    assert_eq!(emojis::weather(WeatherCondition::Fog).name(), "fog");
+    assert_eq!(
+        emojis::weather(WeatherCondition::Snow).name(),
+        "cloud with snow"
+    );
+
+    let modes = [
+        TransportMode::Walk,
+        TransportMode::Bicycle,
+        TransportMode::Scooter,
+        TransportMode::Motorcycle,
+        TransportMode::Car,
+        TransportMode::Taxi,
+        TransportMode::Bus,
+        TransportMode::Tram,
+        TransportMode::Train,
+        TransportMode::Subway,
+        TransportMode::Ship,
+        TransportMode::Ferry,
+        TransportMode::Airplane,
+        TransportMode::Helicopter,
+    ];
+    let names: Vec<_> = modes.iter().map(|&m| emojis::transport(m).name()).collect();
+    assert_eq!(
+        names,
+        [
+            "person walking",
+            "bicycle",
+            "kick scooter",
+            "motorcycle",
+            "automobile",
+            "taxi",
+            "bus",
+            "tram",
+            "train",
+            "metro",
+            "ship",
+            "ferry",
+            "airplane",
+            "helicopter"
+        ]
+    );
+}
+
+#[test]
+fn presets() {
+    use emojis::presets::{self, Level, Status, Style};
+
+    let statuses = [
+        Status::Success,
+        Status::Info,
+        Status::Warning,
+        Status::Error,
+    ];
+    let levels = [
+        Level::Trace,
+        Level::Debug,
+        Level::Info,
+        Level::Warn,
+        Level::Error,
+    ];
+    let expected = [
+        (
+            Style::Symbols,
+            ["✅", "ℹ️", "⚠️", "❌"],
+            ["🔍", "🐛", "ℹ️", "⚠️", "❌"],
+            ["☑️", "⬜"],
+        ),
+        (
+            Style::Circles,
+            ["🟢", "🔵", "🟡", "🔴"],
+            ["⚪", "🟣", "🔵", "🟡", "🔴"],
+            ["🟢", "⚪"],
+        ),
+        (
+            Style::Squares,
+            ["🟩", "🟦", "🟨", "🟥"],
+            ["⬜", "🟪", "🟦", "🟨", "🟥"],
+            ["🟩", "⬜"],
+        ),
+    ];
+    for (style, status, level, checkbox) in expected {
+        let found: Vec<_> = statuses.iter().map(|&s| style.status(s).as_str()).collect();
+        assert_eq!(found, status);
+        let found: Vec<_> = levels.iter().map(|&l| style.level(l).as_str()).collect();
+        assert_eq!(found, level);
+        assert_eq!(style.checkbox(true), checkbox[0]);
+        assert_eq!(style.checkbox(false), checkbox[1]);
+    }
+    assert_eq!(
+        presets::status(Status::Error),
+        Style::default().status(Status::Error)
+    );
+    assert_eq!(presets::level(Level::Info), "ℹ️");
+    assert_eq!(presets::checkbox(false), "⬜");
+}
+
+#[test]
+fn iter_by_len() {
+    let mut count = 0;
+    let mut last = 0;
+    for (len, emojis) in emojis::iter_by_len() {
+        assert!(len > last);
+        last = len;
+        let mut any = false;
+        for emoji in emojis {
+            assert_eq!(emoji.utf8_len(), len);
+            assert_eq!(emoji.as_str().len(), len);
+            any = true;
+            count += 1;
+        }
+        assert!(any);
+    }
+    assert_eq!(last, emojis::MAX_EMOJI_BYTES);
+    let all = emojis::iter()
+        .map(|e| e.skin_tones().map_or(1, Iterator::count))
+        .sum::<usize>();
+    assert_eq!(count, all);
+}
+
+emojis::assert_has!("🫠", "👍🏽", "🇿🇦");
+#[cfg(feature = "shortcodes")]
+emojis::assert_has_shortcode!("melting_face");
+
+#[test]
+fn assert_has() {
+    emojis::assert_has!("🚀");
+    #[cfg(feature = "shortcodes")]
+    emojis::assert_has_shortcode!("rocket", "+1");
+}
+
+#[test]
+fn meta_table_sizes() {
+    use emojis::meta;
+
+    let tables: Vec<_> = meta::table_sizes().collect();
+    for table in &tables {
+        assert_eq!(
+            tables.iter().filter(|t| t.name() == table.name()).count(),
+            1
+        );
+    }
+    assert_eq!(
+        meta::total_bytes(),
+        tables.iter().map(|t| t.bytes()).sum::<usize>()
+    );
+
+    let emojis = tables.iter().find(|t| t.name() == "emojis").unwrap();
+    let strings: usize = emojis::iter()
+        .flat_map(|e| e.skin_tones().map_or_else(|| vec![e], Iterator::collect))
+        .map(|e| e.as_str().len() + e.name().len())
+        .sum();
+    assert!(emojis.bytes() > strings);
+
+    assert_eq!(
+        tables.iter().any(|t| t.name() == "descriptions"),
+        cfg!(feature = "descriptions")
+    );
+    assert!(tables
+        .iter()
+        .filter(|t| t.name() == "descriptions")
+        .all(|t| t.feature() == Some("descriptions")));
+}
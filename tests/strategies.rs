@@ -0,0 +1,44 @@
+#![cfg(feature = "proptest")]
+
+use emojis::strategies::{emoji, text_with_emojis, toned_emoji};
+use emojis::SkinTone;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn strategies_emoji(e in emoji()) {
+        prop_assert_eq!(emojis::get(e.as_str()), Some(e));
+        prop_assert!(matches!(e.skin_tone(), None | Some(SkinTone::Default)));
+    }
+
+    #[test]
+    fn strategies_toned_emoji(e in toned_emoji()) {
+        prop_assert_eq!(emojis::get(e.as_str()), Some(e));
+        prop_assert!(!matches!(e.skin_tone(), None | Some(SkinTone::Default)));
+    }
+
+    #[test]
+    fn strategies_text_with_emojis(text in text_with_emojis(32)) {
+        let mut last = 0;
+        for (start, s) in emojis::pattern().find_in(&text) {
+            prop_assert!(start >= last);
+            prop_assert!(emojis::get(s).is_some());
+            last = start + s.len();
+        }
+    }
+}
+
+#[test]
+fn strategies_text_with_emojis_contains_emojis() {
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    let mut runner = TestRunner::deterministic();
+    let strategy = text_with_emojis(8);
+    let mut found = 0;
+    for _ in 0..64 {
+        let text = strategy.new_tree(&mut runner).unwrap().current();
+        found += emojis::pattern().find_in(&text).count();
+    }
+    assert!(found > 64);
+}
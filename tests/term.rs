@@ -0,0 +1,68 @@
+use emojis::term::{self, Capability};
+
+fn render(emoji: &str, capability: Capability) -> String {
+    term::render(emojis::get(emoji).unwrap(), capability).to_string()
+}
+
+/// Returns every emoji including skin tone variations.
+fn all() -> impl Iterator<Item = &'static emojis::Emoji> {
+    emojis::iter().flat_map(|e| e.skin_tones().map_or_else(|| vec![e], Iterator::collect))
+}
+
+#[cfg(feature = "shortcodes")]
+#[test]
+fn render_fallbacks() {
+    assert_eq!(render("🚀", Capability::Emoji), "🚀");
+    assert_eq!(render("🚀", Capability::TextPresentation), ":rocket:");
+    assert_eq!(render("🚀", Capability::Shortcodes), ":rocket:");
+    assert_eq!(render("🚀", Capability::Ascii), ":rocket:");
+
+    assert_eq!(render("\u{2764}", Capability::Emoji), "❤️");
+    assert_eq!(render("❤️", Capability::TextPresentation), "❤\u{fe0e}");
+    assert_eq!(render("❤️", Capability::Shortcodes), ":heart:");
+    assert_eq!(render("❤️", Capability::Ascii), "<3");
+
+    assert_eq!(
+        render("🙂", Capability::Shortcodes),
+        ":slightly_smiling_face:"
+    );
+    assert_eq!(render("🙂", Capability::Ascii), ":)");
+}
+
+#[test]
+fn render_without_shortcode() {
+    assert_eq!(render("🫨", Capability::Shortcodes), "[shaking face]");
+    assert_eq!(render("🫨", Capability::Ascii), "[shaking face]");
+    assert_eq!(
+        render("👋🏻", Capability::Ascii),
+        "[waving hand: light skin tone]"
+    );
+}
+
+#[test]
+fn render_ascii_is_ascii() {
+    for emoji in all() {
+        let rendered = term::render(emoji, Capability::Ascii).to_string();
+        assert!(rendered.is_ascii(), "{emoji}: {rendered}");
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn detect() {
+    // The environment is process-wide so everything is checked in one test.
+    for name in ["LC_ALL", "LC_CTYPE", "LANG", "TERM", "WT_SESSION"] {
+        std::env::remove_var(name);
+    }
+    std::env::set_var("WT_SESSION", "1");
+    assert_eq!(Capability::detect(), Capability::Emoji);
+    std::env::set_var("LANG", "en_US.UTF-8");
+    assert_eq!(Capability::detect(), Capability::Emoji);
+    std::env::set_var("TERM", "linux");
+    assert_eq!(Capability::detect(), Capability::Shortcodes);
+    std::env::set_var("TERM", "dumb");
+    assert_eq!(Capability::detect(), Capability::Ascii);
+    std::env::set_var("TERM", "xterm-256color");
+    std::env::set_var("LC_ALL", "C");
+    assert_eq!(Capability::detect(), Capability::Ascii);
+}
@@ -0,0 +1,456 @@
+#[cfg(feature = "alloc")]
+use emojis::Group;
+
+#[test]
+#[cfg(feature = "alloc")]
+fn strip_groups() {
+    let tests = [
+        ("nothing here", "nothing here"),
+        ("🇿🇦🇿🇦", ""),
+        ("a🏴‍☠️b", "ab"),
+        ("keep 😀 drop 👍🏽", "keep 😀 drop "),
+        ("1️⃣ 2 #", " 2 #"),
+    ];
+    for (i, o) in tests {
+        let got = emojis::strip_groups(i, &[Group::Flags, Group::PeopleAndBody, Group::Symbols]);
+        assert_eq!(got, o);
+    }
+    assert!(matches!(
+        emojis::strip_groups("😀", &[Group::Flags]),
+        std::borrow::Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn strip_set() {
+    let set: emojis::EmojiSet = emojis::get("👍🏽").into_iter().collect();
+    assert_eq!(emojis::strip_set("👍 👍🏽 👍🏿", &set), "👍  👍🏿");
+}
+
+#[test]
+fn sms_units() {
+    let tests = [("", 0, 1), ("hello", 5, 1), ("👍🏽", 4, 1), ("🏴‍☠️", 5, 1)];
+    for (text, units, segments) in tests {
+        let cost = emojis::sms_units(text);
+        assert_eq!(
+            (cost.units(), cost.segments()),
+            (units, segments),
+            "{}",
+            text
+        );
+    }
+
+    // 35 rockets exactly fill a single segment.
+    assert_eq!(emojis::sms_units(&"🚀".repeat(35)).segments(), 1);
+
+    // A 5 unit pirate flag does not fit after 64 units and starts a new
+    // segment.
+    let text = format!("{}🏴‍☠️", "a".repeat(64));
+    let cost = emojis::sms_units(&text);
+    assert_eq!((cost.units(), cost.segments()), (69, 1));
+    let text = format!("{}🏴‍☠️", "a".repeat(66));
+    let cost = emojis::sms_units(&text);
+    assert_eq!((cost.units(), cost.segments()), (71, 2));
+    let text = format!("{}🏴‍☠️{}", "a".repeat(63), "a".repeat(63));
+    let cost = emojis::sms_units(&text);
+    assert_eq!((cost.units(), cost.segments()), (131, 3));
+}
+
+#[test]
+fn weighted_len() {
+    use emojis::WeightConfig;
+
+    let tests = [
+        ("", 0),
+        ("hello", 5),
+        ("🚀", 2),
+        ("🏴‍☠️ ahoy", 7),
+        ("1️⃣2", 3),
+        ("👍🏽👍", 4),
+    ];
+    for (text, len) in tests {
+        assert_eq!(emojis::weighted_len(text, WeightConfig::default()), len);
+    }
+    assert_eq!(emojis::weighted_len("a🚀b", WeightConfig::new(10, 0)), 10);
+}
+
+#[test]
+fn grapheme_count() {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    for emoji in emojis::Group::iter().flat_map(|g| g.as_slice()) {
+        let text = format!("a{}e\u{301} {}\r\n", emoji, emoji);
+        assert_eq!(
+            emojis::grapheme_count(&text),
+            text.graphemes(true).count(),
+            "{}",
+            emoji.name()
+        );
+    }
+    assert_eq!(emojis::grapheme_count(""), 0);
+    assert_eq!(emojis::grapheme_count("\u{301}"), 1);
+}
+
+#[test]
+fn truncate() {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    // Every prefix is a whole number of graphemes.
+    for emoji in emojis::Group::iter().flat_map(|g| g.as_slice()) {
+        let text = format!("a{}e\u{301}{}", emoji, emoji);
+        let graphemes: Vec<_> = text.graphemes(true).collect();
+        for cells in 0..8 {
+            let (prefix, truncated) = emojis::truncate(&text, cells);
+            assert_eq!(truncated, prefix.len() < text.len());
+            let n = prefix.graphemes(true).count();
+            assert_eq!(graphemes[..n].concat(), prefix, "{}", emoji.name());
+        }
+    }
+
+    assert_eq!(emojis::truncate("", 0), ("", false));
+    assert_eq!(emojis::truncate("abc", 0), ("", true));
+    assert_eq!(emojis::truncate("abc", 3), ("abc", false));
+    assert_eq!(emojis::truncate("🇿🇦🇺🇸", 3), ("🇿🇦", true));
+    assert_eq!(emojis::truncate("日本語", 5), ("日本", true));
+    assert_eq!(emojis::truncate("cafe\u{301}!", 4), ("cafe\u{301}", true));
+
+    // Escape sequences take no cells and are never split.
+    assert_eq!(
+        emojis::truncate("\x1b[31mred\x1b[0m", 2),
+        ("\x1b[31mre", true)
+    );
+    assert_eq!(
+        emojis::truncate("\x1b]8;;https://a.io\x1b\\link\x1b]8;;\x07", 4),
+        ("\x1b]8;;https://a.io\x1b\\link\x1b]8;;\x07", false)
+    );
+    assert_eq!(emojis::truncate("ok\x1b[31", 2), ("ok\x1b[31", false));
+}
+
+#[test]
+fn wrap_indices() {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    fn lines(text: &str, width: usize) -> Vec<&str> {
+        let mut last = 0;
+        let mut lines: Vec<_> = emojis::wrap_indices(text, width)
+            .map(|i| {
+                let line = &text[last..i];
+                last = i;
+                line
+            })
+            .collect();
+        lines.push(&text[last..]);
+        lines
+    }
+
+    // Every line is a whole number of graphemes that fits.
+    for emoji in emojis::Group::iter().flat_map(|g| g.as_slice()) {
+        let text = format!("a{}e\u{301}{}b", emoji, emoji);
+        for width in 1..6 {
+            for line in lines(&text, width) {
+                let (fits, _) = emojis::truncate(line, width.max(2));
+                assert_eq!(fits, line, "{}", emoji.name());
+            }
+        }
+        let breaks: Vec<_> = emojis::wrap_indices(&text, 1).collect();
+        let graphemes: Vec<_> = text
+            .grapheme_indices(true)
+            .skip(1)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(breaks, graphemes, "{}", emoji.name());
+    }
+
+    assert_eq!(lines("", 3), [""]);
+    assert_eq!(lines("abcdefg", 3), ["abc", "def", "g"]);
+    assert_eq!(lines("abcdef", 3), ["abc", "def"]);
+    assert_eq!(lines("🚀🚀🚀", 5), ["🚀🚀", "🚀"]);
+    assert_eq!(lines("a🚀b", 1), ["a", "🚀", "b"]);
+    assert_eq!(lines("ab", 0), ["a", "b"]);
+    assert_eq!(lines("\x1b[1mab\x1b[0mcd", 2), ["\x1b[1mab\x1b[0m", "cd"]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn filter() {
+    use emojis::{EmojiSet, Filter, FilterPolicy};
+
+    let set: EmojiSet = ["🚀", "👍🏽", "🫠"]
+        .iter()
+        .filter_map(|s| emojis::get(s))
+        .collect();
+    let text = "go 🚀👍 👍🏽 🫠!";
+    let tests = [
+        (FilterPolicy::Remove, "go 👍  !"),
+        (
+            FilterPolicy::ReplaceWithName,
+            "go rocket👍 thumbs up: medium skin tone melting face!",
+        ),
+        (
+            FilterPolicy::ReplaceWithShortcode,
+            "go :rocket:👍 thumbs up: medium skin tone :melting_face:!",
+        ),
+        (FilterPolicy::ReplaceWith('#'), "go #👍 # #!"),
+    ];
+    for (policy, expected) in tests {
+        assert_eq!(Filter::new(set, policy).apply(text), expected);
+    }
+    assert!(matches!(
+        Filter::new(set, FilterPolicy::Remove).apply("nothing 👍"),
+        std::borrow::Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn is_emoji_only() {
+    let tests = [
+        ("", false),
+        (" \t", false),
+        ("🚀", true),
+        ("🚀🚀🚀", true),
+        (" 🏴‍☠️ \n 1️⃣ ", true),
+        ("☺\u{fe0e}", true),
+        ("🚀\u{fe0f}", true),
+        ("🚀!", false),
+        ("a🚀", false),
+        ("1", false),
+    ];
+    for (text, expected) in tests {
+        assert_eq!(emojis::is_emoji_only(text), expected, "{:?}", text);
+    }
+}
+
+#[test]
+fn first_and_last() {
+    assert_eq!(emojis::first(""), None);
+    assert_eq!(emojis::last("no emojis"), None);
+
+    let text = "👨‍👩‍👧 and 🇿🇦🇿🇦!";
+    let (range, emoji) = emojis::first(text).unwrap();
+    assert_eq!((&text[range], emoji.as_str()), ("👨‍👩‍👧", "👨‍👩‍👧"));
+    let (range, emoji) = emojis::last(text).unwrap();
+    assert_eq!((&text[range], emoji.as_str()), ("🇿🇦", "🇿🇦"));
+    assert_eq!(emojis::last(text).unwrap().0.end, text.len() - 1);
+}
+
+#[test]
+fn first_keycap() {
+    for (text, expected) in [
+        ("1\u{fe0f}\u{20e3}", Some("1\u{fe0f}\u{20e3}")),
+        ("#\u{20e3}!", Some("#\u{fe0f}\u{20e3}")),
+        ("*\u{fe0f}\u{20e3}", Some("*\u{fe0f}\u{20e3}")),
+        ("1", None),
+        ("#", None),
+        ("1a", None),
+        ("12 #\u{fe0f}", None),
+        ("1\u{fe0f}", None),
+    ] {
+        let found = emojis::first(text).map(|(_, emoji)| emoji.as_str());
+        assert_eq!(found, expected, "{:?}", text);
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn replace_shortcodes_markdown() {
+    let text = "\
+# Release :rocket:
+
+Run `cargo :fire:` or:
+
+~~~sh
+echo :tada:
+~~~
+
+See [the :100: docs](https://example.com/:smile:).
+";
+    let expected = "\
+# Release 🚀
+
+Run `cargo :fire:` or:
+
+~~~sh
+echo :tada:
+~~~
+
+See [the 💯 docs](https://example.com/:smile:).
+";
+    assert_eq!(emojis::replace_shortcodes_markdown(text), expected);
+    assert!(matches!(
+        emojis::replace_shortcodes_markdown("`:rocket:`"),
+        std::borrow::Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn replaced() {
+    use emojis::Replaced;
+
+    #[cfg(feature = "shortcodes")]
+    {
+        let tests = [
+            ("", ""),
+            (":rocket::tada:", "🚀🎉"),
+            ("10:30 :nope: :+1:!", "10:30 :nope: 👍!"),
+        ];
+        for (i, o) in tests {
+            assert_eq!(Replaced::expand_shortcodes(i).to_string(), o);
+        }
+    }
+
+    let tests = [
+        ("", ""),
+        ("🚀🎉", ""),
+        ("a 👍🏽 b 🏴‍☠️", "a  b "),
+        ("1 #", "1 #"),
+    ];
+    for (i, o) in tests {
+        assert_eq!(Replaced::strip_emojis(i).to_string(), o);
+    }
+}
+
+#[test]
+fn histogram() {
+    assert_eq!(emojis::histogram("no emojis").next(), None);
+
+    let text = "👍🏽 👍 👍🏽 🏴‍☠️ 👍🏽";
+    let counts: Vec<_> = emojis::histogram(text)
+        .map(|(emoji, n)| (emoji.as_str(), n))
+        .collect();
+    assert_eq!(counts, [("👍", 1), ("👍🏽", 3), ("🏴‍☠️", 1)]);
+}
+
+#[test]
+fn detect_skin_tone() {
+    use emojis::SkinTone;
+
+    let tests = [
+        ("", None),
+        ("no emojis", None),
+        ("👍 default", None),
+        ("🏿", Some(SkinTone::Dark)),
+        ("👋🏼 then 👍🏿", Some(SkinTone::MediumLight)),
+        ("🧑🏽‍🚀", Some(SkinTone::Medium)),
+        ("🐶🏻 not rgi", Some(SkinTone::Light)),
+    ];
+    for (text, expected) in tests {
+        assert_eq!(emojis::detect_skin_tone(text), expected, "{text}");
+    }
+    for emoji in emojis::iter().filter_map(|e| e.skin_tones()).flatten() {
+        match emoji.skin_tone() {
+            Some(SkinTone::Default) => assert_eq!(emojis::detect_skin_tone(emoji.as_str()), None),
+            tone => assert_eq!(emojis::detect_skin_tone(emoji.as_str()), tone),
+        }
+    }
+}
+
+#[test]
+fn emoji_graphemes() {
+    use emojis::EmojiGraphemes;
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let text = "🧑🏽‍🚀 a🇿🇦 ❤️ 👨‍👩‍👧";
+    let found: Vec<_> = text.graphemes(true).emojis().collect();
+    let expected: Vec<_> = ["🧑🏽‍🚀", " ", "a", "🇿🇦", " ", "❤️", " ", "👨‍👩‍👧"]
+        .iter()
+        .map(|s| emojis::get(s))
+        .collect();
+    assert_eq!(found, expected);
+    assert_eq!(found.iter().flatten().count(), 4);
+
+    let rev: Vec<_> = text.graphemes(true).emojis().rev().collect();
+    assert_eq!(rev, expected.into_iter().rev().collect::<Vec<_>>());
+
+    let words = "🚀 launch".split(' ').emojis();
+    assert_eq!(
+        words.flatten().map(|e| e.as_str()).collect::<Vec<_>>(),
+        ["🚀"]
+    );
+}
+
+#[test]
+fn pattern() {
+    let tests: &[(&str, &[(usize, &str)])] = &[
+        ("", &[]),
+        ("no emojis", &[]),
+        ("🚀", &[(0, "🚀")]),
+        ("a👍🏽b👍", &[(1, "👍🏽"), (10, "👍")]),
+        ("1️⃣ ❤️❤", &[(0, "1️⃣"), (8, "❤️"), (14, "❤")]),
+    ];
+    for (text, expected) in tests {
+        let found: Vec<_> = emojis::pattern().find_in(text).collect();
+        assert_eq!(found, *expected, "{text}");
+        for (i, s) in found {
+            assert_eq!(&text[i..i + s.len()], s);
+        }
+    }
+}
+
+#[test]
+fn could_start_emoji() {
+    let mut firsts = std::collections::HashSet::new();
+    for emoji in emojis::Group::iter().flat_map(|g| g.as_slice()) {
+        for v in emoji.variations() {
+            firsts.insert(v.chars().next().unwrap());
+        }
+    }
+    for c in (0..=0x10ffff).filter_map(char::from_u32) {
+        assert_eq!(emojis::could_start_emoji(c), firsts.contains(&c), "{c:?}");
+    }
+}
+
+#[test]
+fn pattern_long_ascii() {
+    let pad = "abcdefghijklmnopq";
+    let emojis = ["🚀", "1️⃣", "#⃣", "©", "👍🏽", "🇿🇦", "é"];
+    for i in 0..pad.len() {
+        for emoji in emojis {
+            let text = format!("{}{}{}9{}", &pad[..i], emoji, pad, emoji);
+            let found: Vec<_> = emojis::pattern().find_in(&text).collect();
+            let expected: Vec<_> = match emojis::get(emoji) {
+                Some(_) => vec![(i, emoji), (text.len() - emoji.len(), emoji)],
+                None => vec![],
+            };
+            assert_eq!(found, expected, "{text}");
+        }
+    }
+}
+
+#[test]
+fn codepoint_table() {
+    use emojis::codepoint_table::{contains, ALL, STARTS};
+
+    for table in [STARTS, ALL] {
+        assert!(table.windows(2).all(|w| w[0].1 < w[1].0));
+    }
+    for emoji in emojis::iter().flat_map(|e| e.skin_tones().into_iter().flatten().chain([e])) {
+        let mut chars = emoji.as_str().chars();
+        assert!(contains(STARTS, chars.next().unwrap()));
+        assert!(chars.all(|c| contains(ALL, c)));
+    }
+    assert!(contains(ALL, '\u{200d}'));
+    assert!(!contains(STARTS, '\u{200d}'));
+    assert!(!contains(ALL, 'a'));
+}
+
+#[test]
+fn str_ext() {
+    use emojis::EmojiStrExt;
+
+    let text = "go :rocket: 👍🏽 #1";
+    assert!(text.contains_emoji());
+    assert!(!"no emojis here".contains_emoji());
+    let found: Vec<_> = text.emojis().collect();
+    assert_eq!(found, ["👍🏽"]);
+    assert_eq!(
+        text.emojis().collect::<Vec<_>>(),
+        emojis::pattern()
+            .find_in(text)
+            .map(|(_, e)| e)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(text.strip_emojis().to_string(), "go :rocket:  #1");
+    #[cfg(feature = "shortcodes")]
+    assert_eq!(text.expand_shortcodes().to_string(), "go 🚀 👍🏽 #1");
+}
@@ -0,0 +1,248 @@
+#![cfg(feature = "shortcodes")]
+
+use emojis::ShortcodeTokenizer;
+
+fn tokens(tokenizer: ShortcodeTokenizer<'_>) -> Vec<(&str, Option<&str>)> {
+    tokenizer
+        .map(|t| (t.shortcode(), t.emoji().map(|e| e.as_str())))
+        .collect()
+}
+
+#[test]
+fn shortcode_tokenizer() {
+    let text = ":rocket::tada: :nope:smile: :: :a b: :+1:";
+    assert_eq!(
+        tokens(ShortcodeTokenizer::new(text)),
+        [
+            ("rocket", Some("🚀")),
+            ("tada", Some("🎉")),
+            ("nope", None),
+            ("smile", Some("😄")),
+            ("+1", Some("👍")),
+        ]
+    );
+    let token = ShortcodeTokenizer::new("go :rocket:").next().unwrap();
+    assert_eq!(token.range(), 3..11);
+}
+
+#[test]
+fn shortcode_tokenizer_word_boundaries() {
+    let text = "a:rocket: :tada:b :smile: (:fire:)";
+    assert_eq!(
+        tokens(ShortcodeTokenizer::new(text).require_word_boundaries(true)),
+        [("smile", Some("😄")), ("fire", Some("🔥"))]
+    );
+}
+
+#[test]
+fn shortcode_tokenizer_code_spans() {
+    let text = "`:rocket:` ``a ` :tada:`` ```\n:fire:\n``` :smile: `unclosed :100:";
+    assert_eq!(
+        tokens(ShortcodeTokenizer::new(text).skip_code_spans(true)),
+        [("smile", Some("😄")), ("100", Some("💯"))]
+    );
+}
+
+#[test]
+fn shortcode_tokenizer_link_urls() {
+    let text = "[:tada:](http://x/:rocket:) <https://x/:fire:> <:smile:> ](:100:";
+    assert_eq!(
+        tokens(ShortcodeTokenizer::new(text).skip_link_urls(true)),
+        [
+            ("tada", Some("🎉")),
+            ("smile", Some("😄")),
+            ("100", Some("💯"))
+        ]
+    );
+}
+
+#[test]
+fn shortcode_tokenizer_discord() {
+    use emojis::TokenKind;
+
+    let text = "<:pepe:123> <a:blob_dance:456> <:x:1> <:long:> <:name:12a> <:tada:> :fire:";
+    let tokens: Vec<_> = ShortcodeTokenizer::new(text)
+        .discord_custom_emojis(true)
+        .map(|t| (t.shortcode(), t.kind(), &text[t.range()]))
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            (
+                "pepe",
+                TokenKind::DiscordCustom {
+                    id: 123,
+                    animated: false
+                },
+                "<:pepe:123>"
+            ),
+            (
+                "blob_dance",
+                TokenKind::DiscordCustom {
+                    id: 456,
+                    animated: true
+                },
+                "<a:blob_dance:456>"
+            ),
+            ("x", TokenKind::Shortcode, ":x:"),
+            ("long", TokenKind::Shortcode, ":long:"),
+            ("name", TokenKind::Shortcode, ":name:"),
+            ("tada", TokenKind::Shortcode, ":tada:"),
+            ("fire", TokenKind::Shortcode, ":fire:"),
+        ]
+    );
+
+    // Disabled by default.
+    let token = ShortcodeTokenizer::new("<:tada:1>").next().unwrap();
+    assert_eq!(token.kind(), TokenKind::Shortcode);
+    assert_eq!(token.shortcode(), "tada");
+}
+
+#[test]
+fn shortcode_tokenizer_slack_skin_tones() {
+    let text =
+        ":thumbsup::skin-tone-3: :wave::skin-tone-6::rocket::skin-tone-2: :wave::skin-tone-9:";
+    let tokens: Vec<_> = ShortcodeTokenizer::new(text)
+        .map(|t| {
+            (
+                &text[t.range()],
+                t.shortcode(),
+                t.emoji().map(|e| e.as_str()),
+            )
+        })
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            (
+                ":thumbsup::skin-tone-3:",
+                "thumbsup::skin-tone-3",
+                Some("👍🏼")
+            ),
+            (":wave::skin-tone-6:", "wave::skin-tone-6", Some("👋🏿")),
+            (":rocket:", "rocket", Some("🚀")),
+            (":skin-tone-2:", "skin-tone-2", None),
+            (":wave:", "wave", Some("👋")),
+            (":skin-tone-9:", "skin-tone-9", None),
+        ]
+    );
+}
+
+#[test]
+fn profile_roundtrip() {
+    use emojis::Profile;
+
+    let profiles = [
+        Profile::GitHub,
+        Profile::Slack,
+        Profile::Discord,
+        Profile::Matrix,
+    ];
+    for emoji in emojis::Group::iter().flat_map(|g| g.as_slice()) {
+        for profile in profiles {
+            let s = profile.format(emoji).to_string();
+            let parsed: Vec<_> = match s.strip_prefix(':') {
+                Some(_) => profile.parse(&s).map(|t| t.emoji()).collect(),
+                None => vec![emojis::get(&s)],
+            };
+            assert_eq!(parsed, [Some(emoji)], "{profile:?} {s}");
+        }
+    }
+}
+
+#[test]
+fn profile_parse() {
+    use emojis::Profile;
+
+    let text = "`:fire:` [:tada:](x/:rocket:) :wave::skin-tone-2: :wave_tone1: <:pepe:1>";
+    let found = |p: Profile| {
+        p.parse(text)
+            .map(|t| (t.shortcode(), t.emoji().map(|e| e.as_str())))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        found(Profile::GitHub),
+        [
+            ("tada", Some("🎉")),
+            ("wave::skin-tone-2", Some("👋🏻")),
+            ("wave_tone1", None),
+            ("pepe", None),
+        ]
+    );
+    assert_eq!(
+        found(Profile::Discord),
+        [
+            ("tada", Some("🎉")),
+            ("rocket", Some("🚀")),
+            ("wave::skin-tone-2", Some("👋🏻")),
+            ("wave_tone1", Some("👋🏻")),
+            ("pepe", None),
+        ]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn convert_shortcodes() {
+    use emojis::Profile;
+
+    let text = "hi :wave_tone5: `:wave:` :nope: <:pepe:1>";
+    assert_eq!(
+        emojis::convert_shortcodes(text, Profile::Discord, Profile::Slack),
+        "hi :wave::skin-tone-6: `:wave:` :nope: <:pepe:1>"
+    );
+    assert_eq!(
+        emojis::convert_shortcodes(text, Profile::Discord, Profile::Matrix),
+        "hi 👋🏿 `:wave:` :nope: <:pepe:1>"
+    );
+    assert!(matches!(
+        emojis::convert_shortcodes(":nope:", Profile::GitHub, Profile::Slack),
+        std::borrow::Cow::Borrowed(":nope:")
+    ));
+}
+
+#[test]
+fn shortcode_tokenizer_table() {
+    use emojis::{Emoji, GitHubShortcodes, ShortcodeTable};
+
+    struct Pirate;
+
+    impl ShortcodeTable for Pirate {
+        fn get(&self, shortcode: &str) -> Option<&'static Emoji> {
+            match shortcode {
+                "jolly_roger" => emojis::get("🏴‍☠️"),
+                "matey" => emojis::get("👋"),
+                _ => None,
+            }
+        }
+
+        fn shortcode(&self, emoji: &Emoji) -> Option<&str> {
+            (emoji == "🏴‍☠️").then(|| "jolly_roger")
+        }
+    }
+
+    let text = ":jolly_roger: :matey::skin-tone-3: :matey_tone5: :rocket:";
+    assert_eq!(
+        tokens(
+            ShortcodeTokenizer::new(text)
+                .table(&Pirate)
+                .discord_custom_emojis(true)
+        ),
+        [
+            ("jolly_roger", Some("🏴‍☠️")),
+            ("matey", Some("👋")),
+            ("skin-tone-3", None),
+            ("matey_tone5", Some("👋🏿")),
+            ("rocket", None),
+        ]
+    );
+    assert_eq!(
+        Pirate.shortcode(emojis::get("🏴‍☠️").unwrap()),
+        Some("jolly_roger")
+    );
+
+    let rocket = GitHubShortcodes.get("rocket").unwrap();
+    assert_eq!(rocket, "🚀");
+    assert_eq!(GitHubShortcodes.shortcode(rocket), Some("rocket"));
+    assert_eq!(GitHubShortcodes.get("nope"), None);
+}
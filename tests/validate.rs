@@ -0,0 +1,47 @@
+use emojis::{Group, ValidationError};
+
+#[test]
+fn validate_reaction() {
+    let ok = [
+        ("🚀", "🚀"),
+        ("  👍🏽\t", "👍🏽"),
+        ("❤", "❤️"),
+        ("🚀\u{fe0f}", "🚀"),
+        ("1\u{20e3}", "1️⃣"),
+        ("🏴‍☠️", "🏴‍☠️"),
+    ];
+    for (input, expected) in ok {
+        assert_eq!(emojis::validate_reaction(input).unwrap(), expected);
+    }
+
+    let err = [
+        ("", ValidationError::Empty),
+        (" \n", ValidationError::Empty),
+        ("a", ValidationError::NotEmoji),
+        ("🚀a", ValidationError::NotEmoji),
+        ("a 🚀 🚀", ValidationError::NotEmoji),
+        ("🚀🚀", ValidationError::MultipleEmojis),
+        ("🚀 👍🏽", ValidationError::MultipleEmojis),
+    ];
+    for (input, expected) in err {
+        assert_eq!(emojis::validate_reaction(input), Err(expected));
+    }
+}
+
+#[test]
+fn validate_reaction_in() {
+    for emoji in emojis::iter() {
+        assert_eq!(
+            emojis::validate_reaction_in(emoji.as_str(), &[emoji.group()]),
+            Ok(emoji)
+        );
+        assert_eq!(
+            emojis::validate_reaction_in(emoji.as_str(), &[]),
+            Err(ValidationError::GroupNotAllowed(emoji.group()))
+        );
+    }
+    assert_eq!(
+        emojis::validate_reaction_in("", &[Group::Flags]),
+        Err(ValidationError::Empty)
+    );
+}